@@ -0,0 +1,136 @@
+//! Benchmarks for the validation runner.
+//!
+//! The fixture in `benches/fixtures/large_csaf_2_1.json` contains a product tree with 500 products and
+//! 200 vulnerabilities, each carrying product status, remediations and metrics. Run with `cargo bench`.
+//!
+//! Besides wall-clock time, the `allocations` group counts heap allocations per iteration with a
+//! counting global allocator. Tests that are clean on the fixture should only allocate a constant
+//! amount, independent of the number of products and vulnerabilities.
+
+use criterion::measurement::{Measurement, ValueFormatter};
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use csaf::csaf2_1::loader::load_document_from_str;
+use csaf::csaf2_1::testcases::TESTS_2_1;
+use csaf::validation::{ValidationPreset, validate_by_preset};
+use csaf::validations::test_6_1_02::test_6_1_02_multiple_definition_of_product_id;
+use csaf::validations::test_6_1_37::test_6_1_37_date_and_time;
+use csaf::validations::test_6_1_49::test_6_1_49_inconsistent_ssvc_timestamp;
+use csaf::validations::test_6_1_64::test_6_1_64_hash_value_format;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static LARGE_DOCUMENT: &str = include_str!("fixtures/large_csaf_2_1.json");
+
+fn bench_validation(c: &mut Criterion) {
+    let doc = load_document_from_str(LARGE_DOCUMENT).expect("benchmark fixture must be a valid CSAF 2.1 document");
+
+    c.bench_function("load_document_from_str", |b| {
+        b.iter(|| load_document_from_str(black_box(LARGE_DOCUMENT)))
+    });
+    c.bench_function("validate_by_preset/basic", |b| {
+        b.iter(|| validate_by_preset(black_box(&doc), "2.1", ValidationPreset::Basic))
+    });
+    c.bench_function("test_6_1_1_missing_product_id", |b| {
+        b.iter(|| TESTS_2_1.test_6_1_1.validate(black_box(&doc)))
+    });
+    c.bench_function("test_6_1_49_inconsistent_ssvc_timestamp", |b| {
+        b.iter(|| test_6_1_49_inconsistent_ssvc_timestamp(black_box(&doc)))
+    });
+}
+
+/// Number of heap allocations since the start of the process
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting each allocation in [ALLOCATIONS]
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Measures the number of heap allocations instead of wall-clock time
+struct Allocations;
+
+impl Measurement for Allocations {
+    type Intermediate = usize;
+    type Value = usize;
+
+    fn start(&self) -> usize {
+        ALLOCATIONS.load(Ordering::Relaxed)
+    }
+
+    fn end(&self, start: usize) -> usize {
+        ALLOCATIONS.load(Ordering::Relaxed) - start
+    }
+
+    fn add(&self, v1: &usize, v2: &usize) -> usize {
+        v1 + v2
+    }
+
+    fn zero(&self) -> usize {
+        0
+    }
+
+    fn to_f64(&self, value: &usize) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &AllocationsFormatter
+    }
+}
+
+struct AllocationsFormatter;
+
+impl ValueFormatter for AllocationsFormatter {
+    fn scale_values(&self, _typical_value: f64, _values: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+
+    fn scale_throughputs(&self, _typical_value: f64, _throughput: &Throughput, _values: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+}
+
+fn bench_allocations(c: &mut Criterion<Allocations>) {
+    let doc = load_document_from_str(LARGE_DOCUMENT).expect("benchmark fixture must be a valid CSAF 2.1 document");
+
+    let mut group = c.benchmark_group("allocations");
+    group.bench_function("validate_by_preset/basic", |b| {
+        b.iter(|| validate_by_preset(black_box(&doc), "2.1", ValidationPreset::Basic))
+    });
+    group.bench_function("test_6_1_02_multiple_definition_of_product_id", |b| {
+        b.iter(|| test_6_1_02_multiple_definition_of_product_id(black_box(&doc)))
+    });
+    group.bench_function("test_6_1_37_date_and_time", |b| {
+        b.iter(|| test_6_1_37_date_and_time(black_box(&doc)))
+    });
+    group.bench_function("test_6_1_64_hash_value_format", |b| {
+        b.iter(|| test_6_1_64_hash_value_format(black_box(&doc)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_validation);
+criterion_group! {
+    name = allocation_benches;
+    // Allocation counts are deterministic, which the density plots cannot handle
+    config = Criterion::default().with_measurement(Allocations).without_plots();
+    targets = bench_allocations
+}
+criterion_main!(benches, allocation_benches);
@@ -0,0 +1,150 @@
+//! Semantic comparison of CSAF documents
+//!
+//! Two documents can carry the same information, but differ in their serialization: object keys
+//! may appear in any order, date-times may use different offsets, and lists of product or group
+//! IDs are sets whose order has no meaning. [canonicalize] removes these differences, so that
+//! [semantic_eq] can compare documents by their content.
+//!
+//! Lists not named below keep their order, as it is significant in CSAF, e.g. for
+//! `revision_history`, `notes`, `vulnerabilities` or the branches of the product tree. The lists
+//! treated as order-insensitive are:
+//!
+//! * `product_ids` and `group_ids`, wherever they appear
+//! * `products` of metrics (CSAF 2.1) and scores (CSAF 2.0)
+//! * the lists of `/vulnerabilities[]/product_status`, e.g. `known_affected`
+
+use crate::csaf_traits::CsafTraitExt;
+use crate::timestamp::Timestamp;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Keys of lists whose order is insignificant, wherever they appear
+const UNORDERED_KEYS: &[&str] = &["product_ids", "group_ids", "products"];
+
+/// Returns the document as JSON value in canonical form.
+///
+/// Order-insensitive lists are sorted and date-times, i.e. strings under a key `date` or ending
+/// with `_date`, are normalized to UTC. Object keys are always sorted by `serde_json`.
+pub fn canonicalize(doc: &(impl CsafTraitExt + Serialize)) -> serde_json::Value {
+    let mut value = serde_json::to_value(doc).expect("CSAF documents can always be serialized");
+    normalize(&mut value, None, false);
+    value
+}
+
+/// Returns whether two documents are equal after [canonicalize].
+pub fn semantic_eq(a: &(impl CsafTraitExt + Serialize), b: &(impl CsafTraitExt + Serialize)) -> bool {
+    canonicalize(a) == canonicalize(b)
+}
+
+fn normalize(value: &mut Value, key: Option<&str>, in_product_status: bool) {
+    match value {
+        Value::Object(map) => normalize_object(map),
+        Value::Array(items) => {
+            for item in items.iter_mut() {
+                normalize(item, None, false);
+            }
+            if in_product_status || key.is_some_and(|k| UNORDERED_KEYS.contains(&k)) {
+                items.sort_by_cached_key(|item| item.to_string());
+            }
+        },
+        Value::String(s) if key.is_some_and(|k| k == "date" || k.ends_with("_date")) => {
+            if let Ok(timestamp) = Timestamp::parse(s) {
+                *s = timestamp.to_string();
+            }
+        },
+        _ => {},
+    }
+}
+
+fn normalize_object(map: &mut Map<String, Value>) {
+    for (k, v) in map.iter_mut() {
+        if k == "product_status"
+            && let Value::Object(lists) = v
+        {
+            for list in lists.values_mut() {
+                normalize(list, None, true);
+            }
+        } else {
+            normalize(v, Some(k), false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canonical::{canonicalize, semantic_eq};
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use serde_json::json;
+
+    #[test]
+    fn test_semantic_eq() {
+        let a = mock_csaf21(|doc| {
+            doc["document"]["tracking"]["current_release_date"] = json!("2024-01-24T12:00:00+02:00");
+            doc["product_tree"] = json!({
+                "product_groups": [
+                    { "group_id": "CSAFGID-0001", "product_ids": ["CSAFPID-0002", "CSAFPID-0001"] }
+                ]
+            });
+            doc["vulnerabilities"] = json!([{
+                "product_status": { "known_affected": ["CSAFPID-0002", "CSAFPID-0001"] },
+                "notes": [
+                    { "category": "summary", "text": "First." },
+                    { "category": "details", "text": "Second." }
+                ]
+            }]);
+        });
+        let b = mock_csaf21(|doc| {
+            doc["document"]["tracking"]["current_release_date"] = json!("2024-01-24T10:00:00.000Z");
+            doc["product_tree"] = json!({
+                "product_groups": [
+                    { "product_ids": ["CSAFPID-0001", "CSAFPID-0002"], "group_id": "CSAFGID-0001" }
+                ]
+            });
+            doc["vulnerabilities"] = json!([{
+                "notes": [
+                    { "category": "summary", "text": "First." },
+                    { "category": "details", "text": "Second." }
+                ],
+                "product_status": { "known_affected": ["CSAFPID-0001", "CSAFPID-0002"] }
+            }]);
+        });
+        assert!(semantic_eq(&a, &b));
+        assert_eq!(
+            canonicalize(&a)["document"]["tracking"]["current_release_date"],
+            "2024-01-24T10:00:00Z"
+        );
+
+        // The order of notes is significant
+        let reordered_notes = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "notes": [
+                    { "category": "details", "text": "Second." },
+                    { "category": "summary", "text": "First." }
+                ]
+            }]);
+        });
+        let notes = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "notes": [
+                    { "category": "summary", "text": "First." },
+                    { "category": "details", "text": "Second." }
+                ]
+            }]);
+        });
+        assert!(!semantic_eq(&notes, &reordered_notes));
+    }
+
+    #[test]
+    fn test_semantic_eq_revision_history_order() {
+        let revisions = json!([
+            { "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." },
+            { "date": "2024-02-24T10:00:00.000Z", "number": "2", "summary": "Update." }
+        ]);
+        let a = mock_csaf20(|doc| doc["document"]["tracking"]["revision_history"] = revisions.clone());
+        let b = mock_csaf20(|doc| {
+            doc["document"]["tracking"]["revision_history"] = json!([revisions[1], revisions[0]]);
+        });
+        assert!(semantic_eq(&a, &a.clone()));
+        assert!(!semantic_eq(&a, &b));
+    }
+}
@@ -0,0 +1,341 @@
+//! Parsing of Common Platform Enumeration (CPE) names
+//!
+//! Both bindings of CPE 2.3 are supported: the formatted string binding (`cpe:2.3:a:vendor:...`)
+//! with its 11 components after the `cpe:2.3` prefix, and the legacy URI binding (`cpe:/a:vendor:...`)
+//! with up to 7 components, where the edition may pack the extended attributes.
+
+use std::fmt::{Display, Formatter};
+
+/// A CPE name split into its 11 attributes
+///
+/// The attribute values are kept as they appear in the binding, i.e. escape sequences are not
+/// decoded. Attributes that are not present in a URI binding are set to `*` (ANY).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cpe {
+    pub part: String,
+    pub vendor: String,
+    pub product: String,
+    pub version: String,
+    pub update: String,
+    pub edition: String,
+    pub language: String,
+    pub sw_edition: String,
+    pub target_sw: String,
+    pub target_hw: String,
+    pub other: String,
+}
+
+/// Reasons why a string is not a well-formed CPE name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CpeError {
+    /// The string starts neither with `cpe:2.3:` nor with `cpe:/`
+    InvalidPrefix,
+    /// The string has the wrong number of components for its binding
+    ComponentCount(usize),
+    /// The part is not one of `a`, `h`, `o` (or a logical value in the formatted string binding)
+    InvalidPart(String),
+    /// A component of the formatted string binding is empty
+    EmptyComponent(usize),
+    /// A component contains a character that is not allowed at its position
+    IllegalCharacter { component: usize, character: char },
+}
+
+impl Display for CpeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CpeError::InvalidPrefix => write!(f, "CPE must start with 'cpe:2.3:' or 'cpe:/'"),
+            CpeError::ComponentCount(count) => write!(f, "CPE has an invalid number of components: {}", count),
+            CpeError::InvalidPart(part) => write!(f, "CPE has an invalid part: '{}'", part),
+            CpeError::EmptyComponent(component) => write!(f, "CPE component {} is empty", component),
+            CpeError::IllegalCharacter { component, character } => {
+                write!(
+                    f,
+                    "CPE component {} contains the illegal character {:?}",
+                    component, character
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for CpeError {}
+
+/// Number of attributes of a CPE name
+const NUM_ATTRIBUTES: usize = 11;
+/// Maximum number of components of the URI binding
+const MAX_URI_COMPONENTS: usize = 7;
+
+impl Cpe {
+    /// Parses a CPE 2.3 formatted string or a CPE URI.
+    pub fn parse(s: &str) -> Result<Cpe, CpeError> {
+        if let Some(rest) = s.strip_prefix("cpe:2.3:") {
+            Self::parse_formatted_string(rest)
+        } else if s.get(..5).is_some_and(|prefix| prefix.eq_ignore_ascii_case("cpe:/")) {
+            Self::parse_uri(&s[5..])
+        } else {
+            Err(CpeError::InvalidPrefix)
+        }
+    }
+
+    /// Returns the attributes in the order of the formatted string binding.
+    pub fn components(&self) -> [&str; NUM_ATTRIBUTES] {
+        [
+            &self.part,
+            &self.vendor,
+            &self.product,
+            &self.version,
+            &self.update,
+            &self.edition,
+            &self.language,
+            &self.sw_edition,
+            &self.target_sw,
+            &self.target_hw,
+            &self.other,
+        ]
+    }
+
+    fn parse_formatted_string(rest: &str) -> Result<Cpe, CpeError> {
+        let components = split_unescaped(rest);
+        if components.len() != NUM_ATTRIBUTES {
+            // Count the "cpe" and "2.3" prefix components as well
+            return Err(CpeError::ComponentCount(components.len() + 2));
+        }
+        if !["a", "h", "o", "*", "-"].contains(&components[0]) {
+            return Err(CpeError::InvalidPart(components[0].to_string()));
+        }
+        for (i, component) in components.iter().enumerate().skip(1) {
+            check_formatted_string_component(i, component)?;
+        }
+        Ok(Self::from_components(components))
+    }
+
+    fn parse_uri(rest: &str) -> Result<Cpe, CpeError> {
+        let mut components: Vec<&str> = rest.split(':').collect();
+        if components.len() > MAX_URI_COMPONENTS {
+            return Err(CpeError::ComponentCount(components.len()));
+        }
+        if !["", "a", "h", "o", "A", "H", "O"].contains(&components[0]) {
+            return Err(CpeError::InvalidPart(components[0].to_string()));
+        }
+        for (i, component) in components.iter().enumerate().skip(1) {
+            check_uri_component(i, component)?;
+        }
+
+        // A packed edition has the form ~edition~sw_edition~target_sw~target_hw~other
+        let mut extended: Vec<&str> = Vec::new();
+        if let Some(packed) = components.get(5).and_then(|e| e.strip_prefix('~')) {
+            extended = packed.split('~').collect();
+            if extended.len() != 5 {
+                return Err(CpeError::IllegalCharacter {
+                    component: 5,
+                    character: '~',
+                });
+            }
+            components[5] = extended[0];
+        }
+
+        let mut attributes: Vec<&str> = components
+            .into_iter()
+            .map(|c| if c.is_empty() { "*" } else { c })
+            .collect();
+        attributes.resize(MAX_URI_COMPONENTS, "*");
+        attributes.extend(extended.iter().skip(1).map(|c| if c.is_empty() { "*" } else { c }));
+        attributes.resize(NUM_ATTRIBUTES, "*");
+        Ok(Self::from_components(attributes))
+    }
+
+    fn from_components(components: Vec<&str>) -> Cpe {
+        let mut c = components.into_iter().map(str::to_string);
+        let mut next = || c.next().unwrap_or_default();
+        Cpe {
+            part: next(),
+            vendor: next(),
+            product: next(),
+            version: next(),
+            update: next(),
+            edition: next(),
+            language: next(),
+            sw_edition: next(),
+            target_sw: next(),
+            target_hw: next(),
+            other: next(),
+        }
+    }
+}
+
+/// Splits a formatted string at every colon that is not escaped by a backslash.
+fn split_unescaped(s: &str) -> Vec<&str> {
+    let mut components = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            ':' if !escaped => {
+                components.push(&s[start..i]);
+                start = i + 1;
+            },
+            _ => escaped = false,
+        }
+    }
+    components.push(&s[start..]);
+    components
+}
+
+/// Checks a formatted string attribute value, which is either a logical value (`*` or `-`) or a
+/// sequence of alphanumerics, `.`, `_`, `-` and escaped punctuation, optionally surrounded by
+/// unescaped wildcards.
+fn check_formatted_string_component(component: usize, value: &str) -> Result<(), CpeError> {
+    if value == "*" || value == "-" {
+        return Ok(());
+    }
+    if value.is_empty() {
+        return Err(CpeError::EmptyComponent(component));
+    }
+
+    let chars: Vec<char> = value.chars().collect();
+    let body_start = chars.iter().position(|c| *c != '*' && *c != '?').unwrap_or(chars.len());
+    let mut i = body_start;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' {
+            match chars.get(i + 1) {
+                Some(escaped) if escaped.is_ascii_punctuation() => i += 2,
+                Some(escaped) => {
+                    return Err(CpeError::IllegalCharacter {
+                        component,
+                        character: *escaped,
+                    });
+                },
+                None => {
+                    return Err(CpeError::IllegalCharacter {
+                        component,
+                        character: c,
+                    });
+                },
+            }
+        } else if c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '-' {
+            i += 1;
+        } else if (c == '*' || c == '?') && chars[i..].iter().all(|w| *w == '*' || *w == '?') {
+            // Trailing wildcards
+            break;
+        } else {
+            return Err(CpeError::IllegalCharacter {
+                component,
+                character: c,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks a URI attribute value, consisting of alphanumerics, `.`, `_`, `-`, `~` and
+/// percent-encoded octets.
+fn check_uri_component(component: usize, value: &str) -> Result<(), CpeError> {
+    let bytes = value.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        if c == '%' {
+            let is_hex = |j: usize| bytes.get(j).is_some_and(|b| b.is_ascii_hexdigit());
+            if !is_hex(i + 1) || !is_hex(i + 2) {
+                return Err(CpeError::IllegalCharacter {
+                    component,
+                    character: c,
+                });
+            }
+            i += 3;
+        } else if c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '-' || c == '~' {
+            i += 1;
+        } else {
+            let character = value[i..].chars().next().unwrap_or(c);
+            return Err(CpeError::IllegalCharacter { component, character });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpe::{Cpe, CpeError};
+
+    #[test]
+    fn test_parse_formatted_string() {
+        let cpe = Cpe::parse("cpe:2.3:a:vendor:product:1.0:*:*:*:*:*:*:*").unwrap();
+        assert_eq!(
+            cpe.components(),
+            ["a", "vendor", "product", "1.0", "*", "*", "*", "*", "*", "*", "*"]
+        );
+
+        let escaped = Cpe::parse(r"cpe:2.3:a:example\:corp:product*:1.0\!:-:*:en-us:*:*:x64:*").unwrap();
+        assert_eq!(escaped.vendor, r"example\:corp");
+        assert_eq!(escaped.product, "product*");
+
+        assert_eq!(
+            Err(CpeError::ComponentCount(8)),
+            Cpe::parse("cpe:2.3:a:vendor:product:1.0:*:*")
+        );
+        assert_eq!(
+            Err(CpeError::EmptyComponent(3)),
+            Cpe::parse("cpe:2.3:a:vendor:product::*:*:*:*:*:*:*")
+        );
+        assert_eq!(
+            Err(CpeError::InvalidPart("x".to_string())),
+            Cpe::parse("cpe:2.3:x:vendor:product:1.0:*:*:*:*:*:*:*")
+        );
+        assert_eq!(
+            Err(CpeError::IllegalCharacter {
+                component: 2,
+                character: ' '
+            }),
+            Cpe::parse("cpe:2.3:a:vendor:my product:1.0:*:*:*:*:*:*:*")
+        );
+        assert_eq!(
+            Err(CpeError::IllegalCharacter {
+                component: 3,
+                character: '?'
+            }),
+            Cpe::parse("cpe:2.3:a:vendor:product:1.?0:*:*:*:*:*:*:*")
+        );
+    }
+
+    #[test]
+    fn test_parse_uri() {
+        let cpe = Cpe::parse("cpe:/a:vendor:product:1.0").unwrap();
+        assert_eq!(
+            cpe.components(),
+            ["a", "vendor", "product", "1.0", "*", "*", "*", "*", "*", "*", "*"]
+        );
+
+        let packed = Cpe::parse("cpe:/a:vendor:product%21:1.0::~~pro~android~arm~:en-us").unwrap();
+        assert_eq!(
+            packed.components(),
+            [
+                "a",
+                "vendor",
+                "product%21",
+                "1.0",
+                "*",
+                "*",
+                "en-us",
+                "pro",
+                "android",
+                "arm",
+                "*"
+            ]
+        );
+
+        assert_eq!(Err(CpeError::InvalidPrefix), Cpe::parse("cpe:a:vendor"));
+        assert_eq!(
+            Err(CpeError::ComponentCount(8)),
+            Cpe::parse("cpe:/a:vendor:product:1.0:a:b:c:d")
+        );
+        assert_eq!(
+            Err(CpeError::IllegalCharacter {
+                component: 2,
+                character: '%'
+            }),
+            Cpe::parse("cpe:/a:vendor:product%zz")
+        );
+    }
+}
@@ -1,25 +1,27 @@
 use crate::csaf_traits::{
-    BranchTrait, ContentTrait, CsafTrait, CsafVersion, DistributionTrait, DocumentReferenceTrait, DocumentTrait,
-    FileHashTrait, FirstKnownExploitationDatesTrait, FlagTrait, GeneratorTrait, HashTrait, InvolvementTrait,
-    MetricTrait, NoteTrait, ProductGroupTrait, ProductIdentificationHelperTrait, ProductStatusTrait, ProductTrait,
-    ProductTreeTrait, PublisherTrait, RelationshipTrait, RemediationTrait, RevisionTrait, SharingGroupTrait,
-    ThreatTrait, TlpTrait, TrackingTrait, VulnerabilityIdTrait, VulnerabilityTrait, WithOptionalGroupIds,
-    WithOptionalProductIds,
+    AcknowledgmentTrait, AggregateSeverityTrait, BranchTrait, ContentTrait, CsafTraitExt, CsafVersion, CweTrait,
+    DistributionTrait, DocumentReferenceTrait, DocumentTrait, FileHashTrait, FirstKnownExploitationDatesTrait,
+    FlagTrait, GeneratorTrait, HashTrait, InvolvementTrait, MetricTrait, NoteTrait, ProductGroupTrait,
+    ProductIdentificationHelperTrait, ProductStatusTrait, ProductTrait, ProductTreeTrait, PublisherTrait,
+    RelationshipTrait, RemediationTrait, RevisionTrait, SharingGroupTrait, ThreatTrait, TlpTrait, TrackingTrait,
+    VulnerabilityIdTrait, VulnerabilityTrait, WithOptionalGroupIds, WithOptionalProductIds,
 };
 use crate::csaf2_1::ssvc_dp_selection_list::SelectionList;
 use crate::schema::csaf2_0::schema::{
-    Branch, CategoryOfPublisher, CategoryOfReference, CategoryOfTheRemediation, CategoryOfTheThreat,
-    CommonSecurityAdvisoryFramework, CryptographicHashes, CsafVersion as CsafVersion20, DocumentGenerator,
-    DocumentLevelMetaData, DocumentStatus, FileHash, Flag, FullProductNameT, HelperToIdentifyTheProduct, Id,
-    Involvement, LabelOfTheFlag, LabelOfTlp, Note, NoteCategory, PartyCategory, ProductGroup, ProductStatus,
-    ProductTree, Publisher, Reference, Relationship, Remediation, Revision, RulesForSharingDocument, Score, Threat,
-    Tracking, TrafficLightProtocolTlp, Vulnerability,
+    Acknowledgment, AggregateSeverity, Branch, CategoryOfPublisher, CategoryOfReference, CategoryOfTheBranch,
+    CategoryOfTheRemediation, CategoryOfTheThreat, CommonSecurityAdvisoryFramework, CryptographicHashes,
+    CsafVersion as CsafVersion20, Cwe, DocumentGenerator, DocumentLevelMetaData, DocumentStatus, FileHash, Flag,
+    FullProductNameT, HelperToIdentifyTheProduct, Id, Involvement, LabelOfTheFlag, LabelOfTlp, Note, NoteCategory,
+    PartyCategory, PartyStatus, ProductGroup, ProductStatus, ProductTree, Publisher, Reference, Relationship,
+    RelationshipCategory, Remediation, Revision, RulesForSharingDocument, Score, Threat, Tracking,
+    TrafficLightProtocolTlp, Vulnerability,
 };
 use crate::schema::csaf2_1::schema::{
     CategoryOfPublisher as CategoryOfPublisher21, CategoryOfReference as CategoryOfReference21,
-    CategoryOfTheRemediation as Remediation21, CategoryOfTheThreat as CategoryOfTheThreat21,
-    DocumentStatus as Status21, Epss, LabelOfTheFlag as LabelOfTheFlag21, LabelOfTlp as Tlp21,
-    NoteCategory as NoteCategory21, PartyCategory as PartyCategory21,
+    CategoryOfTheBranch as CategoryOfTheBranch21, CategoryOfTheRemediation as Remediation21,
+    CategoryOfTheThreat as CategoryOfTheThreat21, DocumentStatus as Status21, Epss, LabelOfTheFlag as LabelOfTheFlag21,
+    LabelOfTlp as Tlp21, NoteCategory as NoteCategory21, PartyCategory as PartyCategory21,
+    PartyStatus as PartyStatus21, RelationshipCategory as RelationshipCategory21,
 };
 use crate::validation::ValidationError;
 use serde::de::Error;
@@ -63,6 +65,14 @@ impl RemediationTrait for Remediation {
     fn get_date(&self) -> &Option<String> {
         &self.date
     }
+
+    fn get_url(&self) -> &Option<String> {
+        &self.url
+    }
+
+    fn has_restart_required(&self) -> bool {
+        self.restart_required.is_some()
+    }
 }
 
 impl ProductStatusTrait for ProductStatus {
@@ -133,6 +143,10 @@ impl ContentTrait for Score {
         ))
     }
 
+    fn get_ssvc_schema_version(&self) -> Option<&str> {
+        None
+    }
+
     fn get_cvss_v2(&self) -> Option<&Map<String, Value>> {
         if self.cvss_v2.is_empty() {
             None
@@ -186,6 +200,10 @@ impl ThreatTrait for Threat {
             CategoryOfTheThreat::TargetSet => CategoryOfTheThreat21::TargetSet,
         }
     }
+
+    fn get_details(&self) -> &String {
+        self.details.deref()
+    }
 }
 
 impl VulnerabilityTrait for Vulnerability {
@@ -198,8 +216,11 @@ impl VulnerabilityTrait for Vulnerability {
     type InvolvementType = Involvement;
     type VulnerabilityIdType = Id;
     type NoteType = Note;
+    type AcknowledgmentType = Acknowledgment;
+    type ReferenceType = Reference;
     // First known exploitation dates are not implemented in CSAF 2.0
     type FirstKnownExploitationDatesType = ();
+    type CweType = Cwe;
 
     fn get_remediations(&self) -> &Vec<Self::RemediationType> {
         &self.remediations
@@ -213,6 +234,16 @@ impl VulnerabilityTrait for Vulnerability {
         Some(&self.scores)
     }
 
+    fn get_metrics_product_references(&self) -> Vec<(String, String)> {
+        let mut ids: Vec<(String, String)> = Vec::new();
+        for (score_i, score) in self.scores.iter().enumerate() {
+            for (x_i, x) in score.get_products().enumerate() {
+                ids.push((x.to_owned(), format!("scores/{}/products/{}", score_i, x_i)));
+            }
+        }
+        ids
+    }
+
     fn get_threats(&self) -> &Vec<Self::ThreatType> {
         &self.threats
     }
@@ -241,10 +272,22 @@ impl VulnerabilityTrait for Vulnerability {
         &self.ids
     }
 
+    fn get_cwes(&self) -> impl Iterator<Item = &Self::CweType> + '_ {
+        self.cwe.iter()
+    }
+
     fn get_notes(&self) -> Option<&Vec<Self::NoteType>> {
         self.notes.as_deref()
     }
 
+    fn get_acknowledgments(&self) -> Option<&Vec<Self::AcknowledgmentType>> {
+        self.acknowledgments.as_deref()
+    }
+
+    fn get_references(&self) -> Option<&Vec<Self::ReferenceType>> {
+        self.references.as_deref()
+    }
+
     fn get_first_known_exploitation_dates(&self) -> Option<&Vec<Self::FirstKnownExploitationDatesType>> {
         None
     }
@@ -260,6 +303,16 @@ impl VulnerabilityIdTrait for Id {
     }
 }
 
+impl CweTrait for Cwe {
+    fn get_id(&self) -> &String {
+        self.id.deref()
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
+}
+
 impl WithOptionalGroupIds for Flag {
     fn get_group_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
         self.group_ids.as_ref().map(|g| (*g).iter().map(|x| x.deref()))
@@ -310,6 +363,17 @@ impl InvolvementTrait for Involvement {
             PartyCategory::Vendor => PartyCategory21::Vendor,
         }
     }
+
+    fn get_status(&self) -> PartyStatus21 {
+        match self.status {
+            PartyStatus::Completed => PartyStatus21::Completed,
+            PartyStatus::ContactAttempted => PartyStatus21::ContactAttempted,
+            PartyStatus::Disputed => PartyStatus21::Disputed,
+            PartyStatus::InProgress => PartyStatus21::InProgress,
+            PartyStatus::NotContacted => PartyStatus21::NotContacted,
+            PartyStatus::Open => PartyStatus21::Open,
+        }
+    }
 }
 
 impl WithOptionalGroupIds for Involvement {
@@ -318,7 +382,7 @@ impl WithOptionalGroupIds for Involvement {
     }
 }
 
-impl CsafTrait for CommonSecurityAdvisoryFramework {
+impl CsafTraitExt for CommonSecurityAdvisoryFramework {
     type VulnerabilityType = Vulnerability;
     type ProductTreeType = ProductTree;
     type DocumentType = DocumentLevelMetaData;
@@ -336,12 +400,32 @@ impl CsafTrait for CommonSecurityAdvisoryFramework {
     }
 }
 
+impl AggregateSeverityTrait for AggregateSeverity {
+    fn get_namespace(&self) -> Option<&String> {
+        self.namespace.as_ref()
+    }
+
+    fn get_text(&self) -> &String {
+        self.text.deref()
+    }
+}
+
 impl DocumentTrait for DocumentLevelMetaData {
     type TrackingType = Tracking;
     type DistributionType = RulesForSharingDocument;
     type NoteType = Note;
+    type AcknowledgmentType = Acknowledgment;
     type PublisherType = Publisher;
     type DocumentReferenceType = Reference;
+    type AggregateSeverityType = AggregateSeverity;
+
+    fn get_title(&self) -> &String {
+        self.title.deref()
+    }
+
+    fn get_aggregate_severity(&self) -> Option<&Self::AggregateSeverityType> {
+        self.aggregate_severity.as_ref()
+    }
 
     fn get_tracking(&self) -> &Self::TrackingType {
         &self.tracking
@@ -355,10 +439,11 @@ impl DocumentTrait for DocumentLevelMetaData {
     /// Return distribution or a Validation error to satisfy CSAF 2.1 semantics
     fn get_distribution_21(&self) -> Result<&Self::DistributionType, ValidationError> {
         match self.distribution.as_ref() {
-            None => Err(ValidationError {
-                message: "CSAF 2.1 requires the distribution property, but it is not set.".to_string(),
-                instance_path: "/document/distribution".to_string(),
-            }),
+            None => Err(ValidationError::new(
+                "csaf_2_1_required",
+                "CSAF 2.1 requires the distribution property, but it is not set.",
+                "/document/distribution",
+            )),
             Some(distribution) => Ok(distribution),
         }
     }
@@ -367,6 +452,10 @@ impl DocumentTrait for DocumentLevelMetaData {
         self.notes.as_deref()
     }
 
+    fn get_acknowledgments(&self) -> Option<&Vec<Self::AcknowledgmentType>> {
+        self.acknowledgments.as_deref()
+    }
+
     fn get_lang(&self) -> Option<&String> {
         self.lang.as_deref()
     }
@@ -422,6 +511,14 @@ impl PublisherTrait for Publisher {
             CategoryOfPublisher::User => CategoryOfPublisher21::User,
         }
     }
+
+    fn get_namespace(&self) -> &String {
+        &self.namespace
+    }
+
+    fn get_contact_details(&self) -> Option<&String> {
+        self.contact_details.as_deref()
+    }
 }
 
 impl DistributionTrait for RulesForSharingDocument {
@@ -432,6 +529,10 @@ impl DistributionTrait for RulesForSharingDocument {
         &None
     }
 
+    fn get_text(&self) -> Option<&String> {
+        self.text.as_deref()
+    }
+
     /// Return TLP as ref Option, it is an option anyway
     fn get_tlp_20(&self) -> Option<&Self::TlpType> {
         self.tlp.as_ref()
@@ -440,10 +541,11 @@ impl DistributionTrait for RulesForSharingDocument {
     /// Return TLP or a ValidationError to satisfy CSAF 2.1 semantics
     fn get_tlp_21(&self) -> Result<&Self::TlpType, ValidationError> {
         match self.tlp.as_ref() {
-            None => Err(ValidationError {
-                message: "CSAF 2.1 requires the TLP property, but it is not set.".to_string(),
-                instance_path: "/document/distribution/sharing_group/tlp".to_string(),
-            }),
+            None => Err(ValidationError::new(
+                "csaf_2_1_required",
+                "CSAF 2.1 requires the TLP property, but it is not set.",
+                "/document/distribution/sharing_group/tlp",
+            )),
             Some(tlp) => Ok(tlp),
         }
     }
@@ -461,6 +563,24 @@ impl WithOptionalProductIds for Note {
     }
 }
 
+impl AcknowledgmentTrait for Acknowledgment {
+    fn get_names(&self) -> impl Iterator<Item = &String> + '_ {
+        self.names.iter().map(|x| x.deref())
+    }
+
+    fn get_organization(&self) -> Option<&String> {
+        self.organization.as_deref()
+    }
+
+    fn get_summary(&self) -> Option<&String> {
+        self.summary.as_deref()
+    }
+
+    fn get_urls(&self) -> &Vec<String> {
+        &self.urls
+    }
+}
+
 impl NoteTrait for Note {
     fn get_category(&self) -> NoteCategory21 {
         match self.category {
@@ -473,6 +593,14 @@ impl NoteTrait for Note {
             NoteCategory::LegalDisclaimer => NoteCategory21::LegalDisclaimer,
         }
     }
+
+    fn get_text(&self) -> &String {
+        self.text.deref()
+    }
+
+    fn get_title(&self) -> Option<&String> {
+        self.title.as_deref()
+    }
 }
 
 impl SharingGroupTrait for () {
@@ -595,6 +723,27 @@ impl BranchTrait<FullProductNameT> for Branch {
     fn get_product(&self) -> &Option<FullProductNameT> {
         &self.product
     }
+
+    fn get_category(&self) -> CategoryOfTheBranch21 {
+        match self.category {
+            CategoryOfTheBranch::Architecture => CategoryOfTheBranch21::Architecture,
+            CategoryOfTheBranch::HostName => CategoryOfTheBranch21::HostName,
+            CategoryOfTheBranch::Language => CategoryOfTheBranch21::Language,
+            CategoryOfTheBranch::Legacy => CategoryOfTheBranch21::Legacy,
+            CategoryOfTheBranch::PatchLevel => CategoryOfTheBranch21::PatchLevel,
+            CategoryOfTheBranch::ProductFamily => CategoryOfTheBranch21::ProductFamily,
+            CategoryOfTheBranch::ProductName => CategoryOfTheBranch21::ProductName,
+            CategoryOfTheBranch::ProductVersion => CategoryOfTheBranch21::ProductVersion,
+            CategoryOfTheBranch::ProductVersionRange => CategoryOfTheBranch21::ProductVersionRange,
+            CategoryOfTheBranch::ServicePack => CategoryOfTheBranch21::ServicePack,
+            CategoryOfTheBranch::Specification => CategoryOfTheBranch21::Specification,
+            CategoryOfTheBranch::Vendor => CategoryOfTheBranch21::Vendor,
+        }
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
 }
 
 impl ProductGroupTrait for ProductGroup {
@@ -604,6 +753,10 @@ impl ProductGroupTrait for ProductGroup {
     fn get_product_ids(&self) -> impl Iterator<Item = &String> + '_ {
         self.product_ids.iter().map(|x| x.deref())
     }
+
+    fn get_summary(&self) -> Option<&String> {
+        self.summary.as_deref()
+    }
 }
 
 impl RelationshipTrait<FullProductNameT> for Relationship {
@@ -618,6 +771,16 @@ impl RelationshipTrait<FullProductNameT> for Relationship {
     fn get_full_product_name(&self) -> &FullProductNameT {
         &self.full_product_name
     }
+
+    fn get_category(&self) -> RelationshipCategory21 {
+        match self.category {
+            RelationshipCategory::DefaultComponentOf => RelationshipCategory21::DefaultComponentOf,
+            RelationshipCategory::ExternalComponentOf => RelationshipCategory21::ExternalComponentOf,
+            RelationshipCategory::InstalledOn => RelationshipCategory21::InstalledOn,
+            RelationshipCategory::InstalledWith => RelationshipCategory21::InstalledWith,
+            RelationshipCategory::OptionalComponentOf => RelationshipCategory21::OptionalComponentOf,
+        }
+    }
 }
 
 impl ProductTrait for FullProductNameT {
@@ -627,6 +790,10 @@ impl ProductTrait for FullProductNameT {
         self.product_id.deref()
     }
 
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
+
     fn get_product_identification_helper(&self) -> &Option<Self::ProductIdentificationHelperType> {
         &self.product_identification_helper
     }
@@ -635,6 +802,10 @@ impl ProductTrait for FullProductNameT {
 impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
     type HashType = CryptographicHashes;
 
+    fn get_cpe(&self) -> Option<&String> {
+        self.cpe.as_deref()
+    }
+
     fn get_purls(&self) -> Option<&[String]> {
         self.purl.as_ref().map(std::slice::from_ref)
     }
@@ -650,6 +821,18 @@ impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
     fn get_hashes(&self) -> &Vec<Self::HashType> {
         self.hashes.as_ref()
     }
+
+    fn get_sbom_urls(&self) -> &[String] {
+        &self.sbom_urls
+    }
+
+    fn get_skus(&self) -> impl Iterator<Item = &String> + '_ {
+        self.skus.iter().map(|x| x.deref())
+    }
+
+    fn get_x_generic_uris(&self) -> impl Iterator<Item = &String> + '_ {
+        self.x_generic_uris.iter().map(|x| &x.uri)
+    }
 }
 
 impl HashTrait for CryptographicHashes {
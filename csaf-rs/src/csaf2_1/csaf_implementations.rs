@@ -1,19 +1,20 @@
 use crate::csaf_traits::{
-    BranchTrait, ContentTrait, CsafTrait, CsafVersion, DistributionTrait, DocumentReferenceTrait, DocumentTrait,
-    FileHashTrait, FirstKnownExploitationDatesTrait, FlagTrait, GeneratorTrait, HashTrait, InvolvementTrait,
-    MetricTrait, NoteTrait, ProductGroupTrait, ProductIdentificationHelperTrait, ProductStatusTrait, ProductTrait,
-    ProductTreeTrait, PublisherTrait, RelationshipTrait, RemediationTrait, RevisionTrait, SharingGroupTrait,
-    ThreatTrait, TlpTrait, TrackingTrait, VulnerabilityIdTrait, VulnerabilityTrait, WithOptionalGroupIds,
-    WithOptionalProductIds,
+    AcknowledgmentTrait, AggregateSeverityTrait, BranchTrait, ContentTrait, CsafTraitExt, CsafVersion, CweTrait,
+    DistributionTrait, DocumentReferenceTrait, DocumentTrait, FileHashTrait, FirstKnownExploitationDatesTrait,
+    FlagTrait, GeneratorTrait, HashTrait, InvolvementTrait, MetricTrait, NoteTrait, ProductGroupTrait,
+    ProductIdentificationHelperTrait, ProductStatusTrait, ProductTrait, ProductTreeTrait, PublisherTrait,
+    RelationshipTrait, RemediationTrait, RevisionTrait, SharingGroupTrait, ThreatTrait, TlpTrait, TrackingTrait,
+    VulnerabilityIdTrait, VulnerabilityTrait, WithOptionalGroupIds, WithOptionalProductIds,
 };
 use crate::csaf2_1::ssvc_dp_selection_list::SelectionList;
 use crate::schema::csaf2_1::schema::{
-    Branch, CategoryOfPublisher, CategoryOfReference, CategoryOfTheRemediation, CategoryOfTheThreat,
-    CommonSecurityAdvisoryFramework, Content, CryptographicHashes, CsafVersion as CsafVersion21, DocumentGenerator,
-    DocumentLevelMetaData, DocumentStatus, Epss, FileHash, FirstKnownExploitationDate, Flag, FullProductNameT,
-    HelperToIdentifyTheProduct, Id, Involvement, LabelOfTheFlag, LabelOfTlp, Metric, Note, NoteCategory, PartyCategory,
-    ProductGroup, ProductStatus, ProductTree, Publisher, Reference, Relationship, Remediation, Revision,
-    RulesForDocumentSharing, SharingGroup, Threat, Tracking, TrafficLightProtocolTlp, Vulnerability,
+    Acknowledgment, AggregateSeverity, Branch, CategoryOfPublisher, CategoryOfReference, CategoryOfTheBranch,
+    CategoryOfTheRemediation, CategoryOfTheThreat, CommonSecurityAdvisoryFramework, Content, CryptographicHashes,
+    CsafVersion as CsafVersion21, Cwe, DocumentGenerator, DocumentLevelMetaData, DocumentStatus, Epss, FileHash,
+    FirstKnownExploitationDate, Flag, FullProductNameT, HelperToIdentifyTheProduct, Id, Involvement, LabelOfTheFlag,
+    LabelOfTlp, Metric, Note, NoteCategory, PartyCategory, PartyStatus, ProductGroup, ProductStatus, ProductTree,
+    Publisher, Reference, Relationship, RelationshipCategory, Remediation, Revision, RulesForDocumentSharing,
+    SharingGroup, Threat, Tracking, TrafficLightProtocolTlp, Vulnerability,
 };
 use crate::validation::ValidationError;
 use serde_json::{Map, Value};
@@ -40,6 +41,14 @@ impl RemediationTrait for Remediation {
     fn get_date(&self) -> &Option<String> {
         &self.date
     }
+
+    fn get_url(&self) -> &Option<String> {
+        &self.url
+    }
+
+    fn has_restart_required(&self) -> bool {
+        self.restart_required.is_some()
+    }
 }
 
 impl ProductStatusTrait for ProductStatus {
@@ -107,6 +116,10 @@ impl ContentTrait for Content {
         serde_json::from_value::<SelectionList>(Value::Object(self.ssvc_v2.clone()))
     }
 
+    fn get_ssvc_schema_version(&self) -> Option<&str> {
+        self.ssvc_v2.get("schemaVersion").and_then(Value::as_str)
+    }
+
     fn get_cvss_v2(&self) -> Option<&Map<String, Value>> {
         if self.cvss_v2.is_empty() {
             None
@@ -160,6 +173,10 @@ impl ThreatTrait for Threat {
     fn get_category(&self) -> CategoryOfTheThreat {
         self.category
     }
+
+    fn get_details(&self) -> &String {
+        self.details.deref()
+    }
 }
 
 impl VulnerabilityTrait for Vulnerability {
@@ -171,7 +188,10 @@ impl VulnerabilityTrait for Vulnerability {
     type InvolvementType = Involvement;
     type VulnerabilityIdType = Id;
     type NoteType = Note;
+    type AcknowledgmentType = Acknowledgment;
+    type ReferenceType = Reference;
     type FirstKnownExploitationDatesType = FirstKnownExploitationDate;
+    type CweType = Cwe;
 
     fn get_remediations(&self) -> &Vec<Self::RemediationType> {
         &self.remediations
@@ -213,10 +233,22 @@ impl VulnerabilityTrait for Vulnerability {
         &self.ids
     }
 
+    fn get_cwes(&self) -> impl Iterator<Item = &Self::CweType> + '_ {
+        self.cwes.iter().flatten()
+    }
+
     fn get_notes(&self) -> Option<&Vec<Self::NoteType>> {
         self.notes.as_deref()
     }
 
+    fn get_acknowledgments(&self) -> Option<&Vec<Self::AcknowledgmentType>> {
+        self.acknowledgments.as_deref()
+    }
+
+    fn get_references(&self) -> Option<&Vec<Self::ReferenceType>> {
+        self.references.as_deref()
+    }
+
     fn get_first_known_exploitation_dates(&self) -> Option<&Vec<Self::FirstKnownExploitationDatesType>> {
         self.first_known_exploitation_dates.as_ref()
     }
@@ -232,6 +264,16 @@ impl VulnerabilityIdTrait for Id {
     }
 }
 
+impl CweTrait for Cwe {
+    fn get_id(&self) -> &String {
+        self.id.deref()
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
+}
+
 impl WithOptionalGroupIds for Flag {
     fn get_group_ids(&self) -> Option<impl Iterator<Item = &String> + '_> {
         self.group_ids.as_ref().map(|g| (*g).iter().map(|x| x.deref()))
@@ -268,6 +310,10 @@ impl InvolvementTrait for Involvement {
     fn get_party(&self) -> PartyCategory {
         self.party
     }
+
+    fn get_status(&self) -> PartyStatus {
+        self.status
+    }
 }
 
 impl WithOptionalGroupIds for Involvement {
@@ -276,7 +322,7 @@ impl WithOptionalGroupIds for Involvement {
     }
 }
 
-impl CsafTrait for CommonSecurityAdvisoryFramework {
+impl CsafTraitExt for CommonSecurityAdvisoryFramework {
     type VulnerabilityType = Vulnerability;
     type ProductTreeType = ProductTree;
     type DocumentType = DocumentLevelMetaData;
@@ -294,12 +340,32 @@ impl CsafTrait for CommonSecurityAdvisoryFramework {
     }
 }
 
+impl AggregateSeverityTrait for AggregateSeverity {
+    fn get_namespace(&self) -> Option<&String> {
+        self.namespace.as_ref()
+    }
+
+    fn get_text(&self) -> &String {
+        self.text.deref()
+    }
+}
+
 impl DocumentTrait for DocumentLevelMetaData {
     type TrackingType = Tracking;
     type DistributionType = RulesForDocumentSharing;
     type NoteType = Note;
+    type AcknowledgmentType = Acknowledgment;
     type PublisherType = Publisher;
     type DocumentReferenceType = Reference;
+    type AggregateSeverityType = AggregateSeverity;
+
+    fn get_title(&self) -> &String {
+        self.title.deref()
+    }
+
+    fn get_aggregate_severity(&self) -> Option<&Self::AggregateSeverityType> {
+        self.aggregate_severity.as_ref()
+    }
 
     fn get_tracking(&self) -> &Self::TrackingType {
         &self.tracking
@@ -319,6 +385,10 @@ impl DocumentTrait for DocumentLevelMetaData {
         self.notes.as_deref()
     }
 
+    fn get_acknowledgments(&self) -> Option<&Vec<Self::AcknowledgmentType>> {
+        self.acknowledgments.as_deref()
+    }
+
     fn get_lang(&self) -> Option<&String> {
         self.lang.as_deref()
     }
@@ -364,6 +434,14 @@ impl PublisherTrait for Publisher {
     fn get_category(&self) -> CategoryOfPublisher {
         self.category
     }
+
+    fn get_namespace(&self) -> &String {
+        &self.namespace
+    }
+
+    fn get_contact_details(&self) -> Option<&String> {
+        self.contact_details.as_deref()
+    }
 }
 
 impl DistributionTrait for RulesForDocumentSharing {
@@ -374,6 +452,10 @@ impl DistributionTrait for RulesForDocumentSharing {
         &self.sharing_group
     }
 
+    fn get_text(&self) -> Option<&String> {
+        self.text.as_deref()
+    }
+
     /// We normalize to Option here because property was optional in CSAF 2.0
     fn get_tlp_20(&self) -> Option<&Self::TlpType> {
         Some(&self.tlp)
@@ -397,10 +479,36 @@ impl WithOptionalProductIds for Note {
     }
 }
 
+impl AcknowledgmentTrait for Acknowledgment {
+    fn get_names(&self) -> impl Iterator<Item = &String> + '_ {
+        self.names.iter().map(|x| x.deref())
+    }
+
+    fn get_organization(&self) -> Option<&String> {
+        self.organization.as_deref()
+    }
+
+    fn get_summary(&self) -> Option<&String> {
+        self.summary.as_deref()
+    }
+
+    fn get_urls(&self) -> &Vec<String> {
+        &self.urls
+    }
+}
+
 impl NoteTrait for Note {
     fn get_category(&self) -> NoteCategory {
         self.category
     }
+
+    fn get_text(&self) -> &String {
+        self.text.deref()
+    }
+
+    fn get_title(&self) -> Option<&String> {
+        self.title.as_deref()
+    }
 }
 
 impl SharingGroupTrait for SharingGroup {
@@ -505,6 +613,14 @@ impl BranchTrait<FullProductNameT> for Branch {
     fn get_product(&self) -> &Option<FullProductNameT> {
         &self.product
     }
+
+    fn get_category(&self) -> CategoryOfTheBranch {
+        self.category
+    }
+
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
 }
 
 impl ProductGroupTrait for ProductGroup {
@@ -515,6 +631,10 @@ impl ProductGroupTrait for ProductGroup {
     fn get_product_ids(&self) -> impl Iterator<Item = &String> + '_ {
         self.product_ids.iter().map(|x| x.deref())
     }
+
+    fn get_summary(&self) -> Option<&String> {
+        self.summary.as_deref()
+    }
 }
 
 impl RelationshipTrait<FullProductNameT> for Relationship {
@@ -529,6 +649,10 @@ impl RelationshipTrait<FullProductNameT> for Relationship {
     fn get_full_product_name(&self) -> &FullProductNameT {
         &self.full_product_name
     }
+
+    fn get_category(&self) -> RelationshipCategory {
+        self.category
+    }
 }
 
 impl ProductTrait for FullProductNameT {
@@ -538,6 +662,10 @@ impl ProductTrait for FullProductNameT {
         self.product_id.deref()
     }
 
+    fn get_name(&self) -> &String {
+        self.name.deref()
+    }
+
     fn get_product_identification_helper(&self) -> &Option<Self::ProductIdentificationHelperType> {
         &self.product_identification_helper
     }
@@ -546,6 +674,10 @@ impl ProductTrait for FullProductNameT {
 impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
     type HashType = CryptographicHashes;
 
+    fn get_cpe(&self) -> Option<&String> {
+        self.cpe.as_deref()
+    }
+
     fn get_purls(&self) -> Option<&[String]> {
         self.purls.as_deref()
     }
@@ -561,6 +693,18 @@ impl ProductIdentificationHelperTrait for HelperToIdentifyTheProduct {
     fn get_hashes(&self) -> &Vec<Self::HashType> {
         self.hashes.as_ref()
     }
+
+    fn get_sbom_urls(&self) -> &[String] {
+        &self.sbom_urls
+    }
+
+    fn get_skus(&self) -> impl Iterator<Item = &String> + '_ {
+        self.skus.iter().map(|x| x.deref())
+    }
+
+    fn get_x_generic_uris(&self) -> impl Iterator<Item = &String> + '_ {
+        self.x_generic_uris.iter().map(|x| &x.uri)
+    }
 }
 
 impl HashTrait for CryptographicHashes {
@@ -1,29 +1,88 @@
+use crate::error::Error;
+use crate::limits::Limits;
+use crate::parse::{
+    from_slice_versioned, from_slice_versioned_with_limits, from_value_versioned, schema_validate_versioned,
+};
 use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+use serde_json::Value;
+use std::io::Read;
 use std::{fs::File, io::BufReader};
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncRead, AsyncReadExt};
 
-pub fn load_document(path: &str) -> std::io::Result<CommonSecurityAdvisoryFramework> {
+pub fn load_document(path: &str) -> Result<CommonSecurityAdvisoryFramework, Error> {
     println!("Trying to load document {}", path);
 
     let f = File::open(path)?;
     let reader = BufReader::new(f);
-    let doc: CommonSecurityAdvisoryFramework = serde_json::from_reader(reader)?;
+    let doc = from_reader(reader)?;
     println!("Successfully parsed document '{}'", *doc.document.title);
 
     Ok(doc)
 }
 
 /// Load a CSAF document from a JSON string
-pub fn load_document_from_str(json_str: &str) -> std::io::Result<CommonSecurityAdvisoryFramework> {
-    let doc: CommonSecurityAdvisoryFramework = serde_json::from_str(json_str)?;
-    Ok(doc)
+pub fn load_document_from_str(json_str: &str) -> Result<CommonSecurityAdvisoryFramework, Error> {
+    from_slice(json_str.as_bytes())
+}
+
+/// Load a CSAF 2.1 document from a reader
+pub fn from_reader(mut reader: impl Read) -> Result<CommonSecurityAdvisoryFramework, Error> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    from_slice(&bytes)
+}
+
+/// Load a CSAF 2.1 document from an asynchronous reader
+///
+/// Only reading is asynchronous. Parsing and validating are CPU-bound, so async services should
+/// run them, e.g. [validate](CommonSecurityAdvisoryFramework::validate), in
+/// `tokio::task::spawn_blocking`.
+#[cfg(feature = "tokio")]
+pub async fn from_async_reader<R: AsyncRead + Unpin>(mut reader: R) -> Result<CommonSecurityAdvisoryFramework, Error> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).await?;
+    from_slice(&bytes)
+}
+
+/// Load a CSAF 2.1 document from JSON bytes
+pub fn from_slice(bytes: &[u8]) -> Result<CommonSecurityAdvisoryFramework, Error> {
+    from_slice_versioned(bytes, "2.1")
+}
+
+/// Load a CSAF 2.1 document from untrusted JSON bytes, failing with [Error::LimitExceeded] if it
+/// exceeds `limits`
+pub fn from_slice_with_limits(bytes: &[u8], limits: &Limits) -> Result<CommonSecurityAdvisoryFramework, Error> {
+    from_slice_versioned_with_limits(bytes, "2.1", limits)
+}
+
+/// Load a CSAF 2.1 document from JSON bytes, reporting all schema violations instead of the
+/// first one
+pub fn schema_validate(bytes: &[u8]) -> Result<CommonSecurityAdvisoryFramework, Error> {
+    schema_validate_versioned(bytes, "2.1")
+}
+
+/// Converts a JSON value into a CSAF 2.1 document.
+///
+/// Unlike [serde_json::from_value], a value that does not deserialize fails with an
+/// [Error::Parse] reporting the JSON pointer of the offending value.
+impl TryFrom<Value> for CommonSecurityAdvisoryFramework {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        from_value_versioned(value, "2.1")
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::csaf2_1::loader::{from_reader, from_slice, schema_validate};
+    use crate::error::Error;
     use crate::schema::csaf2_1::schema::{
         CategoryOfPublisher, CommonSecurityAdvisoryFramework, DocumentLevelMetaData, JsonSchema, LabelOfTlp, Publisher,
         Revision, RulesForDocumentSharing, Tracking, TrafficLightProtocolTlp,
     };
+    use crate::test_helper::mock_csaf21;
 
     fn mock_document() -> CommonSecurityAdvisoryFramework {
         let now = chrono::Utc::now().to_string();
@@ -69,4 +128,47 @@ mod tests {
     fn it_works() {
         mock_document();
     }
+
+    #[test]
+    fn test_errors() {
+        let mut doc = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        let bytes = serde_json::to_vec(&doc).unwrap();
+        assert!(from_slice(&bytes).is_ok());
+        assert!(from_reader(bytes.as_slice()).is_ok());
+        assert!(schema_validate(&bytes).is_ok());
+
+        assert!(matches!(from_slice(b"{ not json"), Err(Error::Parse(_))));
+
+        doc["document"]["csaf_version"] = "2.0".into();
+        let bytes = serde_json::to_vec(&doc).unwrap();
+        assert!(matches!(from_slice(&bytes), Err(Error::UnsupportedVersion(v)) if v == "2.0"));
+
+        doc["document"]["csaf_version"] = "2.1".into();
+        doc["document"]["lang"] = 42.into();
+        doc["vulnerabilities"] = serde_json::json!([{ "cve": "not a cve" }]);
+        let bytes = serde_json::to_vec(&doc).unwrap();
+        assert!(matches!(from_slice(&bytes), Err(Error::Parse(_))));
+        match schema_validate(&bytes) {
+            Err(Error::Schema(errors)) => assert_eq!(
+                errors.iter().map(|e| e.instance_path.as_str()).collect::<Vec<_>>(),
+                vec!["/document/lang", "/vulnerabilities/0/cve"]
+            ),
+            other => panic!("Expected schema errors, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_from_value() {
+        let mut doc = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        assert!(CommonSecurityAdvisoryFramework::try_from(doc.clone()).is_ok());
+
+        doc["document"]["tracking"]["version"] = 1.into();
+        match CommonSecurityAdvisoryFramework::try_from(doc) {
+            Err(Error::Parse(e)) => assert_eq!(
+                e.to_string(),
+                "invalid type: integer `1`, expected a string at /document/tracking/version"
+            ),
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
 }
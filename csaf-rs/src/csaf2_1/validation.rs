@@ -1,5 +1,5 @@
 use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
-use crate::validation::{Test, Validatable, ValidationPreset};
+use crate::validation::{Test, TestScope, Validatable, ValidationPreset, ValidationResult, validate_by_preset};
 use crate::validations::test_6_1_02::test_6_1_02_multiple_definition_of_product_id;
 use crate::validations::test_6_1_34::test_6_1_34_branches_recursion_depth;
 use crate::validations::test_6_1_35::test_6_1_35_contradicting_remediations;
@@ -10,16 +10,71 @@ use crate::validations::test_6_1_39::test_6_1_39_public_sharing_group_with_no_ma
 use crate::validations::test_6_1_40::test_6_1_40_invalid_sharing_group_name;
 use crate::validations::test_6_1_41::test_6_1_41_missing_sharing_group_name;
 use crate::validations::test_6_1_42::test_6_1_42_purl_consistency;
+use crate::validations::test_6_1_51::test_6_1_51_tracking_id_format;
+use crate::validations::test_6_1_53::test_6_1_53_vendor_fix_for_not_affected_product;
+use crate::validations::test_6_1_54::test_6_1_54_threat_date_after_current_release_date;
+use crate::validations::test_6_1_55::test_6_1_55_invalid_cpe;
+use crate::validations::test_6_1_56::test_6_1_56_relationship_product_name;
+use crate::validations::test_6_1_57::test_6_1_57_acknowledgment_urls_absolute;
+use crate::validations::test_6_1_58::test_6_1_58_translation_into_source_language;
+use crate::validations::test_6_1_59::test_6_1_59_scored_product_not_affected;
+use crate::validations::test_6_1_60::test_6_1_60_required_document_note_category;
+use crate::validations::test_6_1_61::test_6_1_61_vulnerability_order;
+use crate::validations::test_6_1_62::test_6_1_62_remediation_date_before_discovery_date;
+use crate::validations::test_6_1_63::test_6_1_63_publisher_category_per_profile;
+use crate::validations::test_6_1_64::test_6_1_64_hash_value_format;
+use crate::validations::test_6_1_65::test_6_1_65_empty_title_or_tracking_id;
+use crate::validations::test_6_1_66::test_6_1_66_first_and_last_affected_order;
+use crate::validations::test_6_1_67::test_6_1_67_missing_exploit_status_details;
+use crate::validations::test_6_1_68::test_6_1_68_invalid_product_version_range;
+use crate::validations::test_6_1_69::test_6_1_69_aggregate_severity_namespace;
+use crate::validations::test_6_1_70::test_6_1_70_remediation_url_format;
+use crate::validations::test_6_1_71::test_6_1_71_unique_product_group_membership;
+use crate::validations::test_6_1_72::test_6_1_72_consistent_pre_release;
+use crate::validations::test_6_1_73::test_6_1_73_cve_format;
+use crate::validations::test_6_1_74::test_6_1_74_distribution_text_or_tlp;
+use crate::validations::test_6_1_75::test_6_1_75_deprecation_note;
+use crate::validations::test_6_1_76::test_6_1_76_empty_product_identification_helper;
+use crate::validations::test_6_1_77::test_6_1_77_self_reference_publisher_namespace;
+use crate::validations::test_6_1_79::test_6_1_79_remediation_group_ids;
+use crate::validations::test_6_1_80::test_6_1_80_involvement_values;
+use crate::validations::test_6_1_81::test_6_1_81_acknowledgment_without_names;
+use crate::validations::test_6_1_82::test_6_1_82_current_release_date_before_revision;
+use crate::validations::test_6_1_83::test_6_1_83_note_text;
+use crate::validations::test_6_1_84::test_6_1_84_relationship_category;
+use crate::validations::test_6_1_85::test_6_1_85_duplicate_vulnerability_ids;
+use crate::validations::test_6_1_86::test_6_1_86_versionless_product_status;
+use crate::validations::test_6_1_87::test_6_1_87_duplicate_metrics_content;
+use crate::validations::test_6_1_88::test_6_1_88_consistent_timestamp_offsets;
+use crate::validations::test_6_1_89::test_6_1_89_publisher_contact_details;
+use crate::validations::test_6_1_90::test_6_1_90_undefined_relationship_product;
+use crate::validations::test_6_1_91::test_6_1_91_duplicate_cwes;
+use crate::validations::test_6_1_92::test_6_1_92_untrimmed_document_category;
+use crate::validations::test_6_1_93::test_6_1_93_ssvc_decision_point_values;
+use crate::validations::test_6_1_94::test_6_1_94_ssvc_schema_version;
+use crate::validations::test_6_1_95::test_6_1_95_remediation_category;
+use crate::validations::test_6_1_96::test_6_1_96_product_group_summary;
+use crate::validations::test_6_1_97::test_6_1_97_sbom_url_format;
+use crate::validations::test_6_1_98::test_6_1_98_flag_label;
+use crate::validations::test_6_1_99::test_6_1_99_duplicate_note_title;
+use crate::validations::test_6_1_100::test_6_1_100_aggregate_severity;
+use crate::validations::test_6_2_13::test_6_2_13_deprecated_cvss_v3_0;
 use std::collections::HashMap;
 
 impl Validatable<CommonSecurityAdvisoryFramework> for CommonSecurityAdvisoryFramework {
     fn presets(&self) -> HashMap<ValidationPreset, Vec<&str>> {
+        // 6.1.92 runs first, as the profile tests depend on a trimmed document category
         let basic_tests = Vec::from([
-            "6.1.1", "6.1.2", "6.1.34", "6.1.35", "6.1.36", "6.1.37", "6.1.38", "6.1.39", "6.1.40", "6.1.41", "6.1.42",
+            "6.1.92", "6.1.1", "6.1.2", "6.1.34", "6.1.35", "6.1.36", "6.1.37", "6.1.38", "6.1.39", "6.1.40", "6.1.41",
+            "6.1.42", "6.1.51", "6.1.54", "6.1.55", "6.1.56", "6.1.57", "6.1.58", "6.1.59", "6.1.60", "6.1.62",
+            "6.1.63", "6.1.64", "6.1.65", "6.1.66", "6.1.67", "6.1.68", "6.1.69", "6.1.70", "6.1.71", "6.1.72",
+            "6.1.73", "6.1.74", "6.1.75", "6.1.76", "6.1.77", "6.1.79", "6.1.80", "6.1.81", "6.1.82", "6.1.83",
+            "6.1.84", "6.1.85", "6.1.86", "6.1.87", "6.1.88", "6.1.89", "6.1.90", "6.1.91", "6.1.93", "6.1.94",
+            "6.1.95", "6.1.96", "6.1.97", "6.1.98", "6.1.99",
         ]);
         // More tests may be added in extend() here later
-        let extended_tests: Vec<&str> = basic_tests.clone();
-        // extended_tests.extend(["foo"].iter());
+        let mut extended_tests: Vec<&str> = basic_tests.clone();
+        extended_tests.extend(["6.1.53", "6.1.61", "6.1.100", "6.2.13"].iter());
         let full_tests: Vec<&str> = extended_tests.clone();
         // full_tests.extend(["bar"].iter());
         HashMap::from([
@@ -45,10 +100,140 @@ impl Validatable<CommonSecurityAdvisoryFramework> for CommonSecurityAdvisoryFram
             ("6.1.40", test_6_1_40_invalid_sharing_group_name as CsafTest),
             ("6.1.41", test_6_1_41_missing_sharing_group_name as CsafTest),
             ("6.1.42", test_6_1_42_purl_consistency as CsafTest),
+            ("6.1.51", test_6_1_51_tracking_id_format as CsafTest),
+            ("6.1.53", test_6_1_53_vendor_fix_for_not_affected_product as CsafTest),
+            ("6.1.54", test_6_1_54_threat_date_after_current_release_date as CsafTest),
+            ("6.1.55", test_6_1_55_invalid_cpe as CsafTest),
+            ("6.1.56", test_6_1_56_relationship_product_name as CsafTest),
+            ("6.1.57", test_6_1_57_acknowledgment_urls_absolute as CsafTest),
+            ("6.1.58", test_6_1_58_translation_into_source_language as CsafTest),
+            ("6.1.59", test_6_1_59_scored_product_not_affected as CsafTest),
+            ("6.1.60", test_6_1_60_required_document_note_category as CsafTest),
+            ("6.1.61", test_6_1_61_vulnerability_order as CsafTest),
+            ("6.1.62", test_6_1_62_remediation_date_before_discovery_date as CsafTest),
+            ("6.1.63", test_6_1_63_publisher_category_per_profile as CsafTest),
+            ("6.1.64", test_6_1_64_hash_value_format as CsafTest),
+            ("6.1.65", test_6_1_65_empty_title_or_tracking_id as CsafTest),
+            ("6.1.66", test_6_1_66_first_and_last_affected_order as CsafTest),
+            ("6.1.67", test_6_1_67_missing_exploit_status_details as CsafTest),
+            ("6.1.68", test_6_1_68_invalid_product_version_range as CsafTest),
+            ("6.1.69", test_6_1_69_aggregate_severity_namespace as CsafTest),
+            ("6.1.70", test_6_1_70_remediation_url_format as CsafTest),
+            ("6.1.71", test_6_1_71_unique_product_group_membership as CsafTest),
+            ("6.1.72", test_6_1_72_consistent_pre_release as CsafTest),
+            ("6.1.73", test_6_1_73_cve_format as CsafTest),
+            ("6.1.74", test_6_1_74_distribution_text_or_tlp as CsafTest),
+            ("6.1.75", test_6_1_75_deprecation_note as CsafTest),
+            ("6.1.76", test_6_1_76_empty_product_identification_helper as CsafTest),
+            ("6.1.77", test_6_1_77_self_reference_publisher_namespace as CsafTest),
+            ("6.1.79", test_6_1_79_remediation_group_ids as CsafTest),
+            ("6.1.80", test_6_1_80_involvement_values as CsafTest),
+            ("6.1.81", test_6_1_81_acknowledgment_without_names as CsafTest),
+            ("6.1.82", test_6_1_82_current_release_date_before_revision as CsafTest),
+            ("6.1.83", test_6_1_83_note_text as CsafTest),
+            ("6.1.84", test_6_1_84_relationship_category as CsafTest),
+            ("6.1.85", test_6_1_85_duplicate_vulnerability_ids as CsafTest),
+            ("6.1.86", test_6_1_86_versionless_product_status as CsafTest),
+            ("6.1.87", test_6_1_87_duplicate_metrics_content as CsafTest),
+            ("6.1.88", test_6_1_88_consistent_timestamp_offsets as CsafTest),
+            ("6.1.89", test_6_1_89_publisher_contact_details as CsafTest),
+            ("6.1.90", test_6_1_90_undefined_relationship_product as CsafTest),
+            ("6.1.91", test_6_1_91_duplicate_cwes as CsafTest),
+            ("6.1.92", test_6_1_92_untrimmed_document_category as CsafTest),
+            ("6.1.93", test_6_1_93_ssvc_decision_point_values as CsafTest),
+            ("6.1.94", test_6_1_94_ssvc_schema_version as CsafTest),
+            ("6.1.95", test_6_1_95_remediation_category as CsafTest),
+            ("6.1.96", test_6_1_96_product_group_summary as CsafTest),
+            ("6.1.97", test_6_1_97_sbom_url_format as CsafTest),
+            ("6.1.98", test_6_1_98_flag_label as CsafTest),
+            ("6.1.99", test_6_1_99_duplicate_note_title as CsafTest),
+            ("6.1.100", test_6_1_100_aggregate_severity as CsafTest),
+            ("6.2.13", test_6_2_13_deprecated_cvss_v3_0 as CsafTest),
         ])
     }
 
+    fn scopes(&self) -> HashMap<&str, TestScope> {
+        let vulnerability_tests = [
+            "6.1.35", "6.1.36", "6.1.53", "6.1.54", "6.1.59", "6.1.61", "6.1.62", "6.1.66", "6.1.67", "6.1.70",
+            "6.1.73", "6.1.79", "6.1.80", "6.1.85", "6.1.86", "6.1.87", "6.1.91", "6.1.93", "6.1.94", "6.1.95",
+            "6.1.98", "6.2.13",
+        ];
+        let product_tree_tests = [
+            "6.1.2", "6.1.34", "6.1.42", "6.1.55", "6.1.56", "6.1.64", "6.1.68", "6.1.71", "6.1.76", "6.1.84",
+            "6.1.90", "6.1.96", "6.1.97",
+        ];
+        vulnerability_tests
+            .into_iter()
+            .map(|id| (id, TestScope::Vulnerability))
+            .chain(product_tree_tests.into_iter().map(|id| (id, TestScope::ProductTree)))
+            .collect()
+    }
+
     fn doc(&self) -> &CommonSecurityAdvisoryFramework {
         self
     }
 }
+
+impl CommonSecurityAdvisoryFramework {
+    /// Validates this document with the basic preset.
+    ///
+    /// Use [validate_by_preset] or [validate_by_tests](crate::validation::validate_by_tests) to
+    /// select other tests, or a [Validator](crate::validation::validator::Validator) to add custom
+    /// rules or a baseline.
+    pub fn validate(&self) -> ValidationResult {
+        validate_by_preset(self, "2.1", ValidationPreset::Basic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::TestResultStatus::Failure;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate() {
+        let result = mock_csaf21(|_| {}).validate();
+        assert!(result.success);
+        assert_eq!(result.version, "2.1");
+        assert!(!result.test_results.is_empty());
+    }
+
+    #[test]
+    fn test_validate_with_warnings_only() {
+        // 6.1.99 reports duplicate note titles as warnings
+        let note = json!({ "category": "description", "text": "Some text.", "title": "Summary" });
+        let result = mock_csaf21(|doc| doc["document"]["notes"] = json!([note, note])).validate();
+        assert!(result.success);
+        assert_eq!(result.num_errors, 0);
+        assert!(
+            result
+                .test_results
+                .iter()
+                .any(|r| r.test_id == "6.1.99" && matches!(r.status, Failure { .. }))
+        );
+    }
+
+    #[test]
+    fn test_validate_sets_document_id() {
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [
+                    { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                    { "name": "Product B", "product_id": "CSAFPID-9080700" }
+                ]
+            })
+        });
+        let result = doc.validate();
+        assert!(!result.success);
+        for test_result in &result.test_results {
+            if let Failure { errors } = &test_result.status {
+                assert!(
+                    errors
+                        .iter()
+                        .all(|e| e.document_id.as_deref() == Some("MOCK-2024-0001"))
+                );
+            }
+        }
+    }
+}
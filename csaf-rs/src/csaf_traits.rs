@@ -1,23 +1,68 @@
 use crate::schema::csaf2_1::schema::{
-    CategoryOfPublisher, CategoryOfReference, CategoryOfTheRemediation, CategoryOfTheThreat, DocumentStatus, Epss,
-    LabelOfTheFlag, LabelOfTlp, NoteCategory, PartyCategory,
+    CategoryOfPublisher, CategoryOfReference, CategoryOfTheBranch, CategoryOfTheRemediation, CategoryOfTheThreat,
+    DocumentStatus, Epss, LabelOfTheFlag, LabelOfTlp, NoteCategory, PartyCategory, PartyStatus, RelationshipCategory,
 };
 
 use crate::csaf2_1::ssvc_dp_selection_list::SelectionList;
-use crate::helpers::resolve_product_groups;
-use crate::validation::ValidationError;
-use chrono::{DateTime, Utc};
+use crate::helpers::{SUPPORTED_SSVC_SCHEMA_VERSIONS, resolve_product_groups};
+use crate::timestamp::Timestamp;
+use crate::validation::{TestScope, Validatable, ValidationError, ValidationPreset, ValidationResult};
+use crate::walk::{ProductIdCollector, ProductIdKind, UrlCollector, UrlKind, walk};
 use semver::Version;
-use std::collections::{BTreeSet, HashMap, HashSet};
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 use uuid::Uuid;
 
-/// Trait representing an abstract Common Security Advisory Framework (CSAF) document.
+/// Object-safe view of a CSAF document, independent of its CSAF version.
 ///
-/// The `CsafTrait` trait defines the key structure of a CSAF document, allowing
-/// interaction with its vulnerabilities and product tree without tying to a
-/// specific version of the CSAF schema.
+/// `CsafTraitExt` cannot be used as a trait object, because it relies on associated types and its
+/// sub-traits return `impl Iterator`. This trait exposes the version-independent accessors and the
+/// validation runner via dynamic dispatch instead, so that documents of different CSAF versions can
+/// be kept in a heterogeneous collection such as `Vec<Box<dyn CsafTrait>>`.
+///
+/// It is implemented for every document type that implements both `CsafTraitExt` and `Validatable`.
 pub trait CsafTrait {
+    /// Returns the CSAF version of the document
+    fn get_csaf_version(&self) -> &CsafVersion;
+
+    /// Returns the tracking ID of the document
+    fn get_tracking_id(&self) -> &String;
+
+    /// Returns the tracking ID of the document, or `None` if it is empty.
+    fn tracking_id(&self) -> Option<&str> {
+        let id = self.get_tracking_id();
+        (!id.is_empty()).then_some(id.as_str())
+    }
+
+    /// Returns the category of the document as an enum
+    fn get_category(&self) -> DocumentCategory;
+
+    /// Utility function to get all group IDs referenced in the document along with their JSON paths
+    fn get_all_group_references(&self) -> Vec<(String, String)>;
+
+    /// Utility function to get all product IDs referenced in the document along with their JSON paths
+    fn get_all_product_references(&self) -> Vec<(String, String)>;
+
+    /// Validates the document according to a validation preset and returns detailed results
+    fn validate_by_preset(&self, preset: ValidationPreset) -> ValidationResult;
+
+    /// Validates the document according to specific test IDs and returns detailed results
+    fn validate_by_tests(&self, preset: ValidationPreset, test_ids: &[&str]) -> ValidationResult;
+
+    /// Returns the IDs of the tests of a validation preset, in order of execution
+    fn get_preset_test_ids(&self, preset: &ValidationPreset) -> Vec<String>;
+
+    /// Returns the scope of a test, [TestScope::Document] unless the test declares another one
+    fn get_test_scope(&self, test_id: &str) -> TestScope;
+}
+
+/// Extension of [CsafTrait] with typed access to the structure of a CSAF document.
+///
+/// The `CsafTraitExt` trait defines the key structure of a CSAF document, allowing
+/// interaction with its vulnerabilities and product tree without tying to a
+/// specific version of the CSAF schema. As it relies on associated types and its sub-traits return
+/// `impl Iterator`, it cannot be used as a trait object; use [CsafTrait] for that.
+pub trait CsafTraitExt: CsafTrait {
     /// The associated type representing the type of vulnerabilities in this CSAF structure.
     type VulnerabilityType: VulnerabilityTrait;
 
@@ -95,7 +140,72 @@ pub trait CsafTrait {
     /// Retrieves the document meta present in the CSAF document.
     fn get_document(&self) -> &Self::DocumentType;
 
-    /// Utility function to get all group IDs referenced in the document along with their JSON paths
+    /// Returns all product IDs of the document along with their JSON paths, both where products
+    /// are defined and where they are referenced, in the order of [walk](crate::walk::walk).
+    fn iter_all_product_ids(&self) -> impl Iterator<Item = (String, String)>
+    where
+        Self: Sized,
+    {
+        let mut collector = ProductIdCollector::default();
+        walk(self, &mut collector);
+        collector.0.into_iter().map(|(id, _, path)| (id, path))
+    }
+
+    /// Returns the product IDs of [iter_all_product_ids](CsafTraitExt::iter_all_product_ids) of
+    /// the given `kind`, i.e. either the definitions or the references.
+    fn iter_product_ids(&self, kind: ProductIdKind) -> impl Iterator<Item = (String, String)>
+    where
+        Self: Sized,
+    {
+        let mut collector = ProductIdCollector::default();
+        walk(self, &mut collector);
+        collector
+            .0
+            .into_iter()
+            .filter(move |(_, id_kind, _)| *id_kind == kind)
+            .map(|(id, _, path)| (id, path))
+    }
+
+    /// Returns all URLs of the document along with their JSON paths, in the order of
+    /// [walk](crate::walk::walk).
+    fn iter_all_urls(&self) -> impl Iterator<Item = (String, String)>
+    where
+        Self: Sized,
+    {
+        let mut collector = UrlCollector::default();
+        walk(self, &mut collector);
+        collector.0.into_iter().map(|(url, _, path)| (url, path))
+    }
+
+    /// Returns the URLs of [iter_all_urls](CsafTraitExt::iter_all_urls) found in fields of the
+    /// given `kind`, e.g. only remediation URLs.
+    fn iter_urls(&self, kind: UrlKind) -> impl Iterator<Item = (String, String)>
+    where
+        Self: Sized,
+    {
+        let mut collector = UrlCollector::default();
+        walk(self, &mut collector);
+        collector
+            .0
+            .into_iter()
+            .filter(move |(_, url_kind, _)| *url_kind == kind)
+            .map(|(url, _, path)| (url, path))
+    }
+}
+
+impl<T: CsafTraitExt + Validatable<T>> CsafTrait for T {
+    fn get_csaf_version(&self) -> &CsafVersion {
+        self.get_document().get_csaf_version()
+    }
+
+    fn get_tracking_id(&self) -> &String {
+        self.get_document().get_tracking().get_id()
+    }
+
+    fn get_category(&self) -> DocumentCategory {
+        self.get_document().get_category()
+    }
+
     fn get_all_group_references(&self) -> Vec<(String, String)> {
         let mut ids: Vec<(String, String)> = Vec::new();
         ids.append(&mut self.get_document().get_notes_group_references());
@@ -103,7 +213,6 @@ pub trait CsafTrait {
         ids
     }
 
-    /// Utility function to get all product IDs referenced in the document along with their JSON paths
     fn get_all_product_references(&self) -> Vec<(String, String)> {
         let mut ids: Vec<(String, String)> = Vec::new();
         ids.append(&mut self.get_document().get_notes_product_references());
@@ -111,6 +220,29 @@ pub trait CsafTrait {
         ids.append(&mut self.get_product_tree_product_references());
         ids
     }
+
+    fn validate_by_preset(&self, preset: ValidationPreset) -> ValidationResult {
+        let version = CsafTrait::get_csaf_version(self).to_string();
+        crate::validation::validate_by_preset(self, &version, preset)
+    }
+
+    fn validate_by_tests(&self, preset: ValidationPreset, test_ids: &[&str]) -> ValidationResult {
+        let version = CsafTrait::get_csaf_version(self).to_string();
+        crate::validation::validate_by_tests(self, &version, preset, test_ids)
+    }
+
+    fn get_preset_test_ids(&self, preset: &ValidationPreset) -> Vec<String> {
+        self.presets()
+            .remove(preset)
+            .unwrap_or_default()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    fn get_test_scope(&self, test_id: &str) -> TestScope {
+        self.scopes().get(test_id).copied().unwrap_or(TestScope::Document)
+    }
 }
 
 /// Trait representing document meta-level information
@@ -124,15 +256,29 @@ pub trait DocumentTrait {
     /// Type representing document notes
     type NoteType: NoteTrait;
 
+    /// Type representing document acknowledgments
+    type AcknowledgmentType: AcknowledgmentTrait;
+
     /// Type representing document publisher information
     type PublisherType: PublisherTrait;
 
     type DocumentReferenceType: DocumentReferenceTrait;
 
+    /// Type representing the aggregate severity of the document
+    type AggregateSeverityType: AggregateSeverityTrait;
+
+    /// Returns the title of this document
+    fn get_title(&self) -> &String;
+
+    /// Returns the aggregate severity of this document
+    fn get_aggregate_severity(&self) -> Option<&Self::AggregateSeverityType>;
+
     /// Returns the tracking information for this document
     fn get_tracking(&self) -> &Self::TrackingType;
 
     /// Returns the distribution information for this document with CSAF 2.1 semantics
+    // A single error for a malformed document, its size does not matter
+    #[allow(clippy::result_large_err)]
     fn get_distribution_21(&self) -> Result<&Self::DistributionType, ValidationError>;
 
     /// Returns the distribution information for this document with CSAF 2.0 semantics
@@ -150,6 +296,9 @@ pub trait DocumentTrait {
         self.get_notes().extract_product_references("/document/notes")
     }
 
+    /// Returns the acknowledgments associated with this document
+    fn get_acknowledgments(&self) -> Option<&Vec<Self::AcknowledgmentType>>;
+
     /// Returns the language associated with this document.
     fn get_lang(&self) -> Option<&String>;
 
@@ -184,6 +333,15 @@ pub enum CsafVersion {
     X21,
 }
 
+impl Display for CsafVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            CsafVersion::X20 => write!(f, "2.0"),
+            CsafVersion::X21 => write!(f, "2.1"),
+        }
+    }
+}
+
 /// Trait representing document references
 pub trait DocumentReferenceTrait {
     // Returns the category of the document reference as enum
@@ -238,8 +396,23 @@ impl Display for DocumentCategory {
     }
 }
 
+/// Trait representing the aggregate severity of a document
+pub trait AggregateSeverityTrait {
+    /// Returns the namespace of the severity scale, if any
+    fn get_namespace(&self) -> Option<&String>;
+
+    /// Returns the severity
+    fn get_text(&self) -> &String;
+}
+
 pub trait PublisherTrait {
     fn get_category(&self) -> CategoryOfPublisher;
+
+    /// Returns the namespace URL of the publisher
+    fn get_namespace(&self) -> &String;
+
+    /// Returns the contact details of the publisher, if present
+    fn get_contact_details(&self) -> Option<&String>;
 }
 
 /// Trait representing distribution information for a document
@@ -253,15 +426,41 @@ pub trait DistributionTrait {
     /// Returns the sharing group for this distribution
     fn get_sharing_group(&self) -> &Option<Self::SharingGroupType>;
 
+    /// Returns the textual description of the sharing rules, if present
+    fn get_text(&self) -> Option<&String>;
+
     /// Returns the TLP information for this distribution with CSAF 2.0 semantics
     fn get_tlp_20(&self) -> Option<&Self::TlpType>;
 
     /// Returns the TLP information for this distribution with CSAF 2.1 semantics
+    // A single error for a malformed document, its size does not matter
+    #[allow(clippy::result_large_err)]
     fn get_tlp_21(&self) -> Result<&Self::TlpType, ValidationError>;
 }
 
 pub trait NoteTrait: WithOptionalGroupIds + WithOptionalProductIds {
     fn get_category(&self) -> NoteCategory;
+
+    /// Returns the content of the note
+    fn get_text(&self) -> &String;
+
+    /// Returns the title of the note, if present
+    fn get_title(&self) -> Option<&String>;
+}
+
+/// Trait representing an abstract acknowledgment in a CSAF document.
+pub trait AcknowledgmentTrait {
+    /// Returns the names of the contributors being recognized
+    fn get_names(&self) -> impl Iterator<Item = &String> + '_;
+
+    /// Returns the name of the contributing organization being recognized
+    fn get_organization(&self) -> Option<&String>;
+
+    /// Returns the summary of this acknowledgment
+    fn get_summary(&self) -> Option<&String>;
+
+    /// Returns the URLs or locations of the reference to be acknowledged
+    fn get_urls(&self) -> &Vec<String>;
 }
 
 /// Trait representing sharing group information
@@ -287,7 +486,7 @@ pub type RevisionHistory = Vec<RevisionHistoryItem>;
 #[derive(Clone)]
 pub struct RevisionHistoryItem {
     pub path_index: usize,
-    pub date: DateTime<Utc>,
+    pub date: Timestamp,
     pub number: VersionNumber,
 }
 
@@ -329,6 +528,30 @@ pub trait TrackingTrait {
     /// The initial release date of this document
     fn get_initial_release_date(&self) -> &String;
 
+    /// The release date of this document's latest version, or `None` if it is not a valid RFC 3339
+    /// date-time
+    fn get_current_release_timestamp(&self) -> Option<Timestamp> {
+        Timestamp::parse(self.get_current_release_date()).ok()
+    }
+
+    /// The initial release date of this document, or `None` if it is not a valid RFC 3339
+    /// date-time
+    fn get_initial_release_timestamp(&self) -> Option<Timestamp> {
+        Timestamp::parse(self.get_initial_release_date()).ok()
+    }
+
+    /// The newest date of the revision history, or `None` if it is empty
+    ///
+    /// Fails with the index of the first revision whose date is not a valid RFC 3339 date-time.
+    fn get_newest_revision_timestamp(&self) -> Result<Option<Timestamp>, usize> {
+        let mut newest: Option<Timestamp> = None;
+        for (i_r, revision) in self.get_revision_history().iter().enumerate() {
+            let date = revision.get_timestamp().ok_or(i_r)?;
+            newest = Some(newest.map_or(date, |n| n.max(date)));
+        }
+        Ok(newest)
+    }
+
     /// Returns the generator information for this document
     fn get_generator(&self) -> &Option<Self::GeneratorType>;
 
@@ -339,8 +562,7 @@ pub trait TrackingTrait {
     fn get_revision_history_tuples(&self) -> RevisionHistory {
         let mut revision_history: RevisionHistory = Vec::new();
         for (i_r, revision) in self.get_revision_history().iter().enumerate() {
-            let date = DateTime::parse_from_rfc3339(revision.get_date()).map(|dt| dt.with_timezone(&Utc));
-            if let Ok(date) = date {
+            if let Some(date) = revision.get_timestamp() {
                 revision_history.push(RevisionHistoryItem {
                     path_index: i_r,
                     date,
@@ -380,12 +602,26 @@ impl VersionNumber {
     /// Parses a string to either intver or semver
     /// Will panic if not parseable
     pub fn from_number(number: &str) -> Self {
+        VersionNumber::parse(number).expect("Version could not be parsed as intver or semver")
+    }
+
+    /// Parses a string to either intver or semver, returns `None` if it is neither
+    pub fn parse(number: &str) -> Option<Self> {
         if let Ok(number) = number.parse::<u64>() {
-            return VersionNumber::Integer(number);
+            return Some(VersionNumber::Integer(number));
         } else if let Ok(number) = Version::parse(number) {
-            return VersionNumber::Semver(number);
+            return Some(VersionNumber::Semver(number));
+        }
+        None
+    }
+
+    /// Compares two versions, returns `None` if one is intver and the other semver
+    pub fn try_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (VersionNumber::Integer(a), VersionNumber::Integer(b)) => Some(a.cmp(b)),
+            (VersionNumber::Semver(a), VersionNumber::Semver(b)) => Some(a.cmp(b)),
+            _ => None,
         }
-        panic!("Version could not be parsed as intver or semver")
     }
 
     /// Gets the version number for intver / the major version for semver
@@ -484,6 +720,11 @@ pub trait RevisionTrait {
     /// Returns the date associated with this revision entry
     fn get_date(&self) -> &String;
 
+    /// Returns the date of this revision entry, or `None` if it is not a valid RFC 3339 date-time
+    fn get_timestamp(&self) -> Option<Timestamp> {
+        Timestamp::parse(self.get_date()).ok()
+    }
+
     /// Returns the number/identifier of this revision
     fn get_number_string(&self) -> &String;
 
@@ -524,8 +765,17 @@ pub trait VulnerabilityTrait {
     /// The associated type representing vulnerability notes.
     type NoteType: NoteTrait;
 
+    /// The associated type representing vulnerability acknowledgments.
+    type AcknowledgmentType: AcknowledgmentTrait;
+
+    /// The associated type representing vulnerability references.
+    type ReferenceType: DocumentReferenceTrait;
+
     type FirstKnownExploitationDatesType: FirstKnownExploitationDatesTrait;
 
+    /// The associated type representing the CWEs of a vulnerability.
+    type CweType: CweTrait;
+
     /// Retrieves a list of remediations associated with the vulnerability.
     fn get_remediations(&self) -> &Vec<Self::RemediationType>;
 
@@ -611,9 +861,18 @@ pub trait VulnerabilityTrait {
     /// Returns the vulnerability IDs associated with this vulnerability.
     fn get_ids(&self) -> &Option<Vec<Self::VulnerabilityIdType>>;
 
+    /// Returns the CWEs of this vulnerability. In CSAF 2.0, there is at most one.
+    fn get_cwes(&self) -> impl Iterator<Item = &Self::CweType> + '_;
+
     /// Returns the notes associated with this vulnerability.
+    /// Returns the acknowledgments associated with this vulnerability
+    fn get_acknowledgments(&self) -> Option<&Vec<Self::AcknowledgmentType>>;
+
     fn get_notes(&self) -> Option<&Vec<Self::NoteType>>;
 
+    /// Returns the references associated with this vulnerability
+    fn get_references(&self) -> Option<&Vec<Self::ReferenceType>>;
+
     /// Utility function to get all group IDs referenced in notes along with their JSON paths
     fn get_notes_group_references(&self) -> Vec<(String, String)> {
         self.get_notes().extract_group_references("notes")
@@ -629,6 +888,15 @@ pub trait VulnerabilityIdTrait {
     fn get_text(&self) -> &String;
 }
 
+/// Trait for accessing a CWE of a vulnerability
+pub trait CweTrait {
+    /// Returns the ID of the weakness, e.g. `CWE-79`
+    fn get_id(&self) -> &String;
+
+    /// Returns the name of the weakness
+    fn get_name(&self) -> &String;
+}
+
 /// Trait for accessing vulnerability flags information
 pub trait FlagTrait: WithOptionalGroupIds + WithOptionalProductIds {
     /// Returns the date associated with this vulnerability flag
@@ -649,6 +917,9 @@ pub trait InvolvementTrait: WithOptionalGroupIds {
 
     /// Returns the party associated with this vulnerability involvement
     fn get_party(&self) -> PartyCategory;
+
+    /// Returns the status of the involvement of the party
+    fn get_status(&self) -> PartyStatus;
 }
 
 /// Trait representing an abstract remediation in a CSAF document.
@@ -661,6 +932,12 @@ pub trait RemediationTrait: WithOptionalGroupIds + WithOptionalProductIds {
     /// Categories are defined by the CSAF schema.
     fn get_category(&self) -> CategoryOfTheRemediation;
 
+    /// Returns the URL where the remediation can be obtained, if any.
+    fn get_url(&self) -> &Option<String>;
+
+    /// Returns whether the remediation states whether a restart is required.
+    fn has_restart_required(&self) -> bool;
+
     /// Computes a set of all product IDs affected by this remediation, either
     /// directly or through product groups.
     ///
@@ -671,7 +948,7 @@ pub trait RemediationTrait: WithOptionalGroupIds + WithOptionalProductIds {
     /// # Returns
     ///
     /// A `BTreeSet<String>` containing all product IDs, or `None` if none exist.
-    fn get_all_product_ids(&self, doc: &impl CsafTrait) -> Option<BTreeSet<String>> {
+    fn get_all_product_ids(&self, doc: &impl CsafTraitExt) -> Option<BTreeSet<String>> {
         if self.get_product_ids().is_none() && self.get_group_ids().is_none() {
             None
         } else {
@@ -693,7 +970,7 @@ pub trait RemediationTrait: WithOptionalGroupIds + WithOptionalProductIds {
 }
 
 /// Enum representing product status groups
-#[derive(PartialEq, Eq, Hash, Clone, Ord, PartialOrd)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Ord, PartialOrd)]
 pub enum ProductStatusGroup {
     // first_affected, known_affected, last_affected
     Affected,
@@ -723,15 +1000,98 @@ impl Display for ProductStatusGroup {
 }
 
 /// Helper macro to add product status groups to a HashMap
-macro_rules! add_product_status {
-    ($result:ident, $status_group:expr, $getter:expr) => {
-        if let Some(products) = $getter {
-            $result
-                .entry($status_group)
-                .or_insert_with(HashSet::new)
-                .extend(products);
+/// The categories of product status, i.e. the properties of `product_status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum StatusCategory {
+    FirstAffected,
+    FirstFixed,
+    Fixed,
+    KnownAffected,
+    KnownNotAffected,
+    LastAffected,
+    Recommended,
+    UnderInvestigation,
+    /// Only defined by CSAF 2.1
+    Unknown,
+}
+
+impl StatusCategory {
+    /// All categories, in the order of the schema
+    pub const ALL: [StatusCategory; 9] = [
+        StatusCategory::FirstAffected,
+        StatusCategory::FirstFixed,
+        StatusCategory::Fixed,
+        StatusCategory::KnownAffected,
+        StatusCategory::KnownNotAffected,
+        StatusCategory::LastAffected,
+        StatusCategory::Recommended,
+        StatusCategory::UnderInvestigation,
+        StatusCategory::Unknown,
+    ];
+
+    /// Returns the name of the property of `product_status`, e.g. `known_affected`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatusCategory::FirstAffected => "first_affected",
+            StatusCategory::FirstFixed => "first_fixed",
+            StatusCategory::Fixed => "fixed",
+            StatusCategory::KnownAffected => "known_affected",
+            StatusCategory::KnownNotAffected => "known_not_affected",
+            StatusCategory::LastAffected => "last_affected",
+            StatusCategory::Recommended => "recommended",
+            StatusCategory::UnderInvestigation => "under_investigation",
+            StatusCategory::Unknown => "unknown",
         }
-    };
+    }
+
+    /// Returns the product status group this category belongs to.
+    pub fn group(&self) -> ProductStatusGroup {
+        match self {
+            StatusCategory::FirstAffected | StatusCategory::KnownAffected | StatusCategory::LastAffected => {
+                ProductStatusGroup::Affected
+            },
+            StatusCategory::KnownNotAffected => ProductStatusGroup::NotAffected,
+            StatusCategory::FirstFixed | StatusCategory::Fixed => ProductStatusGroup::Fixed,
+            StatusCategory::UnderInvestigation => ProductStatusGroup::UnderInvestigation,
+            StatusCategory::Unknown => ProductStatusGroup::Unknown,
+            StatusCategory::Recommended => ProductStatusGroup::Recommended,
+        }
+    }
+}
+
+/// The product IDs of a `product_status` by category, see [ProductStatusTrait::status_buckets]
+///
+/// Product status only references product IDs, so there are no product groups to resolve.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusBuckets<'a> {
+    buckets: [BTreeSet<&'a String>; 9],
+}
+
+impl<'a> StatusBuckets<'a> {
+    /// Returns the product IDs listed in `category`.
+    pub fn ids(&self, category: StatusCategory) -> &BTreeSet<&'a String> {
+        &self.buckets[category as usize]
+    }
+
+    /// Returns the product IDs listed in any category of `group`.
+    pub fn group(&self, group: &ProductStatusGroup) -> BTreeSet<&'a String> {
+        StatusCategory::ALL
+            .iter()
+            .filter(|c| c.group() == *group)
+            .flat_map(|c| self.ids(*c).iter().copied())
+            .collect()
+    }
+
+    /// Returns the product status groups of each listed product ID.
+    pub fn groups_by_product(&self) -> BTreeMap<&'a String, BTreeSet<ProductStatusGroup>> {
+        let mut result: BTreeMap<&'a String, BTreeSet<ProductStatusGroup>> = BTreeMap::new();
+        for category in StatusCategory::ALL {
+            for product_id in self.ids(category) {
+                result.entry(product_id).or_default().insert(category.group());
+            }
+        }
+        result
+    }
 }
 
 /// Trait representing an abstract product status in a CSAF document.
@@ -790,35 +1150,39 @@ pub trait ProductStatusTrait {
         ids
     }
 
+    /// Returns the product IDs listed in `category`.
+    fn ids_for(&self, category: StatusCategory) -> Vec<&String> {
+        let ids = match category {
+            StatusCategory::FirstAffected => self.get_first_affected().map(|i| i.collect()),
+            StatusCategory::FirstFixed => self.get_first_fixed().map(|i| i.collect()),
+            StatusCategory::Fixed => self.get_fixed().map(|i| i.collect()),
+            StatusCategory::KnownAffected => self.get_known_affected().map(|i| i.collect()),
+            StatusCategory::KnownNotAffected => self.get_known_not_affected().map(|i| i.collect()),
+            StatusCategory::LastAffected => self.get_last_affected().map(|i| i.collect()),
+            StatusCategory::Recommended => self.get_recommended().map(|i| i.collect()),
+            StatusCategory::UnderInvestigation => self.get_under_investigation().map(|i| i.collect()),
+            StatusCategory::Unknown => self.get_unknown().map(|i| i.collect()),
+        };
+        ids.unwrap_or_default()
+    }
+
+    /// Returns the product IDs of all categories at once.
+    fn status_buckets(&self) -> StatusBuckets<'_> {
+        StatusBuckets {
+            buckets: StatusCategory::ALL.map(|category| self.ids_for(category).into_iter().collect()),
+        }
+    }
+
     /// Returns a `HashMap` containing all product IDs grouped by their statuses.
     fn get_all_by_product_status(&self) -> HashMap<ProductStatusGroup, HashSet<&String>> {
         let mut result: HashMap<ProductStatusGroup, HashSet<&String>> = HashMap::new();
-
-        // affected
-        add_product_status!(result, ProductStatusGroup::Affected, self.get_first_affected());
-        add_product_status!(result, ProductStatusGroup::Affected, self.get_last_affected());
-        add_product_status!(result, ProductStatusGroup::Affected, self.get_known_affected());
-
-        // not affected
-        add_product_status!(result, ProductStatusGroup::NotAffected, self.get_known_not_affected());
-
-        // fixed
-        add_product_status!(result, ProductStatusGroup::Fixed, self.get_fixed());
-        add_product_status!(result, ProductStatusGroup::Fixed, self.get_first_fixed());
-
-        // under investigation
-        add_product_status!(
-            result,
-            ProductStatusGroup::UnderInvestigation,
-            self.get_under_investigation()
-        );
-
-        // unknown
-        add_product_status!(result, ProductStatusGroup::Unknown, self.get_unknown());
-
-        // recommended
-        add_product_status!(result, ProductStatusGroup::Recommended, self.get_recommended());
-
+        let buckets = self.status_buckets();
+        for category in StatusCategory::ALL {
+            let ids = buckets.ids(category);
+            if !ids.is_empty() {
+                result.entry(category.group()).or_default().extend(ids.iter().copied());
+            }
+        }
         result
     }
 }
@@ -846,6 +1210,16 @@ pub trait ContentTrait {
     /// encapsulated as a `Result`.
     fn get_ssvc(&self) -> Result<SelectionList, serde_json::Error>;
 
+    /// Returns the `schemaVersion` of the contained SSVC metric, if any, without parsing it.
+    fn get_ssvc_schema_version(&self) -> Option<&str>;
+
+    /// Returns whether the contained SSVC metric declares a schema version that is not one of
+    /// [SUPPORTED_SSVC_SCHEMA_VERSIONS], so that its content cannot be interpreted.
+    fn has_unsupported_ssvc_schema(&self) -> bool {
+        self.get_ssvc_schema_version()
+            .is_some_and(|version| !SUPPORTED_SSVC_SCHEMA_VERSIONS.contains(&version))
+    }
+
     /// Returns a JSON representation of the contained CVSS 2.0 metric, if any.
     fn get_cvss_v2(&self) -> Option<&serde_json::Map<String, serde_json::Value>>;
 
@@ -858,6 +1232,26 @@ pub trait ContentTrait {
     /// Returns a reference to the contained EPSS metric if it exists.
     fn get_epss(&self) -> &Option<Epss>;
 
+    /// Returns an iterator over the types of metrics contained in this content.
+    ///
+    /// CVSS 3.x metrics without a `version` are skipped, as their type cannot be told.
+    fn iter_metric_contents(&self) -> impl Iterator<Item = VulnerabilityMetric> {
+        let cvss_v3 = self
+            .get_cvss_v3()
+            .and_then(|cvss_v3| cvss_v3.get("version"))
+            .and_then(|version| version.as_str())
+            .map(|version| VulnerabilityMetric::CvssV3(version.to_owned()));
+        [
+            self.has_ssvc().then_some(VulnerabilityMetric::SsvcV1),
+            self.get_cvss_v2().map(|_| VulnerabilityMetric::CvssV2),
+            cvss_v3,
+            self.get_cvss_v4().map(|_| VulnerabilityMetric::CvssV4),
+            self.get_epss().as_ref().map(|_| VulnerabilityMetric::Epss),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
     /// This function constructs a JSON path string that can be used to locate the specific
     /// content object within a CSAF document's JSON structure. The path format varies between
     /// CSAF versions due to structural differences in how metrics and content are organized.
@@ -885,7 +1279,7 @@ pub trait ContentTrait {
 }
 
 /// Types of vulnerability metrics known until CSAF 2.1
-#[derive(Hash, Eq, PartialEq, Clone)]
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub enum VulnerabilityMetric {
     SsvcV1,
     CvssV2,
@@ -925,6 +1319,9 @@ pub trait ThreatTrait: WithOptionalGroupIds + WithOptionalProductIds {
 
     /// Returns the category of the threat
     fn get_category(&self) -> CategoryOfTheThreat;
+
+    /// Returns the description of the threat
+    fn get_details(&self) -> &String;
 }
 
 /// Trait representing an abstract product tree in a CSAF document.
@@ -969,6 +1366,15 @@ pub trait ProductTreeTrait {
     /// Retrieves a reference to the list of relationships in the product tree.
     fn get_relationships(&self) -> &Vec<Self::RelationshipType>;
 
+    /// Utility function to get the names of all products defined in the product tree by product ID
+    fn get_product_names(&self) -> HashMap<String, String> {
+        let mut names: HashMap<String, String> = HashMap::new();
+        self.visit_all_products(&mut |product, _path| {
+            names.insert(product.get_product_id().to_owned(), product.get_name().to_owned());
+        });
+        names
+    }
+
     /// Utility function to get all product references in relationships along with their JSON paths
     fn get_relationships_product_references(&self) -> Vec<(String, String)> {
         let mut ids: Vec<(String, String)> = Vec::new();
@@ -1007,31 +1413,37 @@ pub trait ProductTreeTrait {
     /// * `Ok(())` if all products were visited successfully
     /// * `Err(Vec<ValidationError>)` if any callback(s) returned errors for any products
     fn visit_all_products_generic(&self, callback: &mut impl FnMut(&Self::FullProductNameType, &str)) {
+        // Paths are written into reused buffers, so that traversal does not allocate per product
+        let mut path = String::new();
+        let mut product_path = String::new();
+
         // Visit products in branches
         if let Some(branches) = self.get_branches().as_ref() {
             for (i, branch) in branches.iter().enumerate() {
-                branch.visit_branches_rec(
-                    &format!("/product_tree/branches/{}", i),
-                    &mut |branch: &Self::BranchType, path| {
-                        if let Some(product_ref) = branch.get_product() {
-                            callback(product_ref, &format!("{}/product", path));
-                        }
-                    },
-                );
+                path.clear();
+                write!(path, "/product_tree/branches/{}", i).unwrap();
+                visit_branches_in_buffer(branch, &mut path, &mut |branch: &Self::BranchType, path| {
+                    if let Some(product_ref) = branch.get_product() {
+                        product_path.clear();
+                        write!(product_path, "{}/product", path).unwrap();
+                        callback(product_ref, &product_path);
+                    }
+                });
             }
         }
 
         // Visit full_product_names
         for (i, fpn) in self.get_full_product_names().iter().enumerate() {
-            callback(fpn, &format!("/product_tree/full_product_names/{}", i));
+            path.clear();
+            write!(path, "/product_tree/full_product_names/{}", i).unwrap();
+            callback(fpn, &path);
         }
 
         // Visit relationships
         for (i, rel) in self.get_relationships().iter().enumerate() {
-            callback(
-                rel.get_full_product_name(),
-                &format!("/product_tree/relationships/{}/full_product_name", i),
-            );
+            path.clear();
+            write!(path, "/product_tree/relationships/{}/full_product_name", i).unwrap();
+            callback(rel.get_full_product_name(), &path);
         }
     }
 
@@ -1065,6 +1477,12 @@ pub trait BranchTrait<FPN: ProductTrait>: Sized {
     /// Retrieves the full product name associated with this branch, if available.
     fn get_product(&self) -> &Option<FPN>;
 
+    /// Returns the category of this branch.
+    fn get_category(&self) -> CategoryOfTheBranch;
+
+    /// Returns the name of this branch, e.g. the version for branches of category `product_version`.
+    fn get_name(&self) -> &String;
+
     /// Recursively visits all branches in the tree structure,
     /// applying the provided callback function to each branch.
     ///
@@ -1081,12 +1499,7 @@ pub trait BranchTrait<FPN: ProductTrait>: Sized {
     /// * `Ok(())` if the traversal completes successfully
     /// * `Err(Vec<ValidationError>)` if the callback returns an error for any branch
     fn visit_branches_rec(&self, path: &str, callback: &mut impl FnMut(&Self, &str)) {
-        callback(self, path);
-        if let Some(branches) = self.get_branches().as_ref() {
-            for (i, branch) in branches.iter().enumerate() {
-                branch.visit_branches_rec(&format!("{}/branches/{}", path, i), callback);
-            }
-        }
+        visit_branches_in_buffer(self, &mut path.to_string(), callback);
     }
 
     /// Searches for branches that exceed the maximum allowed depth in the branch hierarchy.
@@ -1118,6 +1531,27 @@ pub trait BranchTrait<FPN: ProductTrait>: Sized {
     }
 }
 
+/// Depth-first traversal for [BranchTrait::visit_branches_rec], with the path of the current
+/// branch in `path`.
+///
+/// Child paths are appended to and truncated from the same buffer, so that traversal only
+/// allocates when the buffer grows.
+fn visit_branches_in_buffer<FPN: ProductTrait, B: BranchTrait<FPN>>(
+    branch: &B,
+    path: &mut String,
+    callback: &mut impl FnMut(&B, &str),
+) {
+    callback(branch, path);
+    if let Some(branches) = branch.get_branches() {
+        let len = path.len();
+        for (i, child) in branches.iter().enumerate() {
+            write!(path, "/branches/{}", i).unwrap();
+            visit_branches_in_buffer(child, path, callback);
+            path.truncate(len);
+        }
+    }
+}
+
 /// Trait representing an abstract product group in a CSAF document.
 ///
 /// The `ProductGroupTrait` encapsulates the details of a product group, including
@@ -1128,6 +1562,9 @@ pub trait ProductGroupTrait {
 
     /// Retrieves a vector of product IDs contained within the product group.
     fn get_product_ids(&self) -> impl Iterator<Item = &String> + '_;
+
+    /// Returns the summary describing the purpose of the product group, if any.
+    fn get_summary(&self) -> Option<&String>;
 }
 
 /// Trait representing an abstract relationship in a product tree.
@@ -1140,6 +1577,9 @@ pub trait RelationshipTrait<FPN: ProductTrait> {
 
     /// Retrieves the full product name associated with the relationship.
     fn get_full_product_name(&self) -> &FPN;
+
+    /// Returns the category of the relationship between both products
+    fn get_category(&self) -> RelationshipCategory;
 }
 
 /// Trait representing an abstract full product name in a CSAF document.
@@ -1150,14 +1590,42 @@ pub trait ProductTrait {
     /// Returns the product ID from the full product name.
     fn get_product_id(&self) -> &String;
 
+    /// Returns the name of the product.
+    fn get_name(&self) -> &String;
+
     /// Returns the product identification helper associated with the full product name.
     fn get_product_identification_helper(&self) -> &Option<Self::ProductIdentificationHelperType>;
+
+    /// Visits all hashes of the product identification helper of this product.
+    ///
+    /// The callback receives each hash together with its path, built from `path`, the path of
+    /// this product (e.g. `/product_tree/full_product_names/0`).
+    fn visit_hashes(
+        &self,
+        path: &str,
+        callback: &mut impl FnMut(
+            &<Self::ProductIdentificationHelperType as ProductIdentificationHelperTrait>::HashType,
+            &str,
+        ),
+    ) {
+        if let Some(helper) = self.get_product_identification_helper() {
+            for (hash_i, hash) in helper.get_hashes().iter().enumerate() {
+                callback(
+                    hash,
+                    &format!("{}/product_identification_helper/hashes/{}", path, hash_i),
+                );
+            }
+        }
+    }
 }
 
 /// Trait representing an abstract product identification helper of a full product name.
 pub trait ProductIdentificationHelperTrait {
     type HashType: HashTrait;
 
+    /// Returns the CPE identifying the associated product.
+    fn get_cpe(&self) -> Option<&String>;
+
     /// Returns the PURLs identifying the associated product.
     fn get_purls(&self) -> Option<&[String]>;
 
@@ -1166,6 +1634,15 @@ pub trait ProductIdentificationHelperTrait {
     fn get_serial_numbers(&self) -> Option<impl Iterator<Item = &String> + '_>;
 
     fn get_hashes(&self) -> &Vec<Self::HashType>;
+
+    /// Returns the URLs of SBOMs describing the associated product.
+    fn get_sbom_urls(&self) -> &[String];
+
+    /// Returns the stock keeping units of the associated product.
+    fn get_skus(&self) -> impl Iterator<Item = &String> + '_;
+
+    /// Returns the URIs of the generic identifiers of the associated product.
+    fn get_x_generic_uris(&self) -> impl Iterator<Item = &String> + '_;
 }
 
 /// Trait representing a collection of file_hashes for a file as part of a product identification helper
@@ -1331,3 +1808,71 @@ impl<T: WithOptionalProductIds> ExtractProductReferences<T> for Vec<T> {
         extract_product_id_impl(self.iter(), path_prefix)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf_traits::{
+        CsafTrait, CsafTraitExt, CsafVersion, ProductStatusGroup, ProductStatusTrait, StatusCategory,
+        VulnerabilityTrait,
+    };
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationPreset;
+    use serde_json::json;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_dyn_csaf_trait_heterogeneous_collection() {
+        let docs: Vec<Box<dyn CsafTrait>> = vec![Box::new(mock_csaf20(|_| {})), Box::new(mock_csaf21(|_| {}))];
+
+        let versions: Vec<CsafVersion> = docs.iter().map(|doc| doc.get_csaf_version().clone()).collect();
+        assert_eq!(versions, vec![CsafVersion::X20, CsafVersion::X21]);
+
+        for doc in docs.iter() {
+            assert_eq!(doc.get_tracking_id(), "MOCK-2024-0001");
+            let result = doc.validate_by_preset(ValidationPreset::Basic);
+            assert!(result.success, "Mock document should pass basic validation");
+            assert_eq!(result.version, doc.get_csaf_version().to_string());
+        }
+    }
+
+    #[test]
+    fn test_status_buckets() {
+        let doc = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "product_status": {
+                    "first_affected": ["CSAFPID-1"],
+                    "known_affected": ["CSAFPID-2", "CSAFPID-1"],
+                    "fixed": ["CSAFPID-3"],
+                    "recommended": ["CSAFPID-3"]
+                }
+            }]);
+        });
+        let product_status = doc.get_vulnerabilities()[0].get_product_status().as_ref().unwrap();
+        assert_eq!(
+            product_status.ids_for(StatusCategory::KnownAffected),
+            vec!["CSAFPID-2", "CSAFPID-1"]
+        );
+        assert!(product_status.ids_for(StatusCategory::Unknown).is_empty());
+
+        let buckets = product_status.status_buckets();
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<BTreeSet<String>>();
+        let owned = |set: BTreeSet<&String>| set.into_iter().cloned().collect::<BTreeSet<String>>();
+        assert_eq!(
+            owned(buckets.group(&ProductStatusGroup::Affected)),
+            ids(&["CSAFPID-1", "CSAFPID-2"])
+        );
+        assert_eq!(owned(buckets.ids(StatusCategory::Fixed).clone()), ids(&["CSAFPID-3"]));
+
+        let by_product = buckets.groups_by_product();
+        assert_eq!(by_product.len(), 3);
+        assert_eq!(
+            by_product[&"CSAFPID-3".to_string()],
+            BTreeSet::from([ProductStatusGroup::Fixed, ProductStatusGroup::Recommended])
+        );
+        assert_eq!(
+            StatusCategory::ALL.map(|c| c.name()).join(","),
+            "first_affected,first_fixed,fixed,known_affected,known_not_affected,last_affected,recommended,\
+             under_investigation,unknown"
+        );
+    }
+}
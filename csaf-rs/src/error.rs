@@ -0,0 +1,49 @@
+//! The error type of the public parsing and validation API
+//!
+//! Loading a document can fail for several reasons, e.g. the file cannot be read, it is not valid
+//! JSON or it does not conform to the schema. [Error] combines all of them, so that consumers only
+//! need to match on a single type.
+
+use crate::limits::Limit;
+use crate::validation::ValidationError;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The input is not valid JSON, or does not deserialize into a CSAF document
+    #[error("JSON parsing error: {0}")]
+    Parse(#[from] serde_json::Error),
+    /// The input is not valid YAML
+    #[cfg(feature = "yaml")]
+    #[error("YAML parsing error: {0}")]
+    Yaml(#[from] serde_yaml_ng::Error),
+    /// The document violates the schema, with one finding per offending value
+    #[error("Document does not conform to the schema: {} error(s)", .0.len())]
+    Schema(Vec<ValidationError>),
+    /// The input could not be read
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The document declares a CSAF version that is not supported by the loader
+    #[error("Unsupported CSAF version: {0}")]
+    UnsupportedVersion(String),
+    /// The file is in a format other than JSON, e.g. YAML without the `yaml` feature
+    #[error("Unsupported file format: {0}")]
+    UnsupportedFormat(String),
+    /// The external product tree of the document could not be resolved, see
+    /// [crate::product_tree_loader::resolve_external_product_tree]
+    #[error("{0}")]
+    ExternalProductTree(String),
+    /// The document exceeds one of the configured [crate::limits::Limits]
+    #[error("Document exceeds the limit of {max} {limit}")]
+    LimitExceeded { limit: Limit, max: usize },
+    /// Reading or parsing the file at `path` failed
+    #[error("{}: {source}", path.display())]
+    File { path: PathBuf, source: Box<Error> },
+}
+
+impl From<Vec<ValidationError>> for Error {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        Error::Schema(errors)
+    }
+}
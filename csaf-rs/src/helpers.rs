@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, ProductGroupTrait, ProductTreeTrait};
+use crate::csaf_traits::{CsafTraitExt, ProductGroupTrait, ProductTreeTrait};
 use crate::csaf2_1::ssvc_dp::DecisionPoint;
 use rust_embed::RustEmbed;
 use std::collections::{BTreeSet, HashMap, HashSet};
@@ -11,12 +11,15 @@ pub static SG_NAME_PUBLIC: &str = "Public";
 /// Special name for private sharing groups
 pub static SG_NAME_PRIVATE: &str = "No sharing allowed";
 
+/// Qualitative severity ratings of the CVSS specification
+pub static CVSS_QUALITATIVE_SEVERITY_RATINGS: &[&str] = &["None", "Low", "Medium", "High", "Critical"];
+
 /// Special "max" UUID value
 pub static MAX_UUID: &Uuid = &uuid!("ffffffff-ffff-ffff-ffff-ffffffffffff");
 /// Special "nil" UUID value
 pub static NIL_UUID: &Uuid = &uuid!("00000000-0000-0000-0000-000000000000");
 
-pub fn resolve_product_groups<'a, I>(doc: &impl CsafTrait, product_groups: I) -> Option<BTreeSet<String>>
+pub fn resolve_product_groups<'a, I>(doc: &impl CsafTraitExt, product_groups: I) -> Option<BTreeSet<String>>
 where
     I: IntoIterator<Item = &'a String>,
 {
@@ -33,6 +36,19 @@ where
     })
 }
 
+/// Returns the IDs of all product groups defined in `/product_tree/product_groups`.
+pub fn defined_product_group_ids(doc: &impl CsafTraitExt) -> HashSet<String> {
+    doc.get_product_tree()
+        .as_ref()
+        .map(|tree| {
+            tree.get_product_groups()
+                .iter()
+                .map(|g| g.get_group_id().to_owned())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Counts the number of unescaped '*' characters in a given string.
 /// An asterisk is considered "unescaped" if it is not preceded by a backslash ('\\').
 /// Consecutive backslashes alternate between escaping or not escaping characters.
@@ -57,6 +73,37 @@ pub fn count_unescaped_stars(s: &str) -> u32 {
     count
 }
 
+/// Normalizes a BCP 47 language tag for comparison.
+///
+/// Language tags are case-insensitive, so subtags are brought to their conventional case:
+/// the language in lowercase, a four-letter script in title case and a two-letter region in
+/// uppercase (e.g. "EN-latn-us" becomes "en-Latn-US"). Surrounding whitespace is removed.
+pub fn normalize_language_tag(tag: &str) -> String {
+    tag.trim()
+        .split('-')
+        .enumerate()
+        .map(|(i, subtag)| {
+            if i == 0 {
+                subtag.to_ascii_lowercase()
+            } else if subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic()) {
+                let mut chars = subtag.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase())
+                    .unwrap_or_default()
+            } else if subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()) {
+                subtag.to_ascii_uppercase()
+            } else {
+                subtag.to_ascii_lowercase()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+/// The versions of the SSVC selection list schema whose content can be interpreted
+pub static SUPPORTED_SSVC_SCHEMA_VERSIONS: &[&str] = &["2.0.0"];
+
 #[derive(RustEmbed)]
 #[folder = "assets/ssvc_decision_points/"]
 #[include = "*.json"]
@@ -1,14 +1,75 @@
+pub mod canonical;
+pub mod cpe;
 pub mod csaf2_0;
 pub mod csaf2_1;
 pub mod csaf_traits;
+pub mod error;
 pub mod generated;
 pub mod helpers;
+pub mod limits;
+#[cfg(feature = "network")]
+pub mod network;
+pub mod parse;
+pub mod product_tree_loader;
+pub mod profile;
+pub mod report;
+pub mod sbom;
 pub mod schema;
+#[cfg(feature = "spans")]
+pub mod spans;
 pub mod test_helper;
 pub mod test_result_comparison;
 pub mod test_structure;
 pub mod test_validation;
+pub mod timestamp;
 pub mod validation;
 pub mod validations;
+pub mod version_range;
+pub mod walk;
 #[cfg(feature = "wasm")]
 pub mod wasm;
+
+use crate::error::Error;
+use crate::validation::validator::Validator;
+use crate::validation::{ValidationPreset, ValidationResult};
+
+/// Parses a CSAF document from a JSON string, detects its version and validates it with the
+/// basic preset.
+///
+/// This is the simplest entry point, e.g. for bindings to other languages. Every failure to
+/// load the document is returned as an [Error]: [Error::Parse] if the string is not valid JSON
+/// or does not deserialize, and [Error::UnsupportedVersion] if `/document/csaf_version` is
+/// neither 2.0 nor 2.1.
+pub fn validate_json_str(s: &str) -> Result<ValidationResult, Error> {
+    validation::batch::validate_value(serde_json::from_str(s)?, &Validator::new(ValidationPreset::Basic))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validate_json_str;
+
+    #[test]
+    fn test_validate_json_str() {
+        for json in [
+            serde_json::to_string(&mock_csaf20(|_| {})).unwrap(),
+            serde_json::to_string(&mock_csaf21(|_| {})).unwrap(),
+        ] {
+            let result = validate_json_str(&json).unwrap();
+            assert!(result.success);
+        }
+
+        for malformed in ["", "{", "[]", "null", "{\"document\": 42}", "\u{0}"] {
+            assert!(
+                matches!(validate_json_str(malformed), Err(Error::Parse(_))),
+                "{}",
+                malformed
+            );
+        }
+        let json = serde_json::to_string(&mock_csaf21(|_| {}))
+            .unwrap()
+            .replace("\"csaf_version\":\"2.1\"", "\"csaf_version\":\"3.0\"");
+        assert!(matches!(validate_json_str(&json), Err(Error::UnsupportedVersion(v)) if v == "3.0"));
+    }
+}
@@ -0,0 +1,239 @@
+//! Resource limits for untrusted input
+//!
+//! Deserializing a document allocates memory proportional to its size, and several tests
+//! cross-check all products or vulnerabilities with each other. [Limits] bounds the size of a
+//! document before it is deserialized, so that a hostile document fails with
+//! [Error::LimitExceeded] instead of exhausting memory or time.
+//!
+//! The size is checked on the generic JSON value, i.e. after parsing the bytes but before
+//! building the typed document. The nesting depth of JSON itself is already bounded by
+//! `serde_json`, which fails for documents nested more than 128 levels deep.
+
+use crate::error::Error;
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+
+/// A limit of [Limits] that a document exceeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// The size of the input in bytes
+    DocumentBytes,
+    /// The number of product IDs defined in the product tree
+    ProductIds,
+    /// The number of vulnerabilities
+    Vulnerabilities,
+    /// The nesting depth of branches
+    BranchDepth,
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Limit::DocumentBytes => "document bytes",
+            Limit::ProductIds => "product IDs",
+            Limit::Vulnerabilities => "vulnerabilities",
+            Limit::BranchDepth => "levels of branches",
+        })
+    }
+}
+
+/// Upper bounds for the size of a document
+///
+/// The default branch depth is above the one allowed by test 6.1.34, so that documents failing
+/// the test can still be loaded and validated. Each level of branches nests the JSON two levels
+/// deeper, so `serde_json` rejects documents with more than about 62 levels of branches before
+/// this limit is checked, and the default has to stay below that to be reachable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum size of the input in bytes
+    pub max_document_bytes: usize,
+    /// The maximum number of product IDs defined in the product tree
+    pub max_product_ids: usize,
+    /// The maximum number of vulnerabilities
+    pub max_vulnerabilities: usize,
+    /// The maximum nesting depth of branches, where top-level branches have depth 1
+    pub max_branch_depth: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_document_bytes: 64 * 1024 * 1024,
+            max_product_ids: 100_000,
+            max_vulnerabilities: 10_000,
+            max_branch_depth: 50,
+        }
+    }
+}
+
+impl Limits {
+    /// Returns an error if `bytes` exceeds the maximum document size.
+    pub fn check_bytes(&self, bytes: &[u8]) -> Result<(), Error> {
+        check(Limit::DocumentBytes, bytes.len(), self.max_document_bytes)
+    }
+
+    /// Reads all bytes of `reader`, but no more than the maximum document size, so that an
+    /// endless stream fails with [Error::LimitExceeded] as well.
+    pub fn read(&self, reader: impl Read) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        let max = u64::try_from(self.max_document_bytes).unwrap_or(u64::MAX);
+        reader.take(max.saturating_add(1)).read_to_end(&mut bytes)?;
+        self.check_bytes(&bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns an error if the JSON of a document exceeds any count or depth limit.
+    pub fn check_value(&self, value: &Value) -> Result<(), Error> {
+        let vulnerabilities = value
+            .get("vulnerabilities")
+            .and_then(Value::as_array)
+            .map_or(0, Vec::len);
+        check(Limit::Vulnerabilities, vulnerabilities, self.max_vulnerabilities)?;
+
+        if let Some(product_tree) = value.get("product_tree") {
+            check(Limit::ProductIds, count_product_ids(product_tree), self.max_product_ids)?;
+            if let Some(branches) = product_tree.get("branches") {
+                check_branch_depth(branches, 1, self.max_branch_depth)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check(limit: Limit, actual: usize, max: usize) -> Result<(), Error> {
+    if actual > max {
+        Err(Error::LimitExceeded { limit, max })
+    } else {
+        Ok(())
+    }
+}
+
+/// Counts the `product_id` members anywhere within `value`.
+fn count_product_ids(value: &Value) -> usize {
+    match value {
+        Value::Object(map) => {
+            let own = usize::from(map.get("product_id").is_some_and(Value::is_string));
+            own + map.values().map(count_product_ids).sum::<usize>()
+        },
+        Value::Array(items) => items.iter().map(count_product_ids).sum(),
+        _ => 0,
+    }
+}
+
+/// Fails if any branch of `branches`, which are at `depth`, is nested deeper than `max`.
+fn check_branch_depth(branches: &Value, depth: usize, max: usize) -> Result<(), Error> {
+    let Some(branches) = branches.as_array() else {
+        return Ok(());
+    };
+    if !branches.is_empty() {
+        check(Limit::BranchDepth, depth, max)?;
+    }
+    for branch in branches {
+        if let Some(children) = branch.get("branches") {
+            check_branch_depth(children, depth + 1, max)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf2_1::loader::from_slice_with_limits;
+    use crate::error::Error;
+    use crate::limits::{Limit, Limits};
+    use crate::test_helper::mock_csaf21;
+    use crate::validations::test_6_1_34::MAX_DEPTH;
+    use serde_json::{Value, json};
+
+    fn to_bytes(patch: impl FnOnce(&mut Value)) -> Vec<u8> {
+        let mut doc = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        patch(&mut doc);
+        serde_json::to_vec(&doc).unwrap()
+    }
+
+    fn nested_branches(depth: usize) -> Value {
+        let mut branch = json!({
+            "category": "product_version",
+            "name": "1.0",
+            "product": { "name": "Product 1.0", "product_id": "CSAFPID-0001" }
+        });
+        for _ in 1..depth {
+            branch = json!({ "category": "vendor", "name": "Vendor", "branches": [branch] });
+        }
+        json!([branch])
+    }
+
+    #[test]
+    fn test_limits() {
+        let limits = Limits::default();
+        assert!(from_slice_with_limits(&to_bytes(|_| {}), &limits).is_ok());
+
+        let huge_tree = to_bytes(|doc| {
+            let products: Vec<Value> = (0..=limits.max_product_ids)
+                .map(|i| json!({ "name": format!("Product {}", i), "product_id": format!("CSAFPID-{}", i) }))
+                .collect();
+            doc["product_tree"] = json!({ "full_product_names": products });
+        });
+        assert!(matches!(
+            from_slice_with_limits(&huge_tree, &limits),
+            Err(Error::LimitExceeded {
+                limit: Limit::ProductIds,
+                max: 100_000
+            })
+        ));
+
+        let small = Limits {
+            max_document_bytes: 1024,
+            ..Limits::default()
+        };
+        assert!(matches!(
+            from_slice_with_limits(&huge_tree, &small),
+            Err(Error::LimitExceeded {
+                limit: Limit::DocumentBytes,
+                max: 1024
+            })
+        ));
+
+        let many_vulnerabilities = to_bytes(|doc| doc["vulnerabilities"] = json!(vec![json!({}); 3]));
+        let few = Limits {
+            max_vulnerabilities: 2,
+            ..Limits::default()
+        };
+        assert!(matches!(
+            from_slice_with_limits(&many_vulnerabilities, &few),
+            Err(Error::LimitExceeded {
+                limit: Limit::Vulnerabilities,
+                max: 2
+            })
+        ));
+
+        // Documents failing 6.1.34 are loaded by default
+        let deep = to_bytes(|doc| doc["product_tree"] = json!({ "branches": nested_branches(40) }));
+        assert!(from_slice_with_limits(&deep, &limits).is_ok());
+        let shallow = Limits {
+            max_branch_depth: MAX_DEPTH as usize,
+            ..Limits::default()
+        };
+        let allowed = to_bytes(|doc| doc["product_tree"] = json!({ "branches": nested_branches(30) }));
+        assert!(from_slice_with_limits(&allowed, &shallow).is_ok());
+        let error = from_slice_with_limits(&deep, &shallow).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::LimitExceeded {
+                limit: Limit::BranchDepth,
+                max: 30
+            }
+        ));
+        assert_eq!(error.to_string(), "Document exceeds the limit of 30 levels of branches");
+
+        let too_deep = to_bytes(|doc| doc["product_tree"] = json!({ "branches": nested_branches(51) }));
+        assert!(matches!(
+            from_slice_with_limits(&too_deep, &limits),
+            Err(Error::LimitExceeded {
+                limit: Limit::BranchDepth,
+                max: 50
+            })
+        ));
+    }
+}
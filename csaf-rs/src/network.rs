@@ -0,0 +1,215 @@
+//! Opt-in reachability checks of URLs in a document
+//!
+//! All other checks of this crate are offline and deterministic. The functions of this module
+//! issue HEAD requests and are therefore only available with the `network` feature and never run
+//! as part of a validation preset.
+//!
+//! The HTTP client is provided by the caller, so that timeouts, proxies and TLS settings stay
+//! under their control. [HttpClient] is implemented for `reqwest::blocking::Client`, which is
+//! built without TLS support by this crate; enable a TLS feature of `reqwest`, e.g. `rustls-tls`,
+//! to check `https` URLs. Other clients only have to return the status code of a HEAD request.
+
+use crate::csaf_traits::{CsafTraitExt, DocumentReferenceTrait, DocumentTrait, RemediationTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::CategoryOfReference;
+use crate::validation::{Severity, ValidationError};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// The code of findings reported by [check_reference_reachability]
+pub static REACHABILITY_CODE: &str = "reachability";
+
+/// A blocking HTTP client able to issue HEAD requests
+pub trait HttpClient {
+    /// Issues a HEAD request for `url` and returns the HTTP status code of the response, or a
+    /// description of why no response was received.
+    fn head(&self, url: &str) -> Result<u16, String>;
+}
+
+impl HttpClient for reqwest::blocking::Client {
+    fn head(&self, url: &str) -> Result<u16, String> {
+        self.head(url)
+            .send()
+            .map(|response| response.status().as_u16())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Checks that the `self` references and remediation URLs of `doc` are reachable.
+///
+/// Each distinct URL is requested once, with at least `min_interval` between two requests. URLs
+/// that cannot be reached or respond with a status other than 2xx are reported as warnings with
+/// code [REACHABILITY_CODE], at every place they occur.
+pub fn check_reference_reachability(
+    doc: &impl CsafTraitExt,
+    client: &impl HttpClient,
+    min_interval: Duration,
+) -> Vec<ValidationError> {
+    let mut urls: Vec<(&str, String)> = Vec::new();
+    for (r_i, reference) in doc.get_document().get_references().into_iter().flatten().enumerate() {
+        if *reference.get_category() == CategoryOfReference::Self_ {
+            urls.push((reference.get_url(), format!("/document/references/{}/url", r_i)));
+        }
+    }
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (r_i, remediation) in v.get_remediations().iter().enumerate() {
+            if let Some(url) = remediation.get_url() {
+                urls.push((url, format!("/vulnerabilities/{}/remediations/{}/url", v_i, r_i)));
+            }
+        }
+    }
+
+    let mut responses: HashMap<&str, Result<u16, String>> = HashMap::new();
+    let mut last_request: Option<Instant> = None;
+    let mut errors = Vec::new();
+    for (url, path) in urls {
+        let response = responses.entry(url).or_insert_with(|| {
+            if let Some(elapsed) = last_request.map(|t| t.elapsed()) {
+                std::thread::sleep(min_interval.saturating_sub(elapsed));
+            }
+            last_request = Some(Instant::now());
+            client.head(url)
+        });
+        let message = match response {
+            Ok(status) if (200..300).contains(status) => continue,
+            Ok(status) => format!("URL {} responded with HTTP status {}", url, status),
+            Err(e) => format!("URL {} is not reachable: {}", url, e),
+        };
+        errors.push(ValidationError::new(REACHABILITY_CODE, message, path).with_severity(Severity::Warning));
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::network::{HttpClient, check_reference_reachability};
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::{Severity, ValidationError};
+    use serde_json::json;
+    use std::cell::RefCell;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    /// Returns canned responses and records the requested URLs
+    struct MockClient {
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl HttpClient for MockClient {
+        fn head(&self, url: &str) -> Result<u16, String> {
+            self.requests.borrow_mut().push(url.to_string());
+            match url {
+                "https://example.com/advisory" | "https://example.com/fix" => Ok(200),
+                "https://example.com/moved" => Ok(404),
+                _ => Err("connection refused".to_string()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_reference_reachability() {
+        let doc = mock_csaf21(|doc| {
+            doc["document"]["references"] = json!([
+                { "category": "self", "summary": "Advisory", "url": "https://example.com/advisory" },
+                { "category": "external", "summary": "Blog", "url": "https://unreachable.example.com/blog" }
+            ]);
+            doc["vulnerabilities"] = json!([{
+                "remediations": [
+                    { "category": "vendor_fix", "details": "Update.", "product_ids": ["CSAFPID-1"], "url": "https://example.com/moved" },
+                    { "category": "vendor_fix", "details": "Update.", "product_ids": ["CSAFPID-2"], "url": "https://example.com/fix" },
+                    { "category": "mitigation", "details": "Block.", "product_ids": ["CSAFPID-1"], "url": "https://unreachable.example.com/fix" },
+                    { "category": "mitigation", "details": "Block.", "product_ids": ["CSAFPID-2"], "url": "https://example.com/moved" }
+                ]
+            }]);
+        });
+        let client = MockClient {
+            requests: RefCell::new(Vec::new()),
+        };
+
+        let errors = check_reference_reachability(&doc, &client, Duration::ZERO);
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::new(
+                    "reachability",
+                    "URL https://example.com/moved responded with HTTP status 404",
+                    "/vulnerabilities/0/remediations/0/url",
+                )
+                .with_severity(Severity::Warning),
+                ValidationError::new(
+                    "reachability",
+                    "URL https://unreachable.example.com/fix is not reachable: connection refused",
+                    "/vulnerabilities/0/remediations/2/url",
+                )
+                .with_severity(Severity::Warning),
+                ValidationError::new(
+                    "reachability",
+                    "URL https://example.com/moved responded with HTTP status 404",
+                    "/vulnerabilities/0/remediations/3/url",
+                )
+                .with_severity(Severity::Warning),
+            ]
+        );
+        // External references are not checked, and each URL is requested only once
+        assert_eq!(
+            *client.requests.borrow(),
+            vec![
+                "https://example.com/advisory",
+                "https://example.com/moved",
+                "https://example.com/fix",
+                "https://unreachable.example.com/fix",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_reference_reachability_reqwest() {
+        // Answers HEAD requests for /advisory with 200 and all others with 404
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            for _ in 0..2 {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request_line = String::new();
+                BufReader::new(&stream).read_line(&mut request_line).unwrap();
+                let status = match request_line.starts_with("HEAD /advisory ") {
+                    true => "200 OK",
+                    false => "404 Not Found",
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                )
+                .unwrap();
+            }
+        });
+
+        let doc = mock_csaf21(|doc| {
+            doc["document"]["references"] =
+                json!([{ "category": "self", "summary": "Advisory", "url": format!("{}/advisory", base) }]);
+            doc["vulnerabilities"] = json!([{
+                "remediations": [
+                    { "category": "vendor_fix", "details": "Update.", "product_ids": ["CSAFPID-1"], "url": format!("{}/fix", base) }
+                ]
+            }]);
+        });
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            check_reference_reachability(&doc, &client, Duration::ZERO),
+            vec![
+                ValidationError::new(
+                    "reachability",
+                    format!("URL {}/fix responded with HTTP status 404", base),
+                    "/vulnerabilities/0/remediations/0/url",
+                )
+                .with_severity(Severity::Warning)
+            ]
+        );
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,635 @@
+//! Lenient parsing of partially broken documents
+//!
+//! A single malformed field, e.g. a date that does not match the schema, makes strict parsing of
+//! the whole document fail. [from_slice_lenient] instead removes each offending value, records a
+//! [ValidationError] with code `parse` for its path, and continues, so that all other tests can
+//! still be executed on the remaining document. Unknown product status categories are reported
+//! by test 6.1.52 instead, which is executed on the raw document before it is deserialized.
+//!
+//! [from_slice_strict] goes the other way: serde ignores unknown fields of types that do not
+//! reject them, e.g. all types of CSAF 2.0, so that a misspelled key like `prodcut_status` and
+//! its data are lost silently. Strict parsing reports each unknown field as a [ValidationError]
+//! with code `unknown_field` instead.
+
+use crate::error::Error;
+use crate::limits::Limits;
+use crate::validation::TestResultStatus::Failure;
+use crate::validation::{SourceLocation, ValidationError, ValidationResult};
+use crate::validations::test_6_1_52::strip_unknown_product_status_keys;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+
+/// The code of validation errors recorded during lenient parsing
+pub static PARSE_ERROR_CODE: &str = "parse";
+
+/// The code of validation errors recorded for unknown fields during strict parsing
+pub static UNKNOWN_FIELD_CODE: &str = "unknown_field";
+
+/// A document that could not be parsed, not even leniently
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Why the document could not be parsed
+    pub message: String,
+    /// The JSON pointer of the value that could not be recovered, if the input was valid JSON
+    pub instance_path: Option<String>,
+    /// The position within the input, if the input was not valid JSON
+    pub location: Option<SourceLocation>,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.instance_path, &self.location) {
+            (Some(path), _) => write!(f, "{} at {}", self.message, path),
+            (None, Some(location)) => write!(
+                f,
+                "{} at line {} column {}",
+                self.message, location.line, location.column
+            ),
+            (None, None) => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A document parsed by [from_slice_lenient_with_paths]
+#[derive(Debug, Clone)]
+pub struct LenientDocument<T> {
+    /// The document without the values that failed to deserialize
+    pub doc: T,
+    /// The findings for the removed values, at their JSON pointer in the input
+    pub errors: Vec<ValidationError>,
+    /// The removed array items, in order of removal
+    removals: Vec<Removal>,
+}
+
+/// An array item removed during lenient parsing
+#[derive(Debug, Clone)]
+struct Removal {
+    /// The JSON pointer of the array at the time of removal
+    array: String,
+    /// The index of the item at the time of removal
+    index: usize,
+}
+
+impl<T> LenientDocument<T> {
+    /// Returns the JSON pointer in the input of the value at `path` in [LenientDocument::doc].
+    ///
+    /// The paths differ after array items were removed, e.g. `/vulnerabilities/1` refers to the
+    /// third vulnerability of the input if the first one was removed.
+    pub fn original_path(&self, path: &str) -> String {
+        original_path(&self.removals, path)
+    }
+
+    /// Maps the instance paths of the findings of a validation of [LenientDocument::doc] to the
+    /// input, see [LenientDocument::original_path].
+    pub fn map_result(&self, result: &mut ValidationResult) {
+        for test_result in result.test_results.iter_mut() {
+            if let Failure { errors } = &mut test_result.status {
+                for error in errors.iter_mut() {
+                    error.instance_path = self.original_path(&error.instance_path);
+                }
+            }
+        }
+    }
+}
+
+/// Parses a document, removing every value that fails to deserialize.
+///
+/// Each removed value is reported as a [ValidationError] with code [PARSE_ERROR_CODE] at its
+/// JSON pointer in the input. Removing an optional property makes it `None`, removing an array
+/// element drops it, as lists have no placeholder for a missing item. If a value cannot be
+/// removed without breaking its parent (e.g. a required property), the parent is removed
+/// instead. A [ParseError] is returned if the input is not valid JSON, or if the document itself
+/// cannot be recovered.
+///
+/// Unknown categories in `product_status` are removed as well, and reported with the findings
+/// of [test 6.1.52](crate::validations::test_6_1_52::test_6_1_52_product_status_keys).
+///
+/// The indices of the remaining array items of the document differ from the input once an item
+/// was dropped. Use [from_slice_lenient_with_paths] to map the findings of later tests back.
+pub fn from_slice_lenient<T: DeserializeOwned>(bytes: &[u8]) -> Result<(T, Vec<ValidationError>), ParseError> {
+    from_slice_lenient_with_paths(bytes).map(|lenient| (lenient.doc, lenient.errors))
+}
+
+/// Like [from_slice_lenient], but keeps track of the removed array items, so that paths within
+/// the parsed document can be mapped to the input with [LenientDocument::original_path].
+pub fn from_slice_lenient_with_paths<T: DeserializeOwned>(bytes: &[u8]) -> Result<LenientDocument<T>, ParseError> {
+    let mut value = parse_json(bytes)?;
+
+    let mut errors = strip_unknown_product_status_keys(&mut value).err().unwrap_or_default();
+    let mut removals = Vec::new();
+    loop {
+        // Deserialize from a rendering with known offsets, so that the position of an error
+        // identifies the offending value.
+        let mut text = String::new();
+        let mut spans = Vec::new();
+        render(&value, "", &mut text, &mut spans);
+
+        let error = match serde_json::from_str::<T>(&text) {
+            Ok(doc) => return Ok(LenientDocument { doc, errors, removals }),
+            Err(e) => e,
+        };
+        let message = strip_position(&error);
+        let path = locate(&spans, error.column().saturating_sub(1), &message);
+        let removed = match path.is_empty() {
+            true => None,
+            false => remove_pointer(&mut value, &path),
+        };
+        let Some(removal) = removed else {
+            return Err(ParseError {
+                message,
+                instance_path: Some(original_path(&removals, &path)),
+                location: None,
+            });
+        };
+        errors.push(ValidationError::new(
+            PARSE_ERROR_CODE,
+            message,
+            original_path(&removals, &path),
+        ));
+        removals.extend(removal);
+    }
+}
+
+/// Maps a JSON pointer within a document to the JSON pointer before `removals` were applied.
+fn original_path(removals: &[Removal], path: &str) -> String {
+    let mut path = path.to_string();
+    for removal in removals.iter().rev() {
+        let Some(rest) = path
+            .strip_prefix(removal.array.as_str())
+            .and_then(|rest| rest.strip_prefix('/'))
+        else {
+            continue;
+        };
+        let (token, tail) = rest.find('/').map_or((rest, ""), |i| rest.split_at(i));
+        if let Ok(index) = token.parse::<usize>() {
+            if index >= removal.index {
+                path = format!("{}/{}{}", removal.array, index + 1, tail);
+            }
+        }
+    }
+    path
+}
+
+/// Parses a document, reporting every unknown field.
+///
+/// Unknown fields rejected by their type are removed before deserializing again. Unknown fields
+/// ignored by their type are found by serializing the parsed document and comparing it with the
+/// input. Both are reported as a [ValidationError] with code [UNKNOWN_FIELD_CODE] at their JSON
+/// pointer. Unknown fields with a `null` or empty value are not reported, as they cannot be told
+/// apart from empty known fields. Any other deserialization error fails with a [ParseError].
+pub fn from_slice_strict<T: DeserializeOwned + Serialize>(
+    bytes: &[u8],
+) -> Result<(T, Vec<ValidationError>), ParseError> {
+    let mut value = parse_json(bytes)?;
+
+    let mut errors = Vec::new();
+    let doc = loop {
+        let mut text = String::new();
+        let mut spans = Vec::new();
+        render(&value, "", &mut text, &mut spans);
+
+        let error = match serde_json::from_str::<T>(&text) {
+            Ok(doc) => break doc,
+            Err(e) => e,
+        };
+        let message = strip_position(&error);
+        let path = locate(&spans, error.column().saturating_sub(1), &message);
+        if !message.starts_with("unknown field") || remove_pointer(&mut value, &path).is_none() {
+            return Err(ParseError {
+                message,
+                instance_path: Some(path),
+                location: None,
+            });
+        }
+        errors.push(unknown_field(path));
+    };
+
+    let parsed = serde_json::to_value(&doc).map_err(|e| ParseError {
+        message: strip_position(&e),
+        instance_path: None,
+        location: None,
+    })?;
+    find_dropped_fields(&value, &parsed, "", &mut errors);
+    Ok((doc, errors))
+}
+
+/// Records the members of `input` that are missing from its deserialized and serialized again
+/// counterpart `parsed`.
+fn find_dropped_fields(input: &Value, parsed: &Value, path: &str, errors: &mut Vec<ValidationError>) {
+    match (input, parsed) {
+        (Value::Object(input), Value::Object(parsed)) => {
+            for (key, value) in input {
+                let child = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+                match parsed.get(key) {
+                    Some(parsed_value) => find_dropped_fields(value, parsed_value, &child, errors),
+                    None if !is_empty(value) => errors.push(unknown_field(child)),
+                    None => {},
+                }
+            }
+        },
+        (Value::Array(input), Value::Array(parsed)) if input.len() == parsed.len() => {
+            for (i, (value, parsed_value)) in input.iter().zip(parsed).enumerate() {
+                find_dropped_fields(value, parsed_value, &format!("{}/{}", path, i), errors);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Returns whether `value` may have been dropped when serializing an empty known field.
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
+fn unknown_field(path: String) -> ValidationError {
+    let key = path.rsplit('/').next().unwrap_or_default();
+    let message = format!("Unknown field '{}'", key.replace("~1", "/").replace("~0", "~"));
+    ValidationError::new(UNKNOWN_FIELD_CODE, message, path)
+}
+
+/// Deserializes a document of CSAF `version` from JSON bytes.
+///
+/// Fails with [Error::UnsupportedVersion] if the document declares a different CSAF version.
+pub(crate) fn from_slice_versioned<T: DeserializeOwned>(bytes: &[u8], version: &str) -> Result<T, Error> {
+    from_value_versioned(serde_json::from_slice(bytes)?, version)
+}
+
+/// Like [from_slice_versioned], but fails with [Error::LimitExceeded] before deserializing a
+/// document that exceeds `limits`.
+pub(crate) fn from_slice_versioned_with_limits<T: DeserializeOwned>(
+    bytes: &[u8],
+    version: &str,
+    limits: &Limits,
+) -> Result<T, Error> {
+    limits.check_bytes(bytes)?;
+    let value: Value = serde_json::from_slice(bytes)?;
+    limits.check_value(&value)?;
+    from_value_versioned(value, version)
+}
+
+/// Deserializes a document of CSAF `version` from a JSON value.
+///
+/// A value that does not deserialize fails with an [Error::Parse] whose message ends with the
+/// JSON pointer of the offending value, e.g. `invalid type: integer `1`, expected a string at
+/// /document/tracking/version`, as a value has no line and column to report.
+pub(crate) fn from_value_versioned<T: DeserializeOwned>(value: Value, version: &str) -> Result<T, Error> {
+    check_version(&value, version)?;
+    if let Ok(doc) = T::deserialize(&value) {
+        return Ok(doc);
+    }
+
+    // Deserialize again from a rendering with known offsets to locate the error
+    let mut text = String::new();
+    let mut spans = Vec::new();
+    render(&value, "", &mut text, &mut spans);
+    let error = match serde_json::from_str::<T>(&text) {
+        Ok(doc) => return Ok(doc),
+        Err(e) => e,
+    };
+    let message = strip_position(&error);
+    let path = locate(&spans, error.column().saturating_sub(1), &message);
+    Err(Error::Parse(serde::de::Error::custom(if path.is_empty() {
+        message
+    } else {
+        format!("{} at {}", message, path)
+    })))
+}
+
+/// Like [from_slice_versioned], but reports all schema violations as [Error::Schema] instead of
+/// only the first one.
+pub(crate) fn schema_validate_versioned<T: DeserializeOwned>(bytes: &[u8], version: &str) -> Result<T, Error> {
+    let value: Value = serde_json::from_slice(bytes)?;
+    check_version(&value, version)?;
+    match from_slice_lenient::<T>(bytes) {
+        Ok((doc, errors)) if errors.is_empty() => Ok(doc),
+        Ok((_, errors)) => Err(Error::Schema(errors)),
+        Err(e) => Err(Error::Schema(vec![ValidationError::new(
+            PARSE_ERROR_CODE,
+            e.message,
+            e.instance_path.unwrap_or_default(),
+        )])),
+    }
+}
+
+/// Returns an error if the document declares a CSAF version other than `expected`.
+///
+/// A missing version is left to deserialization, which reports it as a missing field.
+fn check_version(value: &Value, expected: &str) -> Result<(), Error> {
+    match value.pointer("/document/csaf_version").and_then(Value::as_str) {
+        Some(version) if version != expected => Err(Error::UnsupportedVersion(version.to_string())),
+        _ => Ok(()),
+    }
+}
+
+/// Parses JSON bytes into a value, failing with the position of the first syntax error.
+fn parse_json(bytes: &[u8]) -> Result<Value, ParseError> {
+    serde_json::from_slice(bytes).map_err(|e| ParseError {
+        message: format!("Invalid JSON: {}", strip_position(&e)),
+        instance_path: None,
+        location: Some(SourceLocation {
+            line: e.line(),
+            column: e.column(),
+        }),
+    })
+}
+
+/// Returns the message of a serde error without its "at line X column Y" suffix.
+fn strip_position(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    match message.rfind(" at line ") {
+        Some(i) => message[..i].to_string(),
+        None => message,
+    }
+}
+
+/// A key or value of the rendered document
+struct Span {
+    /// Byte offset of the last character
+    end: usize,
+    /// JSON pointer of the member or value
+    path: String,
+    /// For non-empty containers, whether their last child is a scalar
+    last_child_scalar: Option<bool>,
+}
+
+/// Writes `value` as compact JSON, recording the end offset of every key and value.
+fn render(value: &Value, path: &str, text: &mut String, spans: &mut Vec<Span>) {
+    let children: Vec<(String, Option<&String>, &Value)> = match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| {
+                (
+                    format!("{}/{}", path, k.replace('~', "~0").replace('/', "~1")),
+                    Some(k),
+                    v,
+                )
+            })
+            .collect(),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("{}/{}", path, i), None, v))
+            .collect(),
+        scalar => {
+            text.push_str(&scalar.to_string());
+            spans.push(Span {
+                end: text.len() - 1,
+                path: path.to_string(),
+                last_child_scalar: None,
+            });
+            return;
+        },
+    };
+
+    let (open, close) = if value.is_object() { ('{', '}') } else { ('[', ']') };
+    text.push(open);
+    for (i, (child_path, key, child)) in children.iter().enumerate() {
+        if i > 0 {
+            text.push(',');
+        }
+        if let Some(key) = key {
+            text.push_str(&Value::String(key.to_string()).to_string());
+            spans.push(Span {
+                end: text.len() - 1,
+                path: child_path.clone(),
+                last_child_scalar: None,
+            });
+            text.push(':');
+        }
+        render(child, child_path, text, spans);
+    }
+    text.push(close);
+    spans.push(Span {
+        end: text.len() - 1,
+        path: path.to_string(),
+        last_child_scalar: children.last().map(|(_, _, v)| !v.is_object() && !v.is_array()),
+    });
+}
+
+/// Returns the JSON pointer of the value a deserialization error at `offset` refers to.
+///
+/// serde reports errors right after the offending key or value, except for custom errors (e.g. a
+/// string not matching its pattern) of the last member of an object or array, which are reported
+/// after the closing bracket of their container.
+fn locate(spans: &[Span], offset: usize, message: &str) -> String {
+    let Some(i) = spans.iter().position(|s| s.end == offset) else {
+        return String::new();
+    };
+    match spans[i].last_child_scalar {
+        // The span before a container is the one of its last child
+        Some(true) if !message.starts_with("missing field") => spans[i - 1].path.clone(),
+        _ => spans[i].path.clone(),
+    }
+}
+
+/// Removes the value at a JSON pointer from its parent object or array.
+///
+/// Returns `None` if there is no such value, and the [Removal] if the value was an array item.
+fn remove_pointer(value: &mut Value, pointer: &str) -> Option<Option<Removal>> {
+    let (parent, token) = pointer.rsplit_once('/')?;
+    let token = token.replace("~1", "/").replace("~0", "~");
+    match value.pointer_mut(parent)? {
+        Value::Object(map) => map.remove(&token).map(|_| None),
+        Value::Array(items) => match token.parse::<usize>() {
+            Ok(index) if index < items.len() => {
+                items.remove(index);
+                Some(Some(Removal {
+                    array: parent.to_string(),
+                    index,
+                }))
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{ParseError, from_slice_lenient, from_slice_lenient_with_paths, from_slice_strict};
+    use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+    use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::SourceLocation;
+    use crate::validation::TestResultStatus::Failure;
+    use crate::validation::ValidationError;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_slice_lenient() {
+        let mut doc = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        doc["document"]["lang"] = json!(42);
+        doc["vulnerabilities"] = json!([{ "cve": "CVE-2024-0001", "unknown": true }, { "cve": "not a cve" }]);
+        let bytes = serde_json::to_vec(&doc).unwrap();
+
+        let (parsed, errors) = from_slice_lenient::<CommonSecurityAdvisoryFramework>(&bytes).unwrap();
+        assert_eq!(
+            errors.iter().map(|e| e.instance_path.as_str()).collect::<Vec<_>>(),
+            vec!["/document/lang", "/vulnerabilities/0/unknown", "/vulnerabilities/1/cve"]
+        );
+        assert!(
+            errors
+                .iter()
+                .all(|e| e.code == "parse" && !e.message.contains(" at line "))
+        );
+        assert_eq!(parsed.document.lang, None);
+        assert_eq!(parsed.vulnerabilities.len(), 2);
+        assert!(parsed.vulnerabilities[0].cve.is_some());
+        assert_eq!(parsed.vulnerabilities[1].cve, None);
+
+        let (_, errors) =
+            from_slice_lenient::<CommonSecurityAdvisoryFramework>(&serde_json::to_vec(&mock_csaf21(|_| {})).unwrap())
+                .unwrap();
+        assert_eq!(errors, vec![]);
+    }
+
+    #[test]
+    fn test_from_slice_lenient_array_items() {
+        let cwe = |id: &str| json!({ "id": id, "name": "Weakness", "version": "4.13" });
+        let mut doc = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        doc["vulnerabilities"] = json!([
+            1,
+            "not a vulnerability",
+            { "cwes": [cwe("CWE-79"), cwe("CWE-79")] },
+            { "cve": "not a cve" }
+        ]);
+        let bytes = serde_json::to_vec(&doc).unwrap();
+
+        let lenient = from_slice_lenient_with_paths::<CommonSecurityAdvisoryFramework>(&bytes).unwrap();
+        assert_eq!(
+            lenient
+                .errors
+                .iter()
+                .map(|e| e.instance_path.as_str())
+                .collect::<Vec<_>>(),
+            vec!["/vulnerabilities/0", "/vulnerabilities/1", "/vulnerabilities/3/cve"]
+        );
+        assert_eq!(lenient.doc.vulnerabilities.len(), 2);
+        assert_eq!(
+            lenient.original_path("/vulnerabilities/0/cwes/1"),
+            "/vulnerabilities/2/cwes/1"
+        );
+        assert_eq!(lenient.original_path("/vulnerabilities/1"), "/vulnerabilities/3");
+        assert_eq!(lenient.original_path("/document/notes/0"), "/document/notes/0");
+
+        // Findings of later tests refer to the input as well
+        let mut result = lenient.doc.validate();
+        lenient.map_result(&mut result);
+        let paths: Vec<&str> = result
+            .test_results
+            .iter()
+            .filter(|r| r.test_id == "6.1.91")
+            .flat_map(|r| match &r.status {
+                Failure { errors } => errors.iter().map(|e| e.instance_path.as_str()).collect(),
+                _ => Vec::new(),
+            })
+            .collect();
+        assert_eq!(paths, vec!["/vulnerabilities/2/cwes/1"]);
+
+        let (_, errors) = from_slice_lenient::<CommonSecurityAdvisoryFramework>(&bytes).unwrap();
+        assert_eq!(errors, lenient.errors);
+    }
+
+    #[test]
+    fn test_from_slice_lenient_product_status_keys() {
+        let mut doc = serde_json::to_value(mock_csaf20(|_| {})).unwrap();
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-0001" }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "product_status": { "Known_Affected": ["CSAFPID-0001"], "fixed": ["CSAFPID-0001"] }
+        }]);
+        let bytes = serde_json::to_vec(&doc).unwrap();
+
+        // CSAF 2.0 types would drop the unknown category silently
+        let (parsed, errors) = from_slice_lenient::<Csaf20>(&bytes).unwrap();
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.code.as_str(), e.instance_path.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("6.1.52", "/vulnerabilities/0/product_status")]
+        );
+        assert!(parsed.vulnerabilities[0].product_status.is_some());
+
+        // CSAF 2.1 types would reject it as an unknown field
+        let mut doc_21 = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        doc_21["product_tree"] = doc["product_tree"].clone();
+        doc_21["vulnerabilities"] = doc["vulnerabilities"].clone();
+        let (parsed, errors) =
+            from_slice_lenient::<CommonSecurityAdvisoryFramework>(&serde_json::to_vec(&doc_21).unwrap()).unwrap();
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.code.as_str(), e.instance_path.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("6.1.52", "/vulnerabilities/0/product_status")]
+        );
+        assert!(parsed.vulnerabilities[0].product_status.is_some());
+    }
+
+    #[test]
+    fn test_from_slice_lenient_fails() {
+        assert_eq!(
+            Err(ParseError {
+                message: "Invalid JSON: EOF while parsing an object".to_string(),
+                instance_path: None,
+                location: Some(SourceLocation { line: 1, column: 8 }),
+            }),
+            from_slice_lenient::<CommonSecurityAdvisoryFramework>(b"{\"a\": 1 ").map(|_| ())
+        );
+
+        let result = from_slice_lenient::<CommonSecurityAdvisoryFramework>(br#"{"document": 1}"#);
+        assert_eq!(result.map(|_| ()).unwrap_err().instance_path, Some(String::new()));
+    }
+
+    #[test]
+    fn test_from_slice_strict() {
+        let misspelled = |doc: &mut serde_json::Value| {
+            doc["vulnerabilities"] = json!([{
+                "cve": "CVE-2024-0001",
+                "prodcut_status": { "known_affected": ["CSAFPID-0001"] },
+                "notes": []
+            }]);
+        };
+        let expected = vec![ValidationError::new(
+            "unknown_field",
+            "Unknown field 'prodcut_status'",
+            "/vulnerabilities/0/prodcut_status",
+        )];
+
+        // CSAF 2.0 types ignore unknown fields
+        let bytes = serde_json::to_vec(&mock_csaf20(|_| {})).unwrap();
+        let mut doc: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        misspelled(&mut doc);
+        let (parsed, errors) = from_slice_strict::<Csaf20>(&serde_json::to_vec(&doc).unwrap()).unwrap();
+        assert_eq!(expected, errors);
+        assert_eq!(parsed.vulnerabilities[0].product_status, None);
+
+        // CSAF 2.1 types reject them
+        let mut doc = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        misspelled(&mut doc);
+        let (_, errors) =
+            from_slice_strict::<CommonSecurityAdvisoryFramework>(&serde_json::to_vec(&doc).unwrap()).unwrap();
+        assert_eq!(expected, errors);
+
+        let (_, errors) = from_slice_strict::<Csaf20>(&bytes).unwrap();
+        assert_eq!(errors, vec![]);
+
+        doc["document"]["lang"] = json!(42);
+        let result = from_slice_strict::<CommonSecurityAdvisoryFramework>(&serde_json::to_vec(&doc).unwrap());
+        assert_eq!(
+            result.map(|_| ()).unwrap_err().instance_path.as_deref(),
+            Some("/document/lang")
+        );
+    }
+}
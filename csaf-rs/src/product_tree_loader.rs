@@ -0,0 +1,128 @@
+//! Resolution of external product trees
+//!
+//! Some tools store the product tree of a document in a separate file. Such documents mark their
+//! product tree as external with a `$ref` property, e.g. `"product_tree": {"$ref": "tree.json"}`.
+//! Before such a document can be parsed, the reference has to be resolved by a [ProductTreeLoader]
+//! and merged into the document with [resolve_external_product_tree].
+
+use serde_json::{Map, Value};
+
+/// The property marking a product tree as external
+pub static EXTERNAL_REF: &str = "$ref";
+
+/// Product tree properties which are lists, and therefore merged by concatenation
+static LIST_PROPERTIES: &[&str] = &["branches", "full_product_names", "product_groups", "relationships"];
+
+/// Loads external product trees referenced by a document.
+///
+/// Implement this trait to resolve references, e.g. from the file system or via HTTP. The default
+/// implementation does not resolve any reference.
+pub trait ProductTreeLoader {
+    /// Returns the product tree referenced by `reference`, or `None` if it cannot be resolved.
+    fn load(&self, reference: &str) -> Option<Value> {
+        let _ = reference;
+        None
+    }
+}
+
+/// A [ProductTreeLoader] that does not resolve any external product tree
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoExternalProductTree;
+
+impl ProductTreeLoader for NoExternalProductTree {}
+
+/// Replaces an external product tree in `doc` by the product tree returned by `loader`.
+///
+/// Properties defined inline next to `$ref` are kept. If both the inline and the loaded product
+/// tree contain the same list (e.g. `full_product_names`), the loaded entries are appended to the
+/// inline ones. Documents without an external product tree are left untouched.
+pub fn resolve_external_product_tree(doc: &mut Value, loader: &impl ProductTreeLoader) -> Result<(), String> {
+    let Some(product_tree) = doc.get_mut("product_tree").and_then(Value::as_object_mut) else {
+        return Ok(());
+    };
+    let Some(reference) = product_tree.remove(EXTERNAL_REF) else {
+        return Ok(());
+    };
+    let reference = reference
+        .as_str()
+        .ok_or_else(|| format!("The {} of the product tree must be a string", EXTERNAL_REF))?;
+
+    let loaded = loader
+        .load(reference)
+        .ok_or_else(|| format!("Could not resolve external product tree '{}'", reference))?;
+    let Value::Object(loaded) = loaded else {
+        return Err(format!("External product tree '{}' is not a JSON object", reference));
+    };
+
+    merge_product_tree(product_tree, loaded);
+    Ok(())
+}
+
+fn merge_product_tree(product_tree: &mut Map<String, Value>, loaded: Map<String, Value>) {
+    for (key, value) in loaded {
+        match (product_tree.get_mut(&key), value) {
+            (Some(Value::Array(existing)), Value::Array(additional)) if LIST_PROPERTIES.contains(&key.as_str()) => {
+                existing.extend(additional);
+            },
+            (Some(_), _) => {
+                // Inline properties take precedence
+            },
+            (None, value) => {
+                product_tree.insert(key, value);
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::product_tree_loader::{NoExternalProductTree, ProductTreeLoader, resolve_external_product_tree};
+    use serde_json::{Value, json};
+
+    struct StaticLoader;
+
+    impl ProductTreeLoader for StaticLoader {
+        fn load(&self, reference: &str) -> Option<Value> {
+            (reference == "tree.json").then(|| {
+                json!({
+                    "full_product_names": [{ "name": "Product B", "product_id": "CSAFPID-9080701" }],
+                    "product_groups": [{ "group_id": "CSAFGID-1020300", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] }]
+                })
+            })
+        }
+    }
+
+    #[test]
+    fn test_resolve_external_product_tree() {
+        let mut doc = json!({
+            "product_tree": {
+                "$ref": "tree.json",
+                "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+            }
+        });
+        resolve_external_product_tree(&mut doc, &StaticLoader).unwrap();
+        assert_eq!(
+            doc,
+            json!({
+                "product_tree": {
+                    "full_product_names": [
+                        { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                        { "name": "Product B", "product_id": "CSAFPID-9080701" }
+                    ],
+                    "product_groups": [{ "group_id": "CSAFGID-1020300", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] }]
+                }
+            })
+        );
+
+        let mut unresolved = json!({ "product_tree": { "$ref": "tree.json" } });
+        assert_eq!(
+            Err("Could not resolve external product tree 'tree.json'".to_string()),
+            resolve_external_product_tree(&mut unresolved, &NoExternalProductTree)
+        );
+
+        let mut inline = json!({ "product_tree": { "full_product_names": [] } });
+        let expected = inline.clone();
+        resolve_external_product_tree(&mut inline, &NoExternalProductTree).unwrap();
+        assert_eq!(expected, inline);
+    }
+}
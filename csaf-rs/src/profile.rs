@@ -0,0 +1,290 @@
+//! Profile requirements as data
+//!
+//! The value of `/document/category` selects a profile (section 4 of CSAF), and each profile
+//! requires some elements to be present or absent, which is checked by the tests 6.1.27.x. The
+//! simple requirements are modelled as [PresenceRule]s here, so that all of them can be audited
+//! in one place and [validate_profile] checks them with the engine of
+//! [presence](crate::validations::presence). The message of each rule completes the message
+//! "Document with category '...' must ..." of its findings. Requirements spanning several fields
+//! or product sets, i.e. 6.1.27.7 and later, remain dedicated tests.
+
+use crate::csaf_traits::{CsafTraitExt, CsafVersion, DocumentCategory, DocumentTrait};
+use crate::validation::ValidationError;
+use crate::validations::presence::{PresenceRule, Requirement, check_document};
+use serde::Serialize;
+
+/// A document profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// CSAF Base, also used for custom document categories
+    Base,
+    SecurityIncidentResponse,
+    InformationalAdvisory,
+    SecurityAdvisory,
+    Vex,
+    /// Only defined in CSAF 2.1
+    DeprecatedSecurityAdvisory,
+    /// Only defined in CSAF 2.1
+    Withdrawn,
+    /// Only defined in CSAF 2.1
+    Superseded,
+}
+
+const AGGREGATE_SEVERITY: PresenceRule = PresenceRule {
+    code: "6.1.100",
+    path: "/document/aggregate_severity",
+    condition: None,
+    requirement: Requirement::Present,
+    message: "have a '/document/aggregate_severity' element",
+};
+
+const DOCUMENT_NOTES: PresenceRule = PresenceRule {
+    code: "6.1.27.1",
+    path: "/document/notes",
+    condition: None,
+    requirement: Requirement::OneOfCategories(&["description", "details", "general", "summary"]),
+    message: "have at least one document note with category 'description', 'details', 'general' or 'summary'",
+};
+
+const DOCUMENT_REFERENCES: PresenceRule = PresenceRule {
+    code: "6.1.27.2",
+    path: "/document/references",
+    condition: None,
+    requirement: Requirement::OneOfCategories(&["external"]),
+    message: "have at least one reference with category 'external'",
+};
+
+const NO_VULNERABILITIES: PresenceRule = PresenceRule {
+    code: "6.1.27.3",
+    path: "/vulnerabilities",
+    condition: None,
+    requirement: Requirement::Absent,
+    message: "not have a '/vulnerabilities' element",
+};
+
+const PRODUCT_TREE: PresenceRule = PresenceRule {
+    code: "6.1.27.4",
+    path: "/product_tree",
+    condition: None,
+    requirement: Requirement::Present,
+    message: "have a '/product_tree' element",
+};
+
+const VULNERABILITY_NOTES: PresenceRule = PresenceRule {
+    code: "6.1.27.5",
+    path: "/vulnerabilities/*/notes",
+    condition: None,
+    requirement: Requirement::Present,
+    message: "have a notes element in each vulnerability",
+};
+
+const VULNERABILITY_PRODUCT_STATUS: PresenceRule = PresenceRule {
+    code: "6.1.27.6",
+    path: "/vulnerabilities/*/product_status",
+    condition: None,
+    requirement: Requirement::Present,
+    message: "have a product_status element in each vulnerability",
+};
+
+impl Profile {
+    /// Returns the profile of `doc`.
+    ///
+    /// The categories introduced by CSAF 2.1 are custom categories in CSAF 2.0 documents, which
+    /// therefore have the profile [Profile::Base].
+    pub fn of(doc: &impl CsafTraitExt) -> Profile {
+        let document = doc.get_document();
+        let csaf_21 = *document.get_csaf_version() == CsafVersion::X21;
+        match document.get_category() {
+            DocumentCategory::CsafSecurityIncidentResponse => Profile::SecurityIncidentResponse,
+            DocumentCategory::CsafInformationalAdvisory => Profile::InformationalAdvisory,
+            DocumentCategory::CsafSecurityAdvisory => Profile::SecurityAdvisory,
+            DocumentCategory::CsafVex => Profile::Vex,
+            DocumentCategory::CsafDeprecatedSecurityAdvisory if csaf_21 => Profile::DeprecatedSecurityAdvisory,
+            DocumentCategory::CsafWithdrawn if csaf_21 => Profile::Withdrawn,
+            DocumentCategory::CsafSuperseded if csaf_21 => Profile::Superseded,
+            _ => Profile::Base,
+        }
+    }
+
+    /// Returns the single-field requirements of this profile in documents of the given `version`.
+    ///
+    /// Only CSAF 2.1 requires security advisories to have an aggregate severity (6.1.100).
+    pub fn required_fields(&self, version: &CsafVersion) -> &'static [PresenceRule] {
+        match self {
+            Profile::Base => &[],
+            Profile::SecurityIncidentResponse => &[DOCUMENT_NOTES, DOCUMENT_REFERENCES],
+            Profile::InformationalAdvisory => &[DOCUMENT_NOTES, DOCUMENT_REFERENCES, NO_VULNERABILITIES],
+            Profile::SecurityAdvisory if *version == CsafVersion::X20 => {
+                &[PRODUCT_TREE, VULNERABILITY_NOTES, VULNERABILITY_PRODUCT_STATUS]
+            },
+            Profile::SecurityAdvisory => &[
+                PRODUCT_TREE,
+                VULNERABILITY_NOTES,
+                VULNERABILITY_PRODUCT_STATUS,
+                AGGREGATE_SEVERITY,
+            ],
+            Profile::DeprecatedSecurityAdvisory => &[PRODUCT_TREE, VULNERABILITY_NOTES, VULNERABILITY_PRODUCT_STATUS],
+            Profile::Vex => &[PRODUCT_TREE, VULNERABILITY_NOTES],
+            Profile::Withdrawn | Profile::Superseded => &[NO_VULNERABILITIES],
+        }
+    }
+}
+
+/// Evaluates `rules` on `doc` and completes the messages of their findings to "Document with
+/// category '...' must ...".
+fn check_rules<'a>(
+    doc: &(impl CsafTraitExt + Serialize),
+    rules: impl IntoIterator<Item = &'a PresenceRule>,
+) -> Result<(), Vec<ValidationError>> {
+    let rules: Vec<PresenceRule> = rules.into_iter().copied().collect();
+    check_document(doc, &rules).map_err(|mut errors| {
+        let category = doc.get_document().get_category();
+        for error in errors.iter_mut() {
+            error.message = format!("Document with category '{}' must {}", category, error.message);
+        }
+        errors
+    })
+}
+
+/// Checks the requirements of the profile of `doc` with the given `code`.
+///
+/// This is the implementation of the tests 6.1.27.x covered by [Profile::required_fields].
+pub fn check_required_fields(doc: &(impl CsafTraitExt + Serialize), code: &str) -> Result<(), Vec<ValidationError>> {
+    let rules = Profile::of(doc).required_fields(doc.get_document().get_csaf_version());
+    check_rules(doc, rules.iter().filter(|rule| rule.code == code))
+}
+
+/// Checks all requirements of [Profile::required_fields] of the profile and version of `doc`.
+pub fn validate_profile(doc: &(impl CsafTraitExt + Serialize)) -> Result<(), Vec<ValidationError>> {
+    check_rules(
+        doc,
+        Profile::of(doc).required_fields(doc.get_document().get_csaf_version()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf_traits::CsafVersion;
+    use crate::profile::{Profile, check_required_fields, validate_profile};
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use serde_json::json;
+
+    #[test]
+    fn test_profile_of() {
+        let category =
+            |category: &'static str| move |doc: &mut serde_json::Value| doc["document"]["category"] = json!(category);
+        assert_eq!(Profile::Base, Profile::of(&mock_csaf21(|_| {})));
+        assert_eq!(Profile::Vex, Profile::of(&mock_csaf20(category("csaf_vex"))));
+        assert_eq!(
+            Profile::Withdrawn,
+            Profile::of(&mock_csaf21(category("csaf_withdrawn")))
+        );
+        // The categories of CSAF 2.1 are custom categories in CSAF 2.0
+        assert_eq!(Profile::Base, Profile::of(&mock_csaf20(category("csaf_withdrawn"))));
+    }
+
+    #[test]
+    fn test_security_advisory_required_fields() {
+        let codes = |version: CsafVersion| -> Vec<&str> {
+            Profile::SecurityAdvisory
+                .required_fields(&version)
+                .iter()
+                .map(|field| field.code)
+                .collect()
+        };
+        assert_eq!(vec!["6.1.27.4", "6.1.27.5", "6.1.27.6"], codes(CsafVersion::X20));
+        assert_eq!(
+            vec!["6.1.27.4", "6.1.27.5", "6.1.27.6", "6.1.100"],
+            codes(CsafVersion::X21)
+        );
+
+        let valid = mock_csaf21(|doc| {
+            doc["document"]["category"] = json!("csaf_security_advisory");
+            doc["document"]["aggregate_severity"] = json!({ "text": "Critical" });
+            doc["product_tree"] = json!({
+                "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-0001" }]
+            });
+            doc["vulnerabilities"] = json!([{
+                "notes": [{ "category": "summary", "text": "A vulnerability." }],
+                "product_status": { "known_affected": ["CSAFPID-0001"] }
+            }]);
+        });
+        assert_eq!(Ok(()), validate_profile(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["document"]["category"] = json!("csaf_security_advisory");
+            doc["vulnerabilities"] = json!([
+                { "notes": [{ "category": "summary", "text": "A vulnerability." }] },
+                { "title": "Another vulnerability" }
+            ]);
+        });
+        let error = |code: &str, description: &str, path: &str| {
+            ValidationError::new(
+                code,
+                format!("Document with category 'csaf_security_advisory' must {}", description),
+                path,
+            )
+        };
+        assert_eq!(
+            Err(vec![
+                error("6.1.27.4", "have a '/product_tree' element", "/product_tree"),
+                error(
+                    "6.1.27.5",
+                    "have a notes element in each vulnerability",
+                    "/vulnerabilities/1/notes"
+                ),
+                error(
+                    "6.1.27.6",
+                    "have a product_status element in each vulnerability",
+                    "/vulnerabilities/0/product_status"
+                ),
+                error(
+                    "6.1.27.6",
+                    "have a product_status element in each vulnerability",
+                    "/vulnerabilities/1/product_status"
+                ),
+            ]),
+            validate_profile(&invalid)
+        );
+
+        // CSAF 2.0 does not require an aggregate severity
+        let without_severity = |doc: &mut serde_json::Value| {
+            doc["document"]["category"] = json!("csaf_security_advisory");
+            doc["product_tree"] = json!({
+                "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-0001" }]
+            });
+        };
+        assert_eq!(Ok(()), check_required_fields(&mock_csaf20(without_severity), "6.1.100"));
+        assert_eq!(
+            Err(vec![error(
+                "6.1.100",
+                "have a '/document/aggregate_severity' element",
+                "/document/aggregate_severity"
+            )]),
+            check_required_fields(&mock_csaf21(without_severity), "6.1.100")
+        );
+    }
+
+    #[test]
+    fn test_informational_advisory_categories() {
+        let doc = mock_csaf21(|doc| {
+            doc["document"]["category"] = json!("csaf_informational_advisory");
+            doc["document"]["notes"] = json!([{ "category": "legal_disclaimer", "text": "Disclaimer." }]);
+            doc["document"]["references"] = json!([{
+                "category": "external",
+                "summary": "A reference.",
+                "url": "https://example.com/report"
+            }]);
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.27.1",
+                "Document with category 'csaf_informational_advisory' must have at least one document note with \
+                 category 'description', 'details', 'general' or 'summary'",
+                "/document/notes",
+            )]),
+            validate_profile(&doc)
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Rendering of validation results for humans and tools
+//!
+//! Each submodule implements one output format for a [ValidationResult](crate::validation::ValidationResult).
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod html;
+pub mod json;
+pub mod junit;
+pub mod ndjson;
+pub mod text;
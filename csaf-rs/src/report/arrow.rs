@@ -0,0 +1,127 @@
+//! Arrow export of findings, e.g. for loading them into a data warehouse
+//!
+//! [to_record_batch] writes the findings of many documents into a single Arrow [RecordBatch], so
+//! that they can be handed to Arrow or Parquet writers without serializing them to JSON first.
+//! The column names match the fields of the [ndjson](crate::report::ndjson) report.
+
+use crate::report::ndjson::DocumentId;
+use crate::validation::{Severity, ValidationError};
+use ::arrow::array::{ArrayRef, StringBuilder};
+use ::arrow::datatypes::{DataType, Field, Schema};
+use ::arrow::record_batch::RecordBatch;
+use std::sync::Arc;
+
+/// The names of the columns of [to_record_batch], in order
+pub static COLUMN_NAMES: [&str; 5] = ["document_id", "code", "severity", "message", "instance_path"];
+
+/// Returns the schema of [to_record_batch]: one non-nullable UTF-8 column per name in
+/// [COLUMN_NAMES].
+pub fn schema() -> Schema {
+    Schema::new(
+        COLUMN_NAMES
+            .iter()
+            .map(|name| Field::new(*name, DataType::Utf8, false))
+            .collect::<Vec<Field>>(),
+    )
+}
+
+/// Writes the findings of each document into a record batch with one row per finding, keeping
+/// their order.
+///
+/// Like in the [ndjson](crate::report::ndjson) report, the document ID of a row is the one the
+/// test runner recorded in the finding, if any, and the given [DocumentId] otherwise. The
+/// severity is written as its name, e.g. `warning`.
+pub fn to_record_batch(results: &[(DocumentId, Vec<ValidationError>)]) -> RecordBatch {
+    let rows: usize = results.iter().map(|(_, errors)| errors.len()).sum();
+    let mut columns: Vec<StringBuilder> = COLUMN_NAMES
+        .iter()
+        .map(|_| StringBuilder::with_capacity(rows, 0))
+        .collect();
+    for (document_id, errors) in results {
+        for error in errors {
+            let severity = match error.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info => "info",
+            };
+            let values = [
+                error.document_id.as_deref().unwrap_or(document_id),
+                &error.code,
+                severity,
+                &error.message,
+                &error.instance_path,
+            ];
+            for (column, value) in columns.iter_mut().zip(values) {
+                column.append_value(value);
+            }
+        }
+    }
+    let columns: Vec<ArrayRef> = columns
+        .iter_mut()
+        .map(|column| Arc::new(column.finish()) as ArrayRef)
+        .collect();
+    // All columns have one non-null string per row, as declared by the schema
+    RecordBatch::try_new(Arc::new(schema()), columns).expect("columns match the schema")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::arrow::{COLUMN_NAMES, to_record_batch};
+    use crate::validation::{Severity, ValidationError};
+    use ::arrow::array::{Array, StringArray};
+    use ::arrow::datatypes::DataType;
+
+    fn column<'a>(batch: &'a ::arrow::record_batch::RecordBatch, name: &str) -> Vec<&'a str> {
+        let array = batch
+            .column_by_name(name)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        (0..array.len()).map(|i| array.value(i)).collect()
+    }
+
+    #[test]
+    fn test_to_record_batch() {
+        let results = vec![
+            (
+                "MOCK-2024-0001".to_string(),
+                vec![
+                    ValidationError::new("6.1.1", "Missing definition", "/vulnerabilities/0"),
+                    ValidationError::new("6.2.13", "CVSS v3.0 is deprecated", "").with_severity(Severity::Warning),
+                ],
+            ),
+            ("MOCK-2024-0002".to_string(), vec![]),
+            (
+                "advisories/mock-3.json".to_string(),
+                vec![
+                    ValidationError::new("6.1.65", "Empty title", "/document/title").with_document_id("MOCK-2024-0003"),
+                ],
+            ),
+        ];
+        let batch = to_record_batch(&results);
+
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.num_columns(), COLUMN_NAMES.len());
+        for (field, name) in batch.schema().fields().iter().zip(COLUMN_NAMES) {
+            assert_eq!(field.name(), name);
+            assert_eq!(field.data_type(), &DataType::Utf8);
+            assert!(!field.is_nullable());
+        }
+        assert_eq!(
+            column(&batch, "document_id"),
+            ["MOCK-2024-0001", "MOCK-2024-0001", "MOCK-2024-0003"]
+        );
+        assert_eq!(column(&batch, "code"), ["6.1.1", "6.2.13", "6.1.65"]);
+        assert_eq!(column(&batch, "severity"), ["error", "warning", "error"]);
+        assert_eq!(column(&batch, "message")[1], "CVSS v3.0 is deprecated");
+        assert_eq!(
+            column(&batch, "instance_path"),
+            ["/vulnerabilities/0", "", "/document/title"]
+        );
+
+        let empty = to_record_batch(&[]);
+        assert_eq!(empty.num_rows(), 0);
+        assert_eq!(empty.num_columns(), COLUMN_NAMES.len());
+    }
+}
@@ -0,0 +1,180 @@
+//! Self-contained HTML report, e.g. for sharing findings with vendors
+//!
+//! The page has no external dependencies: the stylesheet is inlined and the collapsible sections
+//! use `<details>` elements, so no JavaScript is needed. All strings taken from the document or
+//! the findings are escaped.
+
+use crate::validation::TestResultStatus::Failure;
+use crate::validation::{Severity, ValidationError, ValidationResult};
+use std::fmt::Write;
+
+const STYLE: &str = "body{font-family:sans-serif;margin:2em;color:#222}\
+h1{font-size:1.5em}\
+summary{font-weight:bold;cursor:pointer;padding:.3em 0}\
+ul{list-style:none;padding-left:1em}\
+li{margin:.5em 0;padding:.5em;border-left:4px solid #ccc}\
+.error li{border-color:#c62828}.warning li{border-color:#f9a825}.info li{border-color:#1565c0}\
+.code{font-family:monospace;font-weight:bold;margin-right:.5em;color:inherit}\
+.path{font-family:monospace;font-size:.9em;color:#555}";
+
+/// Renders a validation result as an HTML page titled `doc_title`.
+///
+/// Findings are grouped by severity, starting with errors. Each group is a collapsible section,
+/// and each finding shows its instance path as a breadcrumb. Codes of tests of the specification
+/// link to their section, see [ValidationError::rule_url].
+pub fn render(result: &ValidationResult, doc_title: &str) -> String {
+    let findings: Vec<&ValidationError> = result
+        .test_results
+        .iter()
+        .filter_map(|test_result| match &test_result.status {
+            Failure { errors } => Some(errors),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    let mut out = String::new();
+    writeln!(out, "<!DOCTYPE html>").unwrap();
+    writeln!(out, "<html lang=\"en\">").unwrap();
+    writeln!(out, "<head>").unwrap();
+    writeln!(out, "<meta charset=\"utf-8\">").unwrap();
+    writeln!(out, "<title>Validation report: {}</title>", escape(doc_title)).unwrap();
+    writeln!(out, "<style>{}</style>", STYLE).unwrap();
+    writeln!(out, "</head>").unwrap();
+    writeln!(out, "<body>").unwrap();
+    writeln!(out, "<h1>Validation report: {}</h1>", escape(doc_title)).unwrap();
+    writeln!(
+        out,
+        "<p>CSAF version {}, {} preset: {}</p>",
+        escape(&result.version),
+        result.preset,
+        if result.success {
+            "validation passed".to_string()
+        } else {
+            format!("validation failed with {} error(s)", result.num_errors)
+        }
+    )
+    .unwrap();
+    if result.num_suppressed > 0 {
+        writeln!(
+            out,
+            "<p>{} known finding(s) suppressed by the baseline</p>",
+            result.num_suppressed
+        )
+        .unwrap();
+    }
+
+    for (severity, class, heading) in [
+        (Severity::Error, "error", "Errors"),
+        (Severity::Warning, "warning", "Warnings"),
+        (Severity::Info, "info", "Information"),
+    ] {
+        let group: Vec<&&ValidationError> = findings.iter().filter(|e| e.severity == severity).collect();
+        if group.is_empty() {
+            continue;
+        }
+        writeln!(out, "<details class=\"{}\" open>", class).unwrap();
+        writeln!(out, "<summary>{} ({})</summary>", heading, group.len()).unwrap();
+        writeln!(out, "<ul>").unwrap();
+        for error in group {
+            let code = match error.rule_url() {
+                Some(url) => format!(
+                    "<a class=\"code\" href=\"{}\">{}</a>",
+                    escape(&url),
+                    escape(&error.code)
+                ),
+                None => format!("<span class=\"code\">{}</span>", escape(&error.code)),
+            };
+            writeln!(
+                out,
+                "<li>{}{}<br><span class=\"path\">{}</span></li>",
+                code,
+                escape(&error.message),
+                breadcrumb(&error.instance_path)
+            )
+            .unwrap();
+        }
+        writeln!(out, "</ul>").unwrap();
+        writeln!(out, "</details>").unwrap();
+    }
+
+    writeln!(out, "</body>").unwrap();
+    writeln!(out, "</html>").unwrap();
+    out
+}
+
+/// Renders a JSON pointer as escaped breadcrumb, e.g. `document › tracking › id`.
+fn breadcrumb(instance_path: &str) -> String {
+    if instance_path.is_empty() {
+        return "(document)".to_string();
+    }
+    instance_path
+        .split('/')
+        .skip(1)
+        .map(|token| escape(&token.replace("~1", "/").replace("~0", "~")))
+        .collect::<Vec<_>>()
+        .join(" › ")
+}
+
+/// Escapes the characters with a special meaning in HTML text and attribute values.
+pub(crate) fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::html::{breadcrumb, escape, render};
+    use crate::validation::TestResultStatus::Failure;
+    use crate::validation::{TestResult, ValidationError, ValidationPreset, ValidationResult};
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape(r#"<script>alert("x & 'y'")</script>"#),
+            "&lt;script&gt;alert(&quot;x &amp; &#39;y&#39;&quot;)&lt;/script&gt;"
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb() {
+        assert_eq!(breadcrumb("/document/tracking/id"), "document › tracking › id");
+        assert_eq!(breadcrumb("/product_tree/a~1b~0c"), "product_tree › a/b~c");
+        assert_eq!(breadcrumb(""), "(document)");
+    }
+
+    #[test]
+    fn test_rule_links() {
+        let result = ValidationResult {
+            success: false,
+            version: "2.1".to_string(),
+            preset: ValidationPreset::Basic,
+            test_results: vec![TestResult {
+                test_id: "6.1.2".to_string(),
+                status: Failure {
+                    errors: vec![
+                        ValidationError::new("6.1.2", "Duplicate", "/product_tree"),
+                        ValidationError::new("org-1", "Custom", "/document"),
+                    ],
+                },
+            }],
+            num_errors: 2,
+            num_suppressed: 0,
+        };
+        let html = render(&result, "Test");
+        assert!(html.contains(
+            "<a class=\"code\" href=\"https://docs.oasis-open.org/csaf/csaf/v2.1/csaf-v2.1.html#612\">6.1.2</a>"
+        ));
+        assert!(html.contains("<span class=\"code\">org-1</span>"));
+    }
+}
@@ -0,0 +1,287 @@
+//! Versioned JSON report, e.g. for consumers in other languages
+//!
+//! The serialization of [ValidationResult] follows the internal structure of the test runner and
+//! may change with it. [ValidationReportJson] is the documented wire format instead, which only
+//! changes together with its `schema_version`:
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "success": false,
+//!   "csaf_version": "2.1",
+//!   "preset": "basic",
+//!   "num_errors": 1,
+//!   "num_suppressed": 0,
+//!   "tests": [{ "test_id": "6.1.1", "status": "failure" }],
+//!   "findings": [{
+//!     "test_id": "6.1.1",
+//!     "code": "6.1.1",
+//!     "severity": "error",
+//!     "message": "Missing definition of product_id: CSAFPID-0001",
+//!     "instance_path": "/vulnerabilities/0/product_status/fixed/0",
+//!     "document_id": "MOCK-2024-0001",
+//!     "location": { "line": 3, "column": 7 }
+//!   }]
+//! }
+//! ```
+//!
+//! `document_id` and `location` are omitted if unknown. Adding a field increments
+//! [REPORT_SCHEMA_VERSION], and reports of a newer schema version are rejected when converting
+//! them back into a [ValidationResult].
+
+use crate::validation::TestResultStatus::{Failure, NotFound, Success};
+use crate::validation::{Severity, SourceLocation, TestResult, ValidationError, ValidationPreset, ValidationResult};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The current version of the shape of [ValidationReportJson]
+pub static REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// A validation result in the versioned wire format, see the [module documentation](self)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationReportJson {
+    /// The version of the shape of this report, see [REPORT_SCHEMA_VERSION]
+    pub schema_version: u32,
+    /// Whether no test failed
+    pub success: bool,
+    /// The CSAF version of the validated document
+    pub csaf_version: String,
+    /// The validation preset that was used, i.e. `basic`, `extended` or `full`
+    pub preset: String,
+    /// The total number of findings
+    pub num_errors: usize,
+    /// The number of known findings that were removed by a baseline
+    pub num_suppressed: usize,
+    /// Every executed test, in order
+    pub tests: Vec<TestJson>,
+    /// The findings of all tests, in order
+    pub findings: Vec<FindingJson>,
+}
+
+/// An executed test of a [ValidationReportJson]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestJson {
+    pub test_id: String,
+    /// `success`, `failure` or `not_found`
+    pub status: String,
+}
+
+/// A finding of a [ValidationReportJson]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FindingJson {
+    /// The ID of the test that reported the finding
+    pub test_id: String,
+    /// The code of the finding, usually the same as `test_id`
+    pub code: String,
+    /// `error`, `warning` or `info`
+    pub severity: Severity,
+    pub message: String,
+    pub instance_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
+}
+
+/// Reasons why a [ValidationReportJson] cannot be converted into a [ValidationResult]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportJsonError {
+    /// The report has a newer schema version than [REPORT_SCHEMA_VERSION]
+    UnsupportedSchemaVersion(u32),
+    /// The preset is not one of `basic`, `extended` or `full`
+    UnknownPreset(String),
+    /// The status of a test is not one of `success`, `failure` or `not_found`
+    UnknownStatus(String),
+    /// A finding belongs to a test that is not listed as failed
+    UnexpectedFinding(String),
+}
+
+impl Display for ReportJsonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportJsonError::UnsupportedSchemaVersion(version) => write!(
+                f,
+                "Unsupported report schema version {}, supported up to {}",
+                version, REPORT_SCHEMA_VERSION
+            ),
+            ReportJsonError::UnknownPreset(preset) => write!(f, "Unknown validation preset '{}'", preset),
+            ReportJsonError::UnknownStatus(status) => write!(f, "Unknown test status '{}'", status),
+            ReportJsonError::UnexpectedFinding(test_id) => {
+                write!(f, "Finding of test {} which is not listed as failed", test_id)
+            },
+        }
+    }
+}
+
+impl std::error::Error for ReportJsonError {}
+
+impl From<ValidationResult> for ValidationReportJson {
+    fn from(result: ValidationResult) -> Self {
+        let mut tests = Vec::with_capacity(result.test_results.len());
+        let mut findings = Vec::new();
+        for test_result in result.test_results {
+            let status = match test_result.status {
+                Success => "success",
+                NotFound => "not_found",
+                Failure { errors } => {
+                    findings.extend(errors.into_iter().map(|error| FindingJson {
+                        test_id: test_result.test_id.clone(),
+                        code: error.code,
+                        severity: error.severity,
+                        message: error.message,
+                        instance_path: error.instance_path,
+                        document_id: error.document_id.map(String::from),
+                        location: error.location,
+                    }));
+                    "failure"
+                },
+            };
+            tests.push(TestJson {
+                test_id: test_result.test_id,
+                status: status.to_string(),
+            });
+        }
+        ValidationReportJson {
+            schema_version: REPORT_SCHEMA_VERSION,
+            success: result.success,
+            csaf_version: result.version,
+            preset: result.preset.to_string(),
+            num_errors: result.num_errors,
+            num_suppressed: result.num_suppressed,
+            tests,
+            findings,
+        }
+    }
+}
+
+impl TryFrom<ValidationReportJson> for ValidationResult {
+    type Error = ReportJsonError;
+
+    fn try_from(report: ValidationReportJson) -> Result<Self, Self::Error> {
+        if report.schema_version > REPORT_SCHEMA_VERSION {
+            return Err(ReportJsonError::UnsupportedSchemaVersion(report.schema_version));
+        }
+        let preset =
+            ValidationPreset::from_str(&report.preset).map_err(|_| ReportJsonError::UnknownPreset(report.preset))?;
+
+        let mut test_results = report
+            .tests
+            .into_iter()
+            .map(|test| {
+                let status = match test.status.as_str() {
+                    "success" => Success,
+                    "not_found" => NotFound,
+                    "failure" => Failure { errors: Vec::new() },
+                    _ => return Err(ReportJsonError::UnknownStatus(test.status)),
+                };
+                Ok(TestResult {
+                    test_id: test.test_id,
+                    status,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for finding in report.findings {
+            let errors = test_results.iter_mut().find_map(|r| match &mut r.status {
+                Failure { errors } if r.test_id == finding.test_id => Some(errors),
+                _ => None,
+            });
+            let Some(errors) = errors else {
+                return Err(ReportJsonError::UnexpectedFinding(finding.test_id));
+            };
+            let mut error = ValidationError::new(finding.code, finding.message, finding.instance_path)
+                .with_severity(finding.severity);
+            if let Some(document_id) = finding.document_id {
+                error = error.with_document_id(document_id);
+            }
+            if let Some(location) = finding.location {
+                error = error.with_location(location);
+            }
+            errors.push(error);
+        }
+
+        Ok(ValidationResult {
+            success: report.success,
+            version: report.csaf_version,
+            preset,
+            test_results,
+            num_errors: report.num_errors,
+            num_suppressed: report.num_suppressed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::json::{ReportJsonError, ValidationReportJson};
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::{
+        Severity, SourceLocation, TestResult, TestResultStatus, ValidationError, ValidationPreset, ValidationResult,
+        validate_by_preset,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_round_trip() {
+        let doc = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{ "product_status": { "fixed": ["CSAFPID-0001"] } }]);
+        });
+        let mut result = validate_by_preset(&doc, "2.1", ValidationPreset::Basic);
+        result.merge(ValidationResult {
+            success: false,
+            version: "2.1".to_string(),
+            preset: ValidationPreset::Basic,
+            test_results: vec![TestResult {
+                test_id: "custom".to_string(),
+                status: TestResultStatus::Failure {
+                    errors: vec![
+                        ValidationError::new("custom.1", "Custom finding", "/document")
+                            .with_severity(Severity::Info)
+                            .with_document_id("MOCK-2024-0001")
+                            .with_location(SourceLocation { line: 3, column: 7 }),
+                    ],
+                },
+            }],
+            num_errors: 1,
+            num_suppressed: 0,
+        });
+
+        let report = ValidationReportJson::from(result.clone());
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["preset"], "basic");
+        assert_eq!(
+            json["findings"].as_array().unwrap().last().unwrap(),
+            &json!({
+                "test_id": "custom",
+                "code": "custom.1",
+                "severity": "info",
+                "message": "Custom finding",
+                "instance_path": "/document",
+                "document_id": "MOCK-2024-0001",
+                "location": { "line": 3, "column": 7 }
+            })
+        );
+
+        let parsed: ValidationReportJson = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, report);
+        let round_tripped = ValidationResult::try_from(parsed).unwrap();
+        assert_eq!(
+            serde_json::to_value(&round_tripped).unwrap(),
+            serde_json::to_value(&result).unwrap()
+        );
+
+        let mut newer = report.clone();
+        newer.schema_version = 2;
+        assert_eq!(
+            ValidationResult::try_from(newer).unwrap_err(),
+            ReportJsonError::UnsupportedSchemaVersion(2)
+        );
+        let mut orphan = report;
+        orphan.findings[0].test_id = "6.9.9".to_string();
+        assert_eq!(
+            ValidationResult::try_from(orphan).unwrap_err(),
+            ReportJsonError::UnexpectedFinding("6.9.9".to_string())
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! JUnit XML report, e.g. for showing findings in the test view of a CI system
+//!
+//! A document is rendered as a `<testsuite>` with one `<testcase>` per test. A test case fails if
+//! the test reported at least one error, findings of lower severity are listed in its
+//! `<system-out>`. Tests that were not executed are marked as skipped.
+
+use crate::report::html::escape;
+use crate::validation::TestResultStatus::{Failure, NotFound};
+use crate::validation::{Severity, ValidationError, ValidationResult};
+use std::fmt::Write;
+
+/// Renders the test results of a validation as a JUnit XML test suite named `doc_id`.
+///
+/// Tests of the result that were not found for the CSAF version of the document are skipped.
+/// Use [to_junit_with_tests] to list tests that were not executed at all, e.g. ignored ones.
+pub fn to_junit(result: &ValidationResult, doc_id: &str) -> String {
+    to_junit_with_tests(result, doc_id, &[])
+}
+
+/// Like [to_junit], but adds a skipped test case for each of `test_ids` that is missing in the
+/// result.
+///
+/// Passing the IDs of all tests of the document, e.g. from
+/// [crate::csaf_traits::CsafTrait::get_preset_test_ids] with [ValidationPreset::Full], lists
+/// every known test, including ignored ones and those of other presets.
+///
+/// [ValidationPreset::Full]: crate::validation::ValidationPreset::Full
+pub fn to_junit_with_tests(result: &ValidationResult, doc_id: &str, test_ids: &[String]) -> String {
+    let mut cases = String::new();
+    let (mut tests, mut failures, mut skipped) = (0, 0, 0);
+    for test_result in &result.test_results {
+        let findings: &[ValidationError] = match &test_result.status {
+            Failure { errors } => errors,
+            _ => &[],
+        };
+        let (errors, others): (Vec<&ValidationError>, Vec<&ValidationError>) =
+            findings.iter().partition(|e| e.severity == Severity::Error);
+
+        tests += 1;
+        write!(cases, "  <testcase {}>", case_attributes(&test_result.test_id, doc_id)).unwrap();
+        if matches!(test_result.status, NotFound) {
+            skipped += 1;
+            write!(cases, "<skipped message=\"Test not found\"/>").unwrap();
+        } else if !errors.is_empty() {
+            failures += 1;
+            write!(
+                cases,
+                "<failure message=\"{} error(s)\" type=\"error\">{}</failure>",
+                errors.len(),
+                escape(&lines(&errors))
+            )
+            .unwrap();
+        }
+        if !others.is_empty() {
+            write!(cases, "<system-out>{}</system-out>", escape(&lines(&others))).unwrap();
+        }
+        writeln!(cases, "</testcase>").unwrap();
+    }
+    for test_id in test_ids {
+        if !result.test_results.iter().any(|r| &r.test_id == test_id) {
+            tests += 1;
+            skipped += 1;
+            writeln!(
+                cases,
+                "  <testcase {}><skipped message=\"Test not executed\"/></testcase>",
+                case_attributes(test_id, doc_id)
+            )
+            .unwrap();
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(out, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>").unwrap();
+    writeln!(
+        out,
+        "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\">",
+        escape(doc_id),
+        tests,
+        failures,
+        skipped
+    )
+    .unwrap();
+    out.push_str(&cases);
+    writeln!(out, "</testsuite>").unwrap();
+    out
+}
+
+fn case_attributes(test_id: &str, doc_id: &str) -> String {
+    format!("name=\"{}\" classname=\"{}\"", escape(test_id), escape(doc_id))
+}
+
+/// Formats one finding per line, e.g. `[warning] /document/title: Title is too long`.
+fn lines(findings: &[&ValidationError]) -> String {
+    findings
+        .iter()
+        .map(|e| {
+            let severity = match e.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info => "info",
+            };
+            format!("[{}] {}: {}", severity, e.instance_path, e.message)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
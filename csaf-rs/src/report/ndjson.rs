@@ -0,0 +1,114 @@
+//! Newline-delimited JSON report, e.g. for ingestion into log pipelines
+//!
+//! Every finding is written as a single JSON object on its own line, so that each line can be
+//! parsed independently of the others.
+
+use crate::validation::{Severity, ValidationError};
+use serde::Serialize;
+use std::io::Write;
+
+/// Identifies a document within a batch, e.g. its tracking ID or the path it was read from
+pub type DocumentId = String;
+
+/// A single line of the report
+#[derive(Serialize)]
+struct Finding<'a> {
+    document_id: &'a str,
+    code: &'a str,
+    severity: Severity,
+    message: &'a str,
+    instance_path: &'a str,
+}
+
+/// Writes one line per finding of each document to `w`.
+///
+/// The document ID of a line is the one the test runner recorded in the finding, if any, and the
+/// given [DocumentId] otherwise.
+///
+/// Each line is flushed after it has been written, so that consumers see findings as soon as
+/// they are reported. Documents without findings produce no output.
+pub fn write<W: Write>(results: &[(DocumentId, Vec<ValidationError>)], mut w: W) -> std::io::Result<()> {
+    for (document_id, errors) in results {
+        for error in errors {
+            let finding = Finding {
+                document_id: error.document_id.as_deref().unwrap_or(document_id),
+                code: &error.code,
+                severity: error.severity,
+                message: &error.message,
+                instance_path: &error.instance_path,
+            };
+            serde_json::to_writer(&mut w, &finding)?;
+            w.write_all(b"\n")?;
+            w.flush()?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::ndjson::write;
+    use crate::validation::{Severity, ValidationError};
+    use serde_json::{Value, json};
+
+    #[test]
+    fn test_write() {
+        let results = vec![
+            (
+                "MOCK-2024-0001".to_string(),
+                vec![
+                    ValidationError::new(
+                        "6.1.1",
+                        "Missing definition of product_id: CSAFPID-0001",
+                        "/vulnerabilities/0",
+                    ),
+                    ValidationError::new("6.2.13", "CVSS v3.0 is deprecated,\nplease use CVSS v3.1 instead", "")
+                        .with_severity(Severity::Warning),
+                ],
+            ),
+            ("MOCK-2024-0002".to_string(), vec![]),
+            (
+                "advisories/mock-3.json".to_string(),
+                vec![
+                    ValidationError::new("6.1.65", "The document title must not be empty", "/document/title")
+                        .with_document_id("MOCK-2024-0003"),
+                ],
+            ),
+        ];
+
+        let mut out = Vec::new();
+        write(&results, &mut out).unwrap();
+        let lines: Vec<Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                json!({
+                    "document_id": "MOCK-2024-0001",
+                    "code": "6.1.1",
+                    "severity": "error",
+                    "message": "Missing definition of product_id: CSAFPID-0001",
+                    "instance_path": "/vulnerabilities/0"
+                }),
+                json!({
+                    "document_id": "MOCK-2024-0001",
+                    "code": "6.2.13",
+                    "severity": "warning",
+                    "message": "CVSS v3.0 is deprecated,\nplease use CVSS v3.1 instead",
+                    "instance_path": ""
+                }),
+                json!({
+                    "document_id": "MOCK-2024-0003",
+                    "code": "6.1.65",
+                    "severity": "error",
+                    "message": "The document title must not be empty",
+                    "instance_path": "/document/title"
+                }),
+            ]
+        );
+    }
+}
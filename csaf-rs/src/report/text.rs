@@ -0,0 +1,89 @@
+//! Plain text report, as printed by the command line validator
+
+use crate::validation::TestResultStatus::{Failure, NotFound, Success};
+use crate::validation::{TestResult, ValidationResult};
+use std::fmt::Write;
+
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/// Renders a validation result as text, with one line per test and a summary at the end.
+///
+/// If `colored` is set, the status of each test and the summary are highlighted with ANSI escape
+/// codes, e.g. for output on a terminal.
+pub fn render(result: &ValidationResult, colored: bool) -> String {
+    let paint = |color: &str, text: &str| {
+        if colored {
+            format!("{}{}{}", color, text, RESET)
+        } else {
+            text.to_string()
+        }
+    };
+
+    let mut out = String::new();
+    writeln!(out, "CSAF Version: {}", result.version).unwrap();
+    if let Some(document_id) = document_id(result) {
+        writeln!(out, "Document: {}", document_id).unwrap();
+    }
+    writeln!(out, "Validating document with {:?} preset...\n", result.preset).unwrap();
+
+    for test_result in &result.test_results {
+        render_test_result(&mut out, test_result, &paint);
+    }
+
+    writeln!(out).unwrap();
+    if result.success {
+        writeln!(out, "{}\n", paint(GREEN, "✅ Validation passed! No errors found.")).unwrap();
+    } else {
+        let summary = format!("❌ Validation failed with {} error(s)", result.num_errors);
+        writeln!(out, "{}\n", paint(RED, &summary)).unwrap();
+    }
+    if result.num_suppressed > 0 {
+        writeln!(
+            out,
+            "{} known finding(s) suppressed by the baseline\n",
+            result.num_suppressed
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// Returns the document ID recorded in the first finding that has one.
+fn document_id(result: &ValidationResult) -> Option<&str> {
+    result
+        .test_results
+        .iter()
+        .find_map(|test_result| match &test_result.status {
+            Failure { errors } => errors.iter().find_map(|e| e.document_id.as_deref()),
+            _ => None,
+        })
+}
+
+fn render_test_result(out: &mut String, test_result: &TestResult, paint: &impl Fn(&str, &str) -> String) {
+    // Common prefix for all test statuses
+    let prefix = format!("Executing Test {} ... ", test_result.test_id);
+
+    match &test_result.status {
+        Success => {
+            writeln!(out, "{}{}", prefix, paint(GREEN, "✅ Success")).unwrap();
+        },
+        Failure { errors } => {
+            // Multiple errors are aligned below the first one
+            let error_msg = "❌ ";
+            write!(out, "{}{}", prefix, paint(RED, error_msg)).unwrap();
+            let indent = " ".repeat(prefix.len() + error_msg.len());
+            for (i, error) in errors.iter().enumerate() {
+                if i > 0 {
+                    write!(out, "{}", indent).unwrap();
+                }
+                writeln!(out, "Error: {}", error.message).unwrap();
+            }
+        },
+        NotFound => {
+            writeln!(out, "{}{}", prefix, paint(YELLOW, "⚠️  Test not found")).unwrap();
+        },
+    }
+}
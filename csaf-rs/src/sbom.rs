@@ -0,0 +1,96 @@
+//! Cross-checks between an advisory and a software bill of materials
+//!
+//! Teams pairing CSAF (e.g. VEX) documents with an SBOM want to make sure that every product an
+//! advisory makes statements about is part of their SBOM. This crate does not parse SBOMs, the
+//! caller extracts the known product identifiers from it and passes them to
+//! [cross_check_product_ids].
+
+use crate::csaf_traits::{CsafTraitExt, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
+use crate::validation::ValidationError;
+use std::collections::HashSet;
+
+/// The code of findings emitted by [cross_check_product_ids]
+pub static SBOM_CROSS_CHECK_CODE: &str = "sbom-cross-check";
+
+/// Reports all products referenced by the advisory that are not in `known_products`.
+///
+/// A product is known if its product ID, its name or one of its PURLs is contained in
+/// `known_products`. Findings are reported at the definition of the product in the product tree,
+/// products that are defined but never referenced are not checked.
+pub fn cross_check_product_ids(doc: &impl CsafTraitExt, known_products: &HashSet<String>) -> Vec<ValidationError> {
+    let referenced: HashSet<String> = doc
+        .get_all_product_references()
+        .into_iter()
+        .map(|(product_id, _)| product_id)
+        .collect();
+
+    let mut errors = Vec::new();
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_products(&mut |product, path| {
+            let product_id = product.get_product_id();
+            if !referenced.contains(product_id) {
+                return;
+            }
+            let purls = product
+                .get_product_identification_helper()
+                .as_ref()
+                .and_then(|helper| helper.get_purls())
+                .unwrap_or_default();
+            let known = known_products.contains(product_id)
+                || known_products.contains(product.get_name())
+                || purls.iter().any(|purl| known_products.contains(purl));
+            if !known {
+                errors.push(ValidationError::new(
+                    SBOM_CROSS_CHECK_CODE,
+                    format!(
+                        "Product {} ({}) is referenced by the advisory but not contained in the SBOM",
+                        product_id,
+                        product.get_name()
+                    ),
+                    path,
+                ));
+            }
+        });
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sbom::cross_check_product_ids;
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationError;
+    use serde_json::json;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_cross_check_product_ids() {
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [
+                    { "name": "Product A", "product_id": "CSAFPID-1" },
+                    {
+                        "name": "Product B",
+                        "product_id": "CSAFPID-2",
+                        "product_identification_helper": { "purls": ["pkg:npm/product-b@1.0.0"] }
+                    },
+                    { "name": "Product C", "product_id": "CSAFPID-3" },
+                    { "name": "Product D", "product_id": "CSAFPID-4" }
+                ]
+            });
+            doc["vulnerabilities"] = json!([{
+                "product_status": { "known_affected": ["CSAFPID-1", "CSAFPID-2", "CSAFPID-3"] }
+            }]);
+        });
+
+        let known = HashSet::from(["Product A".to_string(), "pkg:npm/product-b@1.0.0".to_string()]);
+        assert_eq!(
+            cross_check_product_ids(&doc, &known),
+            vec![ValidationError::new(
+                "sbom-cross-check",
+                "Product CSAFPID-3 (Product C) is referenced by the advisory but not contained in the SBOM",
+                "/product_tree/full_product_names/2",
+            )]
+        );
+    }
+}
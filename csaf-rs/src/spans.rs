@@ -0,0 +1,136 @@
+//! Byte-accurate source spans of validation errors
+//!
+//! [SpanIndex] maps the JSON pointer of every value of a document to its byte range within the
+//! source text. It is built from borrowed [RawValue]s, which point directly into the source, so
+//! the offsets are exact and do not need to be reconstructed from lines and columns. This is
+//! what editor integrations such as language servers need to highlight findings.
+//!
+//! Only available with the `spans` feature.
+
+use crate::validation::TestResultStatus::Failure;
+use crate::validation::ValidationResult;
+use serde_json::value::RawValue;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+
+/// Byte ranges of all values of a document, by JSON pointer
+pub struct SpanIndex {
+    spans: HashMap<String, Range<usize>>,
+}
+
+impl SpanIndex {
+    /// Indexes the values of the JSON document `source`.
+    pub fn new(source: &str) -> Result<SpanIndex, serde_json::Error> {
+        let root: &RawValue = serde_json::from_str(source)?;
+        let mut spans = HashMap::new();
+        index(source, root, String::new(), &mut spans)?;
+        Ok(SpanIndex { spans })
+    }
+
+    /// Returns the byte range of the value at `instance_path`.
+    ///
+    /// If the path does not exist, e.g. because it points to a missing property, the range of
+    /// its closest existing ancestor is returned.
+    pub fn get(&self, instance_path: &str) -> Option<Range<usize>> {
+        let mut path = instance_path;
+        loop {
+            if let Some(span) = self.spans.get(path) {
+                return Some(span.clone());
+            }
+            path = &path[..path.rfind('/')?];
+        }
+    }
+
+    /// Sets the span of every finding of `result` from its instance path.
+    pub fn annotate(&self, result: &mut ValidationResult) {
+        for test_result in result.test_results.iter_mut() {
+            if let Failure { errors } = &mut test_result.status {
+                for error in errors.iter_mut() {
+                    error.span = self.get(&error.instance_path);
+                }
+            }
+        }
+    }
+}
+
+fn index(
+    source: &str,
+    value: &RawValue,
+    path: String,
+    spans: &mut HashMap<String, Range<usize>>,
+) -> Result<(), serde_json::Error> {
+    let text = value.get();
+    // A borrowed raw value is a slice of the source, its offset is the distance of the pointers
+    let start = text.as_ptr() as usize - source.as_ptr() as usize;
+    match text.as_bytes().first() {
+        Some(b'{') => {
+            let members: BTreeMap<String, &RawValue> = serde_json::from_str(text)?;
+            for (key, member) in members {
+                let child = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+                index(source, member, child, spans)?;
+            }
+        },
+        Some(b'[') => {
+            let items: Vec<&RawValue> = serde_json::from_str(text)?;
+            for (i, item) in items.into_iter().enumerate() {
+                index(source, item, format!("{}/{}", path, i), spans)?;
+            }
+        },
+        _ => {},
+    }
+    spans.insert(path, start..start + text.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spans::SpanIndex;
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::TestResultStatus::Failure;
+    use serde_json::json;
+
+    #[test]
+    fn test_span_index() {
+        let source = r#"{
+  "document": { "title": "Example", "a/b": [1, {"c": true}] },
+  "vulnerabilities": [ { "cve": "CVE-2024-0001" } ]
+}"#;
+        let index = SpanIndex::new(source).unwrap();
+
+        let span = |path: &str| index.get(path).map(|r| &source[r]);
+        assert_eq!(span(""), Some(source));
+        assert_eq!(span("/document/title"), Some("\"Example\""));
+        assert_eq!(span("/document/a~1b/1"), Some("{\"c\": true}"));
+        assert_eq!(span("/vulnerabilities/0/cve"), Some("\"CVE-2024-0001\""));
+        // Missing values fall back to their closest ancestor
+        assert_eq!(span("/vulnerabilities/0/notes"), Some("{ \"cve\": \"CVE-2024-0001\" }"));
+
+        assert!(SpanIndex::new("{ not json").is_err());
+    }
+
+    #[test]
+    fn test_annotate() {
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [
+                    { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                    { "name": "Product B", "product_id": "CSAFPID-9080700" }
+                ]
+            })
+        });
+        let source = serde_json::to_string_pretty(&doc).unwrap();
+        let mut result = doc.validate();
+        SpanIndex::new(&source).unwrap().annotate(&mut result);
+
+        let errors = result
+            .test_results
+            .iter()
+            .find_map(|r| match &r.status {
+                Failure { errors } if r.test_id == "6.1.2" => Some(errors),
+                _ => None,
+            })
+            .unwrap();
+        let span = errors[0].span.clone().unwrap();
+        assert_eq!(&source[span], "\"CSAFPID-9080700\"");
+    }
+}
@@ -1,9 +1,11 @@
 use crate::csaf2_0::loader::load_document as load_document_20;
 use crate::csaf2_1::loader::load_document as load_document_21;
+use crate::error::Error;
 use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
 use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
 use crate::validation::{Test, ValidationError};
 use glob::GlobResult;
+use serde_json::{Value, json};
 use std::collections::HashMap;
 
 /// Generic test helper that loads all test files matching a specific test number pattern
@@ -20,7 +22,7 @@ use std::collections::HashMap;
 fn run_csaf_tests<CsafType>(
     pattern: &str,
     file_prefix: &str,
-    document_loader: fn(&str) -> std::io::Result<CsafType>,
+    document_loader: fn(&str) -> Result<CsafType, Error>,
     test_function: Test<CsafType>,
     expected_errors: HashMap<&str, Vec<ValidationError>>,
     skipped_tests: &[&str],
@@ -137,3 +139,80 @@ pub fn run_csaf21_tests(
 ) {
     run_csaf21_tests_with_excludes(test_number, test_function, expected_errors, &[])
 }
+
+/// Creates a minimal, valid CSAF 2.0 document for unit tests that do not have a matching test file.
+///
+/// The `patch` function receives the JSON representation of the document and may adjust it
+/// before it is parsed, e.g. to add vulnerabilities or a product tree.
+pub fn mock_csaf20(patch: impl FnOnce(&mut Value)) -> Csaf20 {
+    let mut value = json!({
+        "document": {
+            "category": "csaf_base",
+            "csaf_version": "2.0",
+            "publisher": {
+                "category": "vendor",
+                "contact_details": "security@example.com",
+                "name": "Example Company",
+                "namespace": "https://www.example.com"
+            },
+            "title": "Mock document",
+            "tracking": {
+                "current_release_date": "2024-01-24T10:00:00.000Z",
+                "id": "MOCK-2024-0001",
+                "initial_release_date": "2024-01-24T10:00:00.000Z",
+                "revision_history": [
+                    {
+                        "date": "2024-01-24T10:00:00.000Z",
+                        "number": "1",
+                        "summary": "Initial version."
+                    }
+                ],
+                "status": "final",
+                "version": "1"
+            }
+        }
+    });
+    patch(&mut value);
+    serde_json::from_value(value).expect("Mock CSAF 2.0 document could not be parsed")
+}
+
+/// Creates a minimal, valid CSAF 2.1 document for unit tests that do not have a matching test file.
+///
+/// See [mock_csaf20] for details on the `patch` function.
+pub fn mock_csaf21(patch: impl FnOnce(&mut Value)) -> Csaf21 {
+    let mut value = json!({
+        "$schema": "https://docs.oasis-open.org/csaf/csaf/v2.1/schema/csaf.json",
+        "document": {
+            "category": "csaf_base",
+            "csaf_version": "2.1",
+            "distribution": {
+                "tlp": {
+                    "label": "CLEAR"
+                }
+            },
+            "publisher": {
+                "category": "vendor",
+                "contact_details": "security@example.com",
+                "name": "Example Company",
+                "namespace": "https://www.example.com"
+            },
+            "title": "Mock document",
+            "tracking": {
+                "current_release_date": "2024-01-24T10:00:00.000Z",
+                "id": "MOCK-2024-0001",
+                "initial_release_date": "2024-01-24T10:00:00.000Z",
+                "revision_history": [
+                    {
+                        "date": "2024-01-24T10:00:00.000Z",
+                        "number": "1",
+                        "summary": "Initial version."
+                    }
+                ],
+                "status": "final",
+                "version": "1"
+            }
+        }
+    });
+    patch(&mut value);
+    serde_json::from_value(value).expect("Mock CSAF 2.1 document could not be parsed")
+}
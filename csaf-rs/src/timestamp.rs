@@ -0,0 +1,90 @@
+//! Parsed date-time values
+//!
+//! CSAF documents contain many RFC 3339 date-time strings with arbitrary offsets. [Timestamp]
+//! normalizes them to UTC, so that they can be compared directly, regardless of the offset they
+//! were written with. [Timestamp::parse_with_offset] additionally returns that [Offset].
+
+use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+use std::fmt::{Display, Formatter};
+
+/// An RFC 3339 date-time, normalized to UTC
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(pub DateTime<Utc>);
+
+impl Timestamp {
+    /// Parses an RFC 3339 date-time string with any offset.
+    pub fn parse(s: &str) -> Result<Timestamp, chrono::ParseError> {
+        Self::parse_with_offset(s).map(|(timestamp, _)| timestamp)
+    }
+
+    /// Parses an RFC 3339 date-time string with any offset, returning the offset as well.
+    pub fn parse_with_offset(s: &str) -> Result<(Timestamp, Offset), chrono::ParseError> {
+        let dt = DateTime::parse_from_rfc3339(s)?;
+        let offset = if s.ends_with(['Z', 'z']) {
+            Offset::Z
+        } else {
+            Offset::Numeric(*dt.offset())
+        };
+        Ok((Timestamp(dt.with_timezone(&Utc)), offset))
+    }
+}
+
+/// The offset an RFC 3339 date-time string was written with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Offset {
+    /// `Z`, i.e. UTC
+    Z,
+    /// A numeric offset like `+02:00`, including `+00:00`
+    Numeric(FixedOffset),
+}
+
+impl Display for Offset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Offset::Z => write!(f, "Z"),
+            Offset::Numeric(offset) => write!(f, "{}", offset),
+        }
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(dt: DateTime<Utc>) -> Self {
+        Timestamp(dt)
+    }
+}
+
+impl Display for Timestamp {
+    /// Formats the timestamp as RFC 3339 in UTC, e.g. `2024-01-24T10:00:00Z`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timestamp::{Offset, Timestamp};
+
+    #[test]
+    fn test_timestamp() {
+        let utc = Timestamp::parse("2024-01-24T10:00:00.000Z").unwrap();
+        let offset = Timestamp::parse("2024-01-24T12:00:00+02:00").unwrap();
+        assert_eq!(utc, offset);
+        assert_eq!(utc.to_string(), "2024-01-24T10:00:00Z");
+
+        let later = Timestamp::parse("2024-01-24T11:30:00.5+01:00").unwrap();
+        assert!(later > utc);
+        assert_eq!(later.to_string(), "2024-01-24T10:30:00.500Z");
+
+        assert!(Timestamp::parse("2024-01-24").is_err());
+
+        let offset_of = |s: &str| Timestamp::parse_with_offset(s).unwrap().1.to_string();
+        assert_eq!(offset_of("2024-01-24T10:00:00.000Z"), "Z");
+        assert_eq!(offset_of("2024-01-24T10:00:00z"), "Z");
+        assert_eq!(offset_of("2024-01-24T10:00:00+00:00"), "+00:00");
+        assert_eq!(offset_of("2024-01-24T10:00:00-05:30"), "-05:30");
+        assert_ne!(
+            Timestamp::parse_with_offset("2024-01-24T10:00:00+00:00").unwrap().1,
+            Offset::Z
+        );
+    }
+}
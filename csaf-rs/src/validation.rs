@@ -1,17 +1,200 @@
+pub mod baseline;
+pub mod batch;
+pub mod diff;
+pub mod feed;
+pub mod fix;
+pub mod incremental;
+pub mod messages;
+pub mod rolie;
+pub mod summary;
+pub mod validator;
+
+use crate::csaf_traits::CsafTraitExt;
 use TestResultStatus::*;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use tsify::Tsify;
 
+/// Severity of a validation error
+///
+/// Severities are ordered from lowest to highest, so that the most severe of several errors can be
+/// determined with `max()`.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+/// Position of a validation error within the source text of the validated document
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize, Tsify)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceLocation {
+    /// The line number, starting at 1
+    pub line: usize,
+    /// The column number, starting at 1
+    pub column: usize,
+}
+
+/// A single finding emitted by a validation test
+///
+/// This struct is `#[non_exhaustive]`, so that fields can be added without breaking library
+/// consumers. Use [ValidationError::new] and the `with_*` methods to construct it.
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize, Tsify)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct ValidationError {
+    /// The ID of the test (e.g. "6.1.1") or other check that emitted this error
+    pub code: String,
     pub message: String,
     pub instance_path: String,
+    #[serde(default)]
+    pub severity: Severity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub location: Option<SourceLocation>,
+    /// The tracking ID of the document this error was found in, filled in by the test runner
+    ///
+    /// Stored as `Box<str>` to keep `Result<_, ValidationError>` small.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tsify(optional, type = "string")]
+    pub document_id: Option<Box<str>>,
+    /// The name of the [validator::RuleSet] whose rule reported this error, filled in by the
+    /// [validator::Validator]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tsify(optional, type = "string")]
+    pub rule_set: Option<Box<str>>,
+    /// The ordered arguments of the message, if it was created from a template with
+    /// [ValidationError::with_args], see [messages::MessageCatalog]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tsify(optional, type = "string[]")]
+    pub args: Option<Vec<String>>,
+    /// The byte range of the offending value within the source text, see [crate::spans]
+    #[cfg(feature = "spans")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tsify(optional, type = "{ start: number, end: number }")]
+    pub span: Option<std::ops::Range<usize>>,
 }
 
+impl ValidationError {
+    /// Creates a new validation error with [Severity::Error] and without source location.
+    pub fn new(code: impl Into<String>, message: impl Into<String>, instance_path: impl Into<String>) -> Self {
+        ValidationError {
+            code: code.into(),
+            message: message.into(),
+            instance_path: instance_path.into(),
+            severity: Severity::default(),
+            location: None,
+            document_id: None,
+            rule_set: None,
+            args: None,
+            #[cfg(feature = "spans")]
+            span: None,
+        }
+    }
+
+    /// Creates a new validation error whose message is rendered from the English template of
+    /// `code` in [messages::MessageCatalog] with the given ordered arguments.
+    ///
+    /// The arguments are kept, so that the message can be rendered in other locales later. Codes
+    /// without an English template, e.g. of custom rules, get the code and the arguments as their
+    /// message until a template is registered.
+    pub fn with_args<I>(code: impl Into<String>, args: I, instance_path: impl Into<String>) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        let code = code.into();
+        let args: Vec<String> = args.into_iter().map(|arg| arg.to_string()).collect();
+        let message = messages::render_english(&code, &args);
+        let mut error = ValidationError::new(code, message, instance_path);
+        error.args = Some(args);
+        error
+    }
+
+    /// Sets the severity of this validation error.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets the position of this validation error within the source text of the document.
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets the ID of the document this validation error was found in.
+    pub fn with_document_id(mut self, document_id: impl Into<String>) -> Self {
+        self.document_id = Some(document_id.into().into_boxed_str());
+        self
+    }
+
+    /// Sets the name of the rule set this validation error was reported by.
+    pub fn with_rule_set(mut self, rule_set: impl Into<String>) -> Self {
+        self.rule_set = Some(rule_set.into().into_boxed_str());
+        self
+    }
+
+    /// Returns the URL of the section of the CSAF 2.1 specification defining the test of this
+    /// finding, or `None` if its code is not a test of the specification, e.g. for custom rules.
+    ///
+    /// The fragment consists of the digits of the section number, e.g. `#6149` for `6.1.49`.
+    pub fn rule_url(&self) -> Option<String> {
+        let parts: Vec<&str> = self.code.split('.').collect();
+        let is_spec_test = parts.len() >= 3
+            && parts[0] == "6"
+            && matches!(parts[1], "1" | "2" | "3")
+            && parts[2..]
+                .iter()
+                .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()));
+        is_spec_test.then(|| format!("{}#{}", SPEC_URL, parts.concat()))
+    }
+
+    /// Compares two findings in their canonical order: by code, then by instance path (see
+    /// [compare_instance_paths]), then by message.
+    ///
+    /// The test runner reports the findings of each test in this order, so that results do not
+    /// depend on the order in which a test happens to iterate over the document.
+    pub fn canonical_cmp(&self, other: &Self) -> Ordering {
+        self.code
+            .cmp(&other.code)
+            .then_with(|| compare_instance_paths(&self.instance_path, &other.instance_path))
+            .then_with(|| self.message.cmp(&other.message))
+    }
+}
+
+/// Compares two JSON pointers segment by segment, comparing array indices numerically.
+///
+/// `/vulnerabilities/10` therefore sorts after `/vulnerabilities/2`, and a pointer sorts before
+/// the pointers into its value.
+pub fn compare_instance_paths(a: &str, b: &str) -> Ordering {
+    let mut a_segments = a.split('/');
+    let mut b_segments = b.split('/');
+    loop {
+        let ordering = match (a_segments.next(), b_segments.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+                (Ok(a_index), Ok(b_index)) => a_index.cmp(&b_index).then_with(|| a.cmp(b)),
+                _ => a.cmp(b),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// The published CSAF 2.1 specification that [ValidationError::rule_url] links to
+pub static SPEC_URL: &str = "https://docs.oasis-open.org/csaf/csaf/v2.1/csaf-v2.1.html";
+
 impl std::fmt::Display for ValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "ValidationError: {} at {}", self.message, self.instance_path)
@@ -42,7 +225,7 @@ pub enum TestResultStatus {
 #[serde(rename_all = "camelCase")]
 #[tsify(into_wasm_abi, from_wasm_abi)]
 pub struct ValidationResult {
-    /// Whether the validation was successful (no errors)
+    /// Whether the validation was successful (no findings with [Severity::Error])
     pub success: bool,
     /// The detected CSAF version
     pub version: String,
@@ -50,8 +233,79 @@ pub struct ValidationResult {
     pub preset: ValidationPreset,
     /// Individual test results with execution details
     pub test_results: Vec<TestResult>,
-    /// The total number of errors found during validation
+    /// The total number of findings with [Severity::Error], warnings and infos are not counted
     pub num_errors: usize,
+    /// The number of known findings that were removed by a baseline
+    #[serde(default)]
+    pub num_suppressed: usize,
+}
+
+impl ValidationResult {
+    /// Merges the test results of another validation of the same document into this result.
+    ///
+    /// Results of tests that were executed in both validations are combined into a single test
+    /// result: a failure in either validation makes the combined test fail, and a test that was
+    /// not found in one validation takes the status of the other. The findings of both are
+    /// concatenated, call [ValidationResult::dedup] to collapse overlapping ones.
+    pub fn merge(&mut self, other: ValidationResult) {
+        for other_result in other.test_results {
+            match self.test_results.iter_mut().find(|r| r.test_id == other_result.test_id) {
+                Some(existing) => {
+                    let status = std::mem::replace(&mut existing.status, NotFound);
+                    existing.status = match (status, other_result.status) {
+                        (Failure { mut errors }, Failure { errors: other_errors }) => {
+                            errors.extend(other_errors);
+                            Failure { errors }
+                        },
+                        (failure @ Failure { .. }, _) | (_, failure @ Failure { .. }) => failure,
+                        (NotFound, other_status) => other_status,
+                        (status, _) => status,
+                    };
+                },
+                None => self.test_results.push(other_result),
+            }
+        }
+        self.update_summary();
+    }
+
+    /// Collapses findings within each test result that share the same code, instance path and
+    /// message. The highest severity of the collapsed findings is kept.
+    pub fn dedup(&mut self) {
+        for test_result in self.test_results.iter_mut() {
+            if let Failure { errors } = &mut test_result.status {
+                let mut unique: Vec<ValidationError> = Vec::with_capacity(errors.len());
+                for error in errors.drain(..) {
+                    match unique.iter_mut().find(|e| {
+                        e.code == error.code && e.instance_path == error.instance_path && e.message == error.message
+                    }) {
+                        Some(existing) => {
+                            existing.severity = existing.severity.max(error.severity);
+                            existing.location = existing.location.or(error.location);
+                        },
+                        None => unique.push(error),
+                    }
+                }
+                *errors = unique;
+            }
+        }
+        self.update_summary();
+    }
+
+    /// Recomputes `success` and `num_errors` from the test results.
+    ///
+    /// Only findings with [Severity::Error] are counted, so that warnings and infos do not fail
+    /// the validation.
+    fn update_summary(&mut self) {
+        self.num_errors = self
+            .test_results
+            .iter()
+            .map(|test_result| match &test_result.status {
+                Failure { errors } => errors.iter().filter(|e| e.severity == Severity::Error).count(),
+                _ => 0,
+            })
+            .sum();
+        self.success = self.num_errors == 0;
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize, Tsify)]
@@ -85,6 +339,21 @@ impl Display for ValidationPreset {
     }
 }
 
+/// The part of a document whose findings a test reports
+///
+/// Scopes are declared with [Validatable::scopes] and allow running the tests relevant to a part
+/// of a document only, e.g. with [incremental::validate_vulnerability]. A test may still read the
+/// rest of the document, e.g. to resolve product references against the product tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TestScope {
+    /// Findings anywhere in the document, or spanning several parts of it
+    Document,
+    /// Findings within a single item of `/vulnerabilities`
+    Vulnerability,
+    /// Findings within `/product_tree`
+    ProductTree,
+}
+
 pub trait Validate {
     /// Validates this object according to
     fn validate_by_test<VersionedDocument>(&self, test_id: &str) -> TestResult;
@@ -112,6 +381,11 @@ pub trait Validatable<VersionedDocument> {
     /// Returns a hashmap containing the test function per test ID
     fn tests(&self) -> HashMap<&str, Test<VersionedDocument>>;
 
+    /// Returns the scope per test ID, for tests that are not [TestScope::Document]
+    fn scopes(&self) -> HashMap<&str, TestScope> {
+        HashMap::new()
+    }
+
     fn doc(&self) -> &VersionedDocument;
 }
 
@@ -120,7 +394,21 @@ pub trait Validatable<VersionedDocument> {
 /// This function will check, whether the test_id exists in the Validatable's
 /// tests. If it does, it will execute the test function and return the result.
 /// If not, it will return a TestResult indicating that the test was not found.
-pub fn validate_by_test<VersionedDocument>(target: &impl Validatable<VersionedDocument>, test_id: &str) -> TestResult {
+/// The errors are sorted by [ValidationError::canonical_cmp] and annotated with the tracking ID
+/// of the document.
+///
+/// With the `tracing` feature, each test runs in a debug span named `test` with the field
+/// `test_id`, and the number of findings and the duration of the test are emitted as a debug
+/// event with target `csaf::validation`. Durations are not measured on WASM.
+pub fn validate_by_test<VersionedDocument: CsafTraitExt>(
+    target: &impl Validatable<VersionedDocument>,
+    test_id: &str,
+) -> TestResult {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!(target: "csaf::validation", "test", test_id).entered();
+    #[cfg(all(feature = "tracing", not(target_arch = "wasm32")))]
+    let start = std::time::Instant::now();
+
     // Fetch tests from the validatable
     let tests = target.tests();
 
@@ -128,12 +416,32 @@ pub fn validate_by_test<VersionedDocument>(target: &impl Validatable<VersionedDo
     let status = if let Some(test_fn) = tests.get(test_id) {
         match test_fn(target.doc()) {
             Ok(()) => Success,
-            Err(errors) => Failure { errors },
+            Err(mut errors) => {
+                errors.sort_by(ValidationError::canonical_cmp);
+                if let Some(document_id) = target.doc().tracking_id() {
+                    for error in errors.iter_mut() {
+                        error.document_id = Some(document_id.into());
+                    }
+                }
+                Failure { errors }
+            },
         }
     } else {
         NotFound
     };
 
+    #[cfg(feature = "tracing")]
+    {
+        let findings = match &status {
+            Failure { errors } => errors.len(),
+            _ => 0,
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        tracing::debug!(target: "csaf::validation", findings, duration = ?start.elapsed(), "test finished");
+        #[cfg(target_arch = "wasm32")]
+        tracing::debug!(target: "csaf::validation", findings, "test finished");
+    }
+
     TestResult {
         test_id: test_id.to_string(),
         status,
@@ -141,37 +449,32 @@ pub fn validate_by_test<VersionedDocument>(target: &impl Validatable<VersionedDo
 }
 
 /// Validate document with specific tests and return detailed results.
-pub fn validate_by_tests<VersionedDocument>(
+pub fn validate_by_tests<VersionedDocument: CsafTraitExt>(
     target: &impl Validatable<VersionedDocument>,
     version: &str,
     preset: ValidationPreset,
     test_ids: &[&str],
 ) -> ValidationResult {
-    let mut test_results = Vec::new();
-    let mut success = true;
-    let mut num_errors: usize = 0;
-
     // Loop through tests and gather all results and errors
-    for test_id in test_ids {
-        let test_result = validate_by_test(target, test_id);
-        if let Failure { errors } = &test_result.status {
-            success = false;
-            num_errors += errors.len();
-        }
-        test_results.push(test_result);
-    }
+    let test_results = test_ids
+        .iter()
+        .map(|test_id| validate_by_test(target, test_id))
+        .collect();
 
-    ValidationResult {
-        success,
+    let mut result = ValidationResult {
+        success: true,
         version: version.to_string(),
-        num_errors,
+        num_errors: 0,
+        num_suppressed: 0,
         preset,
         test_results,
-    }
+    };
+    result.update_summary();
+    result
 }
 
 /// Validate document with a preset and return detailed results.
-pub fn validate_by_preset<VersionedDocument>(
+pub fn validate_by_preset<VersionedDocument: CsafTraitExt>(
     target: &impl Validatable<VersionedDocument>,
     version: &str,
     preset: ValidationPreset,
@@ -186,3 +489,166 @@ pub fn validate_by_preset<VersionedDocument>(
     // Forward them to validate_by_tests
     validate_by_tests(target, version, preset, &test_ids)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::TestResultStatus::{Failure, NotFound, Success};
+    use crate::validation::{
+        Severity, SourceLocation, TestResult, ValidationError, ValidationPreset, ValidationResult,
+        compare_instance_paths,
+    };
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_validation_error_builder() {
+        let error = ValidationError::new(
+            "6.1.1",
+            "Missing definition of product_id: CSAFPID-0001",
+            "/vulnerabilities/0",
+        )
+        .with_severity(Severity::Warning)
+        .with_location(SourceLocation { line: 3, column: 7 });
+        assert_eq!(error.code, "6.1.1");
+        assert_eq!(error.severity, Severity::Warning);
+        assert_eq!(error.location, Some(SourceLocation { line: 3, column: 7 }));
+
+        // Severity defaults to error when it is missing in the serialized form
+        let parsed: ValidationError =
+            serde_json::from_str(r#"{"code":"6.1.1","message":"m","instancePath":"/document"}"#).unwrap();
+        assert_eq!(parsed, ValidationError::new("6.1.1", "m", "/document"));
+        assert!(Severity::Error > Severity::Warning && Severity::Warning > Severity::Info);
+    }
+
+    #[test]
+    fn test_canonical_order() {
+        assert_eq!(
+            compare_instance_paths("/vulnerabilities/2", "/vulnerabilities/10"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_instance_paths("/vulnerabilities/1", "/vulnerabilities/1/cve"),
+            Ordering::Less
+        );
+        assert_eq!(compare_instance_paths("/document", "/product_tree"), Ordering::Less);
+        assert_eq!(compare_instance_paths("", "/document"), Ordering::Less);
+
+        let sorted = vec![
+            ValidationError::new("6.1.1", "b", "/vulnerabilities/2/product_status/fixed/0"),
+            ValidationError::new("6.1.1", "a", "/vulnerabilities/10/product_status/fixed/0"),
+            ValidationError::new("6.1.1", "a", "/vulnerabilities/10/product_status/fixed/1"),
+            ValidationError::new("6.1.1", "b", "/vulnerabilities/10/product_status/fixed/1"),
+            ValidationError::new("6.1.2", "a", "/product_tree/full_product_names/0"),
+        ];
+        for rotation in 0..sorted.len() {
+            let mut errors = sorted.clone();
+            errors.rotate_left(rotation);
+            errors.reverse();
+            errors.sort_by(ValidationError::canonical_cmp);
+            assert_eq!(errors, sorted);
+        }
+    }
+
+    fn result(test_results: Vec<(&str, crate::validation::TestResultStatus)>) -> ValidationResult {
+        let mut result = ValidationResult {
+            success: true,
+            version: "2.1".to_string(),
+            preset: ValidationPreset::Basic,
+            test_results: test_results
+                .into_iter()
+                .map(|(test_id, status)| TestResult {
+                    test_id: test_id.to_string(),
+                    status,
+                })
+                .collect(),
+            num_errors: 0,
+            num_suppressed: 0,
+        };
+        result.update_summary();
+        result
+    }
+
+    #[test]
+    fn test_rule_url() {
+        let url = |code: &str| ValidationError::new(code, "", "").rule_url();
+        assert_eq!(
+            url("6.1.49").as_deref(),
+            Some("https://docs.oasis-open.org/csaf/csaf/v2.1/csaf-v2.1.html#6149")
+        );
+        assert_eq!(
+            url("6.1.27.1").as_deref(),
+            Some("https://docs.oasis-open.org/csaf/csaf/v2.1/csaf-v2.1.html#61271")
+        );
+        assert!(url("6.3.8").is_some());
+        for code in ["parse", "org-1", "6.1", "6.4.1", "6.1.", "7.1.1", "6.1.x"] {
+            assert_eq!(url(code), None, "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_validation_result_merge_and_dedup() {
+        let duplicate = ValidationError::new("6.1.2", "Duplicate product ID", "/product_tree/branches/0");
+        let other = ValidationError::new("6.1.2", "Duplicate product ID", "/product_tree/branches/1");
+
+        let mut semantic = result(vec![
+            ("6.1.1", Success),
+            (
+                "6.1.2",
+                Failure {
+                    errors: vec![duplicate.clone().with_severity(Severity::Warning), other.clone()],
+                },
+            ),
+            ("6.1.3", NotFound),
+        ]);
+        let schema = result(vec![
+            (
+                "6.1.2",
+                Failure {
+                    errors: vec![duplicate.clone(), duplicate.clone()],
+                },
+            ),
+            ("6.1.3", Success),
+            ("schema", Success),
+        ]);
+
+        semantic.merge(schema);
+        assert!(!semantic.success);
+        // The warning is not counted
+        assert_eq!(semantic.num_errors, 3);
+        assert_eq!(semantic.test_results.len(), 4);
+        assert!(matches!(semantic.test_results[2].status, Success));
+        assert_eq!(semantic.test_results[3].test_id, "schema");
+
+        semantic.dedup();
+        assert_eq!(semantic.num_errors, 2);
+        match &semantic.test_results[1].status {
+            Failure { errors } => assert_eq!(errors, &vec![duplicate, other]),
+            _ => panic!("Expected test 6.1.2 to fail"),
+        }
+    }
+
+    #[test]
+    fn test_warnings_do_not_fail() {
+        let warning = ValidationError::new("6.1.99", "Duplicate note title", "/document/notes/1/title")
+            .with_severity(Severity::Warning);
+        let warnings = result(vec![
+            ("6.1.1", Success),
+            (
+                "6.1.99",
+                Failure {
+                    errors: vec![warning.clone()],
+                },
+            ),
+        ]);
+        assert!(warnings.success);
+        assert_eq!(warnings.num_errors, 0);
+
+        let errors = result(vec![(
+            "6.1.99",
+            Failure {
+                errors: vec![warning, ValidationError::new("6.1.99", "", "/document/notes/2/title")],
+            },
+        )]);
+        assert!(!errors.success);
+        assert_eq!(errors.num_errors, 1);
+    }
+}
@@ -0,0 +1,127 @@
+//! Suppression of known findings
+//!
+//! A baseline records the findings of a document at some point in time, identified by their code
+//! and instance path. Applying it to a later [ValidationResult] drops all findings it contains, so
+//! that only new findings make a validation fail. This allows adopting the validator for legacy
+//! documents whose findings cannot be fixed right away.
+
+use crate::error::Error;
+use crate::validation::TestResultStatus::{Failure, Success};
+use crate::validation::{ValidationError, ValidationResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// The identity of a finding within a baseline
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub code: String,
+    pub instance_path: String,
+}
+
+impl From<&ValidationError> for BaselineEntry {
+    fn from(error: &ValidationError) -> Self {
+        BaselineEntry {
+            code: error.code.clone(),
+            instance_path: error.instance_path.clone(),
+        }
+    }
+}
+
+/// A set of known findings, stored as a JSON list of [BaselineEntry]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    entries: BTreeSet<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Loads a baseline from a JSON file.
+    pub fn load(path: &Path) -> Result<Baseline, Error> {
+        let entries: Vec<BaselineEntry> = serde_json::from_slice(&fs::read(path)?)?;
+        Ok(Baseline {
+            entries: entries.into_iter().collect(),
+        })
+    }
+
+    /// Creates a baseline containing all findings of `result`.
+    pub fn from_result(result: &ValidationResult) -> Baseline {
+        let entries = result
+            .test_results
+            .iter()
+            .filter_map(|r| match &r.status {
+                Failure { errors } => Some(errors),
+                _ => None,
+            })
+            .flatten()
+            .map(BaselineEntry::from)
+            .collect();
+        Baseline { entries }
+    }
+
+    /// Writes the baseline to a JSON file, with entries sorted by code and instance path.
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        let entries: Vec<&BaselineEntry> = self.entries.iter().collect();
+        fs::write(path, serde_json::to_string_pretty(&entries)?)?;
+        Ok(())
+    }
+
+    /// Returns whether `error` is a known finding.
+    pub fn contains(&self, error: &ValidationError) -> bool {
+        // BTreeSet lookups need an owned key
+        self.entries.contains(&BaselineEntry::from(error))
+    }
+
+    /// Removes all known findings from `result` and counts them in
+    /// [ValidationResult::num_suppressed].
+    ///
+    /// Tests whose findings are all known are reported as successful.
+    pub fn apply(&self, result: &mut ValidationResult) {
+        for test_result in &mut result.test_results {
+            if let Failure { errors } = &mut test_result.status {
+                let before = errors.len();
+                errors.retain(|e| !self.contains(e));
+                result.num_suppressed += before - errors.len();
+                if errors.is_empty() {
+                    test_result.status = Success;
+                }
+            }
+        }
+        result.update_summary();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::baseline::Baseline;
+    use crate::validation::{ValidationPreset, validate_by_preset};
+    use serde_json::json;
+
+    #[test]
+    fn test_baseline() {
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [
+                    { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                    { "name": "Product B", "product_id": "CSAFPID-9080700" }
+                ]
+            });
+        });
+        let mut result = validate_by_preset(&doc, "2.1", ValidationPreset::Basic);
+        assert!(!result.success);
+        let num_errors = result.num_errors;
+
+        let path = std::env::temp_dir().join(format!("csaf-baseline-{}.json", std::process::id()));
+        Baseline::from_result(&result).write(&path).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        baseline.apply(&mut result);
+        assert!(result.success);
+        assert_eq!(result.num_errors, 0);
+        assert_eq!(result.num_suppressed, num_errors);
+
+        assert!(Baseline::load(&std::env::temp_dir().join("csaf-baseline-missing.json")).is_err());
+    }
+}
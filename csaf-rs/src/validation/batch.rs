@@ -0,0 +1,325 @@
+//! Validation of many documents in one call
+//!
+//! The functions in this module take raw document bytes, detect the CSAF version, parse the
+//! document and run a validation preset on it. With the `rayon` feature enabled, batches are
+//! validated in parallel.
+//!
+//! Documents are loaded within the default [Limits], use a [Validator] to configure them.
+
+use crate::csaf_traits::CsafTrait;
+use crate::error::Error;
+use crate::limits::Limits;
+use crate::product_tree_loader::{NoExternalProductTree, ProductTreeLoader};
+use crate::schema::csaf2_0::schema::CommonSecurityAdvisoryFramework as Csaf20;
+use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+use crate::validation::TestResultStatus::{Failure, Success};
+use crate::validation::validator::Validator;
+use crate::validation::{TestResult, ValidationPreset, ValidationResult};
+use crate::validations::test_6_1_52::strip_unknown_product_status_keys;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use serde::Serialize;
+use serde_json::Value;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Result of validating a single file with [validate_bytes_many]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileResult {
+    /// The path the document was read from
+    pub path: PathBuf,
+    /// Why the document could not be parsed, if it could not be parsed
+    pub parse_error: Option<String>,
+    /// The validation result, if the document could be parsed
+    pub findings: Option<ValidationResult>,
+    /// Wall-clock time spent on parsing and validating the document
+    pub duration: Duration,
+}
+
+/// Parses a CSAF document from raw bytes and validates it according to a validation preset.
+///
+/// The CSAF version is detected from `/document/csaf_version`. An error is returned if the bytes
+/// are not valid JSON, the version is missing or unsupported, or the document does not conform to
+/// the schema of its version, or [Error::LimitExceeded] if it exceeds the default [Limits].
+/// External product trees are not resolved, use [validate_bytes_with_loader] for documents
+/// containing one.
+pub fn validate_bytes(bytes: &[u8], preset: ValidationPreset) -> Result<ValidationResult, Error> {
+    validate_bytes_with_loader(bytes, preset, &NoExternalProductTree)
+}
+
+/// Like [validate_bytes], but resolves an external product tree with `loader` and merges it into
+/// the document before parsing it.
+///
+/// A reference that cannot be resolved fails with [Error::ExternalProductTree].
+pub fn validate_bytes_with_loader(
+    bytes: &[u8],
+    preset: ValidationPreset,
+    loader: &impl ProductTreeLoader,
+) -> Result<ValidationResult, Error> {
+    Validator::new(preset).validate_bytes_with_loader(bytes, loader)
+}
+
+/// Validates many documents according to a validation preset.
+///
+/// Each entry of `files` consists of the path of a document and its content. A document that
+/// cannot be parsed does not abort the batch, its [FileResult] carries the parse error instead.
+/// Results are returned in the order of `files`.
+pub fn validate_bytes_many(files: &[(PathBuf, Vec<u8>)], preset: ValidationPreset) -> Vec<FileResult> {
+    #[cfg(feature = "rayon")]
+    let iter = files.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let iter = files.iter();
+
+    iter.map(|(path, bytes)| validate_entry(path, bytes, preset.clone()))
+        .collect()
+}
+
+/// Reads a CSAF document from a file and validates it with `validator`.
+///
+/// The CSAF version is detected from `/document/csaf_version`, and the file is read within the
+/// [limits](Validator::limits) of the validator. Files with a `.yaml` or `.yml` extension are
+/// parsed as YAML with the `yaml` feature, and rejected with [Error::UnsupportedFormat] without
+/// it. Every error is wrapped in [Error::File] with the path of the file attached.
+pub fn validate_file(path: impl AsRef<Path>, validator: &Validator) -> Result<ValidationResult, Error> {
+    let path = path.as_ref();
+    read_and_validate(path, validator).map_err(|e| Error::File {
+        path: path.to_path_buf(),
+        source: Box::new(e),
+    })
+}
+
+fn read_and_validate(path: &Path, validator: &Validator) -> Result<ValidationResult, Error> {
+    match path.extension().and_then(OsStr::to_str) {
+        #[cfg(feature = "yaml")]
+        Some("yaml" | "yml") => {
+            let bytes = validator.limits().read(std::fs::File::open(path)?)?;
+            validate_value(serde_yaml_ng::from_slice(&bytes)?, validator)
+        },
+        #[cfg(not(feature = "yaml"))]
+        Some(extension @ ("yaml" | "yml")) => Err(Error::UnsupportedFormat(extension.to_string())),
+        _ => validator.validate_reader(std::fs::File::open(path)?),
+    }
+}
+
+/// Validates the JSON of a document with `validator`, see [load_value].
+pub(crate) fn validate_value(json_value: Value, validator: &Validator) -> Result<ValidationResult, Error> {
+    let (doc, raw_results) = load_value(json_value, validator.limits())?;
+    Ok(validator.validate_with_raw(doc.as_ref(), raw_results))
+}
+
+/// Deserializes the JSON of a document according to its `/document/csaf_version`, failing with
+/// [Error::LimitExceeded] if it exceeds `limits`.
+///
+/// Test 6.1.52 is executed on the JSON before, as the typed documents cannot represent unknown
+/// product status categories, which are removed. Its result is returned with the document.
+pub(crate) fn load_value(
+    mut json_value: Value,
+    limits: &Limits,
+) -> Result<(Box<dyn CsafTrait>, Vec<TestResult>), Error> {
+    limits.check_value(&json_value)?;
+    let product_status_keys = TestResult {
+        test_id: "6.1.52".to_string(),
+        status: match strip_unknown_product_status_keys(&mut json_value) {
+            Ok(()) => Success,
+            Err(errors) => Failure { errors },
+        },
+    };
+    let doc: Box<dyn CsafTrait> = match json_value.pointer("/document/csaf_version").and_then(Value::as_str) {
+        Some("2.0") => Box::new(serde_json::from_value::<Csaf20>(json_value)?),
+        Some("2.1") => Box::new(serde_json::from_value::<Csaf21>(json_value)?),
+        Some(version) => return Err(Error::UnsupportedVersion(version.to_string())),
+        None => {
+            // Let deserialization report the missing version
+            serde_json::from_value::<Csaf21>(json_value)?;
+            return Err(Error::UnsupportedVersion(String::new()));
+        },
+    };
+    Ok((doc, vec![product_status_keys]))
+}
+
+fn validate_entry(path: &Path, bytes: &[u8], preset: ValidationPreset) -> FileResult {
+    let start = Instant::now();
+    let (parse_error, findings) = match validate_bytes(bytes, preset) {
+        Ok(result) => (None, Some(result)),
+        Err(e) => (Some(e.to_string()), None),
+    };
+    FileResult {
+        path: path.to_path_buf(),
+        parse_error,
+        findings,
+        duration: start.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::limits::Limits;
+    use crate::product_tree_loader::ProductTreeLoader;
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::TestResultStatus::{Failure, Success};
+    use crate::validation::ValidationPreset;
+    use crate::validation::batch::{validate_bytes, validate_bytes_many, validate_bytes_with_loader, validate_file};
+    use crate::validation::validator::Validator;
+    use serde_json::{Value, json};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_validate_bytes_many() {
+        let files = vec![
+            (PathBuf::from("broken.json"), b"{ not json".to_vec()),
+            (
+                PathBuf::from("valid.json"),
+                serde_json::to_vec(&mock_csaf21(|_| {})).unwrap(),
+            ),
+            (
+                PathBuf::from("unsupported.json"),
+                br#"{"document": {"csaf_version": "1.2"}}"#.to_vec(),
+            ),
+        ];
+
+        let results = validate_bytes_many(&files, ValidationPreset::Basic);
+        assert_eq!(results.len(), 3);
+
+        assert_eq!(results[0].path, PathBuf::from("broken.json"));
+        assert!(
+            results[0]
+                .parse_error
+                .as_ref()
+                .unwrap()
+                .starts_with("JSON parsing error")
+        );
+        assert!(results[0].findings.is_none());
+
+        assert_eq!(results[1].parse_error, None);
+        let findings = results[1].findings.as_ref().unwrap();
+        assert_eq!(findings.version, "2.1");
+        assert!(findings.success);
+
+        assert_eq!(results[2].parse_error.as_deref(), Some("Unsupported CSAF version: 1.2"));
+
+        let serialized = serde_json::to_value(&results[0]).unwrap();
+        assert_eq!(serialized["path"], "broken.json");
+        assert!(serialized["duration"].is_object());
+    }
+
+    struct TreeLoader;
+
+    impl ProductTreeLoader for TreeLoader {
+        fn load(&self, _reference: &str) -> Option<Value> {
+            Some(json!({ "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }] }))
+        }
+    }
+
+    #[test]
+    fn test_validate_bytes_with_loader() {
+        let mut doc = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        doc["product_tree"] = json!({ "$ref": "tree.json" });
+        let bytes = serde_json::to_vec(&doc).unwrap();
+
+        match validate_bytes(&bytes, ValidationPreset::Basic) {
+            Err(Error::ExternalProductTree(message)) => {
+                assert_eq!(message, "Could not resolve external product tree 'tree.json'")
+            },
+            other => panic!("Expected an unresolved product tree, got {:?}", other),
+        }
+        assert!(
+            validate_bytes_with_loader(&bytes, ValidationPreset::Basic, &TreeLoader)
+                .unwrap()
+                .success
+        );
+    }
+
+    #[test]
+    fn test_validate_bytes_product_status_keys() {
+        let mut doc = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-0001" }]
+        });
+        doc["vulnerabilities"] = json!([{ "product_status": { "Known_Affected": ["CSAFPID-0001"] } }]);
+        let bytes = serde_json::to_vec(&doc).unwrap();
+
+        let result = validate_bytes(&bytes, ValidationPreset::Basic).unwrap();
+        assert!(!result.success);
+        let product_status_keys = result.test_results.iter().find(|r| r.test_id == "6.1.52").unwrap();
+        match &product_status_keys.status {
+            Failure { errors } => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].instance_path, "/vulnerabilities/0/product_status");
+                assert_eq!(errors[0].document_id.as_deref(), Some("MOCK-2024-0001"));
+                assert_eq!(errors[0].rule_set.as_deref(), Some("csaf"));
+            },
+            _ => panic!("Expected test 6.1.52 to fail"),
+        }
+
+        let ignoring = Validator::builder().ignore(["6.1.52"]).build().unwrap();
+        let result = ignoring.validate_bytes(&bytes).unwrap();
+        assert!(!result.test_results.iter().any(|r| r.test_id == "6.1.52"));
+
+        let valid = serde_json::to_vec(&mock_csaf21(|_| {})).unwrap();
+        let result = validate_bytes(&valid, ValidationPreset::Basic).unwrap();
+        assert!(
+            result
+                .test_results
+                .iter()
+                .any(|r| r.test_id == "6.1.52" && matches!(r.status, Success))
+        );
+    }
+
+    #[test]
+    fn test_validate_file() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let validator = Validator::new(ValidationPreset::Full);
+
+        let result = validate_file(fixtures.join("duplicate-product-id-2.1.json"), &validator).unwrap();
+        assert_eq!(result.version, "2.1");
+        assert_eq!(result.preset, ValidationPreset::Full);
+        assert!(!result.success);
+
+        let missing = fixtures.join("missing.json");
+        match validate_file(&missing, &validator) {
+            Err(Error::File { path, source }) => {
+                assert_eq!(path, missing);
+                assert!(matches!(*source, Error::Io(_)));
+            },
+            other => panic!("Expected an I/O error, got {:?}", other),
+        }
+
+        let small = Validator::builder()
+            .limits(Limits {
+                max_document_bytes: 64,
+                ..Limits::default()
+            })
+            .build()
+            .unwrap();
+        assert!(matches!(
+            validate_file(fixtures.join("duplicate-product-id-2.1.json"), &small),
+            Err(Error::File { source, .. }) if matches!(*source, Error::LimitExceeded { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn test_validate_yaml_file() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let validator = Validator::new(ValidationPreset::Full);
+        let json = validate_file(fixtures.join("duplicate-product-id-2.1.json"), &validator).unwrap();
+        let yaml = validate_file(fixtures.join("duplicate-product-id-2.1.yaml"), &validator).unwrap();
+        assert_eq!(
+            serde_json::to_value(&json.test_results).unwrap(),
+            serde_json::to_value(&yaml.test_results).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "yaml"))]
+    fn test_validate_yaml_file() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        assert!(matches!(
+            validate_file(fixtures.join("duplicate-product-id-2.1.yaml"), &Validator::new(ValidationPreset::Full)),
+            Err(Error::File { source, .. }) if matches!(*source, Error::UnsupportedFormat(_))
+        ));
+    }
+}
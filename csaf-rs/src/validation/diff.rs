@@ -0,0 +1,179 @@
+//! Comparison of two validation results
+//!
+//! [diff_reports] classifies the findings of two results of the same document, e.g. before and
+//! after changing a test, as added, removed or unchanged. Findings are identified by their code
+//! and instance path, like the entries of a [crate::validation::baseline::Baseline], so that a
+//! changed message does not count as a change, while a moved finding counts as removed from its
+//! old path and added at its new one.
+
+use crate::validation::TestResultStatus::Failure;
+use crate::validation::baseline::BaselineEntry;
+use crate::validation::{ValidationError, ValidationResult};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// The findings of two validation results, classified by [diff_reports]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportDiff {
+    /// Findings of the new result only
+    pub added: Vec<ValidationError>,
+    /// Findings of the old result only
+    pub removed: Vec<ValidationError>,
+    /// Findings of both results, as reported in the new one
+    pub unchanged: Vec<ValidationError>,
+}
+
+impl ReportDiff {
+    /// Returns whether both results have the same findings.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl Display for ReportDiff {
+    /// Summarizes the diff, e.g. `2 added, 1 removed, 5 unchanged`, followed by one line per
+    /// added or removed finding.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} added, {} removed, {} unchanged",
+            self.added.len(),
+            self.removed.len(),
+            self.unchanged.len()
+        )?;
+        for (sign, errors) in [('+', &self.added), ('-', &self.removed)] {
+            for error in errors {
+                write!(
+                    f,
+                    "\n{} [{}] {}: {}",
+                    sign, error.code, error.instance_path, error.message
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Classifies the findings of `old` and `new` by their code and instance path.
+///
+/// Repeated findings with the same identity are matched one by one, so that a finding reported
+/// twice in `new` but once in `old` counts as one unchanged and one added finding.
+pub fn diff_reports(old: &ValidationResult, new: &ValidationResult) -> ReportDiff {
+    let mut diff = ReportDiff::default();
+    let mut remaining = count_findings(old);
+    for error in findings(new) {
+        if take(&mut remaining, error) {
+            diff.unchanged.push(error.clone());
+        } else {
+            diff.added.push(error.clone());
+        }
+    }
+    let mut remaining = count_findings(new);
+    diff.removed = findings(old).filter(|e| !take(&mut remaining, e)).cloned().collect();
+    diff
+}
+
+fn count_findings(result: &ValidationResult) -> HashMap<BaselineEntry, usize> {
+    let mut counts = HashMap::new();
+    for error in findings(result) {
+        *counts.entry(BaselineEntry::from(error)).or_default() += 1;
+    }
+    counts
+}
+
+/// Decrements the count of the identity of `error`, returning whether it was positive.
+fn take(counts: &mut HashMap<BaselineEntry, usize>, error: &ValidationError) -> bool {
+    match counts.get_mut(&BaselineEntry::from(error)) {
+        Some(count) if *count > 0 => {
+            *count -= 1;
+            true
+        },
+        _ => false,
+    }
+}
+
+fn findings(result: &ValidationResult) -> impl Iterator<Item = &ValidationError> {
+    result
+        .test_results
+        .iter()
+        .filter_map(|r| match &r.status {
+            Failure { errors } => Some(errors),
+            _ => None,
+        })
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::TestResultStatus::{Failure, Success};
+    use crate::validation::diff::{ReportDiff, diff_reports};
+    use crate::validation::{TestResult, ValidationError, ValidationPreset, ValidationResult};
+
+    fn result(errors: &[(&str, &str)]) -> ValidationResult {
+        let mut result = ValidationResult {
+            success: true,
+            version: "2.1".to_string(),
+            preset: ValidationPreset::Basic,
+            test_results: ["6.1.1", "6.1.2"]
+                .into_iter()
+                .map(|test_id| {
+                    let errors: Vec<ValidationError> = errors
+                        .iter()
+                        .filter(|(code, _)| *code == test_id)
+                        .map(|(code, path)| ValidationError::new(*code, format!("Finding at {}", path), *path))
+                        .collect();
+                    TestResult {
+                        test_id: test_id.to_string(),
+                        status: if errors.is_empty() { Success } else { Failure { errors } },
+                    }
+                })
+                .collect(),
+            num_errors: 0,
+            num_suppressed: 0,
+        };
+        result.update_summary();
+        result
+    }
+
+    #[test]
+    fn test_diff_reports() {
+        let old = result(&[("6.1.1", "/a"), ("6.1.2", "/b"), ("6.1.2", "/c")]);
+        assert!(diff_reports(&old, &old).is_empty());
+        assert_eq!(diff_reports(&old, &old).unchanged.len(), 3);
+
+        // The finding at /c moved to /d, the one of 6.1.1 was fixed, another one was introduced
+        let new = result(&[("6.1.2", "/b"), ("6.1.2", "/d"), ("6.1.2", "/d")]);
+        let diff = diff_reports(&old, &new);
+        assert_eq!(
+            diff,
+            ReportDiff {
+                added: vec![
+                    ValidationError::new("6.1.2", "Finding at /d", "/d"),
+                    ValidationError::new("6.1.2", "Finding at /d", "/d"),
+                ],
+                removed: vec![
+                    ValidationError::new("6.1.1", "Finding at /a", "/a"),
+                    ValidationError::new("6.1.2", "Finding at /c", "/c"),
+                ],
+                unchanged: vec![ValidationError::new("6.1.2", "Finding at /b", "/b")],
+            }
+        );
+        assert_eq!(
+            diff.to_string(),
+            "2 added, 2 removed, 1 unchanged\n\
+             + [6.1.2] /d: Finding at /d\n\
+             + [6.1.2] /d: Finding at /d\n\
+             - [6.1.1] /a: Finding at /a\n\
+             - [6.1.2] /c: Finding at /c"
+        );
+
+        // Repeated findings are matched one by one
+        let diff = diff_reports(&new, &result(&[("6.1.2", "/b"), ("6.1.2", "/d")]));
+        assert_eq!(diff.removed, vec![ValidationError::new("6.1.2", "Finding at /d", "/d")]);
+        assert_eq!(diff.unchanged.len(), 2);
+
+        assert_eq!(serde_json::to_value(&diff).unwrap()["removed"][0]["instancePath"], "/d");
+    }
+}
@@ -0,0 +1,100 @@
+//! Validations spanning multiple CSAF documents
+//!
+//! Contrary to the tests in [crate::validations], which look at a single document, the checks in
+//! this module are executed against a collection of documents, e.g. all documents of a feed, or
+//! against two versions of the same document.
+
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, TrackingTrait};
+use crate::validation::ValidationError;
+use crate::validations::test_6_1_78::check_status_transition;
+use std::collections::BTreeMap;
+
+/// Validates a feed of CSAF documents for conflicts between the documents.
+///
+/// Currently, it is checked that no two documents share the same tracking ID. Each conflict is
+/// reported once per affected document. As the errors do not relate to a single document, the
+/// instance path is prefixed with the index of the affected document in `docs`.
+pub fn validate_feed(docs: &[impl CsafTraitExt]) -> Result<(), Vec<ValidationError>> {
+    // Map to store each tracking ID with the indices of all documents using it
+    let mut tracking_ids: BTreeMap<&String, Vec<usize>> = BTreeMap::new();
+    for (d_i, doc) in docs.iter().enumerate() {
+        tracking_ids
+            .entry(doc.get_document().get_tracking().get_id())
+            .or_default()
+            .push(d_i);
+    }
+
+    let mut errors: Option<Vec<ValidationError>> = Option::None;
+    for (id, indices) in tracking_ids.iter().filter(|(_, indices)| indices.len() > 1) {
+        let index_list = indices
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        for d_i in indices {
+            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                "6.1.51",
+                format!("Tracking ID {} is used by multiple documents: {}", id, index_list),
+                format!("/{}/document/tracking/id", d_i),
+            ));
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Validates the new version `current` of a document against its previous version `previous`.
+///
+/// Currently, the transition of the tracking status is checked by test 6.1.78. The instance paths
+/// refer to `current`.
+pub fn validate_update(previous: &impl CsafTraitExt, current: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let prev_status = previous.get_document().get_tracking().get_status();
+    let new_status = current.get_document().get_tracking().get_status();
+    check_status_transition(prev_status, new_status).map_or(Ok(()), |error| Err(vec![error]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::feed::{validate_feed, validate_update};
+    use crate::validation::{Severity, ValidationError};
+
+    #[test]
+    fn test_validate_feed() {
+        let first = mock_csaf21(|_| {});
+        let second = mock_csaf21(|doc| doc["document"]["tracking"]["id"] = "MOCK-2024-0002".into());
+        let third = mock_csaf21(|_| {});
+
+        assert_eq!(Ok(()), validate_feed(&[first.clone(), second.clone()]));
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.51",
+                    "Tracking ID MOCK-2024-0001 is used by multiple documents: 0, 2",
+                    "/0/document/tracking/id"
+                ),
+                ValidationError::new(
+                    "6.1.51",
+                    "Tracking ID MOCK-2024-0001 is used by multiple documents: 0, 2",
+                    "/2/document/tracking/id"
+                ),
+            ]),
+            validate_feed(&[first, second, third])
+        );
+    }
+
+    #[test]
+    fn test_validate_update() {
+        let draft = mock_csaf20(|doc| doc["document"]["tracking"]["status"] = "draft".into());
+        let final_ = mock_csaf21(|doc| doc["document"]["tracking"]["status"] = "final".into());
+
+        assert_eq!(Ok(()), validate_update(&draft, &final_));
+        assert_eq!(
+            Err(vec![
+                ValidationError::with_args("6.1.78", ["final", "draft"], "/document/tracking/status")
+                    .with_severity(Severity::Warning)
+            ]),
+            validate_update(&final_, &draft)
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Automatic fixes for findings
+//!
+//! [suggest_fix] fixes a finding of one of the tests in [FIXABLE_CODES] in the JSON of the
+//! document it was found in. Only fixes that do not change the meaning of the document are
+//! offered, so e.g. an unsorted revision history (6.1.14) is never fixed, as it is unknown whether
+//! the dates or the numbers are wrong.
+//!
+//! Fixes may shift the instance paths of other findings, e.g. when removing an item, so the
+//! document should be validated again before fixing further findings.
+
+use crate::validation::ValidationError;
+use crate::validations::test_6_1_64::HASH_LENGTHS;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Codes of the tests whose findings can be fixed by [suggest_fix]
+pub static FIXABLE_CODES: &[&str] = &["6.1.51", "6.1.64", "6.1.85"];
+
+/// A fix applied to a document by [suggest_fix]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FixApplied {
+    /// Code of the fixed finding
+    pub code: String,
+    /// Instance path of the fixed finding
+    pub instance_path: String,
+    /// Human-readable description of the change
+    pub description: String,
+}
+
+/// Fixes `error` in `doc`, returning what was changed.
+///
+/// Returns `None` and leaves `doc` unchanged if the code of `error` is not in [FIXABLE_CODES],
+/// or if the finding cannot be fixed safely, e.g. a hash value of the wrong length (6.1.64).
+pub fn suggest_fix(doc: &mut Value, error: &ValidationError) -> Option<FixApplied> {
+    let description = match error.code.as_str() {
+        "6.1.51" => trim_tracking_id(doc, &error.instance_path),
+        "6.1.64" => lowercase_hash_value(doc, &error.instance_path),
+        "6.1.85" => remove_duplicate_vulnerability_id(doc, &error.instance_path),
+        _ => None,
+    }?;
+    Some(FixApplied {
+        code: error.code.clone(),
+        instance_path: error.instance_path.clone(),
+        description,
+    })
+}
+
+/// Removes leading and trailing whitespace from the tracking ID, unless nothing else is left.
+fn trim_tracking_id(doc: &mut Value, path: &str) -> Option<String> {
+    let id = doc.pointer_mut(path)?;
+    let trimmed = id.as_str()?.trim();
+    if trimmed.is_empty() || trimmed == id.as_str()? {
+        return None;
+    }
+    let trimmed = trimmed.to_string();
+    *id = Value::String(trimmed.clone());
+    Some(format!(
+        "Removed leading and trailing whitespace from tracking ID '{}'",
+        trimmed
+    ))
+}
+
+/// Lowercases a hash value, if this makes it a valid digest of its algorithm.
+fn lowercase_hash_value(doc: &mut Value, path: &str) -> Option<String> {
+    let file_hash = doc.pointer_mut(path.strip_suffix("/value")?)?;
+    let algorithm = file_hash.get("algorithm")?.as_str()?.to_lowercase();
+    let (_, length) = HASH_LENGTHS.iter().find(|(a, _)| *a == algorithm)?;
+    let value = file_hash.get_mut("value")?;
+    let lowercase = value.as_str()?.to_ascii_lowercase();
+    let valid = lowercase.len() == *length && lowercase.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'));
+    if !valid || lowercase == value.as_str()? {
+        return None;
+    }
+    *value = Value::String(lowercase.clone());
+    Some(format!("Lowercased {} hash value to '{}'", algorithm, lowercase))
+}
+
+/// Removes an item of `/vulnerabilities[]/ids` that repeats an earlier one.
+fn remove_duplicate_vulnerability_id(doc: &mut Value, path: &str) -> Option<String> {
+    let (ids_path, index) = path.rsplit_once('/')?;
+    let index: usize = index.parse().ok()?;
+    let ids = doc.pointer_mut(ids_path)?.as_array_mut()?;
+    let key = |id: &Value| (id.get("system_name").cloned(), id.get("text").cloned());
+    let duplicate = key(ids.get(index)?);
+    if !ids[..index].iter().any(|id| key(id) == duplicate) {
+        return None;
+    }
+    let removed = ids.remove(index);
+    Some(format!(
+        "Removed duplicate vulnerability ID '{}' of system '{}'",
+        removed["text"].as_str().unwrap_or_default(),
+        removed["system_name"].as_str().unwrap_or_default()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework as Csaf21;
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationError;
+    use crate::validation::fix::{FixApplied, suggest_fix};
+    use crate::validations::test_6_1_51::test_6_1_51_tracking_id_format;
+    use crate::validations::test_6_1_64::test_6_1_64_hash_value_format;
+    use crate::validations::test_6_1_85::test_6_1_85_duplicate_vulnerability_ids;
+    use serde_json::{Value, json};
+
+    /// Fixes all findings of `test` in `doc`, returning the fixes and the fixed document.
+    fn fix_all(doc: Csaf21, test: fn(&Csaf21) -> Result<(), Vec<ValidationError>>) -> (Vec<FixApplied>, Csaf21) {
+        let mut value = serde_json::to_value(&doc).unwrap();
+        let mut fixes = Vec::new();
+        // Fix the last finding first, so that removing an item does not shift the others
+        for error in test(&doc).unwrap_err().iter().rev() {
+            fixes.push(suggest_fix(&mut value, error).expect("Finding could not be fixed"));
+        }
+        (fixes, serde_json::from_value(value).unwrap())
+    }
+
+    #[test]
+    fn test_suggest_fix() {
+        // The schema rejects surrounding whitespace, so the finding only occurs in the raw JSON
+        let mut value = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        value["document"]["tracking"]["id"] = json!(" MOCK-2024-0001\n");
+        let error = ValidationError::new(
+            "6.1.51",
+            "Tracking ID ' MOCK-2024-0001\n' must not have leading or trailing whitespace",
+            "/document/tracking/id",
+        );
+        assert_eq!(
+            suggest_fix(&mut value, &error),
+            Some(FixApplied {
+                code: "6.1.51".to_string(),
+                instance_path: "/document/tracking/id".to_string(),
+                description: "Removed leading and trailing whitespace from tracking ID 'MOCK-2024-0001'".to_string(),
+            })
+        );
+        let fixed: Csaf21 = serde_json::from_value(value).unwrap();
+        assert_eq!(Ok(()), test_6_1_51_tracking_id_format(&fixed));
+
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [{
+                    "name": "Product A",
+                    "product_id": "CSAFPID-0001",
+                    "product_identification_helper": {
+                        "hashes": [{
+                            "filename": "product_a.so",
+                            "file_hashes": [{ "algorithm": "MD5", "value": "D41D8CD98F00B204E9800998ECF8427E" }]
+                        }]
+                    }
+                }]
+            });
+        });
+        let (fixes, fixed) = fix_all(doc, test_6_1_64_hash_value_format);
+        assert_eq!(
+            fixes[0].description,
+            "Lowercased md5 hash value to 'd41d8cd98f00b204e9800998ecf8427e'"
+        );
+        assert_eq!(Ok(()), test_6_1_64_hash_value_format(&fixed));
+
+        let doc = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "ids": [
+                    { "system_name": "GHSA", "text": "GHSA-1234" },
+                    { "system_name": "GHSA", "text": "GHSA-1234" },
+                    { "system_name": "Vendor", "text": "VULN-1" },
+                    { "system_name": "GHSA", "text": "GHSA-1234" }
+                ]
+            }]);
+        });
+        let (fixes, fixed) = fix_all(doc, test_6_1_85_duplicate_vulnerability_ids);
+        assert_eq!(fixes.len(), 2);
+        assert_eq!(Ok(()), test_6_1_85_duplicate_vulnerability_ids(&fixed));
+    }
+
+    #[test]
+    fn test_suggest_fix_unfixable() {
+        let mut doc = json!({
+            "document": { "tracking": { "id": "  " } },
+            "file_hashes": [{ "algorithm": "sha256", "value": "ABC" }]
+        });
+        let original = doc.clone();
+        for (code, path) in [
+            ("6.1.51", "/document/tracking/id"),
+            ("6.1.64", "/file_hashes/0/value"),
+            ("6.1.14", "/document/tracking/revision_history/0"),
+        ] {
+            let error = ValidationError::new(code, "Finding", path);
+            assert_eq!(None, suggest_fix(&mut doc, &error));
+        }
+        assert_eq!(doc, original);
+
+        // An item that does not repeat an earlier one is kept
+        let mut doc: Value = json!({ "ids": [{ "system_name": "GHSA", "text": "GHSA-1234" }] });
+        assert_eq!(
+            None,
+            suggest_fix(&mut doc, &ValidationError::new("6.1.85", "Finding", "/ids/0"))
+        );
+    }
+}
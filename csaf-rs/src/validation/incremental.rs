@@ -0,0 +1,107 @@
+//! Validation of a single vulnerability, e.g. while it is being edited
+//!
+//! [validate_vulnerability] is the incremental counterpart of [crate::validation::diff]: instead
+//! of validating the whole document again and comparing the results, it only executes the tests
+//! declared as [TestScope::Vulnerability] and keeps the findings within the given vulnerability.
+//! These tests still see the whole document, so that product references are resolved against the
+//! full product tree and vulnerabilities can be compared with each other.
+
+use crate::csaf_traits::CsafTrait;
+use crate::validation::TestResultStatus::{Failure, Success};
+use crate::validation::validator::{Validator, attribute};
+use crate::validation::{TestScope, ValidationError};
+
+/// Validates the vulnerability at `index` with the vulnerability-scoped tests of `validator`.
+///
+/// Document-level and product tree tests as well as custom rules are not executed, nor are the
+/// built-in tests if the validator replaced their [rule set](crate::validation::validator::RuleSet).
+/// The tests that the validator ignores are skipped and its baseline and locale are applied, like
+/// in [Validator::validate]. An `index` beyond the vulnerabilities of the document has no findings.
+pub fn validate_vulnerability(doc: &dyn CsafTrait, index: usize, validator: &Validator) -> Vec<ValidationError> {
+    let Some(rule_set) = validator.builtin_rule_set() else {
+        return Vec::new();
+    };
+    let test_ids: Vec<String> = validator
+        .test_ids(doc)
+        .into_iter()
+        .filter(|id| doc.get_test_scope(id) == TestScope::Vulnerability)
+        .collect();
+    let test_ids: Vec<&str> = test_ids.iter().map(String::as_str).collect();
+    let mut result = doc.validate_by_tests(validator.preset().clone(), &test_ids);
+    result.test_results = attribute(result.test_results, rule_set);
+
+    let subtree = format!("/vulnerabilities/{}", index);
+    for test_result in result.test_results.iter_mut() {
+        if let Failure { errors } = &mut test_result.status {
+            errors.retain(|e| {
+                e.instance_path
+                    .strip_prefix(&subtree)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            });
+            if errors.is_empty() {
+                test_result.status = Success;
+            }
+        }
+    }
+
+    validator
+        .finish(result)
+        .test_results
+        .into_iter()
+        .flat_map(|r| match r.status {
+            Failure { errors } => errors,
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::incremental::validate_vulnerability;
+    use crate::validation::validator::Validator;
+    use crate::validation::{ValidationError, ValidationPreset};
+    use serde_json::{Value, json};
+
+    fn cwe(id: &str) -> Value {
+        json!({ "id": id, "name": "Weakness", "version": "4.13" })
+    }
+
+    #[test]
+    fn test_validate_vulnerability() {
+        let doc = mock_csaf21(|doc| {
+            // 6.1.76 fails in the product tree, 6.1.91 in both vulnerabilities
+            doc["product_tree"] = json!({
+                "full_product_names": [{
+                    "name": "Product A",
+                    "product_id": "CSAFPID-0001",
+                    "product_identification_helper": {}
+                }]
+            });
+            doc["vulnerabilities"] = json!([
+                { "cwes": [cwe("CWE-79"), cwe("CWE-79")] },
+                { "cwes": [cwe("CWE-89"), cwe("CWE-79"), cwe("CWE-89")] }
+            ]);
+        });
+        let validator = Validator::new(ValidationPreset::Basic);
+        assert_eq!(validator.validate(&doc).num_errors, 3);
+
+        assert_eq!(
+            validate_vulnerability(&doc, 1, &validator),
+            vec![
+                ValidationError::new(
+                    "6.1.91",
+                    "CWE-89 duplicates the CWE at /vulnerabilities/1/cwes/0",
+                    "/vulnerabilities/1/cwes/2"
+                )
+                .with_document_id("MOCK-2024-0001")
+                .with_rule_set("csaf")
+            ]
+        );
+        assert_eq!(validate_vulnerability(&doc, 0, &validator).len(), 1);
+        assert!(validate_vulnerability(&doc, 2, &validator).is_empty());
+
+        let validator = Validator::builder().ignore(["6.1.91"]).build().unwrap();
+        assert!(validate_vulnerability(&doc, 1, &validator).is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+//! Translatable messages of findings
+//!
+//! Tests emitting their findings with [ValidationError::with_args] record the ordered arguments
+//! of the message, so that a [MessageCatalog] can render it in any registered locale later. The
+//! `message` field always holds the English rendering, so consumers not interested in other
+//! locales are not affected.
+//!
+//! Findings created with [ValidationError::new] only carry their pre-formatted English message,
+//! which is returned unchanged for every locale. Templates are keyed by code, so only tests that
+//! emit a single kind of message can use them. So far these are 6.1.4, 6.1.5, 6.1.76, 6.1.77,
+//! 6.1.78 and 6.1.79; the findings of all other tests are English only.
+
+use crate::validation::TestResultStatus::Failure;
+use crate::validation::{ValidationError, ValidationResult};
+use std::collections::HashMap;
+
+/// The locale of the `message` field of findings
+pub static DEFAULT_LOCALE: &str = "en";
+
+/// The English message templates by code, where `{0}`, `{1}`, ... stand for the arguments
+static ENGLISH_TEMPLATES: &[(&str, &str)] = &[
+    ("6.1.4", "Missing definition of product_group_id: {0}"),
+    ("6.1.5", "Duplicate definition for product group ID {0}"),
+    (
+        "6.1.76",
+        "Product identification helper of product '{0}' does not contain any identifier",
+    ),
+    (
+        "6.1.77",
+        "Host '{0}' of the self reference differs from host '{1}' of the publisher namespace {2}",
+    ),
+    (
+        "6.1.78",
+        "Tracking status must not change from '{0}' to '{1}' between two versions of a document",
+    ),
+    (
+        "6.1.79",
+        "Remediation {0} of vulnerability {1} references the undefined product group ID {2}",
+    ),
+];
+
+/// Message templates by code and locale
+///
+/// A new catalog contains the English templates. Further locales are added with
+/// [MessageCatalog::register]. Codes without a template in the requested locale fall back to
+/// English.
+#[derive(Debug, Clone)]
+pub struct MessageCatalog {
+    templates: HashMap<(String, String), String>,
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        let templates = ENGLISH_TEMPLATES
+            .iter()
+            .map(|(code, template)| ((code.to_string(), DEFAULT_LOCALE.to_string()), template.to_string()))
+            .collect();
+        MessageCatalog { templates }
+    }
+}
+
+impl MessageCatalog {
+    /// Creates a catalog with the English templates.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces the template of `code` in `locale`.
+    pub fn register(&mut self, code: impl Into<String>, locale: impl Into<String>, template: impl Into<String>) {
+        self.templates.insert((code.into(), locale.into()), template.into());
+    }
+
+    /// Returns the template of `code` in `locale`, if there is one.
+    pub fn template(&self, code: &str, locale: &str) -> Option<&str> {
+        self.templates
+            .get(&(code.to_string(), locale.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Renders the message of `error` in `locale`.
+    ///
+    /// Falls back to the English template, and to the pre-formatted message for findings
+    /// without arguments or template.
+    pub fn render(&self, error: &ValidationError, locale: &str) -> String {
+        let Some(args) = &error.args else {
+            return error.message.clone();
+        };
+        match self
+            .template(&error.code, locale)
+            .or_else(|| self.template(&error.code, DEFAULT_LOCALE))
+        {
+            Some(template) => render_template(template, args),
+            None => error.message.clone(),
+        }
+    }
+
+    /// Replaces the messages of all findings of `result` with their rendering in `locale`.
+    pub fn localize(&self, result: &mut ValidationResult, locale: &str) {
+        for test_result in result.test_results.iter_mut() {
+            if let Failure { errors } = &mut test_result.status {
+                for error in errors.iter_mut() {
+                    error.message = self.render(error, locale);
+                }
+            }
+        }
+    }
+}
+
+/// Renders the English template of `code`, used for the `message` of [ValidationError::with_args].
+///
+/// Codes without a template, e.g. those of custom rules, are rendered as the code followed by the
+/// arguments.
+pub(crate) fn render_english(code: &str, args: &[String]) -> String {
+    match ENGLISH_TEMPLATES.iter().find(|(c, _)| *c == code) {
+        Some((_, template)) => render_template(template, args),
+        None if args.is_empty() => code.to_string(),
+        None => format!("{}: {}", code, args.join(", ")),
+    }
+}
+
+/// Replaces the placeholders `{0}`, `{1}`, ... of `template` with `args`.
+///
+/// Placeholders without argument are kept as they are.
+fn render_template(template: &str, args: &[String]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let arg = after
+            .find('}')
+            .and_then(|end| after[..end].parse::<usize>().ok().map(|i| (i, end)))
+            .and_then(|(i, end)| args.get(i).map(|arg| (arg, end)));
+        match arg {
+            Some((arg, end)) => {
+                out.push_str(arg);
+                rest = &after[end + 1..];
+            },
+            None => {
+                out.push('{');
+                rest = after;
+            },
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationError;
+    use crate::validation::messages::{MessageCatalog, render_english, render_template};
+    use serde_json::json;
+
+    #[test]
+    fn test_render_template() {
+        let args = ["a".to_string(), "b".to_string()];
+        assert_eq!(render_template("{1} and {0}", &args), "b and a");
+        assert_eq!(render_template("{2} {x} {", &args), "{2} {x} {");
+    }
+
+    #[test]
+    fn test_render_english_without_template() {
+        let args = ["a".to_string(), "b".to_string()];
+        assert_eq!(render_english("org-1", &args), "org-1: a, b");
+        assert_eq!(render_english("org-1", &[]), "org-1");
+
+        let mut catalog = MessageCatalog::new();
+        let error = ValidationError::with_args("org-1", ["a"], "/document");
+        assert_eq!(error.message, "org-1: a");
+        catalog.register("org-1", "de", "Eigene Regel: {0}");
+        assert_eq!(catalog.render(&error, "de"), "Eigene Regel: a");
+    }
+
+    #[test]
+    fn test_catalog() {
+        let mut catalog = MessageCatalog::new();
+        catalog.register("6.1.4", "de", "Fehlende Definition der Produktgruppen-ID: {0}");
+
+        let error = ValidationError::with_args(
+            "6.1.4",
+            ["CSAFGID-0001"],
+            "/vulnerabilities/0/remediations/0/group_ids/0",
+        );
+        assert_eq!(error.message, "Missing definition of product_group_id: CSAFGID-0001");
+        assert_eq!(
+            catalog.render(&error, "de"),
+            "Fehlende Definition der Produktgruppen-ID: CSAFGID-0001"
+        );
+        // Unknown locales fall back to English, pre-formatted messages are kept
+        assert_eq!(catalog.render(&error, "fr"), error.message);
+        let plain = ValidationError::new("6.1.4", "Pre-formatted", "");
+        assert_eq!(catalog.render(&plain, "de"), "Pre-formatted");
+
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [{
+                    "name": "Product A",
+                    "product_id": "CSAFPID-0001",
+                    "product_identification_helper": {}
+                }]
+            });
+        });
+        let mut result = doc.validate();
+        catalog.register(
+            "6.1.76",
+            "de",
+            "Die Hilfe zur Identifikation des Produkts '{0}' enthält keinen Identifikator",
+        );
+        catalog.localize(&mut result, "de");
+        let messages: Vec<&str> = result
+            .test_results
+            .iter()
+            .filter(|r| r.test_id == "6.1.76")
+            .flat_map(|r| match &r.status {
+                crate::validation::TestResultStatus::Failure { errors } => errors.as_slice(),
+                _ => &[],
+            })
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec!["Die Hilfe zur Identifikation des Produkts 'CSAFPID-0001' enthält keinen Identifikator"]
+        );
+    }
+}
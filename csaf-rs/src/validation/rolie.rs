@@ -0,0 +1,359 @@
+//! Validation of a local copy of a ROLIE feed
+//!
+//! Trusted providers distribute their documents in ROLIE feeds, which are listed in their
+//! `provider-metadata.json`. Each entry of a feed links the document and a file with its hash.
+//! [validate_feed_dir] checks that every feed, document and hash file exists in a mirrored
+//! directory, and that the documents match their hashes. It also validates each document with
+//! [validate_bytes_many]. Signatures are not verified.
+
+use crate::error::Error;
+use crate::validation::batch::{FileResult, validate_bytes_many};
+use crate::validation::{ValidationError, ValidationPreset};
+use crate::validations::test_6_1_64::HASH_LENGTHS;
+use percent_encoding::percent_decode_str;
+use serde_json::Value;
+use sha2::{Digest, Sha256, Sha512};
+use std::path::{Path, PathBuf};
+use url::Url;
+
+/// The code of validation errors concerning the structure of a feed
+pub static FEED_ERROR_CODE: &str = "feed";
+
+/// The name of the file listing the feeds of a provider
+pub static PROVIDER_METADATA: &str = "provider-metadata.json";
+
+/// Result of [validate_feed_dir]
+#[derive(Debug, Clone)]
+pub struct FeedResult {
+    /// Problems with the feed itself, e.g. missing files or hash mismatches
+    ///
+    /// The document ID of each error is the path of the metadata or feed file containing the
+    /// offending link, relative to the feed directory, and the instance path its JSON pointer.
+    pub errors: Vec<ValidationError>,
+    /// The validation results of all documents referenced by the feeds, in order
+    pub documents: Vec<FileResult>,
+}
+
+/// Validates the feeds listed in the `provider-metadata.json` of `dir` or of
+/// `dir/.well-known/csaf`.
+///
+/// Links are mapped to files by their URL relative to the directory of the `canonical_url` of
+/// the provider metadata. Links outside of this directory are reported as errors. Fails if the
+/// provider metadata cannot be read or parsed.
+pub fn validate_feed_dir(dir: impl AsRef<Path>, preset: ValidationPreset) -> Result<FeedResult, Error> {
+    let dir = dir.as_ref();
+    let metadata_path = [
+        dir.join(PROVIDER_METADATA),
+        dir.join(".well-known/csaf").join(PROVIDER_METADATA),
+    ]
+    .into_iter()
+    .find(|path| path.is_file())
+    .unwrap_or_else(|| dir.join(PROVIDER_METADATA));
+    let metadata: Value = read_json(&metadata_path)?;
+    let mut feed = Feed {
+        root: metadata_path.parent().unwrap_or(dir).to_path_buf(),
+        base: None,
+        errors: Vec::new(),
+    };
+    let metadata_id = feed.relative(&metadata_path);
+
+    let canonical_url = metadata.get("canonical_url").and_then(Value::as_str);
+    feed.base = canonical_url.and_then(|url| Url::parse(url).and_then(|url| url.join("./")).ok());
+    let Some(base) = feed.base.clone() else {
+        feed.error(
+            &metadata_id,
+            "/canonical_url",
+            "Provider metadata has no valid canonical_url",
+        );
+        return Ok(FeedResult {
+            errors: feed.errors,
+            documents: Vec::new(),
+        });
+    };
+
+    let mut files = Vec::new();
+    let distributions = metadata.get("distributions").and_then(Value::as_array);
+    for (d_i, distribution) in distributions.into_iter().flatten().enumerate() {
+        let feeds = distribution.pointer("/rolie/feeds").and_then(Value::as_array);
+        for (f_i, feed_link) in feeds.into_iter().flatten().enumerate() {
+            let pointer = format!("/distributions/{}/rolie/feeds/{}/url", d_i, f_i);
+            let Some(feed_path) = feed.resolve(&metadata_id, &pointer, &base, feed_link.get("url")) else {
+                continue;
+            };
+            match read_json(&feed_path) {
+                Ok(rolie) => feed.check_entries(&feed_path, &rolie, &mut files),
+                Err(e) => feed.error(&metadata_id, &pointer, format!("Feed cannot be read: {}", e)),
+            }
+        }
+    }
+
+    Ok(FeedResult {
+        errors: feed.errors,
+        documents: validate_bytes_many(&files, preset),
+    })
+}
+
+/// The state of [validate_feed_dir]
+struct Feed {
+    /// The directory corresponding to `base`
+    root: PathBuf,
+    /// The directory of the canonical URL of the provider metadata
+    base: Option<Url>,
+    errors: Vec<ValidationError>,
+}
+
+impl Feed {
+    /// Checks the entries of a ROLIE feed, collecting the documents they link.
+    fn check_entries(&mut self, feed_path: &Path, rolie: &Value, files: &mut Vec<(PathBuf, Vec<u8>)>) {
+        let feed_id = self.relative(feed_path);
+        let Some(feed_url) = self.base.as_ref().and_then(|base| base.join(&feed_id).ok()) else {
+            return;
+        };
+        let entries = rolie.pointer("/feed/entry").and_then(Value::as_array);
+        for (e_i, entry) in entries.into_iter().flatten().enumerate() {
+            let pointer = format!("/feed/entry/{}/content/src", e_i);
+            let Some(doc_path) = self.resolve(&feed_id, &pointer, &feed_url, entry.pointer("/content/src")) else {
+                continue;
+            };
+            let bytes = match std::fs::read(&doc_path) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    self.error(&feed_id, &pointer, format!("Document cannot be read: {}", e));
+                    continue;
+                },
+            };
+
+            let links = entry.get("link").and_then(Value::as_array);
+            match links.into_iter().flatten().position(|link| link["rel"] == "hash") {
+                Some(l_i) => {
+                    let pointer = format!("/feed/entry/{}/link/{}/href", e_i, l_i);
+                    if let Some(hash_path) = self.resolve(&feed_id, &pointer, &feed_url, entry["link"][l_i].get("href"))
+                    {
+                        self.check_hash(&feed_id, &pointer, &hash_path, &bytes);
+                    }
+                },
+                None => self.error(
+                    &feed_id,
+                    &format!("/feed/entry/{}/link", e_i),
+                    "Entry has no link with rel 'hash'",
+                ),
+            }
+            files.push((doc_path, bytes));
+        }
+    }
+
+    /// Checks that the hash file at `hash_path` contains the digest of `bytes`.
+    ///
+    /// The algorithm is taken from the extension of the hash file, e.g. `.sha256`.
+    fn check_hash(&mut self, feed_id: &str, pointer: &str, hash_path: &Path, bytes: &[u8]) {
+        let content = match std::fs::read_to_string(hash_path) {
+            Ok(content) => content,
+            Err(e) => return self.error(feed_id, pointer, format!("Hash file cannot be read: {}", e)),
+        };
+        let algorithm = hash_path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+        let actual = match algorithm {
+            "sha256" => format!("{:x}", Sha256::digest(bytes)),
+            "sha512" => format!("{:x}", Sha512::digest(bytes)),
+            _ => return self.error(feed_id, pointer, format!("Unsupported hash algorithm '{}'", algorithm)),
+        };
+
+        // Hash files are in the format of sha256sum, i.e. the digest followed by the file name
+        let expected = content.split_whitespace().next().unwrap_or_default();
+        let length = HASH_LENGTHS
+            .iter()
+            .find(|(a, _)| *a == algorithm)
+            .map_or(0, |(_, length)| *length);
+        if expected.len() != length || !expected.chars().all(|c| c.is_ascii_hexdigit()) {
+            self.error(
+                feed_id,
+                pointer,
+                format!("Hash file does not start with a {} digest", algorithm),
+            );
+        } else if !expected.eq_ignore_ascii_case(&actual) {
+            self.error(
+                feed_id,
+                pointer,
+                format!("Document does not match its {} hash {}", algorithm, expected),
+            );
+        }
+    }
+
+    /// Maps the URL `link` relative to `from` to a file within the feed directory.
+    ///
+    /// The segments of the URL path are percent-decoded to file names. Links that are not strings,
+    /// outside of the feed directory or to missing files are reported.
+    fn resolve(&mut self, file_id: &str, pointer: &str, from: &Url, link: Option<&Value>) -> Option<PathBuf> {
+        let Some(link) = link.and_then(Value::as_str) else {
+            self.error(file_id, pointer, "Link is missing");
+            return None;
+        };
+        let relative = from.join(link).ok().and_then(|url| {
+            let base = self.base.as_ref()?;
+            url.as_str().strip_prefix(base.as_str()).and_then(decode_path)
+        });
+        let Some(relative) = relative else {
+            self.error(
+                file_id,
+                pointer,
+                format!("Link {} is outside of the feed directory", link),
+            );
+            return None;
+        };
+        let path = self.root.join(&relative);
+        if !path.is_file() {
+            self.error(file_id, pointer, format!("File {} is missing", relative));
+            return None;
+        }
+        Some(path)
+    }
+
+    /// Returns `path` relative to the feed directory, with `/` as separator.
+    fn relative(&self, path: &Path) -> String {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn error(&mut self, file_id: &str, pointer: &str, message: impl Into<String>) {
+        self.errors
+            .push(ValidationError::new(FEED_ERROR_CODE, message, pointer).with_document_id(file_id));
+    }
+}
+
+/// Percent-decodes each segment of the relative URL path `path`.
+///
+/// Returns `None` if a decoded segment is not valid UTF-8 or would leave its directory, e.g.
+/// `..%2Fsecret.json`.
+fn decode_path(path: &str) -> Option<String> {
+    let segments = path
+        .split('/')
+        .map(|segment| {
+            let decoded = percent_decode_str(segment).decode_utf8().ok()?;
+            let escapes = decoded == ".." || decoded.contains(['/', '\\']);
+            (!escapes).then(|| decoded.into_owned())
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(segments.join("/"))
+}
+
+fn read_json(path: &Path) -> Result<Value, Error> {
+    let read = || -> Result<Value, Error> { Ok(serde_json::from_slice(&std::fs::read(path)?)?) };
+    read().map_err(|e| Error::File {
+        path: path.to_path_buf(),
+        source: Box::new(e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationPreset;
+    use crate::validation::rolie::validate_feed_dir;
+    use serde_json::json;
+    use sha2::{Digest, Sha256};
+    use std::path::Path;
+
+    fn write(dir: &Path, path: &str, content: &[u8]) {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_validate_feed_dir() {
+        let dir = std::env::temp_dir().join(format!("csaf-rolie-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let base = "https://example.com/.well-known/csaf";
+        write(
+            &dir,
+            ".well-known/csaf/provider-metadata.json",
+            &serde_json::to_vec(&json!({
+                "canonical_url": format!("{}/provider-metadata.json", base),
+                "distributions": [{ "rolie": { "feeds": [
+                    { "url": format!("{}/white/feed.json", base) },
+                    { "url": "https://other.example.com/feed.json" }
+                ] } }]
+            }))
+            .unwrap(),
+        );
+        let entry = |name: &str| {
+            json!({
+                "content": { "src": format!("{}/white/2024/{}", base, name) },
+                "link": [{ "rel": "hash", "href": format!("2024/{}.sha256", name) }]
+            })
+        };
+        write(
+            &dir,
+            ".well-known/csaf/white/feed.json",
+            &serde_json::to_vec(&json!({ "feed": { "entry": [
+                entry("a.json"),
+                entry("b.json"),
+                entry("c.json"),
+                entry("my%20advisory.json"),
+                entry("..%2Fb.json")
+            ] } }))
+            .unwrap(),
+        );
+        let doc = serde_json::to_vec(&mock_csaf21(|_| {})).unwrap();
+        write(&dir, ".well-known/csaf/white/2024/a.json", &doc);
+        let hash = format!("{:x}  a.json\n", Sha256::digest(&doc));
+        write(&dir, ".well-known/csaf/white/2024/a.json.sha256", hash.as_bytes());
+        write(&dir, ".well-known/csaf/white/2024/b.json", b"{ not json");
+        write(&dir, ".well-known/csaf/white/2024/b.json.sha256", hash.as_bytes());
+        write(&dir, ".well-known/csaf/white/2024/my advisory.json", &doc);
+        write(
+            &dir,
+            ".well-known/csaf/white/2024/my advisory.json.sha256",
+            hash.as_bytes(),
+        );
+
+        let result = validate_feed_dir(&dir, ValidationPreset::Basic).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let errors: Vec<(&str, &str, &str)> = result
+            .errors
+            .iter()
+            .map(|e| {
+                (
+                    e.document_id.as_deref().unwrap(),
+                    e.instance_path.as_str(),
+                    e.message.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "white/feed.json",
+                    "/feed/entry/1/link/0/href",
+                    &*format!("Document does not match its sha256 hash {}", &hash[..64])
+                ),
+                (
+                    "white/feed.json",
+                    "/feed/entry/2/content/src",
+                    "File white/2024/c.json is missing"
+                ),
+                (
+                    "white/feed.json",
+                    "/feed/entry/4/content/src",
+                    "Link https://example.com/.well-known/csaf/white/2024/..%2Fb.json is outside of the feed directory"
+                ),
+                (
+                    "provider-metadata.json",
+                    "/distributions/0/rolie/feeds/1/url",
+                    "Link https://other.example.com/feed.json is outside of the feed directory"
+                ),
+            ]
+        );
+        assert_eq!(result.documents.len(), 3);
+        assert!(result.documents[0].findings.as_ref().unwrap().success);
+        assert!(result.documents[1].parse_error.is_some());
+        assert!(result.documents[2].findings.as_ref().unwrap().success);
+
+        assert!(validate_feed_dir(std::env::temp_dir().join("csaf-rolie-missing"), ValidationPreset::Basic).is_err());
+    }
+}
@@ -0,0 +1,95 @@
+//! Finding counts for dashboards and metrics exporters
+//!
+//! A [Summary] counts the findings of one or more [ValidationResult]s by code and by severity.
+//! It is computed from the findings of the test results and serializes to a flat JSON object,
+//! e.g. `{"byCode":{"6.1.2":1},"bySeverity":{"error":1},"total":1}`.
+
+use crate::validation::TestResultStatus::Failure;
+use crate::validation::{Severity, ValidationResult};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Numbers of findings by code and by severity
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Summary {
+    /// The number of findings per code, i.e. test ID
+    pub by_code: BTreeMap<String, usize>,
+    /// The number of findings per severity
+    pub by_severity: BTreeMap<Severity, usize>,
+    /// The total number of findings
+    pub total: usize,
+}
+
+impl Summary {
+    /// Adds the findings of `result` to this summary.
+    fn add(&mut self, result: &ValidationResult) {
+        for test_result in &result.test_results {
+            if let Failure { errors } = &test_result.status {
+                for error in errors {
+                    *self.by_code.entry(error.code.clone()).or_default() += 1;
+                    *self.by_severity.entry(error.severity).or_default() += 1;
+                    self.total += 1;
+                }
+            }
+        }
+    }
+}
+
+impl ValidationResult {
+    /// Counts the findings of this result by code and by severity.
+    pub fn summary(&self) -> Summary {
+        let mut summary = Summary::default();
+        summary.add(self);
+        summary
+    }
+}
+
+/// Counts the findings of all `results` by code and by severity, e.g. of a batch of documents.
+pub fn aggregate_summaries(results: &[ValidationResult]) -> Summary {
+    let mut summary = Summary::default();
+    for result in results {
+        summary.add(result);
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::Severity;
+    use crate::validation::summary::aggregate_summaries;
+    use serde_json::json;
+
+    #[test]
+    fn test_summary() {
+        let valid = mock_csaf21(|_| {}).validate();
+        assert_eq!(0, valid.summary().total);
+
+        let duplicate = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [
+                    { "name": "Product A", "product_id": "CSAFPID-0001" },
+                    { "name": "Product B", "product_id": "CSAFPID-0001" }
+                ]
+            })
+        })
+        .validate();
+        let summary = duplicate.summary();
+        assert_eq!(Some(&1), summary.by_code.get("6.1.2"));
+        assert_eq!(summary.total, summary.by_severity.values().sum::<usize>());
+        assert_eq!(summary.total, summary.by_code.values().sum::<usize>());
+
+        let aggregated = aggregate_summaries(&[duplicate.clone(), valid, duplicate]);
+        assert_eq!(Some(&2), aggregated.by_code.get("6.1.2"));
+        assert_eq!(2 * summary.total, aggregated.total);
+        assert_eq!(
+            summary.by_severity.get(&Severity::Error).map(|n| 2 * n),
+            aggregated.by_severity.get(&Severity::Error).copied()
+        );
+
+        let json = serde_json::to_value(&aggregated).unwrap();
+        assert_eq!(json["byCode"]["6.1.2"], 2);
+        assert_eq!(json["total"], aggregated.total);
+    }
+}
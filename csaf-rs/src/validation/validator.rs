@@ -0,0 +1,1055 @@
+//! A configurable validator combining the built-in tests with custom rules
+//!
+//! Rules are organized in [RuleSet]s, i.e. named and versioned collections of rules. A validator
+//! starts with the [CSAF rule set](RuleSet::csaf) of built-in tests of the selected preset, and
+//! downstream crates can [add](Validator::add_rule_set) own rule sets, e.g. for
+//! organization-specific advisory conventions. Custom rules appear in the [ValidationResult] under
+//! their own ID, and every finding names its rule set in [ValidationError::rule_set]. Rules that
+//! are [registered](Validator::register) individually belong to the rule set [CUSTOM_RULE_SET].
+//!
+//! [Validator::run_with_timeout] bounds the time spent on a single document, e.g. for untrusted
+//! input whose size makes the quadratic cross-checks of some tests expensive.
+//!
+//! [Validator::validate_bytes] and [Validator::validate_reader] load untrusted documents within
+//! the [Limits] of the validator before validating them.
+//!
+//! [Validator::builder] configures all other aspects of a validator in one expression.
+
+use crate::csaf_traits::CsafTrait;
+use crate::error::Error;
+use crate::limits::Limits;
+use crate::product_tree_loader::{NoExternalProductTree, ProductTreeLoader, resolve_external_product_tree};
+use crate::validation::TestResultStatus::{Failure, Success};
+use crate::validation::baseline::Baseline;
+use crate::validation::batch::validate_value;
+use crate::validation::messages::MessageCatalog;
+use crate::validation::{Severity, TestResult, ValidationError, ValidationPreset, ValidationResult};
+use serde_json::Value;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
+
+/// The code and test ID of the finding reported when [Validator::run_with_timeout] runs out of time
+pub static TIMEOUT_CODE: &str = "timeout";
+
+/// The name of the rule set of the built-in tests, see [RuleSet::csaf]
+pub static CSAF_RULE_SET: &str = "csaf";
+
+/// The name of the rule set of rules registered with [Validator::register]
+pub static CUSTOM_RULE_SET: &str = "custom";
+
+/// A custom validation function, returning all findings for the given document
+///
+/// Custom tests are `Send` and `Sync`, so that [Validator::run_with_timeout] can execute them on
+/// a worker thread.
+pub type CustomTest = Box<dyn Fn(&dyn CsafTrait) -> Vec<ValidationError> + Send + Sync>;
+
+/// A [CustomTest] shared with the worker thread of [Validator::run_with_timeout]
+type SharedTest = Arc<dyn Fn(&dyn CsafTrait) -> Vec<ValidationError> + Send + Sync>;
+
+/// A custom validation rule of a [RuleSet]
+#[derive(Clone)]
+struct CustomRule {
+    id: String,
+    title: String,
+    severity: Severity,
+    test: SharedTest,
+}
+
+/// A built-in test or custom rule executed by the worker thread of [Validator::run_with_timeout]
+#[cfg(not(target_arch = "wasm32"))]
+enum Job {
+    Builtin(String),
+    Custom(CustomRule),
+}
+
+/// The rules of a [RuleSet]
+enum Rules {
+    /// The built-in tests of the preset of the validator
+    Builtin,
+    Custom(Vec<CustomRule>),
+}
+
+/// A named and versioned collection of rules, executed by a [Validator]
+///
+/// ```
+/// use csaf::validation::validator::{RuleSet, Validator};
+/// use csaf::validation::{Severity, ValidationError};
+///
+/// let internal = RuleSet::new("example-org", "1.2").register(
+///     "org-1",
+///     "Tracking ID prefix",
+///     Severity::Warning,
+///     Box::new(|doc| match doc.get_tracking_id().starts_with("ORG-") {
+///         true => vec![],
+///         false => vec![ValidationError::new("", "Tracking ID must start with ORG-", "/document/tracking/id")],
+///     }),
+/// );
+/// let validator = Validator::builder().rule_set(internal).build().unwrap();
+/// assert_eq!(
+///     validator.rule_sets().collect::<Vec<_>>(),
+///     vec![("csaf", env!("CARGO_PKG_VERSION")), ("example-org", "1.2")]
+/// );
+/// ```
+pub struct RuleSet {
+    name: String,
+    version: String,
+    rules: Rules,
+}
+
+impl RuleSet {
+    /// Returns the rule set of the built-in tests, named [CSAF_RULE_SET] and versioned like this
+    /// crate.
+    ///
+    /// Its tests are selected by the preset of the validator.
+    pub fn csaf() -> Self {
+        RuleSet {
+            name: CSAF_RULE_SET.to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            rules: Rules::Builtin,
+        }
+    }
+
+    /// Creates an empty rule set for custom rules.
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        RuleSet {
+            name: name.into(),
+            version: version.into(),
+            rules: Rules::Custom(Vec::new()),
+        }
+    }
+
+    /// Adds a custom rule to this rule set, see [Validator::register].
+    ///
+    /// Rules cannot be added to the [CSAF rule set](RuleSet::csaf), so they are ignored there.
+    pub fn register(mut self, id: &str, title: &str, severity: Severity, f: CustomTest) -> Self {
+        self.insert(CustomRule {
+            id: id.to_string(),
+            title: title.to_string(),
+            severity,
+            test: Arc::from(f),
+        });
+        self
+    }
+
+    /// Returns the name of this rule set.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the version of this rule set.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the ID and title of each custom rule, in order of registration.
+    pub fn custom_rules(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        let rules = match &self.rules {
+            Rules::Builtin => &[][..],
+            Rules::Custom(rules) => rules.as_slice(),
+        };
+        rules.iter().map(|r| (r.id.as_str(), r.title.as_str()))
+    }
+
+    /// Adds a rule, replacing a rule with the same ID.
+    fn insert(&mut self, rule: CustomRule) {
+        let Rules::Custom(rules) = &mut self.rules else {
+            return;
+        };
+        match rules.iter_mut().find(|r| r.id == rule.id) {
+            Some(existing) => *existing = rule,
+            None => rules.push(rule),
+        }
+    }
+}
+
+/// Adds `rule_set` to `rule_sets`, replacing a rule set with the same name.
+fn add_rule_set(rule_sets: &mut Vec<RuleSet>, rule_set: RuleSet) {
+    match rule_sets.iter_mut().find(|s| s.name == rule_set.name) {
+        Some(existing) => *existing = rule_set,
+        None => rule_sets.push(rule_set),
+    }
+}
+
+/// Returns the rule set [CUSTOM_RULE_SET], adding it if necessary.
+fn custom_rule_set(rule_sets: &mut Vec<RuleSet>) -> &mut RuleSet {
+    let index = match rule_sets.iter().position(|s| s.name == CUSTOM_RULE_SET) {
+        Some(index) => index,
+        None => {
+            rule_sets.push(RuleSet::new(CUSTOM_RULE_SET, ""));
+            rule_sets.len() - 1
+        },
+    };
+    &mut rule_sets[index]
+}
+
+/// Validates documents with the built-in tests of a preset and any added rule sets
+pub struct Validator {
+    preset: ValidationPreset,
+    rule_sets: Vec<RuleSet>,
+    baseline: Option<Baseline>,
+    ignored: Vec<String>,
+    only: Option<Vec<String>>,
+    fail_on: Option<Severity>,
+    messages: Option<(MessageCatalog, String)>,
+    limits: Limits,
+}
+
+impl Validator {
+    /// Creates a validator running the built-in tests of `preset` and no custom rules.
+    pub fn new(preset: ValidationPreset) -> Self {
+        Validator {
+            preset,
+            rule_sets: vec![RuleSet::csaf()],
+            baseline: None,
+            ignored: Vec::new(),
+            only: None,
+            fail_on: None,
+            messages: None,
+            limits: Limits::default(),
+        }
+    }
+
+    /// Returns a builder for a validator, starting with the mandatory tests.
+    ///
+    /// ```
+    /// use csaf::validation::Severity;
+    /// use csaf::validation::validator::Validator;
+    ///
+    /// let validator = Validator::builder()
+    ///     .include_optional()
+    ///     .ignore(["6.1.10", "6.2.1"])
+    ///     .fail_on(Severity::Warning)
+    ///     .locale("de")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> ValidatorBuilder {
+        ValidatorBuilder::default()
+    }
+
+    /// Loads a baseline file, whose findings are removed from all subsequent validation results.
+    ///
+    /// See [Baseline] for the file format.
+    pub fn baseline(&mut self, path: &Path) -> Result<&mut Self, Error> {
+        self.baseline = Some(Baseline::load(path)?);
+        Ok(self)
+    }
+
+    /// Registers a custom rule in the rule set [CUSTOM_RULE_SET], which is executed after the
+    /// rule sets added before.
+    ///
+    /// Every finding of the rule is reported with `id` as its code and with the given `severity`.
+    /// Registering a rule with an ID that is already registered replaces the previous rule.
+    pub fn register(&mut self, id: &str, title: &str, severity: Severity, f: CustomTest) -> &mut Self {
+        custom_rule_set(&mut self.rule_sets).insert(CustomRule {
+            id: id.to_string(),
+            title: title.to_string(),
+            severity,
+            test: Arc::from(f),
+        });
+        self
+    }
+
+    /// Adds a rule set, which is executed after the rule sets added before.
+    ///
+    /// Adding a rule set with the name of an existing one, including [CSAF_RULE_SET], replaces it.
+    pub fn add_rule_set(&mut self, rule_set: RuleSet) -> &mut Self {
+        add_rule_set(&mut self.rule_sets, rule_set);
+        self
+    }
+
+    /// Returns the name and version of each rule set, in order of execution.
+    pub fn rule_sets(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        self.rule_sets.iter().map(|s| (s.name(), s.version()))
+    }
+
+    /// Returns the ID and title of each custom rule of all rule sets, in order of execution.
+    pub fn custom_rules(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
+        self.rule_sets.iter().flat_map(RuleSet::custom_rules)
+    }
+
+    /// Validates a document with all rule sets, i.e. the built-in tests of the preset and all
+    /// custom rules.
+    ///
+    /// Built-in tests that need the raw JSON of a document, i.e. 6.1.52, are only executed when
+    /// the validator loads the document itself, e.g. with [Validator::validate_bytes].
+    pub fn validate(&self, doc: &dyn CsafTrait) -> ValidationResult {
+        self.validate_with_raw(doc, Vec::new())
+    }
+
+    /// Like [Validator::validate], but adds `raw_results` of built-in tests that were executed on
+    /// the raw JSON of the document to the results of the built-in tests.
+    pub(crate) fn validate_with_raw(&self, doc: &dyn CsafTrait, mut raw_results: Vec<TestResult>) -> ValidationResult {
+        let document_id: Option<Box<str>> = Some(doc.get_tracking_id().as_str())
+            .filter(|id| !id.is_empty())
+            .map(Box::from);
+        raw_results.retain(|r| {
+            !self.ignored.contains(&r.test_id) && self.only.as_ref().is_none_or(|only| only.contains(&r.test_id))
+        });
+        for raw_result in raw_results.iter_mut() {
+            if let Failure { errors } = &mut raw_result.status {
+                for error in errors.iter_mut() {
+                    error.document_id = document_id.clone();
+                }
+                errors.sort_by(ValidationError::canonical_cmp);
+            }
+        }
+
+        let mut result = doc.validate_by_tests(self.preset.clone(), &[]);
+        for rule_set in &self.rule_sets {
+            match &rule_set.rules {
+                Rules::Builtin => {
+                    let builtin = if self.ignored.is_empty() && self.only.is_none() {
+                        doc.validate_by_preset(self.preset.clone())
+                    } else {
+                        let test_ids = self.test_ids(doc);
+                        let test_ids: Vec<&str> = test_ids.iter().map(String::as_str).collect();
+                        doc.validate_by_tests(self.preset.clone(), &test_ids)
+                    };
+                    result.test_results.extend(attribute(
+                        std::mem::take(&mut raw_results)
+                            .into_iter()
+                            .chain(builtin.test_results)
+                            .collect(),
+                        &rule_set.name,
+                    ));
+                },
+                Rules::Custom(rules) => {
+                    result.test_results.extend(
+                        rules
+                            .iter()
+                            .filter(|r| !self.ignored.contains(&r.id))
+                            .map(|rule| run_custom_rule(doc, &rule_set.name, rule)),
+                    );
+                },
+            }
+        }
+        self.finish(result)
+    }
+
+    /// Parses a document from JSON bytes and validates it with all rule sets.
+    ///
+    /// The CSAF version is detected from `/document/csaf_version`. The document fails with
+    /// [Error::LimitExceeded] if it exceeds the [limits](Validator::limits) of this validator,
+    /// and with another [Error] if it cannot be loaded, see [crate::validation::batch::validate_bytes].
+    pub fn validate_bytes(&self, bytes: &[u8]) -> Result<ValidationResult, Error> {
+        self.validate_bytes_with_loader(bytes, &NoExternalProductTree)
+    }
+
+    /// Like [Validator::validate_bytes], but resolves an external product tree with `loader`
+    /// first. The limits are checked on the document including the loaded product tree.
+    pub fn validate_bytes_with_loader(
+        &self,
+        bytes: &[u8],
+        loader: &impl ProductTreeLoader,
+    ) -> Result<ValidationResult, Error> {
+        self.limits.check_bytes(bytes)?;
+        let mut json_value: Value = serde_json::from_slice(bytes)?;
+        resolve_external_product_tree(&mut json_value, loader).map_err(Error::ExternalProductTree)?;
+        validate_value(json_value, self)
+    }
+
+    /// Like [Validator::validate_bytes], but reads the document from `reader`, see [Limits::read].
+    pub fn validate_reader(&self, reader: impl Read) -> Result<ValidationResult, Error> {
+        self.validate_bytes(&self.limits.read(reader)?)
+    }
+
+    /// Like [Validator::validate], but stops waiting for the tests once `timeout` has elapsed.
+    ///
+    /// The built-in tests and custom rules are executed one after another on a worker thread,
+    /// which sends each result to the calling thread. If the deadline passes before all results
+    /// were received, the result is partial and contains an additional failed test
+    /// [TIMEOUT_CODE] listing the tests that were not completed. A test that is still running
+    /// cannot be interrupted: the worker thread is detached, finishes that test in the
+    /// background and discards its result.
+    ///
+    /// This spawns a thread and relies on `std::time::Instant`, and is therefore not available
+    /// on WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run_with_timeout(&self, doc: Arc<dyn CsafTrait + Send + Sync>, timeout: Duration) -> ValidationResult {
+        let deadline = Instant::now() + timeout;
+        let mut result = doc.validate_by_tests(self.preset.clone(), &[]);
+
+        let mut jobs: Vec<(String, Job)> = Vec::new();
+        for rule_set in &self.rule_sets {
+            match &rule_set.rules {
+                Rules::Builtin => jobs.extend(
+                    self.test_ids(doc.as_ref())
+                        .into_iter()
+                        .map(|test_id| (rule_set.name.clone(), Job::Builtin(test_id))),
+                ),
+                Rules::Custom(rules) => jobs.extend(
+                    rules
+                        .iter()
+                        .filter(|r| !self.ignored.contains(&r.id))
+                        .map(|rule| (rule_set.name.clone(), Job::Custom(rule.clone()))),
+                ),
+            }
+        }
+        let ids: Vec<String> = jobs
+            .iter()
+            .map(|(_, job)| match job {
+                Job::Builtin(test_id) => test_id.clone(),
+                Job::Custom(rule) => rule.id.clone(),
+            })
+            .collect();
+
+        let (sender, receiver) = mpsc::channel();
+        let preset = self.preset.clone();
+        std::thread::spawn(move || {
+            for (rule_set, job) in jobs {
+                let test_results = match job {
+                    Job::Builtin(test_id) => attribute(
+                        doc.validate_by_tests(preset.clone(), &[&test_id]).test_results,
+                        &rule_set,
+                    ),
+                    Job::Custom(rule) => vec![run_custom_rule(doc.as_ref(), &rule_set, &rule)],
+                };
+                // The calling thread stops receiving once the deadline has passed
+                if sender.send(test_results).is_err() {
+                    return;
+                }
+            }
+        });
+
+        let mut completed = 0;
+        while completed < ids.len() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            match receiver.recv_timeout(remaining) {
+                Ok(test_results) => {
+                    result.test_results.extend(test_results);
+                    completed += 1;
+                },
+                Err(_) => break,
+            }
+        }
+
+        let skipped = &ids[completed..];
+        if !skipped.is_empty() {
+            result.test_results.push(TestResult {
+                test_id: TIMEOUT_CODE.to_string(),
+                status: Failure {
+                    errors: vec![ValidationError::new(
+                        TIMEOUT_CODE,
+                        format!(
+                            "Validation exceeded the time budget of {:?}, {} test(s) were not completed: {}",
+                            timeout,
+                            skipped.len(),
+                            skipped.join(", ")
+                        ),
+                        "",
+                    )],
+                },
+            });
+        }
+        self.finish(result)
+    }
+
+    /// Returns the limits for loading untrusted documents, which are applied by
+    /// [Validator::validate_bytes] and [Validator::validate_reader].
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    /// Returns the validation preset whose built-in tests are executed.
+    pub fn preset(&self) -> &ValidationPreset {
+        &self.preset
+    }
+
+    /// Returns the name of the rule set of the built-in tests, unless it was replaced.
+    pub(crate) fn builtin_rule_set(&self) -> Option<&str> {
+        self.rule_sets
+            .iter()
+            .find(|s| matches!(s.rules, Rules::Builtin))
+            .map(RuleSet::name)
+    }
+
+    /// Returns the IDs of the built-in tests of the preset, or the selected ones, that are not
+    /// ignored.
+    pub(crate) fn test_ids(&self, doc: &dyn CsafTrait) -> Vec<String> {
+        let mut test_ids = match &self.only {
+            Some(only) => only.clone(),
+            None => doc.get_preset_test_ids(&self.preset),
+        };
+        test_ids.retain(|id| !self.ignored.contains(id));
+        test_ids
+    }
+
+    /// Updates the summary of a result, applies the baseline, the failure threshold and the
+    /// locale.
+    pub(crate) fn finish(&self, mut result: ValidationResult) -> ValidationResult {
+        result.update_summary();
+        if let Some(baseline) = &self.baseline {
+            baseline.apply(&mut result);
+        }
+        if let Some(fail_on) = self.fail_on {
+            result.success = !result.test_results.iter().any(|r| match &r.status {
+                Failure { errors } => errors.iter().any(|e| e.severity >= fail_on),
+                _ => false,
+            });
+        }
+        if let Some((catalog, locale)) = &self.messages {
+            catalog.localize(&mut result, locale);
+        }
+        result
+    }
+}
+
+/// Executes a custom rule of the rule set `rule_set` on `doc`.
+fn run_custom_rule(doc: &dyn CsafTrait, rule_set: &str, rule: &CustomRule) -> TestResult {
+    let document_id: Option<Box<str>> = Some(doc.get_tracking_id().as_str())
+        .filter(|id| !id.is_empty())
+        .map(Box::from);
+    let mut errors: Vec<ValidationError> = (rule.test)(doc)
+        .into_iter()
+        .map(|mut e| {
+            e.code = rule.id.clone();
+            e.document_id = document_id.clone();
+            e.rule_set = Some(rule_set.into());
+            e.with_severity(rule.severity)
+        })
+        .collect();
+    errors.sort_by(ValidationError::canonical_cmp);
+    TestResult {
+        test_id: rule.id.clone(),
+        status: if errors.is_empty() { Success } else { Failure { errors } },
+    }
+}
+
+/// Sets the rule set of all findings of `test_results`.
+pub(crate) fn attribute(mut test_results: Vec<TestResult>, rule_set: &str) -> Vec<TestResult> {
+    for test_result in test_results.iter_mut() {
+        if let Failure { errors } = &mut test_result.status {
+            for error in errors.iter_mut() {
+                error.rule_set = Some(rule_set.into());
+            }
+        }
+    }
+    test_results
+}
+
+/// Fluent configuration of a [Validator], see [Validator::builder]
+pub struct ValidatorBuilder {
+    preset: ValidationPreset,
+    rule_sets: Vec<RuleSet>,
+    baseline: Option<PathBuf>,
+    ignored: Vec<String>,
+    only: Option<Vec<String>>,
+    fail_on: Option<Severity>,
+    catalog: Option<MessageCatalog>,
+    locale: Option<String>,
+    limits: Limits,
+}
+
+impl Default for ValidatorBuilder {
+    fn default() -> Self {
+        ValidatorBuilder {
+            preset: ValidationPreset::Basic,
+            rule_sets: vec![RuleSet::csaf()],
+            baseline: None,
+            ignored: Vec::new(),
+            only: None,
+            fail_on: None,
+            catalog: None,
+            locale: None,
+            limits: Limits::default(),
+        }
+    }
+}
+
+impl ValidatorBuilder {
+    /// Runs the mandatory tests only, i.e. the [ValidationPreset::Basic] preset.
+    pub fn mandatory_only(mut self) -> Self {
+        self.preset = ValidationPreset::Basic;
+        self
+    }
+
+    /// Runs the optional tests in addition to the mandatory ones, i.e. the
+    /// [ValidationPreset::Extended] preset.
+    pub fn include_optional(mut self) -> Self {
+        self.preset = ValidationPreset::Extended;
+        self
+    }
+
+    /// Runs all tests including the informative ones, i.e. the [ValidationPreset::Full] preset.
+    pub fn include_informative(mut self) -> Self {
+        self.preset = ValidationPreset::Full;
+        self
+    }
+
+    /// Skips the built-in tests and custom rules with the given IDs.
+    pub fn ignore<I>(mut self, ids: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.ignored.extend(ids.into_iter().map(Into::into));
+        self
+    }
+
+    /// Runs only the built-in tests with the given IDs, whether or not they belong to the preset.
+    ///
+    /// Tests that are not implemented for the version of a document are reported as not found.
+    pub fn only<I>(mut self, ids: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.only
+            .get_or_insert_with(Vec::new)
+            .extend(ids.into_iter().map(Into::into));
+        self
+    }
+
+    /// Only fails the validation for findings of at least `severity`.
+    ///
+    /// By default, only findings with [Severity::Error] fail the validation, see
+    /// [ValidationResult::success].
+    pub fn fail_on(mut self, severity: Severity) -> Self {
+        self.fail_on = Some(severity);
+        self
+    }
+
+    /// Suppresses the known findings of a baseline file, which is loaded by [ValidatorBuilder::build].
+    pub fn baseline(mut self, path: impl Into<PathBuf>) -> Self {
+        self.baseline = Some(path.into());
+        self
+    }
+
+    /// Renders the messages of findings in the locale `tag`, see [MessageCatalog].
+    pub fn locale(mut self, tag: impl Into<String>) -> Self {
+        self.locale = Some(tag.into());
+        self
+    }
+
+    /// Uses `catalog` to render messages in the configured locale, instead of one with the
+    /// English templates only.
+    pub fn catalog(mut self, catalog: MessageCatalog) -> Self {
+        self.catalog = Some(catalog);
+        self
+    }
+
+    /// Sets the limits for loading untrusted documents, see [Validator::limits].
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Registers a custom rule, see [Validator::register].
+    pub fn register(mut self, id: &str, title: &str, severity: Severity, f: CustomTest) -> Self {
+        custom_rule_set(&mut self.rule_sets).insert(CustomRule {
+            id: id.to_string(),
+            title: title.to_string(),
+            severity,
+            test: Arc::from(f),
+        });
+        self
+    }
+
+    /// Adds a rule set, see [Validator::add_rule_set].
+    pub fn rule_set(mut self, rule_set: RuleSet) -> Self {
+        add_rule_set(&mut self.rule_sets, rule_set);
+        self
+    }
+
+    /// Creates the validator, loading the baseline file if one was configured.
+    pub fn build(self) -> Result<Validator, Error> {
+        let baseline = match &self.baseline {
+            Some(path) => Some(Baseline::load(path)?),
+            None => None,
+        };
+        Ok(Validator {
+            preset: self.preset,
+            rule_sets: self.rule_sets,
+            baseline,
+            ignored: self.ignored,
+            only: self.only,
+            fail_on: self.fail_on,
+            messages: self.locale.map(|locale| (self.catalog.unwrap_or_default(), locale)),
+            limits: self.limits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf_traits::CsafTrait;
+    use crate::error::Error;
+    use crate::limits::{Limit, Limits};
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::TestResultStatus::{Failure, Success};
+    use crate::validation::messages::MessageCatalog;
+    use crate::validation::validator::{CSAF_RULE_SET, CUSTOM_RULE_SET, CustomTest, RuleSet, TIMEOUT_CODE, Validator};
+    use crate::validation::{Severity, TestResult, ValidationError, ValidationPreset};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    fn require_org_prefix(doc: &dyn CsafTrait) -> Vec<ValidationError> {
+        if doc.get_tracking_id().starts_with("ORG-") {
+            vec![]
+        } else {
+            vec![ValidationError::new(
+                "",
+                format!("Tracking ID {} must start with ORG-", doc.get_tracking_id()),
+                "/document/tracking/id",
+            )]
+        }
+    }
+
+    #[test]
+    fn test_custom_rule() {
+        let mut validator = Validator::new(ValidationPreset::Basic);
+        validator.register(
+            "org-1",
+            "Tracking ID prefix",
+            Severity::Warning,
+            Box::new(require_org_prefix),
+        );
+        assert_eq!(
+            validator.custom_rules().collect::<Vec<_>>(),
+            vec![("org-1", "Tracking ID prefix")]
+        );
+
+        // The warning of the custom rule does not fail the validation
+        let result = validator.validate(&mock_csaf21(|_| {}));
+        assert!(result.success);
+        assert_eq!(result.num_errors, 0);
+        let custom = result.test_results.last().unwrap();
+        assert_eq!(custom.test_id, "org-1");
+        match &custom.status {
+            Failure { errors } => assert_eq!(
+                errors,
+                &vec![
+                    ValidationError::new(
+                        "org-1",
+                        "Tracking ID MOCK-2024-0001 must start with ORG-",
+                        "/document/tracking/id"
+                    )
+                    .with_severity(Severity::Warning)
+                    .with_document_id("MOCK-2024-0001")
+                    .with_rule_set("custom")
+                ]
+            ),
+            _ => panic!("Expected custom rule to fail"),
+        }
+
+        let doc = mock_csaf21(|doc| doc["document"]["tracking"]["id"] = "ORG-2024-0001".into());
+        let result = validator.validate(&doc);
+        assert!(result.success);
+        assert!(matches!(result.test_results.last().unwrap().status, Success));
+    }
+
+    #[test]
+    fn test_rule_sets() {
+        let internal = RuleSet::new("example-org", "1.2")
+            .register(
+                "org-1",
+                "Tracking ID prefix",
+                Severity::Warning,
+                Box::new(require_org_prefix),
+            )
+            .register(
+                "org-2",
+                "Internal reference",
+                Severity::Error,
+                Box::new(|_| {
+                    vec![ValidationError::new(
+                        "",
+                        "Missing internal reference",
+                        "/document/references",
+                    )]
+                }),
+            );
+        let validator = Validator::builder()
+            .rule_set(internal)
+            .register("custom-1", "Always passes", Severity::Info, Box::new(|_| vec![]))
+            .build()
+            .unwrap();
+        assert_eq!(
+            validator.rule_sets().collect::<Vec<_>>(),
+            vec![
+                (CSAF_RULE_SET, env!("CARGO_PKG_VERSION")),
+                ("example-org", "1.2"),
+                (CUSTOM_RULE_SET, "")
+            ]
+        );
+        assert_eq!(
+            validator.custom_rules().map(|(id, _)| id).collect::<Vec<_>>(),
+            vec!["org-1", "org-2", "custom-1"]
+        );
+
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = serde_json::json!({
+                "full_product_names": [{
+                    "name": "Product A",
+                    "product_id": "CSAFPID-0001",
+                    "product_identification_helper": {}
+                }]
+            });
+        });
+        let result = validator.validate(&doc);
+        let findings: Vec<(&str, &str)> = result
+            .test_results
+            .iter()
+            .flat_map(|r| match &r.status {
+                Failure { errors } => errors.iter().collect(),
+                _ => vec![],
+            })
+            .map(|e| (e.code.as_str(), e.rule_set.as_deref().unwrap()))
+            .collect();
+        assert_eq!(
+            findings,
+            vec![
+                ("6.1.76", CSAF_RULE_SET),
+                ("org-1", "example-org"),
+                ("org-2", "example-org")
+            ]
+        );
+        let test_ids: Vec<&str> = result.test_results.iter().map(|r| r.test_id.as_str()).collect();
+        assert_eq!(test_ids[test_ids.len() - 3..], ["org-1", "org-2", "custom-1"]);
+
+        // Replacing the CSAF rule set with an empty one leaves the internal rules only
+        let mut validator = validator;
+        validator.add_rule_set(RuleSet::new(CSAF_RULE_SET, "0"));
+        assert_eq!(validator.validate(&doc).test_results.len(), 3);
+    }
+
+    #[test]
+    fn test_custom_rule_order() {
+        let paths = ["/vulnerabilities/10/cve", "/vulnerabilities/2/cve", "/document/title"];
+        let findings = |paths: Vec<&'static str>| -> CustomTest {
+            Box::new(move |_| {
+                paths
+                    .iter()
+                    .map(|path| ValidationError::new("", "Finding", *path))
+                    .collect()
+            })
+        };
+        let errors = |validator: &Validator| match validator.validate(&mock_csaf21(|_| {})).test_results.pop() {
+            Some(TestResult {
+                status: Failure { errors },
+                ..
+            }) => errors.into_iter().map(|e| e.instance_path).collect::<Vec<_>>(),
+            _ => panic!("Expected custom rule to fail"),
+        };
+
+        let mut forward = Validator::new(ValidationPreset::Basic);
+        forward.register("org-2", "Order", Severity::Info, findings(paths.to_vec()));
+        let mut reversed = Validator::new(ValidationPreset::Basic);
+        reversed.register(
+            "org-2",
+            "Order",
+            Severity::Info,
+            findings(paths.iter().rev().copied().collect()),
+        );
+        assert_eq!(
+            errors(&forward),
+            ["/document/title", "/vulnerabilities/2/cve", "/vulnerabilities/10/cve"]
+        );
+        assert_eq!(errors(&forward), errors(&reversed));
+    }
+
+    #[test]
+    fn test_run_with_timeout() {
+        let mut validator = Validator::new(ValidationPreset::Basic);
+        validator.register(
+            "org-1",
+            "Tracking ID prefix",
+            Severity::Warning,
+            Box::new(require_org_prefix),
+        );
+        let doc = Arc::new(mock_csaf21(|_| {}));
+
+        let complete = validator.run_with_timeout(doc.clone(), Duration::from_secs(3600));
+        let expected = validator.validate(doc.as_ref());
+        assert_eq!(
+            complete.test_results.iter().map(|r| &r.test_id).collect::<Vec<_>>(),
+            expected.test_results.iter().map(|r| &r.test_id).collect::<Vec<_>>()
+        );
+        assert_eq!(complete.num_errors, expected.num_errors);
+
+        let expired = validator.run_with_timeout(doc.clone(), Duration::ZERO);
+        assert_eq!(expired.test_results.len(), 1);
+        let timeout = &expired.test_results[0];
+        assert_eq!(timeout.test_id, TIMEOUT_CODE);
+        match &timeout.status {
+            Failure { errors } => {
+                assert_eq!(errors.len(), 1);
+                assert!(
+                    errors[0]
+                        .message
+                        .starts_with("Validation exceeded the time budget of 0ns")
+                );
+                assert!(errors[0].message.ends_with(", org-1"));
+            },
+            _ => panic!("Expected a timeout finding"),
+        }
+        assert!(!expired.success);
+
+        // A slow rule does not block the caller beyond the deadline
+        validator.register(
+            "org-2",
+            "Slow rule",
+            Severity::Error,
+            Box::new(|_| {
+                std::thread::sleep(Duration::from_secs(5));
+                vec![]
+            }),
+        );
+        validator.register("org-3", "Fast rule", Severity::Error, Box::new(|_| vec![]));
+        let start = Instant::now();
+        let partial = validator.run_with_timeout(doc, Duration::from_millis(500));
+        assert!(start.elapsed() < Duration::from_secs(4));
+        let ids: Vec<&str> = partial.test_results.iter().map(|r| r.test_id.as_str()).collect();
+        assert_eq!(ids[ids.len() - 2..], ["org-1", TIMEOUT_CODE]);
+        match &partial.test_results.last().unwrap().status {
+            Failure { errors } => assert!(
+                errors[0]
+                    .message
+                    .ends_with("2 test(s) were not completed: org-2, org-3")
+            ),
+            _ => panic!("Expected a timeout finding"),
+        }
+    }
+
+    #[test]
+    fn test_validate_bytes_with_limits() {
+        let doc = mock_csaf21(|doc| doc["vulnerabilities"] = serde_json::json!([{}, {}]));
+        let bytes = serde_json::to_vec(&doc).unwrap();
+
+        let result = Validator::new(ValidationPreset::Basic).validate_bytes(&bytes).unwrap();
+        assert_eq!(result.version, "2.1");
+        assert!(result.success);
+        assert!(result.test_results.iter().all(|r| match &r.status {
+            Failure { errors } => errors.iter().all(|e| e.rule_set.as_deref() == Some(CSAF_RULE_SET)),
+            _ => true,
+        }));
+
+        let few = Validator::builder()
+            .limits(Limits {
+                max_vulnerabilities: 1,
+                ..Limits::default()
+            })
+            .build()
+            .unwrap();
+        assert!(matches!(
+            few.validate_bytes(&bytes),
+            Err(Error::LimitExceeded {
+                limit: Limit::Vulnerabilities,
+                max: 1
+            })
+        ));
+
+        // Streams are not read beyond the maximum document size
+        let small = Validator::builder()
+            .limits(Limits {
+                max_document_bytes: 64,
+                ..Limits::default()
+            })
+            .build()
+            .unwrap();
+        assert!(matches!(
+            small.validate_reader(std::io::repeat(b' ')),
+            Err(Error::LimitExceeded {
+                limit: Limit::DocumentBytes,
+                max: 64
+            })
+        ));
+        assert!(few.validate_reader(&bytes[..]).is_err());
+        assert!(
+            Validator::new(ValidationPreset::Basic)
+                .validate_reader(&bytes[..])
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = serde_json::json!({
+                "full_product_names": [{
+                    "name": "Product A",
+                    "product_id": "CSAFPID-0001",
+                    "product_identification_helper": {}
+                }]
+            });
+        });
+        let failed_tests = |result: &crate::validation::ValidationResult| {
+            result
+                .test_results
+                .iter()
+                .filter(|r| matches!(r.status, Failure { .. }))
+                .map(|r| r.test_id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let builder = || {
+            Validator::builder().register(
+                "org-1",
+                "Tracking ID prefix",
+                Severity::Warning,
+                Box::new(require_org_prefix),
+            )
+        };
+        let result = builder().build().unwrap().validate(&doc);
+        assert_eq!(failed_tests(&result), vec!["6.1.76", "org-1"]);
+        assert!(!result.success);
+
+        // The remaining custom rule only emits a warning
+        let result = builder().ignore(["6.1.76"]).build().unwrap().validate(&doc);
+        assert_eq!(failed_tests(&result), vec!["org-1"]);
+        assert!(result.success);
+        assert!(!result.test_results.iter().any(|r| r.test_id == "6.1.76"));
+
+        let result = builder().only(["6.1.76", "6.2.13"]).build().unwrap().validate(&doc);
+        assert_eq!(
+            result
+                .test_results
+                .iter()
+                .map(|r| r.test_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["6.1.76", "6.2.13", "org-1"]
+        );
+
+        let result = builder()
+            .ignore(["6.1.76"])
+            .fail_on(Severity::Warning)
+            .build()
+            .unwrap()
+            .validate(&doc);
+        assert_eq!(failed_tests(&result), vec!["org-1"]);
+        assert!(!result.success);
+
+        let mut catalog = MessageCatalog::new();
+        catalog.register("6.1.76", "de", "Leere Hilfe zur Identifikation von '{0}'");
+        let result = builder()
+            .include_optional()
+            .ignore(["org-1"])
+            .catalog(catalog)
+            .locale("de")
+            .build()
+            .unwrap()
+            .validate(&doc);
+        let finding = result
+            .test_results
+            .iter()
+            .find(|r| r.test_id == "6.1.76")
+            .and_then(|r| match &r.status {
+                Failure { errors } => errors.first(),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(finding.message, "Leere Hilfe zur Identifikation von 'CSAFPID-0001'");
+        assert!(!result.test_results.iter().any(|r| r.test_id == "org-1"));
+
+        assert!(Validator::builder().baseline("does-not-exist.json").build().is_err());
+    }
+}
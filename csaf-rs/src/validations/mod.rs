@@ -1,3 +1,4 @@
+pub mod presence;
 pub mod test_6_1_01;
 pub mod test_6_1_02;
 pub mod test_6_1_03;
@@ -58,3 +59,55 @@ pub mod test_6_1_46;
 pub mod test_6_1_47;
 pub mod test_6_1_48;
 pub mod test_6_1_49;
+// pub mod test_6_1_50;
+pub mod test_6_1_100;
+pub mod test_6_1_51;
+pub mod test_6_1_52;
+pub mod test_6_1_53;
+pub mod test_6_1_54;
+pub mod test_6_1_55;
+pub mod test_6_1_56;
+pub mod test_6_1_57;
+pub mod test_6_1_58;
+pub mod test_6_1_59;
+pub mod test_6_1_60;
+pub mod test_6_1_61;
+pub mod test_6_1_62;
+pub mod test_6_1_63;
+pub mod test_6_1_64;
+pub mod test_6_1_65;
+pub mod test_6_1_66;
+pub mod test_6_1_67;
+pub mod test_6_1_68;
+pub mod test_6_1_69;
+pub mod test_6_1_70;
+pub mod test_6_1_71;
+pub mod test_6_1_72;
+pub mod test_6_1_73;
+pub mod test_6_1_74;
+pub mod test_6_1_75;
+pub mod test_6_1_76;
+pub mod test_6_1_77;
+pub mod test_6_1_78;
+pub mod test_6_1_79;
+pub mod test_6_1_80;
+pub mod test_6_1_81;
+pub mod test_6_1_82;
+pub mod test_6_1_83;
+pub mod test_6_1_84;
+pub mod test_6_1_85;
+pub mod test_6_1_86;
+pub mod test_6_1_87;
+pub mod test_6_1_88;
+pub mod test_6_1_89;
+pub mod test_6_1_90;
+pub mod test_6_1_91;
+pub mod test_6_1_92;
+pub mod test_6_1_93;
+pub mod test_6_1_94;
+pub mod test_6_1_95;
+pub mod test_6_1_96;
+pub mod test_6_1_97;
+pub mod test_6_1_98;
+pub mod test_6_1_99;
+pub mod test_6_2_13;
@@ -0,0 +1,267 @@
+//! Declarative presence tests
+//!
+//! Many tests only require a field to be present, absent or not empty, possibly only if another
+//! field of the same object has a certain value. Such tests are expressed as a list of
+//! [PresenceRule]s, which [check] evaluates on the JSON of a document. Their findings are ordinary
+//! [ValidationError]s, so a test written as rules cannot be told apart from a hand-written one.
+//!
+//! The tests 6.1.65, 6.1.74 and 6.1.89 consist of rules only, and the requirements of the
+//! document profiles in [crate::profile] are rules as well.
+
+use crate::validation::ValidationError;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Requires the field at `path` to fulfill `requirement` in all objects matching `condition`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresenceRule {
+    /// Code of the findings
+    pub code: &'static str,
+    /// JSON pointer of the required field, where a `*` segment matches every item of an array
+    pub path: &'static str,
+    /// Restricts the rule to the objects containing the field that match the condition
+    pub condition: Option<Condition>,
+    /// What is required of the field
+    pub requirement: Requirement,
+    /// Message of the findings, where `{}` is replaced by the value of the field of `condition`
+    pub message: &'static str,
+}
+
+/// Condition on the object containing the required field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    /// The field with the given name is a string with one of the given values
+    FieldIn(&'static str, &'static [&'static str]),
+}
+
+/// Requirement for the field of a [PresenceRule]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requirement {
+    /// The field is present and not `null`
+    Present,
+    /// The field is present and neither `null`, a string of whitespace only, an empty array nor
+    /// an empty object
+    NonEmpty,
+    /// The field is not present, `null`, an empty array or an empty object
+    Absent,
+    /// The field is an array with at least one item whose `category` is one of the given values
+    OneOfCategories(&'static [&'static str]),
+    /// The field, if present, is an object with at least one of the given members not `null`
+    AnyMember(&'static [&'static str]),
+}
+
+/// Evaluates `rules` on the JSON of a document, reporting findings in the order of the rules.
+///
+/// Rules whose containing object does not exist, e.g. below an optional field, do not apply.
+pub fn check(doc: &Value, rules: &[PresenceRule]) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for rule in rules {
+        let (parent_path, field) = rule.path.rsplit_once('/').unwrap_or(("", rule.path));
+        let mut parents = Vec::new();
+        resolve(doc, String::new(), parent_path.split('/').skip(1), &mut parents);
+        for (path, parent) in parents {
+            let condition_value = match &rule.condition {
+                None => None,
+                Some(Condition::FieldIn(name, values)) => match parent.get(name).and_then(Value::as_str) {
+                    Some(value) if values.contains(&value) => Some(value),
+                    _ => continue,
+                },
+            };
+            let fulfilled = match (&rule.requirement, parent.get(unescape(field))) {
+                (Requirement::Absent | Requirement::AnyMember(_), None | Some(Value::Null)) => true,
+                (_, None | Some(Value::Null)) => false,
+                (Requirement::Present, Some(_)) => true,
+                (Requirement::NonEmpty, Some(Value::String(s))) => !s.trim().is_empty(),
+                (Requirement::NonEmpty, Some(Value::Array(a))) => !a.is_empty(),
+                (Requirement::NonEmpty, Some(Value::Object(o))) => !o.is_empty(),
+                (Requirement::NonEmpty, Some(_)) => true,
+                (Requirement::Absent, Some(Value::Array(a))) => a.is_empty(),
+                (Requirement::Absent, Some(Value::Object(o))) => o.is_empty(),
+                (Requirement::Absent, Some(_)) => false,
+                (Requirement::OneOfCategories(categories), Some(value)) => {
+                    value.as_array().into_iter().flatten().any(|item| {
+                        item.get("category")
+                            .and_then(Value::as_str)
+                            .is_some_and(|category| categories.contains(&category))
+                    })
+                },
+                (Requirement::AnyMember(members), Some(value)) => members
+                    .iter()
+                    .any(|member| value.get(member).is_some_and(|v| !v.is_null())),
+            };
+            if !fulfilled {
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    rule.code,
+                    rule.message.replace("{}", condition_value.unwrap_or_default()),
+                    format!("{}/{}", path, field),
+                ));
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Evaluates `rules` on a document, see [check].
+///
+/// A document that cannot be serialized to JSON is reported as a single finding with the code of
+/// the first rule.
+pub fn check_document(doc: &impl Serialize, rules: &[PresenceRule]) -> Result<(), Vec<ValidationError>> {
+    match serde_json::to_value(doc) {
+        Ok(value) => check(&value, rules),
+        Err(e) => match rules.first() {
+            Some(rule) => Err(vec![ValidationError::new(
+                rule.code,
+                format!("Document could not be serialized: {}", e),
+                "",
+            )]),
+            None => Ok(()),
+        },
+    }
+}
+
+/// Collects the objects matching the remaining `segments` below `value` at `path`.
+fn resolve<'a, 's>(
+    value: &'a Value,
+    path: String,
+    mut segments: impl Iterator<Item = &'s str> + Clone,
+    found: &mut Vec<(String, &'a Value)>,
+) {
+    let Some(segment) = segments.next() else {
+        if value.is_object() {
+            found.push((path, value));
+        }
+        return;
+    };
+    match (segment, value) {
+        ("*", Value::Array(items)) => {
+            for (i, item) in items.iter().enumerate() {
+                resolve(item, format!("{}/{}", path, i), segments.clone(), found);
+            }
+        },
+        (key, Value::Object(object)) => {
+            if let Some(child) = object.get(&unescape(key)) {
+                resolve(child, format!("{}/{}", path, key), segments, found);
+            }
+        },
+        _ => {},
+    }
+}
+
+fn unescape(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::ValidationError;
+    use crate::validations::presence::{Condition, PresenceRule, Requirement, check, check_document};
+    use serde::{Serialize, Serializer};
+    use serde_json::json;
+
+    static RULES: &[PresenceRule] = &[
+        PresenceRule {
+            code: "x.1",
+            path: "/items/*/name",
+            condition: None,
+            requirement: Requirement::NonEmpty,
+            message: "Item must have a name",
+        },
+        PresenceRule {
+            code: "x.2",
+            path: "/items/*/a~1b",
+            condition: Some(Condition::FieldIn("kind", &["special"])),
+            requirement: Requirement::Present,
+            message: "Item of kind {} must have a/b",
+        },
+        PresenceRule {
+            code: "x.3",
+            path: "/missing/field",
+            condition: None,
+            requirement: Requirement::Present,
+            message: "Never reported",
+        },
+        PresenceRule {
+            code: "x.4",
+            path: "/items/*/tags",
+            condition: None,
+            requirement: Requirement::Absent,
+            message: "Item must not have tags",
+        },
+        PresenceRule {
+            code: "x.5",
+            path: "/notes",
+            condition: None,
+            requirement: Requirement::OneOfCategories(&["summary", "details"]),
+            message: "Notes must contain a summary or details",
+        },
+        PresenceRule {
+            code: "x.6",
+            path: "/sharing",
+            condition: None,
+            requirement: Requirement::AnyMember(&["text", "tlp"]),
+            message: "Sharing must have a text or TLP",
+        },
+    ];
+
+    /// Fails to serialize, like a map with non-string keys
+    struct Unserializable;
+
+    impl Serialize for Unserializable {
+        fn serialize<S: Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("key must be a string"))
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let valid = json!({
+            "items": [
+                { "name": "A", "kind": "special", "a/b": false, "tags": [] },
+                { "name": ["B"], "kind": "plain", "tags": null }
+            ],
+            "notes": [{ "category": "legal_disclaimer" }, { "category": "details" }],
+            "sharing": { "text": null, "tlp": { "label": "CLEAR" } }
+        });
+        assert_eq!(Ok(()), check(&valid, RULES));
+
+        let invalid = json!({
+            "items": [
+                { "name": " ", "kind": "special", "a/b": null },
+                { "name": {}, "kind": "special", "a/b": 0, "tags": ["x"] },
+                { "kind": "plain", "tags": {} }
+            ],
+            "notes": [{ "category": "legal_disclaimer" }],
+            "sharing": { "text": null }
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new("x.1", "Item must have a name", "/items/0/name"),
+                ValidationError::new("x.1", "Item must have a name", "/items/1/name"),
+                ValidationError::new("x.1", "Item must have a name", "/items/2/name"),
+                ValidationError::new("x.2", "Item of kind special must have a/b", "/items/0/a~1b"),
+                ValidationError::new("x.4", "Item must not have tags", "/items/1/tags"),
+                ValidationError::new("x.5", "Notes must contain a summary or details", "/notes"),
+                ValidationError::new("x.6", "Sharing must have a text or TLP", "/sharing"),
+            ]),
+            check(&invalid, RULES)
+        );
+        // Missing notes lack the required category, a missing sharing has no empty members
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "x.5",
+                "Notes must contain a summary or details",
+                "/notes"
+            )]),
+            check(&json!({}), RULES)
+        );
+
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "x.1",
+                "Document could not be serialized: key must be a string",
+                ""
+            )]),
+            check_document(&Unserializable, RULES)
+        );
+    }
+}
@@ -1,23 +1,22 @@
-use crate::csaf_traits::{CsafTrait, ProductTrait, ProductTreeTrait};
+use crate::csaf_traits::CsafTraitExt;
 use crate::validation::ValidationError;
+use crate::walk::ProductIdKind;
 use std::collections::HashSet;
 
-fn validate_missing_product_id<Doc: CsafTrait>(doc: &Doc) -> Result<(), Vec<ValidationError>> {
-    let mut definitions_set = HashSet::<String>::new();
-    if let Some(tree) = doc.get_product_tree().as_ref() {
-        tree.visit_all_products(&mut |fpn, _path| {
-            definitions_set.insert(fpn.get_product_id().to_owned());
-        });
-    }
+fn validate_missing_product_id<Doc: CsafTraitExt>(doc: &Doc) -> Result<(), Vec<ValidationError>> {
+    let definitions_set: HashSet<String> = doc
+        .iter_product_ids(ProductIdKind::Definition)
+        .map(|(id, _)| id)
+        .collect();
 
-    let references = doc.get_all_product_references();
     let mut errors: Option<Vec<ValidationError>> = Option::None;
-    for (ref_id, ref_path) in references.iter() {
-        if !definitions_set.contains(ref_id) {
-            errors.get_or_insert_with(Vec::new).push(ValidationError {
-                message: format!("Missing definition of product_id: {}", ref_id),
-                instance_path: ref_path.to_string(),
-            });
+    for (ref_id, ref_path) in doc.iter_product_ids(ProductIdKind::Reference) {
+        if !definitions_set.contains(&ref_id) {
+            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                "6.1.1",
+                format!("Missing definition of product_id: {}", ref_id),
+                ref_path,
+            ));
         }
     }
     errors.map_or(Ok(()), Err)
@@ -57,38 +56,44 @@ mod tests {
     fn test_6_1_01() {
         TESTS_2_0.test_6_1_1.expect(
             Err(vec![
-                ValidationError {
-                    message: "Missing definition of product_id: CSAFPID-9080700".to_string(),
-                    instance_path: "/product_tree/product_groups/0/product_ids/0".to_string(),
-                },
-                ValidationError {
-                    message: "Missing definition of product_id: CSAFPID-9080701".to_string(),
-                    instance_path: "/product_tree/product_groups/0/product_ids/1".to_string(),
-                },
+                ValidationError::new(
+                    "6.1.1",
+                    "Missing definition of product_id: CSAFPID-9080700",
+                    "/product_tree/product_groups/0/product_ids/0",
+                ),
+                ValidationError::new(
+                    "6.1.1",
+                    "Missing definition of product_id: CSAFPID-9080701",
+                    "/product_tree/product_groups/0/product_ids/1",
+                ),
             ]),
             Err(vec![
-                ValidationError {
-                    message: "Missing definition of product_id: CSAFPID-9080701".to_string(),
-                    instance_path: "/vulnerabilities/0/flags/0/product_ids/1".to_string(),
-                },
-                ValidationError {
-                    message: "Missing definition of product_id: CSAFPID-9080702".to_string(),
-                    instance_path: "/vulnerabilities/1/flags/0/product_ids/0".to_string(),
-                },
+                ValidationError::new(
+                    "6.1.1",
+                    "Missing definition of product_id: CSAFPID-9080701",
+                    "/vulnerabilities/0/flags/0/product_ids/1",
+                ),
+                ValidationError::new(
+                    "6.1.1",
+                    "Missing definition of product_id: CSAFPID-9080702",
+                    "/vulnerabilities/1/flags/0/product_ids/0",
+                ),
             ]),
             Ok(()),
             Ok(()),
         );
 
         TESTS_2_1.test_6_1_1.expect(Err(vec![
-            ValidationError {
-                message: "Missing definition of product_id: CSAFPID-9080700".to_string(),
-                instance_path: "/product_tree/product_groups/0/product_ids/0".to_string(),
-            },
-            ValidationError {
-                message: "Missing definition of product_id: CSAFPID-9080701".to_string(),
-                instance_path: "/product_tree/product_groups/0/product_ids/1".to_string(),
-            },
+            ValidationError::new(
+                "6.1.1",
+                "Missing definition of product_id: CSAFPID-9080700",
+                "/product_tree/product_groups/0/product_ids/0",
+            ),
+            ValidationError::new(
+                "6.1.1",
+                "Missing definition of product_id: CSAFPID-9080701",
+                "/product_tree/product_groups/0/product_ids/1",
+            ),
         ]));
     }
 }
@@ -1,8 +1,8 @@
-use crate::csaf_traits::{CsafTrait, ProductTrait, ProductTreeTrait};
+use crate::csaf_traits::{CsafTraitExt, ProductTrait, ProductTreeTrait};
 use crate::validation::ValidationError;
 use std::collections::HashSet;
 
-pub fn test_6_1_02_multiple_definition_of_product_id(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_02_multiple_definition_of_product_id(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     // Map to store each key with all of its paths
     let mut products = HashSet::<String>::new();
 
@@ -10,10 +10,11 @@ pub fn test_6_1_02_multiple_definition_of_product_id(doc: &impl CsafTrait) -> Re
     if let Some(tree) = doc.get_product_tree().as_ref() {
         tree.visit_all_products(&mut |product, path| {
             if products.contains(product.get_product_id()) {
-                errors.get_or_insert_with(Vec::new).push(ValidationError {
-                    message: format!("Duplicate definition for product ID {}", product.get_product_id()),
-                    instance_path: format!("{}/product_id", path),
-                });
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.2",
+                    format!("Duplicate definition for product ID {}", product.get_product_id()),
+                    format!("{}/product_id", path),
+                ));
             } else {
                 products.insert(product.get_product_id().to_owned());
             }
@@ -34,10 +35,11 @@ mod tests {
     fn test_test_6_1_02() {
         let errors = HashMap::from([(
             "01",
-            vec![ValidationError {
-                message: "Duplicate definition for product ID CSAFPID-9080700".to_string(),
-                instance_path: "/product_tree/full_product_names/1/product_id".to_string(),
-            }],
+            vec![ValidationError::new(
+                "6.1.2",
+                "Duplicate definition for product ID CSAFPID-9080700",
+                "/product_tree/full_product_names/1/product_id",
+            )],
         )]);
         run_csaf20_tests("02", test_6_1_02_multiple_definition_of_product_id, errors.clone());
         run_csaf21_tests("02", test_6_1_02_multiple_definition_of_product_id, errors);
@@ -1,6 +1,6 @@
-use crate::csaf_traits::{CsafTrait, ProductTrait, ProductTreeTrait, RelationshipTrait};
+use crate::csaf_traits::{CsafTraitExt, ProductTrait, ProductTreeTrait, RelationshipTrait};
 use crate::validation::ValidationError;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
 /// Find the first cycle in the given `relation_map`, if any.
 ///
@@ -8,7 +8,7 @@ use std::collections::{HashMap, HashSet};
 /// - `Vec` of the product IDs forming the detected cycle
 /// - Index of the CSAF relation containing the product ID where the cycle was first detected
 pub fn find_cycle<'a>(
-    relation_map: &'a HashMap<String, HashMap<String, usize>>,
+    relation_map: &'a BTreeMap<String, BTreeMap<String, usize>>,
     product_id: &'a str,
     visited: &mut HashSet<&'a str>,
 ) -> Option<(Vec<String>, usize)> {
@@ -40,23 +40,25 @@ pub fn find_cycle<'a>(
     None
 }
 
-pub fn test_6_1_03_circular_definition_of_product_id(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_03_circular_definition_of_product_id(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let mut errors: Option<Vec<ValidationError>> = None;
     if let Some(tree) = doc.get_product_tree().as_ref() {
-        let mut relation_map = HashMap::<String, HashMap<String, usize>>::new();
+        let mut relation_map = BTreeMap::<String, BTreeMap<String, usize>>::new();
 
         for (i_r, r) in tree.get_relationships().iter().enumerate() {
             let rel_prod_id = r.get_full_product_name().get_product_id();
             if r.get_product_reference() == rel_prod_id {
-                errors.get_or_insert_with(Vec::new).push(ValidationError {
-                    message: "Relationship references itself via product_reference".to_string(),
-                    instance_path: format!("/product_tree/relationships/{}/product_reference", i_r),
-                });
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.3",
+                    "Relationship references itself via product_reference",
+                    format!("/product_tree/relationships/{}/product_reference", i_r),
+                ));
             } else if r.get_relates_to_product_reference() == rel_prod_id {
-                errors.get_or_insert_with(Vec::new).push(ValidationError {
-                    message: "Relationship references itself via relates_to_product_reference".to_string(),
-                    instance_path: format!("/product_tree/relationships/{}/relates_to_product_reference", i_r),
-                });
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.3",
+                    "Relationship references itself via relates_to_product_reference",
+                    format!("/product_tree/relationships/{}/relates_to_product_reference", i_r),
+                ));
             } else {
                 match relation_map.get_mut(r.get_product_reference()) {
                     Some(v) => {
@@ -65,7 +67,7 @@ pub fn test_6_1_03_circular_definition_of_product_id(doc: &impl CsafTrait) -> Re
                     None => {
                         relation_map.insert(
                             r.get_product_reference().to_owned(),
-                            HashMap::from([(r.get_relates_to_product_reference().to_owned(), i_r)]),
+                            BTreeMap::from([(r.get_relates_to_product_reference().to_owned(), i_r)]),
                         );
                     },
                 }
@@ -76,10 +78,11 @@ pub fn test_6_1_03_circular_definition_of_product_id(doc: &impl CsafTrait) -> Re
         let mut visited = HashSet::new();
         for product_id in relation_map.keys() {
             if let Some((cycle, relation_index)) = find_cycle(&relation_map, product_id, &mut visited) {
-                errors.get_or_insert_with(Vec::new).push(ValidationError {
-                    message: format!("Found product relationship cycle: {}", cycle.join(" -> ")),
-                    instance_path: format!("/product_tree/relationships/{}", relation_index),
-                });
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.3",
+                    format!("Found product relationship cycle: {}", cycle.join(" -> ")),
+                    format!("/product_tree/relationships/{}", relation_index),
+                ));
             }
         }
     }
@@ -92,16 +95,17 @@ mod tests {
     use crate::test_helper::{run_csaf20_tests, run_csaf21_tests};
     use crate::validation::ValidationError;
     use crate::validations::test_6_1_03::test_6_1_03_circular_definition_of_product_id;
-    use std::collections::{HashMap, HashSet};
+    use std::collections::{BTreeMap, HashMap, HashSet};
 
     #[test]
     fn test_test_6_1_03() {
         let errors = HashMap::from([(
             "01",
-            vec![ValidationError {
-                message: "Relationship references itself via relates_to_product_reference".to_string(),
-                instance_path: "/product_tree/relationships/0/relates_to_product_reference".to_string(),
-            }],
+            vec![ValidationError::new(
+                "6.1.3",
+                "Relationship references itself via relates_to_product_reference",
+                "/product_tree/relationships/0/relates_to_product_reference",
+            )],
         )]);
         run_csaf20_tests("03", test_6_1_03_circular_definition_of_product_id, errors.clone());
         run_csaf21_tests("03", test_6_1_03_circular_definition_of_product_id, errors);
@@ -110,22 +114,22 @@ mod tests {
     #[test]
     fn test_find_cycle() {
         // Create a relation map with a non-trivial cycle: B -> C -> D -> B
-        let mut relation_map = HashMap::new();
+        let mut relation_map = BTreeMap::new();
 
-        relation_map.insert("A".to_string(), HashMap::from([("B".to_string(), 0)]));
+        relation_map.insert("A".to_string(), BTreeMap::from([("B".to_string(), 0)]));
         relation_map.insert(
             "B".to_string(),
-            HashMap::from([("C".to_string(), 1), ("E".to_string(), 2)]),
+            BTreeMap::from([("C".to_string(), 1), ("E".to_string(), 2)]),
         );
         relation_map.insert(
             "C".to_string(),
-            HashMap::from([("D".to_string(), 3), ("F".to_string(), 4)]),
+            BTreeMap::from([("D".to_string(), 3), ("F".to_string(), 4)]),
         );
-        relation_map.insert("D".to_string(), HashMap::from([("B".to_string(), 5)]));
+        relation_map.insert("D".to_string(), BTreeMap::from([("B".to_string(), 5)]));
 
         // Also add some nodes that aren't part of the cycle
-        relation_map.insert("E".to_string(), HashMap::from([("F".to_string(), 6)]));
-        relation_map.insert("F".to_string(), HashMap::from([("G".to_string(), 7)]));
+        relation_map.insert("E".to_string(), BTreeMap::from([("F".to_string(), 6)]));
+        relation_map.insert("F".to_string(), BTreeMap::from([("G".to_string(), 7)]));
 
         // Test cycle detection starting from the first node
         let mut visited = HashSet::new();
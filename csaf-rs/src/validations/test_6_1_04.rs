@@ -1,22 +1,20 @@
-use crate::csaf_traits::{CsafTrait, ProductGroupTrait, ProductTreeTrait};
+use crate::csaf_traits::CsafTraitExt;
+use crate::helpers::defined_product_group_ids;
 use crate::validation::ValidationError;
-use std::collections::HashSet;
 
-pub fn test_6_1_04_missing_definition_of_product_group_id(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_04_missing_definition_of_product_group_id(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let mut errors: Option<Vec<ValidationError>> = Option::None;
-    if let Some(tree) = doc.get_product_tree().as_ref() {
-        let mut known_groups = HashSet::<String>::new();
-        for g in tree.get_product_groups().iter() {
-            known_groups.insert(g.get_group_id().to_owned());
-        }
+    if doc.get_product_tree().is_some() {
+        let known_groups = defined_product_group_ids(doc);
 
         let product_group_references = doc.get_all_group_references();
         for (ref_id, ref_path) in product_group_references.iter() {
             if !known_groups.contains(ref_id) {
-                errors.get_or_insert_with(Vec::new).push(ValidationError {
-                    message: format!("Missing definition of product_group_id: {}", ref_id),
-                    instance_path: ref_path.to_owned(),
-                });
+                errors.get_or_insert_with(Vec::new).push(ValidationError::with_args(
+                    "6.1.4",
+                    [ref_id],
+                    ref_path.to_owned(),
+                ));
             }
         }
     }
@@ -36,22 +34,17 @@ mod tests {
         let errors = HashMap::from([
             (
                 "01",
-                vec![ValidationError {
-                    message: "Missing definition of product_group_id: CSAFGID-1020301".to_string(),
-                    instance_path: "/vulnerabilities/0/threats/0/group_ids/0".to_string(),
-                }],
+                vec![ValidationError::with_args(
+                    "6.1.4",
+                    ["CSAFGID-1020301"],
+                    "/vulnerabilities/0/threats/0/group_ids/0",
+                )],
             ),
             (
                 "02",
                 vec![
-                    ValidationError {
-                        message: "Missing definition of product_group_id: CSAFGID-1020300".to_string(),
-                        instance_path: "/vulnerabilities/0/flags/0/group_ids/0".to_string(),
-                    },
-                    ValidationError {
-                        message: "Missing definition of product_group_id: CSAFGID-1020301".to_string(),
-                        instance_path: "/vulnerabilities/1/flags/0/group_ids/0".to_string(),
-                    },
+                    ValidationError::with_args("6.1.4", ["CSAFGID-1020300"], "/vulnerabilities/0/flags/0/group_ids/0"),
+                    ValidationError::with_args("6.1.4", ["CSAFGID-1020301"], "/vulnerabilities/1/flags/0/group_ids/0"),
                 ],
             ),
         ]);
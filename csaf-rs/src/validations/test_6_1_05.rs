@@ -1,8 +1,10 @@
-use crate::csaf_traits::{CsafTrait, ProductGroupTrait, ProductTreeTrait};
+use crate::csaf_traits::{CsafTraitExt, ProductGroupTrait, ProductTreeTrait};
 use crate::validation::ValidationError;
 use std::collections::HashMap;
 
-pub fn test_6_1_05_multiple_definition_of_product_group_id(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_05_multiple_definition_of_product_group_id(
+    doc: &impl CsafTraitExt,
+) -> Result<(), Vec<ValidationError>> {
     let mut product_group_ids_with_paths = HashMap::<String, Vec<String>>::new();
 
     if let Some(tree) = doc.get_product_tree().as_ref() {
@@ -18,10 +20,9 @@ pub fn test_6_1_05_multiple_definition_of_product_group_id(doc: &impl CsafTrait)
         .iter()
         .filter(|(_, paths)| paths.len() > 1)
         .flat_map(|(group_id, paths)| {
-            paths.iter().map(move |path| ValidationError {
-                message: format!("Duplicate definition for product group ID {}", group_id),
-                instance_path: path.clone(),
-            })
+            paths
+                .iter()
+                .map(move |path| ValidationError::with_args("6.1.5", [group_id], path.clone()))
         })
         .collect();
 
@@ -40,14 +41,8 @@ mod tests {
         let errors = HashMap::from([(
             "01",
             vec![
-                ValidationError {
-                    message: "Duplicate definition for product group ID CSAFGID-1020300".to_string(),
-                    instance_path: "/product_tree/product_groups/0/group_id".to_string(),
-                },
-                ValidationError {
-                    message: "Duplicate definition for product group ID CSAFGID-1020300".to_string(),
-                    instance_path: "/product_tree/product_groups/1/group_id".to_string(),
-                },
+                ValidationError::with_args("6.1.5", ["CSAFGID-1020300"], "/product_tree/product_groups/0/group_id"),
+                ValidationError::with_args("6.1.5", ["CSAFGID-1020300"], "/product_tree/product_groups/1/group_id"),
             ],
         )]);
         run_csaf20_tests(
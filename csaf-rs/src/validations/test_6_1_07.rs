@@ -1,11 +1,10 @@
-use crate::csaf_traits::VulnerabilityMetric::{CvssV2, CvssV3, CvssV4, Epss, SsvcV1};
 use crate::csaf_traits::{
-    ContentTrait, CsafTrait, MetricTrait, VulnerabilityMetric, VulnerabilityTrait, get_metric_prop_name,
+    ContentTrait, CsafTraitExt, MetricTrait, VulnerabilityMetric, VulnerabilityTrait, get_metric_prop_name,
 };
 use crate::validation::ValidationError;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
-type ProductMetricsMap = HashMap<String, HashMap<(VulnerabilityMetric, Option<String>), Vec<String>>>;
+type ProductMetricsMap = BTreeMap<String, BTreeMap<(VulnerabilityMetric, Option<String>), Vec<String>>>;
 fn gather_product_metrics(
     vulnerability: &impl VulnerabilityTrait,
     vulnerability_index: usize,
@@ -14,28 +13,10 @@ fn gather_product_metrics(
 
     metrics?;
 
-    let mut product_metrics: ProductMetricsMap = HashMap::new();
+    let mut product_metrics: ProductMetricsMap = BTreeMap::new();
     for (metric_index, metric) in metrics.unwrap().iter().enumerate() {
         let content = metric.get_content();
-        let mut present_metric_types = HashSet::<VulnerabilityMetric>::new();
-        if content.has_ssvc() {
-            present_metric_types.insert(SsvcV1);
-        }
-        if content.get_cvss_v2().is_some() {
-            present_metric_types.insert(CvssV2);
-        }
-        if let Some(cvss_v3) = content.get_cvss_v3() {
-            if let Some(version) = cvss_v3.get("version").and_then(|v| v.as_str()) {
-                // Use as_str because otherwise additional quotation marks would be included
-                present_metric_types.insert(CvssV3(version.to_owned()));
-            }
-        }
-        if content.get_cvss_v4().is_some() {
-            present_metric_types.insert(CvssV4);
-        }
-        if content.get_epss().is_some() {
-            present_metric_types.insert(Epss);
-        }
+        let present_metric_types: HashSet<VulnerabilityMetric> = content.iter_metric_contents().collect();
 
         for product_id in metric.get_products() {
             for metric_type in present_metric_types.iter() {
@@ -53,7 +34,7 @@ fn gather_product_metrics(
 }
 
 /// Test 6.1.7: Check for multiple identical metric types per vulnerability.
-pub fn test_6_1_07_multiple_same_scores_per_product(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_07_multiple_same_scores_per_product(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let mut errors: Option<Vec<ValidationError>> = None;
     for (vulnerability_index, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
         let product_metrics = gather_product_metrics(vulnerability, vulnerability_index);
@@ -62,10 +43,11 @@ pub fn test_6_1_07_multiple_same_scores_per_product(doc: &impl CsafTrait) -> Res
                 for ((metric_type, _), paths) in metrics_map.iter() {
                     if paths.len() > 1 {
                         for path in paths {
-                            errors.get_or_insert_with(Vec::new).push(ValidationError {
-                                message: create_error_message(metric_type, p),
-                                instance_path: format!("{}/{}", path, get_metric_prop_name(metric_type.to_owned())),
-                            });
+                            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                                "6.1.7",
+                                create_error_message(metric_type, p),
+                                format!("{}/{}", path, get_metric_prop_name(metric_type.to_owned())),
+                            ));
                         }
                     }
                 }
@@ -81,11 +63,12 @@ fn create_error_message(score_type: &VulnerabilityMetric, product_id: &str) -> S
 
 #[cfg(test)]
 mod tests {
-    use crate::test_helper::{run_csaf20_tests, run_csaf21_tests};
+    use crate::test_helper::{mock_csaf21, run_csaf20_tests, run_csaf21_tests};
     use crate::validation::ValidationError;
     use crate::validations::test_6_1_07::{
         VulnerabilityMetric, create_error_message, test_6_1_07_multiple_same_scores_per_product,
     };
+    use serde_json::json;
     use std::collections::HashMap;
 
     #[test]
@@ -99,14 +82,16 @@ mod tests {
             HashMap::from([(
                 "01",
                 vec![
-                    ValidationError {
-                        message: cvss_v31_error_message.to_string(),
-                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3".to_string(),
-                    },
-                    ValidationError {
-                        message: cvss_v31_error_message.to_string(),
-                        instance_path: "/vulnerabilities/0/scores/1/cvss_v3".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.7",
+                        cvss_v31_error_message.to_string(),
+                        "/vulnerabilities/0/scores/0/cvss_v3",
+                    ),
+                    ValidationError::new(
+                        "6.1.7",
+                        cvss_v31_error_message.to_string(),
+                        "/vulnerabilities/0/scores/1/cvss_v3",
+                    ),
                 ],
             )]),
         );
@@ -120,93 +105,143 @@ mod tests {
                 (
                     "01",
                     vec![
-                        ValidationError {
-                            message: cvss_v31_error_message.to_string(),
-                            instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3".to_string(),
-                        },
-                        ValidationError {
-                            message: cvss_v31_error_message.to_string(),
-                            instance_path: "/vulnerabilities/0/metrics/1/content/cvss_v3".to_string(),
-                        },
+                        ValidationError::new(
+                            "6.1.7",
+                            cvss_v31_error_message.to_string(),
+                            "/vulnerabilities/0/metrics/0/content/cvss_v3",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            cvss_v31_error_message.to_string(),
+                            "/vulnerabilities/0/metrics/1/content/cvss_v3",
+                        ),
                     ],
                 ),
                 (
                     "02",
                     vec![
-                        ValidationError {
-                            message: cvss_v30_error_message.to_string(),
-                            instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3".to_string(),
-                        },
-                        ValidationError {
-                            message: cvss_v30_error_message.to_string(),
-                            instance_path: "/vulnerabilities/0/metrics/1/content/cvss_v3".to_string(),
-                        },
+                        ValidationError::new(
+                            "6.1.7",
+                            cvss_v30_error_message.to_string(),
+                            "/vulnerabilities/0/metrics/0/content/cvss_v3",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            cvss_v30_error_message.to_string(),
+                            "/vulnerabilities/0/metrics/1/content/cvss_v3",
+                        ),
                     ],
                 ),
                 (
                     "03",
                     vec![
-                        ValidationError {
-                            message: create_error_message(&VulnerabilityMetric::CvssV2, "CSAFPID-9080700"),
-                            instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v2".to_string(),
-                        },
-                        ValidationError {
-                            message: create_error_message(&VulnerabilityMetric::CvssV2, "CSAFPID-9080700"),
-                            instance_path: "/vulnerabilities/0/metrics/1/content/cvss_v2".to_string(),
-                        },
+                        ValidationError::new(
+                            "6.1.7",
+                            create_error_message(&VulnerabilityMetric::CvssV2, "CSAFPID-9080700"),
+                            "/vulnerabilities/0/metrics/0/content/cvss_v2",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            create_error_message(&VulnerabilityMetric::CvssV2, "CSAFPID-9080700"),
+                            "/vulnerabilities/0/metrics/1/content/cvss_v2",
+                        ),
                     ],
                 ),
                 (
                     "04",
                     vec![
-                        ValidationError {
-                            message: create_error_message(&VulnerabilityMetric::CvssV4, "CSAFPID-9080700"),
-                            instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                        },
-                        ValidationError {
-                            message: create_error_message(&VulnerabilityMetric::CvssV4, "CSAFPID-9080700"),
-                            instance_path: "/vulnerabilities/0/metrics/1/content/cvss_v4".to_string(),
-                        },
+                        ValidationError::new(
+                            "6.1.7",
+                            create_error_message(&VulnerabilityMetric::CvssV4, "CSAFPID-9080700"),
+                            "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            create_error_message(&VulnerabilityMetric::CvssV4, "CSAFPID-9080700"),
+                            "/vulnerabilities/0/metrics/1/content/cvss_v4",
+                        ),
                     ],
                 ),
                 (
                     "05",
                     vec![
-                        ValidationError {
-                            message: cvss_v31_error_message.to_string(),
-                            instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3".to_string(),
-                        },
-                        ValidationError {
-                            message: cvss_v31_error_message.to_string(),
-                            instance_path: "/vulnerabilities/0/metrics/1/content/cvss_v3".to_string(),
-                        },
-                        ValidationError {
-                            message: cvss_v30_error_message.to_string(),
-                            instance_path: "/vulnerabilities/1/metrics/1/content/cvss_v3".to_string(),
-                        },
-                        ValidationError {
-                            message: cvss_v30_error_message.to_string(),
-                            instance_path: "/vulnerabilities/1/metrics/2/content/cvss_v3".to_string(),
-                        },
-                        ValidationError {
-                            message: create_error_message(&VulnerabilityMetric::CvssV2, "CSAFPID-9080701"),
-                            instance_path: "/vulnerabilities/2/metrics/0/content/cvss_v2".to_string(),
-                        },
-                        ValidationError {
-                            message: create_error_message(&VulnerabilityMetric::CvssV2, "CSAFPID-9080701"),
-                            instance_path: "/vulnerabilities/2/metrics/1/content/cvss_v2".to_string(),
-                        },
-                        ValidationError {
-                            message: create_error_message(&VulnerabilityMetric::CvssV4, "CSAFPID-9080701"),
-                            instance_path: "/vulnerabilities/3/metrics/0/content/cvss_v4".to_string(),
-                        },
-                        ValidationError {
-                            message: create_error_message(&VulnerabilityMetric::CvssV4, "CSAFPID-9080701"),
-                            instance_path: "/vulnerabilities/3/metrics/1/content/cvss_v4".to_string(),
-                        },
+                        ValidationError::new(
+                            "6.1.7",
+                            cvss_v31_error_message.to_string(),
+                            "/vulnerabilities/0/metrics/0/content/cvss_v3",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            cvss_v31_error_message.to_string(),
+                            "/vulnerabilities/0/metrics/1/content/cvss_v3",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            cvss_v30_error_message.to_string(),
+                            "/vulnerabilities/1/metrics/1/content/cvss_v3",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            cvss_v30_error_message.to_string(),
+                            "/vulnerabilities/1/metrics/2/content/cvss_v3",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            create_error_message(&VulnerabilityMetric::CvssV2, "CSAFPID-9080701"),
+                            "/vulnerabilities/2/metrics/0/content/cvss_v2",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            create_error_message(&VulnerabilityMetric::CvssV2, "CSAFPID-9080701"),
+                            "/vulnerabilities/2/metrics/1/content/cvss_v2",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            create_error_message(&VulnerabilityMetric::CvssV4, "CSAFPID-9080701"),
+                            "/vulnerabilities/3/metrics/0/content/cvss_v4",
+                        ),
+                        ValidationError::new(
+                            "6.1.7",
+                            create_error_message(&VulnerabilityMetric::CvssV4, "CSAFPID-9080701"),
+                            "/vulnerabilities/3/metrics/1/content/cvss_v4",
+                        ),
                     ],
                 ),
             ]),
         );
     }
+
+    #[test]
+    fn test_test_6_1_07_insertion_order() {
+        // The products of both metrics are inserted into the map of metrics per product in the
+        // order they are listed. The findings must not depend on that order.
+        let validate = |products: &[&str]| {
+            let doc = mock_csaf21(|doc| {
+                let cvss_v3 = json!({
+                    "version": "3.1",
+                    "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                    "baseScore": 9.8,
+                    "baseSeverity": "CRITICAL"
+                });
+                doc["vulnerabilities"] = json!([{
+                    "metrics": [
+                        { "content": { "cvss_v3": cvss_v3 }, "products": products },
+                        { "content": { "cvss_v3": cvss_v3 }, "products": products }
+                    ]
+                }]);
+            });
+            test_6_1_07_multiple_same_scores_per_product(&doc)
+        };
+
+        let ids = ["CSAFPID-0003", "CSAFPID-0001", "CSAFPID-0004", "CSAFPID-0002"];
+        let expected = validate(&ids);
+        assert_eq!(expected.as_ref().map_err(Vec::len), Err(8));
+
+        let mut reversed = ids;
+        reversed.reverse();
+        assert_eq!(expected, validate(&reversed));
+        let mut sorted = ids;
+        sorted.sort();
+        assert_eq!(expected, validate(&sorted));
+    }
 }
@@ -3,14 +3,14 @@ use serde_json::{Map, Value};
 
 use crate::{
     csaf_traits::{
-        ContentTrait, CsafTrait, MetricTrait, VulnerabilityMetric, VulnerabilityTrait, get_metric_prop_name,
+        ContentTrait, CsafTraitExt, MetricTrait, VulnerabilityMetric, VulnerabilityTrait, get_metric_prop_name,
     },
     validation::ValidationError,
 };
 
 /// 6.1.8 Invalid CVSS
 /// Invalid CVSS object according to scheme
-pub fn test_6_1_08_invalid_cvss(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_08_invalid_cvss(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let cvss20_validator = create_validator(include_str!("../../assets/cvss-v2.0.json"));
     let cvss30_validator = create_validator(include_str!("../../assets/cvss-v3.0.json"));
     let cvss31_validator = create_validator(include_str!("../../assets/cvss-v3.1.json"));
@@ -69,10 +69,11 @@ fn evaluate_cvss(
     let value = serde_json::to_value(cvss_value).unwrap();
     let evaluation = validator.evaluate(&value);
     for error in evaluation.iter_errors() {
-        errors.push(ValidationError {
-            message: error.error.to_string(),
-            instance_path: instance_path.clone(),
-        });
+        errors.push(ValidationError::new(
+            "6.1.8",
+            error.error.to_string(),
+            instance_path.clone(),
+        ));
     }
 }
 
@@ -91,24 +92,27 @@ mod tests {
             HashMap::from([
                 (
                     "01",
-                    vec![ValidationError {
-                        message: "\"baseSeverity\" is a required property".to_string(),
-                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.8",
+                        "\"baseSeverity\" is a required property",
+                        "/vulnerabilities/0/scores/0/cvss_v3",
+                    )],
                 ),
                 (
                     "02",
-                    vec![ValidationError {
-                        message: "\"baseSeverity\" is a required property".to_string(),
-                        instance_path: "/vulnerabilities/0/scores/0/cvss_v3".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.8",
+                        "\"baseSeverity\" is a required property",
+                        "/vulnerabilities/0/scores/0/cvss_v3",
+                    )],
                 ),
                 (
                     "03",
-                    vec![ValidationError {
-                        message: "\"version\" is a required property".to_string(),
-                        instance_path: "/vulnerabilities/0/scores/0/cvss_v2".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.8",
+                        "\"version\" is a required property",
+                        "/vulnerabilities/0/scores/0/cvss_v2",
+                    )],
                 ),
             ]),
         );
@@ -119,70 +123,86 @@ mod tests {
             HashMap::from([
                 (
                     "01",
-                    vec![ValidationError {
-                        message: "\"baseSeverity\" is a required property".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.8",
+                        "\"baseSeverity\" is a required property",
+                        "/vulnerabilities/0/metrics/0/content/cvss_v3",
+                    )],
                 ),
                 (
                     "02",
-                    vec![ValidationError {
-                        message: "\"baseSeverity\" is a required property".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v3".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.8",
+                        "\"baseSeverity\" is a required property",
+                        "/vulnerabilities/0/metrics/0/content/cvss_v3",
+                    )],
                 ),
                 (
                     "03",
-                    vec![ValidationError {
-                        message: "\"version\" is a required property".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v2".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.8",
+                        "\"version\" is a required property",
+                        "/vulnerabilities/0/metrics/0/content/cvss_v2",
+                    )],
                 ),
                 (
                     "04",
-                    vec![ValidationError {
-                        message: "\"baseSeverity\" is a required property".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.8",
+                        "\"baseSeverity\" is a required property",
+                        "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                    )],
                 ),
                 (
                     "05",
-                    vec![ValidationError {
-                        message: "Unevaluated properties are not allowed ('threatScore', 'threatSeverity' were unexpected)".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                    },
-                    ValidationError {
-                        message: "False schema does not allow \"CRITICAL\"".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                    },
-                    ValidationError {
-                        message: "False schema does not allow 9.3".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                    }],
+                    vec![
+                        ValidationError::new(
+                            "6.1.8",
+                            "Unevaluated properties are not allowed ('threatScore', 'threatSeverity' were unexpected)",
+                            "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                        ),
+                        ValidationError::new(
+                            "6.1.8",
+                            "False schema does not allow \"CRITICAL\"",
+                            "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                        ),
+                        ValidationError::new(
+                            "6.1.8",
+                            "False schema does not allow 9.3",
+                            "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                        ),
+                    ],
                 ),
                 (
                     "06",
-                    vec![ValidationError {
-                        message: "Unevaluated properties are not allowed ('environmentalScore', 'environmentalSeverity', 'threatScore', 'threatSeverity' were unexpected)".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                    },
-                    ValidationError {
-                        message: "False schema does not allow \"CRITICAL\"".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                    },
-                    ValidationError {
-                        message: "False schema does not allow \"MEDIUM\"".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                    },
-                    ValidationError {
-                        message: "False schema does not allow 9.3".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                    },
-                    ValidationError {
-                        message: "False schema does not allow 5.4".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/cvss_v4".to_string(),
-                    }],
-                )
+                    vec![
+                        ValidationError::new(
+                            "6.1.8",
+                            "Unevaluated properties are not allowed ('environmentalScore', 'environmentalSeverity', 'threatScore', 'threatSeverity' were unexpected)",
+                            "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                        ),
+                        ValidationError::new(
+                            "6.1.8",
+                            "False schema does not allow \"CRITICAL\"",
+                            "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                        ),
+                        ValidationError::new(
+                            "6.1.8",
+                            "False schema does not allow \"MEDIUM\"",
+                            "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                        ),
+                        ValidationError::new(
+                            "6.1.8",
+                            "False schema does not allow 9.3",
+                            "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                        ),
+                        ValidationError::new(
+                            "6.1.8",
+                            "False schema does not allow 5.4",
+                            "/vulnerabilities/0/metrics/0/content/cvss_v4",
+                        ),
+                    ],
+                ),
             ]),
         );
     }
@@ -0,0 +1,54 @@
+use crate::csaf_traits::CsafTraitExt;
+use crate::profile::check_required_fields;
+use crate::validation::ValidationError;
+use serde::Serialize;
+
+/// 6.1.100 Aggregate Severity
+///
+/// This test only applies to documents with `/document/category` with value
+/// `csaf_security_advisory`.
+///
+/// Documents with this category must have a `/document/aggregate_severity` element, so that
+/// readers can prioritize the advisory. CSAF itself declares the element optional in all profiles
+/// and does not prohibit it for `csaf_vex`, so the test is part of the extended preset only.
+///
+/// The requirement is defined in [crate::profile::Profile::required_fields]. The test only
+/// applies to CSAF 2.1, CSAF 2.0 does not require an aggregate severity.
+pub fn test_6_1_100_aggregate_severity(doc: &(impl CsafTraitExt + Serialize)) -> Result<(), Vec<ValidationError>> {
+    check_required_fields(doc, "6.1.100")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_100::test_6_1_100_aggregate_severity;
+    use serde_json::{Value, json};
+
+    #[test]
+    fn test_test_6_1_100() {
+        let valid = mock_csaf21(|doc| {
+            doc["document"]["category"] = json!("csaf_security_advisory");
+            doc["document"]["aggregate_severity"] = json!({ "text": "Critical" });
+        });
+        assert_eq!(Ok(()), test_6_1_100_aggregate_severity(&valid));
+        for category in ["csaf_base", "csaf_vex", "csaf_deprecated_security_advisory"] {
+            let doc = mock_csaf21(|doc| doc["document"]["category"] = json!(category));
+            assert_eq!(Ok(()), test_6_1_100_aggregate_severity(&doc));
+        }
+
+        // CSAF 2.0 does not require an aggregate severity
+        let advisory = |doc: &mut Value| doc["document"]["category"] = json!("csaf_security_advisory");
+        assert_eq!(Ok(()), test_6_1_100_aggregate_severity(&mock_csaf20(advisory)));
+
+        let invalid = mock_csaf21(advisory);
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.100",
+                "Document with category 'csaf_security_advisory' must have a '/document/aggregate_severity' element",
+                "/document/aggregate_severity",
+            )]),
+            test_6_1_100_aggregate_severity(&invalid)
+        );
+    }
+}
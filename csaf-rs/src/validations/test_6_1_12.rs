@@ -1,8 +1,8 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait};
 use crate::generated::language_subtags::is_valid_language_subtag;
 use crate::validation::ValidationError;
 
-pub fn test_6_1_12_language(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_12_language(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let document = doc.get_document();
 
     let mut errors: Option<Vec<ValidationError>> = None;
@@ -28,10 +28,11 @@ fn validate_language_code(lang_code: &str, json_path: &str) -> Result<(), Vec<Va
     let primary_subtag = lang_code.split('-').next().unwrap_or(lang_code);
 
     if !is_valid_language_subtag(primary_subtag) {
-        return Err(vec![ValidationError {
-            message: create_error_message(lang_code, primary_subtag),
-            instance_path: json_path.to_string(),
-        }]);
+        return Err(vec![ValidationError::new(
+            "6.1.12",
+            create_error_message(lang_code, primary_subtag),
+            json_path.to_string(),
+        )]);
     }
 
     Ok(())
@@ -55,10 +56,11 @@ mod tests {
     fn test_test_6_1_12() {
         let errors = HashMap::from([(
             "01",
-            vec![ValidationError {
-                message: create_error_message("EZ", "EZ"),
-                instance_path: "/document/lang".to_string(),
-            }],
+            vec![ValidationError::new(
+                "6.1.12",
+                create_error_message("EZ", "EZ"),
+                "/document/lang",
+            )],
         )]);
         run_csaf20_tests("12", test_6_1_12_language, errors.clone());
         run_csaf21_tests("12", test_6_1_12_language, errors);
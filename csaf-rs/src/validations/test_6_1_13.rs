@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
+use crate::csaf_traits::{CsafTraitExt, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
 use crate::validation::ValidationError;
 use packageurl::PackageUrl;
 use regex::Regex;
@@ -6,7 +6,7 @@ use std::str::FromStr;
 use std::sync::LazyLock;
 
 static PURL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^pkg:[A-Za-z.\-+][A-Za-z0-9.\-+]*/.+").unwrap());
-pub fn test_6_1_13_purl(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_13_purl(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let mut errors: Option<Vec<ValidationError>> = None;
 
     if let Some(product_tree) = doc.get_product_tree() {
@@ -16,18 +16,20 @@ pub fn test_6_1_13_purl(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>
                     for (i, purl_str) in purls.iter().enumerate() {
                         // Check against PURL regex
                         if !PURL_REGEX.is_match(purl_str) {
-                            errors.get_or_insert_with(Vec::new).push(ValidationError {
-                                message: format!("PURL doesn't comply with CSAF PURL regex: {}", purl_str),
-                                instance_path: format!("{}/product_identification_helper/purls/{}", path, i),
-                            });
+                            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                                "6.1.13",
+                                format!("PURL doesn't comply with CSAF PURL regex: {}", purl_str),
+                                format!("{}/product_identification_helper/purls/{}", path, i),
+                            ));
                             continue;
                         }
                         // Parse the PURL
                         if let Err(e) = PackageUrl::from_str(purl_str) {
-                            errors.get_or_insert_with(Vec::new).push(ValidationError {
-                                message: format!("Invalid PURL format: {}, Error: {}", purl_str, e),
-                                instance_path: format!("{}/product_identification_helper/purls/{}", path, i),
-                            });
+                            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                                "6.1.13",
+                                format!("Invalid PURL format: {}, Error: {}", purl_str, e),
+                                format!("{}/product_identification_helper/purls/{}", path, i),
+                            ));
                         }
                     }
                 }
@@ -48,14 +50,22 @@ mod tests {
     #[test]
     fn test_test_6_1_13() {
         let errors = HashMap::from([
-            ("01", vec![ValidationError {
-                message: "Invalid PURL format: pkg:maven/@1.3.4, Error: missing name".to_string(),
-                instance_path: "/product_tree/full_product_names/0/product_identification_helper/purls/0".to_string(),
-            }]),
-            ("02", vec![ValidationError {
-                message: "Invalid PURL format: pkg:oci/com.example/product-A@sha256%3Add134261219b2, Error: no namespace allowed for type \"oci\"".to_string(),
-                instance_path: "/product_tree/full_product_names/0/product_identification_helper/purls/0".to_string(),
-            }]),
+            (
+                "01",
+                vec![ValidationError::new(
+                    "6.1.13",
+                    "Invalid PURL format: pkg:maven/@1.3.4, Error: missing name",
+                    "/product_tree/full_product_names/0/product_identification_helper/purls/0",
+                )],
+            ),
+            (
+                "02",
+                vec![ValidationError::new(
+                    "6.1.13",
+                    "Invalid PURL format: pkg:oci/com.example/product-A@sha256%3Add134261219b2, Error: no namespace allowed for type \"oci\"",
+                    "/product_tree/full_product_names/0/product_identification_helper/purls/0",
+                )],
+            ),
         ]);
         run_csaf20_tests("13", test_6_1_13_purl, errors.clone());
         run_csaf21_tests("13", test_6_1_13_purl, errors);
@@ -1,11 +1,11 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionHistorySortable, TrackingTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, RevisionHistorySortable, TrackingTrait};
 use crate::validation::ValidationError;
 
 /// 6.1.14 Sorted Revision History
 ///
 /// The revision history items, when sorted by their `/document/tracking/revision_history[]/date` field,
 /// must be in the same order as when sorted by their `/document/tracking/revision_history[]/number` field.
-pub fn test_6_1_14_sorted_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_14_sorted_revision_history(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     // Generate tuples of (revision history path index, date, number)
     let mut rev_history_tuples_sort_by_date = doc.get_document().get_tracking().get_revision_history_tuples();
     let mut rev_history_tuples_sort_by_number = rev_history_tuples_sort_by_date.clone();
@@ -18,16 +18,17 @@ pub fn test_6_1_14_sorted_revision_history(doc: &impl CsafTrait) -> Result<(), V
     let mut errors = Vec::new();
     for i in 0..rev_history_tuples_sort_by_date.len() {
         if rev_history_tuples_sort_by_date[i].date != rev_history_tuples_sort_by_number[i].date {
-            errors.push(ValidationError {
-                message: format!(
+            errors.push(ValidationError::new(
+                "6.1.14",
+                format!(
                     "Revision history is not sorted by date, revision with number {} is out of place",
                     rev_history_tuples_sort_by_date[i].number
                 ),
-                instance_path: format!(
+                format!(
                     "/document/tracking/revision_history/{}",
                     rev_history_tuples_sort_by_date[i].path_index
                 ),
-            });
+            ));
         }
     }
 
@@ -51,136 +52,136 @@ mod tests {
             (
                 "01",
                 vec![
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 2 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/0".to_string(),
-                    },
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 1 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/1".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 2 is out of place",
+                        "/document/tracking/revision_history/0",
+                    ),
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 1 is out of place",
+                        "/document/tracking/revision_history/1",
+                    ),
                 ],
             ),
             (
                 "02",
                 vec![
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 2 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/0".to_string(),
-                    },
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 1 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/1".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 2 is out of place",
+                        "/document/tracking/revision_history/0",
+                    ),
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 1 is out of place",
+                        "/document/tracking/revision_history/1",
+                    ),
                 ],
             ),
             (
                 "03",
                 vec![
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 2 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/1".to_string(),
-                    },
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 1 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/0".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 2 is out of place",
+                        "/document/tracking/revision_history/1",
+                    ),
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 1 is out of place",
+                        "/document/tracking/revision_history/0",
+                    ),
                 ],
             ),
             (
                 "04",
                 vec![
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 2.0.0 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/0".to_string(),
-                    },
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 1.0.0 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/1".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 2.0.0 is out of place",
+                        "/document/tracking/revision_history/0",
+                    ),
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 1.0.0 is out of place",
+                        "/document/tracking/revision_history/1",
+                    ),
                 ],
             ),
             (
                 "05",
                 vec![
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 2.0.0 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/0".to_string(),
-                    },
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 1.0.0 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/1".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 2.0.0 is out of place",
+                        "/document/tracking/revision_history/0",
+                    ),
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 1.0.0 is out of place",
+                        "/document/tracking/revision_history/1",
+                    ),
                 ],
             ),
             (
                 "06",
                 vec![
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 10 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/9".to_string(),
-                    },
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 9 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/8".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 10 is out of place",
+                        "/document/tracking/revision_history/9",
+                    ),
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 9 is out of place",
+                        "/document/tracking/revision_history/8",
+                    ),
                 ],
             ),
             (
                 "07",
                 vec![
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 1.10.0 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/10".to_string(),
-                    },
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 1.9.0 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/9".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 1.10.0 is out of place",
+                        "/document/tracking/revision_history/10",
+                    ),
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 1.9.0 is out of place",
+                        "/document/tracking/revision_history/9",
+                    ),
                 ],
             ),
             (
                 "08",
                 vec![
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 2 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/1".to_string(),
-                    },
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 1 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/0".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 2 is out of place",
+                        "/document/tracking/revision_history/1",
+                    ),
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 1 is out of place",
+                        "/document/tracking/revision_history/0",
+                    ),
                 ],
             ),
             (
                 "09",
                 vec![
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 2 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/0".to_string(),
-                    },
-                    ValidationError {
-                        message: "Revision history is not sorted by date, revision with number 1 is out of place"
-                            .to_string(),
-                        instance_path: "/document/tracking/revision_history/1".to_string(),
-                    },
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 2 is out of place",
+                        "/document/tracking/revision_history/0",
+                    ),
+                    ValidationError::new(
+                        "6.1.14",
+                        "Revision history is not sorted by date, revision with number 1 is out of place",
+                        "/document/tracking/revision_history/1",
+                    ),
                 ],
             ),
         ]);
@@ -1,11 +1,11 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait, PublisherTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, PublisherTrait};
 use crate::schema::csaf2_1::schema::CategoryOfPublisher;
 use crate::validation::ValidationError;
 
 /// 6.1.15 Translator
 ///
 /// If the `/document/publisher/category` is "translator", then the `/document/source_lang` must be present.
-pub fn test_6_1_15_translator(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_15_translator(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let document = doc.get_document();
 
     // This test only applies if the publisher category is "translator"
@@ -15,10 +15,11 @@ pub fn test_6_1_15_translator(doc: &impl CsafTrait) -> Result<(), Vec<Validation
 
     // Check if source_lang is present
     if document.get_source_lang().is_none() {
-        return Err(vec![ValidationError {
-            message: "source_lang is required when the publisher category is 'translator'".to_string(),
-            instance_path: "/document/source_lang".to_string(),
-        }]);
+        return Err(vec![ValidationError::new(
+            "6.1.15",
+            "source_lang is required when the publisher category is 'translator'",
+            "/document/source_lang",
+        )]);
     }
 
     Ok(())
@@ -36,17 +37,19 @@ mod tests {
         let errors = HashMap::from([
             (
                 "01",
-                vec![ValidationError {
-                    message: "source_lang is required when the publisher category is 'translator'".to_string(),
-                    instance_path: "/document/source_lang".to_string(),
-                }],
+                vec![ValidationError::new(
+                    "6.1.15",
+                    "source_lang is required when the publisher category is 'translator'",
+                    "/document/source_lang",
+                )],
             ),
             (
                 "02",
-                vec![ValidationError {
-                    message: "source_lang is required when the publisher category is 'translator'".to_string(),
-                    instance_path: "/document/source_lang".to_string(),
-                }],
+                vec![ValidationError::new(
+                    "6.1.15",
+                    "source_lang is required when the publisher category is 'translator'",
+                    "/document/source_lang",
+                )],
             ),
         ]);
         run_csaf20_tests("15", test_6_1_15_translator, errors.clone());
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionHistorySortable, TrackingTrait, VersionNumber};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, RevisionHistorySortable, TrackingTrait, VersionNumber};
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::ValidationError;
 
@@ -7,7 +7,7 @@ use crate::validation::ValidationError;
 /// `/document/tracking/version` must be equal to the last `/document/tracking/revision_history[]/number` when
 /// sorting the revision history ascending by `date`. Build metadata is ignored. Pre-release parts are ignored
 /// if `/document/status` is "draft".
-pub fn test_6_1_16_latest_document_version(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_16_latest_document_version(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let document = doc.get_document();
 
     let mut revision_history = doc.get_document().get_tracking().get_revision_history_tuples();
@@ -55,13 +55,14 @@ pub fn test_6_1_16_latest_document_version(doc: &impl CsafTrait) -> Result<(), V
 }
 
 fn test_6_1_16_err_generator(doc_version: String, latest_number: String, doc_status: String) -> ValidationError {
-    ValidationError {
-        message: format!(
+    ValidationError::new(
+        "6.1.16",
+        format!(
             "The document version '{}' is not equal to the latest revision history number '{}' in document with status '{}'",
             doc_version, latest_number, doc_status
         ),
-        instance_path: "/document/tracking/version".to_string(),
-    }
+        "/document/tracking/version",
+    )
 }
 
 #[cfg(test)]
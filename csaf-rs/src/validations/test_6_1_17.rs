@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, TrackingTrait};
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::ValidationError;
 
@@ -6,7 +6,7 @@ use crate::validation::ValidationError;
 ///
 /// For `/document/version` to be 0, 0.y.z or contain a pre-release part,`/document/status` must be "draft".
 /// This checks the inverse: If the document status is not "draft", the version must not be 0, 0.y.z or contain a pre-release part.
-pub fn test_6_1_17_document_status_draft(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_17_document_status_draft(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let status = doc.get_document().get_tracking().get_status();
 
     // Test does not apply if document status is "draft"
@@ -31,10 +31,11 @@ pub fn test_6_1_17_document_status_draft(doc: &impl CsafTrait) -> Result<(), Vec
     }
 
     if let Some(error_message) = error_message {
-        return Err(vec![ValidationError {
-            message: error_message,
-            instance_path: "/document/tracking/version".to_string(),
-        }]);
+        return Err(vec![ValidationError::new(
+            "6.1.17",
+            error_message,
+            "/document/tracking/version",
+        )]);
     }
 
     Ok(())
@@ -51,10 +52,11 @@ mod tests {
     fn test_test_6_1_14() {
         let errors = HashMap::from([(
             "01",
-            vec![ValidationError {
-                message: "The document version is '0.9.5' but the document status is 'final'".to_string(),
-                instance_path: "/document/tracking/version".to_string(),
-            }],
+            vec![ValidationError::new(
+                "6.1.17",
+                "The document version is '0.9.5' but the document status is 'final'",
+                "/document/tracking/version",
+            )],
         )]);
         run_csaf20_tests("17", test_6_1_17_document_status_draft, errors.clone());
         run_csaf21_tests("17", test_6_1_17_document_status_draft, errors);
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionTrait, TrackingTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, RevisionTrait, TrackingTrait};
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::ValidationError;
 
@@ -6,7 +6,7 @@ use crate::validation::ValidationError;
 ///
 /// For documents with `/document/status` "final" or "interim", no item in `/document/tracking/revision_history[]`
 /// may have the version 0 or 0.y.z.
-pub fn test_6_1_18_released_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_18_released_revision_history(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let status = doc.get_document().get_tracking().get_status();
 
     // This test is only relevant for documents with status 'interim' and 'final'
@@ -20,13 +20,14 @@ pub fn test_6_1_18_released_revision_history(doc: &impl CsafTrait) -> Result<(),
     for (i_r, revision) in revision_history.iter().enumerate() {
         let number = revision.get_number();
         if number.is_intver_is_zero() || number.is_semver_is_major_zero() {
-            errors.push(ValidationError {
-                message: format!(
+            errors.push(ValidationError::new(
+                "6.1.18",
+                format!(
                     "Document with status '{}' contains a revision history item with number '{}'",
                     status, number
                 ),
-                instance_path: format!("/document/tracking/revision_history/{}/number", i_r),
-            });
+                format!("/document/tracking/revision_history/{}/number", i_r),
+            ));
         }
     }
 
@@ -48,10 +49,11 @@ mod tests {
     fn test_test_6_1_14() {
         let errors = HashMap::from([(
             "01",
-            vec![ValidationError {
-                message: "Document with status 'final' contains a revision history item with number '0'".to_string(),
-                instance_path: "/document/tracking/revision_history/0/number".to_string(),
-            }],
+            vec![ValidationError::new(
+                "6.1.18",
+                "Document with status 'final' contains a revision history item with number '0'",
+                "/document/tracking/revision_history/0/number",
+            )],
         )]);
         run_csaf20_tests("18", test_6_1_18_released_revision_history, errors.clone());
         run_csaf21_tests("18", test_6_1_18_released_revision_history, errors);
@@ -1,11 +1,11 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionTrait, TrackingTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, RevisionTrait, TrackingTrait};
 use crate::validation::ValidationError;
 
 /// 6.1.19 Revision History Entries for Pre-release Versions
 ///
 /// No item in `/document/tracking/revision_history[]` may have a version with a pre-release part (i.e. "1.0.0-rc1").
 pub fn test_6_1_19_revision_history_entries_for_prerelease_versions(
-    doc: &impl CsafTrait,
+    doc: &impl CsafTraitExt,
 ) -> Result<(), Vec<ValidationError>> {
     // Check that no revision history item has a pre-release part
     let mut errors = Vec::new();
@@ -13,10 +13,11 @@ pub fn test_6_1_19_revision_history_entries_for_prerelease_versions(
     for (i_r, revision) in revision_history.iter().enumerate() {
         let number = revision.get_number();
         if number.is_semver_has_prerelease() {
-            errors.push(ValidationError {
-                message: format!("revision history item number '{}' contains a pre-release part", number),
-                instance_path: format!("/document/tracking/revision_history/{}/number", i_r),
-            });
+            errors.push(ValidationError::new(
+                "6.1.19",
+                format!("revision history item number '{}' contains a pre-release part", number),
+                format!("/document/tracking/revision_history/{}/number", i_r),
+            ));
         }
     }
 
@@ -39,17 +40,19 @@ mod tests {
         let errors = HashMap::from([
             (
                 "01",
-                vec![ValidationError {
-                    message: "revision history item number '1.0.0-rc' contains a pre-release part".to_string(),
-                    instance_path: "/document/tracking/revision_history/0/number".to_string(),
-                }],
+                vec![ValidationError::new(
+                    "6.1.19",
+                    "revision history item number '1.0.0-rc' contains a pre-release part",
+                    "/document/tracking/revision_history/0/number",
+                )],
             ),
             (
                 "02",
-                vec![ValidationError {
-                    message: "revision history item number '1.0.0-rc' contains a pre-release part".to_string(),
-                    instance_path: "/document/tracking/revision_history/0/number".to_string(),
-                }],
+                vec![ValidationError::new(
+                    "6.1.19",
+                    "revision history item number '1.0.0-rc' contains a pre-release part",
+                    "/document/tracking/revision_history/0/number",
+                )],
             ),
         ]);
         run_csaf20_tests(
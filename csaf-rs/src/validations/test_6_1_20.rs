@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait, TrackingTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, TrackingTrait};
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::validation::ValidationError;
 
@@ -6,7 +6,7 @@ use crate::validation::ValidationError;
 ///
 /// For documents with status "final" or "interim", the `/document/version` field must not contain
 /// a pre-release part (e.g. "1.0.0-alpha").
-pub fn test_6_1_20_non_draft_document_version(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_20_non_draft_document_version(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let tracking = doc.get_document().get_tracking();
     let status = tracking.get_status();
 
@@ -18,13 +18,14 @@ pub fn test_6_1_20_non_draft_document_version(doc: &impl CsafTrait) -> Result<()
     // Check if there is a pre-release part
     let version = tracking.get_version();
     if version.is_semver_has_prerelease() {
-        return Err(vec![ValidationError {
-            message: format!(
+        return Err(vec![ValidationError::new(
+            "6.1.20",
+            format!(
                 "The document status is {} but the document version {} contains a pre-release part",
                 status, version
             ),
-            instance_path: "/document/version".to_string(),
-        }]);
+            "/document/version",
+        )]);
     }
 
     Ok(())
@@ -39,12 +40,11 @@ mod tests {
     fn test_test_6_1_20() {
         let errors = std::collections::HashMap::from([(
             "01",
-            vec![crate::validation::ValidationError {
-                message:
-                    "The document status is interim but the document version 1.0.0-alpha contains a pre-release part"
-                        .to_string(),
-                instance_path: "/document/version".to_string(),
-            }],
+            vec![crate::validation::ValidationError::new(
+                "6.1.20",
+                "The document status is interim but the document version 1.0.0-alpha contains a pre-release part",
+                "/document/version",
+            )],
         )]);
         run_csaf20_tests("20", test_6_1_20_non_draft_document_version, errors.clone());
         run_csaf21_tests("20", test_6_1_20_non_draft_document_version, errors);
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionHistorySortable, TrackingTrait, VersionNumber};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, RevisionHistorySortable, TrackingTrait, VersionNumber};
 use crate::validation::ValidationError;
 
 /// 6.1.21 Missing Item in Revision History
@@ -7,7 +7,7 @@ use crate::validation::ValidationError;
 /// all integers in the range between the `number` of first revision history and the last revision history.
 /// Also, it has to be ensured that the first item has either a version 0 or 1.
 /// This applies to the version number for integer versioning and to the major version for semantic versioning.
-pub fn test_6_1_21_missing_item_in_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_21_missing_item_in_revision_history(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let mut errors: Option<Vec<ValidationError>> = None;
 
     // Generate and sort the revision history tuples by date first and by number second
@@ -66,13 +66,14 @@ fn test_6_1_21_err_wrong_first_version_generator(version: VersionNumber, path: S
         VersionNumber::Semver(_) => "semver version of 0.y.z or 1.y.z",
     }
     .to_string();
-    ValidationError {
-        message: format!(
+    ValidationError::new(
+        "6.1.21",
+        format!(
             "The first revision history item should have {}, but was {}",
             version_error, version
         ),
-        instance_path: format!("/document/tracking/revision_history/{}", path),
-    }
+        format!("/document/tracking/revision_history/{}", path),
+    )
 }
 
 fn test_6_1_21_err_missing_version_in_range(
@@ -91,13 +92,14 @@ fn test_6_1_21_err_missing_version_in_range(
         VersionNumber::Semver(_) => format!("semver version range {}.y.z to {}.y.z", first_number, last_number),
     }
     .to_string();
-    ValidationError {
-        message: format!(
+    ValidationError::new(
+        "6.1.21",
+        format!(
             "Missing revision history item with {} number {}",
             version_error, version_error_range
         ),
-        instance_path: "/document/tracking/revision_history".to_string(),
-    }
+        "/document/tracking/revision_history",
+    )
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DocumentTrait, RevisionTrait, TrackingTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, RevisionTrait, TrackingTrait};
 use crate::validation::ValidationError;
 use std::collections::HashMap;
 
@@ -6,7 +6,9 @@ use std::collections::HashMap;
 ///
 /// Items of the revision history must not contain the same string in the
 /// `/document/tracking/revision_history[]/number` field.
-pub fn test_6_1_22_multiple_definition_in_revision_history(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_22_multiple_definition_in_revision_history(
+    doc: &impl CsafTraitExt,
+) -> Result<(), Vec<ValidationError>> {
     let revision_history = doc.get_document().get_tracking().get_revision_history();
 
     // Map occurrence paths indexes to revision numbers
@@ -22,10 +24,11 @@ pub fn test_6_1_22_multiple_definition_in_revision_history(doc: &impl CsafTrait)
     for (number, paths) in &number_paths {
         if paths.len() > 1 {
             for path in paths.iter() {
-                errors.push(ValidationError {
-                    message: format!("Duplicate definition of revision history number {}", number),
-                    instance_path: format!("/document/tracking/revision_history/{}/number", path),
-                });
+                errors.push(ValidationError::new(
+                    "6.1.22",
+                    format!("Duplicate definition of revision history number {}", number),
+                    format!("/document/tracking/revision_history/{}/number", path),
+                ));
             }
         }
     }
@@ -47,14 +50,16 @@ mod tests {
         let errors = std::collections::HashMap::from([(
             "01",
             vec![
-                crate::validation::ValidationError {
-                    message: "Duplicate definition of revision history number 1".to_string(),
-                    instance_path: "/document/tracking/revision_history/0/number".to_string(),
-                },
-                crate::validation::ValidationError {
-                    message: "Duplicate definition of revision history number 1".to_string(),
-                    instance_path: "/document/tracking/revision_history/1/number".to_string(),
-                },
+                crate::validation::ValidationError::new(
+                    "6.1.22",
+                    "Duplicate definition of revision history number 1",
+                    "/document/tracking/revision_history/0/number",
+                ),
+                crate::validation::ValidationError::new(
+                    "6.1.22",
+                    "Duplicate definition of revision history number 1",
+                    "/document/tracking/revision_history/1/number",
+                ),
             ],
         )]);
         run_csaf20_tests(
@@ -1,11 +1,11 @@
-use crate::csaf_traits::{CsafTrait, VulnerabilityTrait};
+use crate::csaf_traits::{CsafTraitExt, VulnerabilityTrait};
 use crate::validation::ValidationError;
 use std::collections::HashMap;
 
 /// Test 6.1.23: Multiple Use of Same CVE
 ///
 /// Vulnerability items must not contain the same string in the `/vulnerabilities[]/cve` field.
-pub fn test_6_1_23_multiple_use_of_same_cve(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_23_multiple_use_of_same_cve(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let vulnerabilities = doc.get_vulnerabilities();
 
     // Map occurrence paths indexes to CVE identifiers
@@ -23,10 +23,11 @@ pub fn test_6_1_23_multiple_use_of_same_cve(doc: &impl CsafTrait) -> Result<(),
     for (cve, paths) in &cve_paths {
         if paths.len() > 1 {
             for path in paths.iter() {
-                errors.push(ValidationError {
-                    message: format!("Duplicate usage of same CVE identifier '{}'", cve),
-                    instance_path: format!("/vulnerabilities/{}/cve", path),
-                });
+                errors.push(ValidationError::new(
+                    "6.1.23",
+                    format!("Duplicate usage of same CVE identifier '{}'", cve),
+                    format!("/vulnerabilities/{}/cve", path),
+                ));
             }
         }
     }
@@ -48,14 +49,16 @@ mod tests {
         let errors = std::collections::HashMap::from([(
             "01",
             vec![
-                crate::validation::ValidationError {
-                    message: "Duplicate usage of same CVE identifier 'CVE-2017-0145'".to_string(),
-                    instance_path: "/vulnerabilities/0/cve".to_string(),
-                },
-                crate::validation::ValidationError {
-                    message: "Duplicate usage of same CVE identifier 'CVE-2017-0145'".to_string(),
-                    instance_path: "/vulnerabilities/1/cve".to_string(),
-                },
+                crate::validation::ValidationError::new(
+                    "6.1.23",
+                    "Duplicate usage of same CVE identifier 'CVE-2017-0145'",
+                    "/vulnerabilities/0/cve",
+                ),
+                crate::validation::ValidationError::new(
+                    "6.1.23",
+                    "Duplicate usage of same CVE identifier 'CVE-2017-0145'",
+                    "/vulnerabilities/1/cve",
+                ),
             ],
         )]);
         run_csaf20_tests("23", test_6_1_23_multiple_use_of_same_cve, errors.clone());
@@ -1,13 +1,13 @@
-use crate::csaf_traits::{CsafTrait, InvolvementTrait, VulnerabilityTrait};
+use crate::csaf_traits::{CsafTraitExt, InvolvementTrait, VulnerabilityTrait};
 use crate::schema::csaf2_1::schema::PartyCategory;
 use crate::validation::ValidationError;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Test 6.1.24: Multiple Definition in Involvements
 ///
 /// Vulnerability items must not contain the same tuples of the `/vulnerabilities[]/involvements[]/date`
 /// and `/vulnerabilities[]/involvements[]/party` fields.
-pub fn test_6_1_24_multiple_definition_in_involvements(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_24_multiple_definition_in_involvements(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let vulnerabilities = doc.get_vulnerabilities();
 
     let mut errors = Vec::new();
@@ -16,7 +16,7 @@ pub fn test_6_1_24_multiple_definition_in_involvements(doc: &impl CsafTrait) ->
         let involvements = vulnerability.get_involvements();
         if let Some(involvements) = involvements {
             // Map involvement path indices to (date,party) tuples
-            let mut date_party_paths_map: HashMap<(String, PartyCategory), Vec<usize>> = HashMap::new();
+            let mut date_party_paths_map: BTreeMap<(String, PartyCategory), Vec<usize>> = BTreeMap::new();
             for (inv_r, involvement) in involvements.iter().enumerate() {
                 if let Some(date) = involvement.get_date() {
                     let party = involvement.get_party();
@@ -28,13 +28,14 @@ pub fn test_6_1_24_multiple_definition_in_involvements(doc: &impl CsafTrait) ->
             for ((date, party), paths) in &date_party_paths_map {
                 if paths.len() > 1 {
                     for path in paths.iter() {
-                        errors.push(ValidationError {
-                            message: format!(
+                        errors.push(ValidationError::new(
+                            "6.1.24",
+                            format!(
                                 "Duplicate usage of tuple of involvement date {} and party {}",
                                 date, party
                             ),
-                            instance_path: format!("/vulnerabilities/{}/involvements/{}", vul_r, path),
-                        });
+                            format!("/vulnerabilities/{}/involvements/{}", vul_r, path),
+                        ));
                     }
                 }
             }
@@ -59,35 +60,31 @@ mod tests {
             (
                 "01",
                 vec![
-                    crate::validation::ValidationError {
-                        message:
-                            "Duplicate usage of tuple of involvement date 2021-04-23T10:00:00.000Z and party vendor"
-                                .to_string(),
-                        instance_path: "/vulnerabilities/0/involvements/0".to_string(),
-                    },
-                    crate::validation::ValidationError {
-                        message:
-                            "Duplicate usage of tuple of involvement date 2021-04-23T10:00:00.000Z and party vendor"
-                                .to_string(),
-                        instance_path: "/vulnerabilities/0/involvements/1".to_string(),
-                    },
+                    crate::validation::ValidationError::new(
+                        "6.1.24",
+                        "Duplicate usage of tuple of involvement date 2021-04-23T10:00:00.000Z and party vendor",
+                        "/vulnerabilities/0/involvements/0",
+                    ),
+                    crate::validation::ValidationError::new(
+                        "6.1.24",
+                        "Duplicate usage of tuple of involvement date 2021-04-23T10:00:00.000Z and party vendor",
+                        "/vulnerabilities/0/involvements/1",
+                    ),
                 ],
             ),
             (
                 "02",
                 vec![
-                    crate::validation::ValidationError {
-                        message:
-                            "Duplicate usage of tuple of involvement date 2021-04-23T10:00:00.000Z and party vendor"
-                                .to_string(),
-                        instance_path: "/vulnerabilities/0/involvements/0".to_string(),
-                    },
-                    crate::validation::ValidationError {
-                        message:
-                            "Duplicate usage of tuple of involvement date 2021-04-23T10:00:00.000Z and party vendor"
-                                .to_string(),
-                        instance_path: "/vulnerabilities/0/involvements/1".to_string(),
-                    },
+                    crate::validation::ValidationError::new(
+                        "6.1.24",
+                        "Duplicate usage of tuple of involvement date 2021-04-23T10:00:00.000Z and party vendor",
+                        "/vulnerabilities/0/involvements/0",
+                    ),
+                    crate::validation::ValidationError::new(
+                        "6.1.24",
+                        "Duplicate usage of tuple of involvement date 2021-04-23T10:00:00.000Z and party vendor",
+                        "/vulnerabilities/0/involvements/1",
+                    ),
                 ],
             ),
         ]);
@@ -95,35 +92,31 @@ mod tests {
             (
                 "01",
                 vec![
-                    crate::validation::ValidationError {
-                        message:
-                            "Duplicate usage of tuple of involvement date 2023-08-23T10:00:00.000Z and party vendor"
-                                .to_string(),
-                        instance_path: "/vulnerabilities/0/involvements/0".to_string(),
-                    },
-                    crate::validation::ValidationError {
-                        message:
-                            "Duplicate usage of tuple of involvement date 2023-08-23T10:00:00.000Z and party vendor"
-                                .to_string(),
-                        instance_path: "/vulnerabilities/0/involvements/1".to_string(),
-                    },
+                    crate::validation::ValidationError::new(
+                        "6.1.24",
+                        "Duplicate usage of tuple of involvement date 2023-08-23T10:00:00.000Z and party vendor",
+                        "/vulnerabilities/0/involvements/0",
+                    ),
+                    crate::validation::ValidationError::new(
+                        "6.1.24",
+                        "Duplicate usage of tuple of involvement date 2023-08-23T10:00:00.000Z and party vendor",
+                        "/vulnerabilities/0/involvements/1",
+                    ),
                 ],
             ),
             (
                 "02",
                 vec![
-                    crate::validation::ValidationError {
-                        message:
-                            "Duplicate usage of tuple of involvement date 2023-08-23T10:00:00.000Z and party vendor"
-                                .to_string(),
-                        instance_path: "/vulnerabilities/0/involvements/0".to_string(),
-                    },
-                    crate::validation::ValidationError {
-                        message:
-                            "Duplicate usage of tuple of involvement date 2023-08-23T10:00:00.000Z and party vendor"
-                                .to_string(),
-                        instance_path: "/vulnerabilities/0/involvements/1".to_string(),
-                    },
+                    crate::validation::ValidationError::new(
+                        "6.1.24",
+                        "Duplicate usage of tuple of involvement date 2023-08-23T10:00:00.000Z and party vendor",
+                        "/vulnerabilities/0/involvements/0",
+                    ),
+                    crate::validation::ValidationError::new(
+                        "6.1.24",
+                        "Duplicate usage of tuple of involvement date 2023-08-23T10:00:00.000Z and party vendor",
+                        "/vulnerabilities/0/involvements/1",
+                    ),
                 ],
             ),
         ]);
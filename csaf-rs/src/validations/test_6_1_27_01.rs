@@ -1,6 +1,7 @@
-use crate::csaf_traits::{CsafTrait, DocumentCategory, DocumentTrait, NoteTrait};
-use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::csaf_traits::CsafTraitExt;
+use crate::profile::check_required_fields;
 use crate::validation::ValidationError;
+use serde::Serialize;
 
 /// 6.1.27.1 Document Notes
 ///
@@ -9,44 +10,10 @@ use crate::validation::ValidationError;
 ///
 /// Documents with these categories must have at least one entry in `/document/notes` with `category` values
 /// of `description`, `details`, `general` or `summary`.
-pub fn test_6_1_27_01_document_notes(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
-    let doc_category = doc.get_document().get_category();
-
-    // check document category
-    if doc_category != DocumentCategory::CsafInformationalAdvisory
-        && doc_category != DocumentCategory::CsafSecurityIncidentResponse
-    {
-        return Ok(());
-    }
-
-    // check if there is a document note with the required category
-    let mut found_valid_note = false;
-    if let Some(notes) = doc.get_document().get_notes() {
-        for note in notes.iter() {
-            let category = note.get_category();
-            if category == NoteCategory::Description
-                || category == NoteCategory::Details
-                || category == NoteCategory::General
-                || category == NoteCategory::Summary
-            {
-                found_valid_note = true;
-                break;
-            }
-        }
-    }
-
-    // if there isn't a note with the required category, return an error
-    if !found_valid_note {
-        return Err(vec![ValidationError {
-            message: format!(
-                "Document with category '{}' must have at least one document note with category 'description', 'details', 'general' or 'summary'",
-                doc_category
-            ),
-            instance_path: "/document/notes".to_string(),
-        }]);
-    }
-
-    Ok(())
+///
+/// The requirement is defined in [crate::profile::Profile::required_fields].
+pub fn test_6_1_27_01_document_notes(doc: &(impl CsafTraitExt + Serialize)) -> Result<(), Vec<ValidationError>> {
+    check_required_fields(doc, "6.1.27.1")
 }
 
 #[cfg(test)]
@@ -60,10 +27,11 @@ mod tests {
     fn test_test_6_1_27_01() {
         let errors = HashMap::from([(
             "01",
-            vec![ValidationError {
-                message: "Document with category 'csaf_security_incident_response' must have at least one document note with category 'description', 'details', 'general' or 'summary'".to_string(),
-                instance_path: "/document/notes".to_string(),
-            }],
+            vec![ValidationError::new(
+                "6.1.27.1",
+                "Document with category 'csaf_security_incident_response' must have at least one document note with category 'description', 'details', 'general' or 'summary'",
+                "/document/notes",
+            )],
         )]);
         run_csaf20_tests("27-01", test_6_1_27_01_document_notes, errors.clone());
         run_csaf21_tests("27-01", test_6_1_27_01_document_notes, errors);
@@ -1,6 +1,7 @@
-use crate::csaf_traits::{CsafTrait, DocumentCategory, DocumentReferenceTrait, DocumentTrait};
-use crate::schema::csaf2_1::schema::CategoryOfReference;
+use crate::csaf_traits::CsafTraitExt;
+use crate::profile::check_required_fields;
 use crate::validation::ValidationError;
+use serde::Serialize;
 
 /// 6.1.27.2 Document References
 ///
@@ -9,39 +10,10 @@ use crate::validation::ValidationError;
 ///
 /// Documents with these categories must have at least one entry in `/document/notes` with `category` values
 /// of `description`, `details`, `general` or `summary`.
-pub fn test_6_1_27_02_document_references(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
-    let doc_category = doc.get_document().get_category();
-
-    // check document category
-    if doc_category != DocumentCategory::CsafInformationalAdvisory
-        && doc_category != DocumentCategory::CsafSecurityIncidentResponse
-    {
-        return Ok(());
-    }
-
-    // check if there is a document reference with category 'external'
-    let mut found_external_reference = false;
-    if let Some(references) = doc.get_document().get_references() {
-        for reference in references.iter() {
-            if CategoryOfReference::External == *reference.get_category() {
-                found_external_reference = true;
-                break;
-            };
-        }
-    }
-
-    // if there isn't a reference with category 'external', return an error
-    if !found_external_reference {
-        return Err(vec![ValidationError {
-            message: format!(
-                "Document with category '{}' must have at least one reference with category 'external'",
-                doc_category
-            ),
-            instance_path: "/document/references".to_string(),
-        }]);
-    }
-
-    Ok(())
+///
+/// The requirement is defined in [crate::profile::Profile::required_fields].
+pub fn test_6_1_27_02_document_references(doc: &(impl CsafTraitExt + Serialize)) -> Result<(), Vec<ValidationError>> {
+    check_required_fields(doc, "6.1.27.2")
 }
 
 #[cfg(test)]
@@ -55,10 +27,11 @@ mod tests {
     fn test_test_6_1_27_02() {
         let errors = HashMap::from([(
             "01",
-            vec![ValidationError {
-                message: "Document with category 'csaf_informational_advisory' must have at least one reference with category 'external'".to_string(),
-                instance_path: "/document/references".to_string(),
-            }],
+            vec![ValidationError::new(
+                "6.1.27.2",
+                "Document with category 'csaf_informational_advisory' must have at least one reference with category 'external'",
+                "/document/references",
+            )],
         )]);
         run_csaf20_tests("27-02", test_6_1_27_02_document_references, errors.clone());
         run_csaf21_tests("27-02", test_6_1_27_02_document_references, errors);
@@ -1,5 +1,7 @@
-use crate::csaf_traits::{CsafTrait, CsafVersion, DocumentCategory, DocumentTrait};
+use crate::csaf_traits::CsafTraitExt;
+use crate::profile::check_required_fields;
 use crate::validation::ValidationError;
+use serde::Serialize;
 
 /// 6.1.27.3 Vulnerabilities
 ///
@@ -8,35 +10,10 @@ use crate::validation::ValidationError;
 /// value `csaf_withdrawn` and `csaf_superseded` for `/document/csaf_version` `2.1`.
 ///
 /// Documents with this category must not have a `/vulnerabilities` element.
-pub fn test_6_1_27_03_vulnerability(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
-    // check if document is relevant document category in csaf 2.0
-    if *doc.get_document().get_csaf_version() == CsafVersion::X20
-        && doc.get_document().get_category() != DocumentCategory::CsafInformationalAdvisory
-    {
-        return Ok(());
-    }
-
-    // check if document is relevant document category in csaf 2.1
-    if *doc.get_document().get_csaf_version() == CsafVersion::X21 {
-        let doc_category = doc.get_document().get_category();
-        if doc_category != DocumentCategory::CsafInformationalAdvisory
-            && doc_category != DocumentCategory::CsafWithdrawn
-            && doc_category != DocumentCategory::CsafSuperseded
-        {
-            return Ok(());
-        }
-    }
-
-    // return error if there are elements in /vulnerabilities
-    if !doc.get_vulnerabilities().is_empty() {
-        return Err(vec![ValidationError {
-            message: "Document with category 'csaf_informational_advisory' must not have a '/vulnerabilities' element"
-                .to_string(),
-            instance_path: "/vulnerabilities".to_string(),
-        }]);
-    }
-
-    Ok(())
+///
+/// The requirement is defined in [crate::profile::Profile::required_fields].
+pub fn test_6_1_27_03_vulnerability(doc: &(impl CsafTraitExt + Serialize)) -> Result<(), Vec<ValidationError>> {
+    check_required_fields(doc, "6.1.27.3")
 }
 
 #[cfg(test)]
@@ -51,30 +28,27 @@ mod tests {
         let errors = HashMap::from([
             (
                 "01",
-                vec![ValidationError {
-                message:
-                    "Document with category 'csaf_informational_advisory' must not have a '/vulnerabilities' element"
-                        .to_string(),
-                instance_path: "/vulnerabilities".to_string(),
-            }],
+                vec![ValidationError::new(
+                    "6.1.27.3",
+                    "Document with category 'csaf_informational_advisory' must not have a '/vulnerabilities' element",
+                    "/vulnerabilities",
+                )],
             ),
             (
                 "02",
-                vec![ValidationError {
-                message:
-                    "Document with category 'csaf_informational_advisory' must not have a '/vulnerabilities' element"
-                        .to_string(),
-                instance_path: "/vulnerabilities".to_string(),
-            }],
+                vec![ValidationError::new(
+                    "6.1.27.3",
+                    "Document with category 'csaf_informational_advisory' must not have a '/vulnerabilities' element",
+                    "/vulnerabilities",
+                )],
             ),
             (
                 "03",
-                vec![ValidationError {
-                message:
-                    "Document with category 'csaf_informational_advisory' must not have a '/vulnerabilities' element"
-                        .to_string(),
-                instance_path: "/vulnerabilities".to_string(),
-            }],
+                vec![ValidationError::new(
+                    "6.1.27.3",
+                    "Document with category 'csaf_informational_advisory' must not have a '/vulnerabilities' element",
+                    "/vulnerabilities",
+                )],
             ),
         ]);
         run_csaf20_tests("27-03", test_6_1_27_03_vulnerability, errors.clone());
@@ -1,5 +1,7 @@
-use crate::csaf_traits::{CsafTrait, CsafVersion, DocumentCategory, DocumentTrait};
+use crate::csaf_traits::CsafTraitExt;
+use crate::profile::check_required_fields;
 use crate::validation::ValidationError;
+use serde::Serialize;
 
 /// 6.1.27.4 Product Tree
 ///
@@ -8,51 +10,31 @@ use crate::validation::ValidationError;
 /// value `csaf_deprecated_security_advisory` for `/document/csaf_version` `2.1`.
 ///
 /// Documents with this category must have a `/product_tree` element.
-pub fn test_6_1_27_04_product_tree(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
-    let doc_category = doc.get_document().get_category();
-
-    // check if document is relevant document category in csaf 2.0
-    if *doc.get_document().get_csaf_version() == CsafVersion::X20
-        && doc_category != DocumentCategory::CsafSecurityAdvisory
-        && doc_category != DocumentCategory::CsafVex
-    {
-        return Ok(());
-    }
-
-    // check if document is relevant document category in csaf 2.1
-    if *doc.get_document().get_csaf_version() == CsafVersion::X21
-        && doc_category != DocumentCategory::CsafSecurityAdvisory
-        && doc_category != DocumentCategory::CsafVex
-        && doc_category != DocumentCategory::CsafDeprecatedSecurityAdvisory
-    {
-        return Ok(());
-    }
-
-    // return error if there are there isn't a product tree
-    if doc.get_product_tree().is_none() {
-        return Err(vec![test_6_1_27_04_err_generator(doc_category)]);
-    }
-
-    Ok(())
-}
-
-fn test_6_1_27_04_err_generator(document_category: DocumentCategory) -> ValidationError {
-    ValidationError {
-        message: format!(
-            "Document with category '{}' must have a '/product_tree' element",
-            document_category
-        ),
-        instance_path: "/product_tree".to_string(),
-    }
+///
+/// The requirement is defined in [crate::profile::Profile::required_fields].
+pub fn test_6_1_27_04_product_tree(doc: &(impl CsafTraitExt + Serialize)) -> Result<(), Vec<ValidationError>> {
+    check_required_fields(doc, "6.1.27.4")
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf_traits::DocumentCategory;
     use crate::test_helper::{run_csaf20_tests, run_csaf21_tests};
-    use crate::validations::test_6_1_27_04::{test_6_1_27_04_err_generator, test_6_1_27_04_product_tree};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_27_04::test_6_1_27_04_product_tree;
     use std::collections::HashMap;
 
+    fn test_6_1_27_04_err_generator(document_category: DocumentCategory) -> ValidationError {
+        ValidationError::new(
+            "6.1.27.4",
+            format!(
+                "Document with category '{}' must have a '/product_tree' element",
+                document_category
+            ),
+            "/product_tree",
+        )
+    }
+
     #[test]
     fn test_test_6_1_27_04() {
         let errors = HashMap::from([
@@ -1,5 +1,7 @@
-use crate::csaf_traits::{CsafTrait, CsafVersion, DocumentCategory, DocumentTrait, VulnerabilityTrait};
+use crate::csaf_traits::CsafTraitExt;
+use crate::profile::check_required_fields;
 use crate::validation::ValidationError;
+use serde::Serialize;
 
 /// 6.1.27.6 Product Status
 ///
@@ -8,54 +10,31 @@ use crate::validation::ValidationError;
 /// value `csaf_deprecated_security_advisory` for `/document/csaf_version` `2.1`.
 ///
 /// Documents with these categories must have a `/vulnerabilities[]/product_status` element.
-pub fn test_6_1_27_06_product_status(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
-    let doc_category = doc.get_document().get_category();
-
-    // check if document is relevant document category in csaf 2.0
-    if *doc.get_document().get_csaf_version() == CsafVersion::X20
-        && doc_category != DocumentCategory::CsafSecurityAdvisory
-    {
-        return Ok(());
-    }
-
-    // check if document is relevant document category in csaf 2.1
-    if *doc.get_document().get_csaf_version() == CsafVersion::X21
-        && doc_category != DocumentCategory::CsafSecurityAdvisory
-        && doc_category != DocumentCategory::CsafDeprecatedSecurityAdvisory
-    {
-        return Ok(());
-    }
-
-    let mut errors: Option<Vec<ValidationError>> = None;
-    // return error if there are vulnerabilities without product_status
-    for (v_i, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
-        if vulnerability.get_product_status().is_none() {
-            errors
-                .get_or_insert_with(Vec::new)
-                .push(test_6_1_27_06_err_generator(&doc_category, &v_i));
-        }
-    }
-
-    errors.map_or(Ok(()), Err)
-}
-
-fn test_6_1_27_06_err_generator(document_category: &DocumentCategory, vuln_path_index: &usize) -> ValidationError {
-    ValidationError {
-        message: format!(
-            "Document with category '{}' must have a product_status element in each vulnerability",
-            document_category
-        ),
-        instance_path: format!("/vulnerabilities/{}/product_status", vuln_path_index),
-    }
+///
+/// The requirement is defined in [crate::profile::Profile::required_fields].
+pub fn test_6_1_27_06_product_status(doc: &(impl CsafTraitExt + Serialize)) -> Result<(), Vec<ValidationError>> {
+    check_required_fields(doc, "6.1.27.6")
 }
 
 #[cfg(test)]
 mod tests {
     use crate::csaf_traits::DocumentCategory;
     use crate::test_helper::{run_csaf20_tests, run_csaf21_tests};
-    use crate::validations::test_6_1_27_06::{test_6_1_27_06_err_generator, test_6_1_27_06_product_status};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_27_06::test_6_1_27_06_product_status;
     use std::collections::HashMap;
 
+    fn test_6_1_27_06_err_generator(document_category: &DocumentCategory, vuln_path_index: &usize) -> ValidationError {
+        ValidationError::new(
+            "6.1.27.6",
+            format!(
+                "Document with category '{}' must have a product_status element in each vulnerability",
+                document_category
+            ),
+            format!("/vulnerabilities/{}/product_status", vuln_path_index),
+        )
+    }
+
     #[test]
     fn test_test_6_1_27_06() {
         let errors = HashMap::from([
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DocumentCategory, DocumentTrait, ProductStatusTrait, VulnerabilityTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentCategory, DocumentTrait, ProductStatusTrait, VulnerabilityTrait};
 use crate::validation::ValidationError;
 
 /// 6.1.27.7 VEX Product Status
@@ -7,7 +7,7 @@ use crate::validation::ValidationError;
 ///
 /// In documents with this category each `/vulnerabilities[]/product_status` must have at least one
 /// of the elements: `fixed`, `known_affected`, `known_not_affected` or `under_investigation`
-pub fn test_6_1_27_07_vex_product_status(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_27_07_vex_product_status(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let doc_category = doc.get_document().get_category();
 
     if doc_category != DocumentCategory::CsafVex {
@@ -34,13 +34,14 @@ pub fn test_6_1_27_07_vex_product_status(doc: &impl CsafTrait) -> Result<(), Vec
 }
 
 fn test_6_1_27_07_err_generator(document_category: &DocumentCategory, vuln_path_index: &usize) -> ValidationError {
-    ValidationError {
-        message: format!(
+    ValidationError::new(
+        "6.1.27.7",
+        format!(
             "Document with category '{}' must provide at least one fixed, known_affected, known_unaffected or under_investigation product_status in each vulnerability",
             document_category
         ),
-        instance_path: format!("/vulnerabilities/{}/product_status", vuln_path_index),
-    }
+        format!("/vulnerabilities/{}/product_status", vuln_path_index),
+    )
 }
 
 #[cfg(test)]
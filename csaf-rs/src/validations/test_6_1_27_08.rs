@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DocumentCategory, DocumentTrait, VulnerabilityTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentCategory, DocumentTrait, VulnerabilityTrait};
 use crate::validation::ValidationError;
 
 /// 6.1.27.8 Vulnerability ID
@@ -7,7 +7,7 @@ use crate::validation::ValidationError;
 ///
 /// In documents with this category each `/vulnerabilities[]` item must have at the `cve` or the `ids`
 /// element.
-pub fn test_6_1_27_08_vulnerability_id(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_27_08_vulnerability_id(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let doc_category = doc.get_document().get_category();
 
     if doc_category != DocumentCategory::CsafVex {
@@ -28,13 +28,14 @@ pub fn test_6_1_27_08_vulnerability_id(doc: &impl CsafTrait) -> Result<(), Vec<V
 }
 
 fn test_6_1_27_08_err_generator(document_category: &DocumentCategory, vuln_path_index: &usize) -> ValidationError {
-    ValidationError {
-        message: format!(
+    ValidationError::new(
+        "6.1.27.8",
+        format!(
             "Document with category '{}' must provide at at least either cve or ids  in each vulnerability",
             document_category
         ),
-        instance_path: format!("/vulnerabilities/{}/product_status", vuln_path_index),
-    }
+        format!("/vulnerabilities/{}/product_status", vuln_path_index),
+    )
 }
 
 #[cfg(test)]
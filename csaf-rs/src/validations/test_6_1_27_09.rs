@@ -1,11 +1,11 @@
 use crate::csaf_traits::{
-    CsafTrait, DocumentCategory, DocumentTrait, ProductStatusTrait, ThreatTrait, VulnerabilityTrait,
+    CsafTraitExt, DocumentCategory, DocumentTrait, ProductStatusTrait, ThreatTrait, VulnerabilityTrait,
     WithOptionalGroupIds, WithOptionalProductIds,
 };
 use crate::helpers::resolve_product_groups;
 use crate::schema::csaf2_1::schema::CategoryOfTheThreat;
 use crate::validation::ValidationError;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
 /// 6.1.27.9 Impact Statement
 ///
@@ -14,7 +14,7 @@ use std::collections::{HashMap, HashSet};
 /// Each item in `/vulnerabilities[]/product_status/known_not_affected` must have a corresponding
 /// impact statement in `/vulnerabilities[]/flags` or `/vulnerabilities[]/threats`. For impact statements under
 /// `threats`, the category must be `impact`.
-pub fn test_6_1_27_09_impact_statement(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_27_09_impact_statement(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let doc_category = doc.get_document().get_category();
     let vulnerabilities = doc.get_vulnerabilities();
 
@@ -28,7 +28,7 @@ pub fn test_6_1_27_09_impact_statement(doc: &impl CsafTrait) -> Result<(), Vec<V
     // for each vulnerability
     for (v_i, vulnerability) in vulnerabilities.iter().enumerate() {
         // generate hashmap of all known_not_affected product or group ids with value of known_not_affected path index
-        let mut known_not_affected_product_or_group_ids: HashMap<String, usize> = HashMap::new();
+        let mut known_not_affected_product_or_group_ids: BTreeMap<String, usize> = BTreeMap::new();
         if let Some(product_status) = vulnerability.get_product_status() {
             if let Some(known_not_affected) = product_status.get_known_not_affected() {
                 for (kna_i, known_not_affected_entry) in known_not_affected.into_iter().enumerate() {
@@ -105,18 +105,19 @@ fn test_6_1_27_09_err_generator(
     vuln_path_index: usize,
     known_not_affected_path_index: usize,
 ) -> ValidationError {
-    ValidationError {
-        message: format!(
+    ValidationError::new(
+        "6.1.27.9",
+        format!(
             "In documents with category '{}', vulnerability product status 'known_not_affected' entries \
             must have a corresponding impact statement in 'flags' or 'threats' with category 'impact'. \
             Found 'known_not_affected' product status entry '{}' without impact statement.",
             document_category, product_or_group_id
         ),
-        instance_path: format!(
+        format!(
             "/vulnerabilities/{}/product_status/known_not_affected/{}",
             vuln_path_index, known_not_affected_path_index
         ),
-    }
+    )
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
-use crate::csaf_traits::{CsafTrait, DocumentCategory, DocumentTrait, ProductStatusTrait, VulnerabilityTrait};
+use crate::csaf_traits::{CsafTraitExt, DocumentCategory, DocumentTrait, ProductStatusTrait, VulnerabilityTrait};
 use crate::helpers::resolve_product_groups;
 use crate::validation::ValidationError;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
 /// 6.1.27.10 Action Statement
 ///
@@ -10,7 +10,7 @@ use std::collections::{HashMap, HashSet};
 /// Each item in `/vulnerabilities[]/product_status/known_affected` must have a corresponding
 /// action statement in `/vulnerabilities[]/remediations`
 ///
-pub fn test_6_1_27_10_action_statement(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_27_10_action_statement(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let doc_category = doc.get_document().get_category();
     let vulnerabilities = doc.get_vulnerabilities();
 
@@ -24,7 +24,7 @@ pub fn test_6_1_27_10_action_statement(doc: &impl CsafTrait) -> Result<(), Vec<V
     // for each vulnerability
     for (v_i, vulnerability) in vulnerabilities.iter().enumerate() {
         // generate hashmap of all known_affected product or group ids with value of known_not_affected path index
-        let mut known_affected_product_or_group_ids: HashMap<String, usize> = HashMap::new();
+        let mut known_affected_product_or_group_ids: BTreeMap<String, usize> = BTreeMap::new();
         if let Some(product_status) = vulnerability.get_product_status() {
             if let Some(known_affected) = product_status.get_known_affected() {
                 for (kna_i, known_affected_entry) in known_affected.into_iter().enumerate() {
@@ -83,18 +83,19 @@ fn test_6_1_27_10_err_generator(
     vuln_path_index: usize,
     known_affected_path_index: usize,
 ) -> ValidationError {
-    ValidationError {
-        message: format!(
+    ValidationError::new(
+        "6.1.27.10",
+        format!(
             "In documents with category '{}', vulnerability product status 'known_affected' entries \
             must have a corresponding action statement in 'remediations'. \
             Found 'known_affected' product status entry '{}' without action statement.",
             document_category, product_or_group_id
         ),
-        instance_path: format!(
+        format!(
             "/vulnerabilities/{}/product_status/known_not_affected/{}",
             vuln_path_index, known_affected_path_index
         ),
-    }
+    )
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, CsafVersion, DocumentCategory, DocumentTrait};
+use crate::csaf_traits::{CsafTraitExt, CsafVersion, DocumentCategory, DocumentTrait};
 use crate::validation::ValidationError;
 
 /// 6.1.27.11 Vulnerabilities
@@ -8,7 +8,7 @@ use crate::validation::ValidationError;
 /// value `csaf_deprecated_security_advisory` for `/document/csaf_version` `2.1`.
 ///
 /// In documents with this category a `/vulnerabilities[]` element must exist.
-pub fn test_6_1_27_11_vulnerabilities(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_27_11_vulnerabilities(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let doc_category = doc.get_document().get_category();
 
     // check if document is relevant document category in csaf 2.0
@@ -36,13 +36,14 @@ pub fn test_6_1_27_11_vulnerabilities(doc: &impl CsafTrait) -> Result<(), Vec<Va
 }
 
 fn test_6_1_27_11_err_generator(document_category: &DocumentCategory) -> ValidationError {
-    ValidationError {
-        message: format!(
+    ValidationError::new(
+        "6.1.27.11",
+        format!(
             "Document with category '{}' must not have a '/vulnerabilities' element",
             document_category
         ),
-        instance_path: "/vulnerabilities".to_string(),
-    }
+        "/vulnerabilities",
+    )
 }
 
 #[cfg(test)]
@@ -1,10 +1,10 @@
-use crate::csaf_traits::{CsafTrait, VulnerabilityTrait, WithOptionalProductIds};
+use crate::csaf_traits::{CsafTraitExt, VulnerabilityTrait, WithOptionalProductIds};
 use crate::validation::ValidationError;
 
 /// 6.1.29 Remediation without Product Reference
 ///
 /// Each item in `/vulnerabilities[]/remediations` must have at least one of the elements group_ids or product_ids.
-pub fn test_6_1_29_remediation_without_product_reference(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_29_remediation_without_product_reference(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let vulnerabilities = doc.get_vulnerabilities();
 
     let mut errors: Option<Vec<ValidationError>> = None;
@@ -22,10 +22,11 @@ pub fn test_6_1_29_remediation_without_product_reference(doc: &impl CsafTrait) -
 }
 
 fn test_6_1_29_err_generator(vuln_index: usize, rem_index: usize) -> ValidationError {
-    ValidationError {
-        message: "Remediations need to at least have one of the elements group_ids or product_ids".to_string(),
-        instance_path: format!("/vulnerabilities/{}/remediations/{}", vuln_index, rem_index),
-    }
+    ValidationError::new(
+        "6.1.29",
+        "Remediations need to at least have one of the elements group_ids or product_ids",
+        format!("/vulnerabilities/{}/remediations/{}", vuln_index, rem_index),
+    )
 }
 
 #[cfg(test)]
@@ -1,11 +1,11 @@
-use crate::csaf_traits::{CsafTrait, VulnerabilityTrait, WithOptionalGroupIds, WithOptionalProductIds};
+use crate::csaf_traits::{CsafTraitExt, VulnerabilityTrait, WithOptionalGroupIds, WithOptionalProductIds};
 use crate::validation::ValidationError;
 
 /// 6.1.32 Flag without Product Reference
 ///
 /// Each `/vulnerabilities[]/flags[]` item needs to contain at least one element
 /// in it's `group_ids` or `product_ids` arrays.
-pub fn test_6_1_32_flag_without_product_reference(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_32_flag_without_product_reference(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let mut errors = Vec::new();
 
     // Check each flag in each vulnerability
@@ -23,10 +23,11 @@ pub fn test_6_1_32_flag_without_product_reference(doc: &impl CsafTrait) -> Resul
                 {
                     continue;
                 }
-                errors.push(ValidationError {
-                    message: "Each flag must reference at least one group_id or product_id".to_string(),
-                    instance_path: format!("/vulnerabilities/{}/flags/{}", v_r, f_r),
-                });
+                errors.push(ValidationError::new(
+                    "6.1.32",
+                    "Each flag must reference at least one group_id or product_id",
+                    format!("/vulnerabilities/{}/flags/{}", v_r, f_r),
+                ));
             }
         }
     }
@@ -49,10 +50,11 @@ mod tests {
     fn test_test_6_1_32() {
         let errors = HashMap::from([(
             "01",
-            vec![ValidationError {
-                message: "Each flag must reference at least one group_id or product_id".to_string(),
-                instance_path: "/vulnerabilities/0/flags/0".to_string(),
-            }],
+            vec![ValidationError::new(
+                "6.1.32",
+                "Each flag must reference at least one group_id or product_id",
+                "/vulnerabilities/0/flags/0",
+            )],
         )]);
         run_csaf20_tests("32", test_6_1_32_flag_without_product_reference, errors.clone());
         run_csaf21_tests("32", test_6_1_32_flag_without_product_reference, errors);
@@ -1,17 +1,19 @@
-use crate::csaf_traits::{BranchTrait, CsafTrait, ProductTreeTrait};
+use crate::csaf_traits::{BranchTrait, CsafTraitExt, ProductTreeTrait};
 use crate::validation::ValidationError;
 
-static MAX_DEPTH: u32 = 30;
+/// The maximum allowed nesting depth of branches
+pub static MAX_DEPTH: u32 = 30;
 
-pub fn test_6_1_34_branches_recursion_depth(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_34_branches_recursion_depth(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     if let Some(tree) = doc.get_product_tree().as_ref() {
         if let Some(branches) = tree.get_branches() {
             for (i, branch) in branches.iter().enumerate() {
                 if let Some(path) = branch.find_excessive_branch_depth(MAX_DEPTH) {
-                    return Err(vec![ValidationError {
-                        message: format!("Branches recursion depth too big (> {})", MAX_DEPTH),
-                        instance_path: format!("/product_tree/branches/{}{}", i, path),
-                    }]);
+                    return Err(vec![ValidationError::new(
+                        "6.1.34",
+                        format!("Branches recursion depth too big (> {})", MAX_DEPTH),
+                        format!("/product_tree/branches/{}{}", i, path),
+                    )]);
                 }
             }
         }
@@ -34,27 +36,27 @@ mod tests {
             HashMap::from([
                 (
                     "01",
-                    vec![ValidationError {
-                        message: "Branches recursion depth too big (> 30)".to_string(),
-                        instance_path: "/product_tree/branches/0/branches/0/branches/0/branches/0\
+                    vec![ValidationError::new(
+                        "6.1.34",
+                        "Branches recursion depth too big (> 30)",
+                        "/product_tree/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
-                    /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0"
-                            .to_string(),
-                    }],
+                    /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0",
+                    )],
                 ),
                 (
                     "02",
-                    vec![ValidationError {
-                        message: "Branches recursion depth too big (> 30)".to_string(),
-                        instance_path: "/product_tree/branches/0/branches/0/branches/1/branches/0\
+                    vec![ValidationError::new(
+                        "6.1.34",
+                        "Branches recursion depth too big (> 30)",
+                        "/product_tree/branches/0/branches/0/branches/1/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
                     /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
-                    /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0"
-                            .to_string(),
-                    }],
+                    /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0",
+                    )],
                 ),
             ]),
         );
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, RemediationTrait, VulnerabilityTrait};
+use crate::csaf_traits::{CsafTraitExt, RemediationTrait, VulnerabilityTrait};
 use crate::schema::csaf2_1::schema::CategoryOfTheRemediation;
 use crate::validation::ValidationError;
 use std::collections::BTreeMap;
@@ -16,7 +16,7 @@ static MUT_EX_STATES: &[CategoryOfTheRemediation] = &[
     CategoryOfTheRemediation::VendorFix,
 ];
 
-pub fn test_6_1_35_contradicting_remediations(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_35_contradicting_remediations(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
         // Data struct to store observed remediation categories per product IT
         let mut product_categories: BTreeMap<String, Vec<CategoryOfTheRemediation>> = BTreeMap::new();
@@ -36,8 +36,9 @@ pub fn test_6_1_35_contradicting_remediations(doc: &impl CsafTrait) -> Result<()
                             // Checks if the current category conflicts with any other in the group of mutually exclusive ones.
                             || MUT_EX_STATES.contains(&cat) && exist_cat_set.iter().any(|e_cat| MUT_EX_STATES.contains(e_cat))
                         {
-                            return Err(vec![ValidationError {
-                                message: format!(
+                            return Err(vec![ValidationError::new(
+                                "6.1.35",
+                                format!(
                                     "Product {} has contradicting remediations: {} and {}",
                                     p,
                                     exist_cat_set
@@ -47,8 +48,8 @@ pub fn test_6_1_35_contradicting_remediations(doc: &impl CsafTrait) -> Result<()
                                         .join(", "),
                                     cat
                                 ),
-                                instance_path: format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
-                            }]);
+                                format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                            )]);
                         }
                         exist_cat_set.push(cat);
                     } else {
@@ -74,22 +75,38 @@ mod tests {
             "35",
             test_6_1_35_contradicting_remediations,
             HashMap::from([
-                ("01", vec![ValidationError {
-                    message: "Product CSAFPID-9080700 has contradicting remediations: no_fix_planned and vendor_fix".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/1".to_string(),
-                }]),
-                ("02", vec![ValidationError {
-                    message: "Product CSAFPID-9080700 has contradicting remediations: none_available and mitigation".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/1".to_string(),
-                }]),
-                ("03", vec![ValidationError {
-                    message: "Product CSAFPID-9080702 has contradicting remediations: workaround, fix_planned and optional_patch".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/2".to_string(),
-                }]),
-                ("04", vec![ValidationError {
-                    message: "Product CSAFPID-9080701 has contradicting remediations: mitigation, fix_planned and optional_patch".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/2".to_string(),
-                }]),
+                (
+                    "01",
+                    vec![ValidationError::new(
+                        "6.1.35",
+                        "Product CSAFPID-9080700 has contradicting remediations: no_fix_planned and vendor_fix",
+                        "/vulnerabilities/0/remediations/1",
+                    )],
+                ),
+                (
+                    "02",
+                    vec![ValidationError::new(
+                        "6.1.35",
+                        "Product CSAFPID-9080700 has contradicting remediations: none_available and mitigation",
+                        "/vulnerabilities/0/remediations/1",
+                    )],
+                ),
+                (
+                    "03",
+                    vec![ValidationError::new(
+                        "6.1.35",
+                        "Product CSAFPID-9080702 has contradicting remediations: workaround, fix_planned and optional_patch",
+                        "/vulnerabilities/0/remediations/2",
+                    )],
+                ),
+                (
+                    "04",
+                    vec![ValidationError::new(
+                        "6.1.35",
+                        "Product CSAFPID-9080701 has contradicting remediations: mitigation, fix_planned and optional_patch",
+                        "/vulnerabilities/0/remediations/2",
+                    )],
+                ),
             ]),
         );
     }
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, ProductStatusGroup, ProductStatusTrait, RemediationTrait, VulnerabilityTrait};
+use crate::csaf_traits::{CsafTraitExt, ProductStatusGroup, ProductStatusTrait, RemediationTrait, VulnerabilityTrait};
 use crate::schema::csaf2_1::schema::CategoryOfTheRemediation;
 use crate::validation::ValidationError;
 
@@ -21,7 +21,7 @@ const FIXED_CONFLICTS: &[CategoryOfTheRemediation] = &[
 ];
 
 pub fn test_6_1_36_status_group_contradicting_remediation_categories(
-    doc: &impl CsafTrait,
+    doc: &impl CsafTraitExt,
 ) -> Result<(), Vec<ValidationError>> {
     for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(product_status) = v.get_product_status() {
@@ -40,35 +40,38 @@ pub fn test_6_1_36_status_group_contradicting_remediation_categories(
                     for p in product_ids {
                         if let Some(affected_products) = affected_products {
                             if affected_products.contains(&p) && cat == CategoryOfTheRemediation::OptionalPatch {
-                                return Err(vec![ValidationError {
-                                    message: format!(
+                                return Err(vec![ValidationError::new(
+                                    "6.1.36",
+                                    format!(
                                         "Product {} is listed as affected but has conflicting remediation category {}",
                                         p, cat
                                     ),
-                                    instance_path: format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
-                                }]);
+                                    format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                                )]);
                             }
                         }
                         if let Some(not_affected_products) = not_affected_products {
                             if not_affected_products.contains(&p) && NOT_AFFECTED_CONFLICTS.contains(&cat) {
-                                return Err(vec![ValidationError {
-                                    message: format!(
+                                return Err(vec![ValidationError::new(
+                                    "6.1.36",
+                                    format!(
                                         "Product {} is listed as not affected but has conflicting remediation category {}",
                                         p, cat
                                     ),
-                                    instance_path: format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
-                                }]);
+                                    format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                                )]);
                             }
                         }
                         if let Some(fixed_products) = fixed_products {
                             if fixed_products.contains(&p) && FIXED_CONFLICTS.contains(&cat) {
-                                return Err(vec![ValidationError {
-                                    message: format!(
+                                return Err(vec![ValidationError::new(
+                                    "6.1.36",
+                                    format!(
                                         "Product {} is listed as fixed but has conflicting remediation category {}",
                                         p, cat
                                     ),
-                                    instance_path: format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
-                                }]);
+                                    format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                                )]);
                             }
                         }
                     }
@@ -92,22 +95,38 @@ mod tests {
             "36",
             test_6_1_36_status_group_contradicting_remediation_categories,
             HashMap::from([
-                ("01", vec![ValidationError {
-                    message: "Product CSAFPID-9080700 is listed as not affected but has conflicting remediation category vendor_fix".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/0".to_string()
-                }]),
-                ("02", vec![ValidationError {
-                    message: "Product CSAFPID-9080703 is listed as fixed but has conflicting remediation category none_available".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/0".to_string()
-                }]),
-                ("03", vec![ValidationError {
-                    message: "Product CSAFPID-9080700 is listed as affected but has conflicting remediation category optional_patch".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/0".to_string(),
-                }]),
-                ("04", vec![ValidationError {
-                    message: "Product CSAFPID-9080700 is listed as fixed but has conflicting remediation category no_fix_planned".to_string(),
-                    instance_path: "/vulnerabilities/0/remediations/0".to_string(),
-                }]),
+                (
+                    "01",
+                    vec![ValidationError::new(
+                        "6.1.36",
+                        "Product CSAFPID-9080700 is listed as not affected but has conflicting remediation category vendor_fix",
+                        "/vulnerabilities/0/remediations/0",
+                    )],
+                ),
+                (
+                    "02",
+                    vec![ValidationError::new(
+                        "6.1.36",
+                        "Product CSAFPID-9080703 is listed as fixed but has conflicting remediation category none_available",
+                        "/vulnerabilities/0/remediations/0",
+                    )],
+                ),
+                (
+                    "03",
+                    vec![ValidationError::new(
+                        "6.1.36",
+                        "Product CSAFPID-9080700 is listed as affected but has conflicting remediation category optional_patch",
+                        "/vulnerabilities/0/remediations/0",
+                    )],
+                ),
+                (
+                    "04",
+                    vec![ValidationError::new(
+                        "6.1.36",
+                        "Product CSAFPID-9080700 is listed as fixed but has conflicting remediation category no_fix_planned",
+                        "/vulnerabilities/0/remediations/0",
+                    )],
+                ),
             ]),
         );
     }
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait, TlpTrait};
+use crate::csaf_traits::{CsafTraitExt, DistributionTrait, DocumentTrait, SharingGroupTrait, TlpTrait};
 use crate::helpers::MAX_UUID;
 use crate::schema::csaf2_1::schema::LabelOfTlp::Clear;
 use crate::validation::ValidationError;
@@ -12,22 +12,23 @@ use crate::validation::ValidationError;
 ///
 /// # Arguments
 ///
-/// * `doc` - A reference to an object implementing the `CsafTrait` interface.
+/// * `doc` - A reference to an object implementing the `CsafTraitExt` interface.
 ///
 /// # Returns
 ///
 /// * `Ok(())` if the validation passes.
 /// * `Err(vec![ValidationError])` if the validation fails, with a message explaining the reason
 ///   and the JSON path to the invalid element.
-pub fn test_6_1_38_non_public_sharing_group_max_uuid(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_38_non_public_sharing_group_max_uuid(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
 
     if let Some(sharing_group) = distribution.get_sharing_group() {
         if sharing_group.get_id() == MAX_UUID && distribution.get_tlp_21().map_err(|e| vec![e])?.get_label() != Clear {
-            return Err(vec![ValidationError {
-                message: "Document must be public (TLD CLEAR) when using max UUID as sharing group ID.".to_string(),
-                instance_path: "/document/distribution/sharing_group/tlp/label".to_string(),
-            }]);
+            return Err(vec![ValidationError::new(
+                "6.1.38",
+                "Document must be public (TLD CLEAR) when using max UUID as sharing group ID.",
+                "/document/distribution/sharing_group/tlp/label",
+            )]);
         }
     }
 
@@ -43,10 +44,11 @@ mod tests {
 
     #[test]
     fn test_test_6_1_38() {
-        let expected_error = ValidationError {
-            message: "Document must be public (TLD CLEAR) when using max UUID as sharing group ID.".to_string(),
-            instance_path: "/document/distribution/sharing_group/tlp/label".to_string(),
-        };
+        let expected_error = ValidationError::new(
+            "6.1.38",
+            "Document must be public (TLD CLEAR) when using max UUID as sharing group ID.",
+            "/document/distribution/sharing_group/tlp/label",
+        );
 
         run_csaf21_tests(
             "38",
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait, TlpTrait, TrackingTrait};
+use crate::csaf_traits::{CsafTraitExt, DistributionTrait, DocumentTrait, SharingGroupTrait, TlpTrait, TrackingTrait};
 use crate::helpers::{MAX_UUID, NIL_UUID};
 use crate::schema::csaf2_1::schema::DocumentStatus;
 use crate::schema::csaf2_1::schema::LabelOfTlp::Clear;
@@ -14,13 +14,13 @@ use crate::validation::ValidationError;
 ///
 /// # Arguments
 ///
-/// - `doc`: A document implementing the `CsafTrait` interface.
+/// - `doc`: A document implementing the `CsafTraitExt` interface.
 ///
 /// # Returns
 ///
 /// - `Ok(())` if the validation passes.
 /// - `Err(vec![ValidationError])` if the requirements are not met.
-pub fn test_6_1_39_public_sharing_group_with_no_max_uuid(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_39_public_sharing_group_with_no_max_uuid(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
 
     if distribution.get_tlp_21().map_err(|e| vec![e])?.get_label() == Clear {
@@ -32,12 +32,11 @@ pub fn test_6_1_39_public_sharing_group_with_no_max_uuid(doc: &impl CsafTrait) -
             {
                 Ok(())
             } else {
-                Err(vec![ValidationError {
-                    message:
-                        "Document with TLP CLEAR and sharing group must use max UUID or nil UUID plus draft status."
-                            .to_string(),
-                    instance_path: "/document/distribution/sharing_group/id".to_string(),
-                }])
+                Err(vec![ValidationError::new(
+                    "6.1.39",
+                    "Document with TLP CLEAR and sharing group must use max UUID or nil UUID plus draft status.",
+                    "/document/distribution/sharing_group/id",
+                )])
             };
         }
     }
@@ -54,11 +53,11 @@ mod tests {
 
     #[test]
     fn test_test_6_1_39() {
-        let expected_error = ValidationError {
-            message: "Document with TLP CLEAR and sharing group must use max UUID or nil UUID plus draft status."
-                .to_string(),
-            instance_path: "/document/distribution/sharing_group/id".to_string(),
-        };
+        let expected_error = ValidationError::new(
+            "6.1.39",
+            "Document with TLP CLEAR and sharing group must use max UUID or nil UUID plus draft status.",
+            "/document/distribution/sharing_group/id",
+        );
 
         run_csaf21_tests(
             "39",
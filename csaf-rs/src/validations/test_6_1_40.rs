@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait};
+use crate::csaf_traits::{CsafTraitExt, DistributionTrait, DocumentTrait, SharingGroupTrait};
 use crate::helpers::{MAX_UUID, NIL_UUID, SG_NAME_PRIVATE, SG_NAME_PUBLIC};
 use crate::validation::ValidationError;
 
@@ -14,36 +14,38 @@ use crate::validation::ValidationError;
 ///
 /// # Arguments
 ///
-/// * `doc` - A reference to an object implementing the `CsafTrait` interface.
+/// * `doc` - A reference to an object implementing the `CsafTraitExt` interface.
 ///
 /// # Returns
 ///
 /// * `Ok(())` if the validation passes.
 /// * `Err(vec![ValidationError])` if the validation fails, with a message explaining the reason
 ///   and the JSON path to the invalid element.
-pub fn test_6_1_40_invalid_sharing_group_name(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_40_invalid_sharing_group_name(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
 
     if let Some(sharing_group) = distribution.get_sharing_group() {
         if let Some(sharing_group_name) = sharing_group.get_name() {
             if sharing_group_name == SG_NAME_PUBLIC {
                 if sharing_group.get_id() != MAX_UUID {
-                    return Err(vec![ValidationError {
-                        message: format!(
+                    return Err(vec![ValidationError::new(
+                        "6.1.40",
+                        format!(
                             "Sharing group name \"{}\" is prohibited without max UUID.",
                             SG_NAME_PUBLIC
                         ),
-                        instance_path: "/document/distribution/sharing_group/name".to_string(),
-                    }]);
+                        "/document/distribution/sharing_group/name",
+                    )]);
                 }
             } else if sharing_group_name == SG_NAME_PRIVATE && sharing_group.get_id() != NIL_UUID {
-                return Err(vec![ValidationError {
-                    message: format!(
+                return Err(vec![ValidationError::new(
+                    "6.1.40",
+                    format!(
                         "Sharing group name \"{}\" is prohibited without nil UUID.",
                         SG_NAME_PRIVATE
                     ),
-                    instance_path: "/document/distribution/sharing_group/name".to_string(),
-                }]);
+                    "/document/distribution/sharing_group/name",
+                )]);
             }
         }
     }
@@ -66,23 +68,25 @@ mod tests {
             HashMap::from([
                 (
                     "01",
-                    vec![ValidationError {
-                        message: format!(
+                    vec![ValidationError::new(
+                        "6.1.40",
+                        format!(
                             "Sharing group name \"{}\" is prohibited without max UUID.",
                             SG_NAME_PUBLIC
                         ),
-                        instance_path: "/document/distribution/sharing_group/name".to_string(),
-                    }],
+                        "/document/distribution/sharing_group/name",
+                    )],
                 ),
                 (
                     "02",
-                    vec![ValidationError {
-                        message: format!(
+                    vec![ValidationError::new(
+                        "6.1.40",
+                        format!(
                             "Sharing group name \"{}\" is prohibited without nil UUID.",
                             SG_NAME_PRIVATE
                         ),
-                        instance_path: "/document/distribution/sharing_group/name".to_string(),
-                    }],
+                        "/document/distribution/sharing_group/name",
+                    )],
                 ),
             ]),
         );
@@ -1,4 +1,4 @@
-use crate::csaf_traits::{CsafTrait, DistributionTrait, DocumentTrait, SharingGroupTrait};
+use crate::csaf_traits::{CsafTraitExt, DistributionTrait, DocumentTrait, SharingGroupTrait};
 use crate::helpers::{MAX_UUID, NIL_UUID, SG_NAME_PRIVATE, SG_NAME_PUBLIC};
 use crate::validation::ValidationError;
 
@@ -12,14 +12,14 @@ use crate::validation::ValidationError;
 ///
 /// # Arguments
 ///
-/// * `doc` - A reference to an object implementing the `CsafTrait` interface.
+/// * `doc` - A reference to an object implementing the `CsafTraitExt` interface.
 ///
 /// # Returns
 ///
 /// * `Ok(())` if the validation passes.
 /// * `Err(vec![ValidationError])` if the validation fails, with a message explaining the reason
 ///   and the JSON path to the invalid element.
-pub fn test_6_1_41_missing_sharing_group_name(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_41_missing_sharing_group_name(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let distribution = doc.get_document().get_distribution_21().map_err(|e| vec![e])?;
 
     if let Some(sharing_group) = distribution.get_sharing_group() {
@@ -29,10 +29,11 @@ pub fn test_6_1_41_missing_sharing_group_name(doc: &impl CsafTrait) -> Result<()
             match sharing_group.get_name() {
                 Some(name) if name == SG_NAME_PUBLIC => {},
                 _ => {
-                    return Err(vec![ValidationError {
-                        message: format!("Max UUID requires sharing group name to be \"{}\".", SG_NAME_PUBLIC),
-                        instance_path: "/document/distribution/sharing_group/name".to_string(),
-                    }]);
+                    return Err(vec![ValidationError::new(
+                        "6.1.41",
+                        format!("Max UUID requires sharing group name to be \"{}\".", SG_NAME_PUBLIC),
+                        "/document/distribution/sharing_group/name",
+                    )]);
                 },
             }
         }
@@ -42,10 +43,11 @@ pub fn test_6_1_41_missing_sharing_group_name(doc: &impl CsafTrait) -> Result<()
             match sharing_group.get_name() {
                 Some(name) if name == SG_NAME_PRIVATE => {},
                 _ => {
-                    return Err(vec![ValidationError {
-                        message: format!("Nil UUID requires sharing group name to be \"{}\".", SG_NAME_PRIVATE),
-                        instance_path: "/document/distribution/sharing_group/name".to_string(),
-                    }]);
+                    return Err(vec![ValidationError::new(
+                        "6.1.41",
+                        format!("Nil UUID requires sharing group name to be \"{}\".", SG_NAME_PRIVATE),
+                        "/document/distribution/sharing_group/name",
+                    )]);
                 },
             }
         }
@@ -69,31 +71,35 @@ mod tests {
             HashMap::from([
                 (
                     "01",
-                    vec![ValidationError {
-                        message: format!("Max UUID requires sharing group name to be \"{}\".", SG_NAME_PUBLIC),
-                        instance_path: "/document/distribution/sharing_group/name".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.41",
+                        format!("Max UUID requires sharing group name to be \"{}\".", SG_NAME_PUBLIC),
+                        "/document/distribution/sharing_group/name",
+                    )],
                 ),
                 (
                     "02",
-                    vec![ValidationError {
-                        message: format!("Nil UUID requires sharing group name to be \"{}\".", SG_NAME_PRIVATE),
-                        instance_path: "/document/distribution/sharing_group/name".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.41",
+                        format!("Nil UUID requires sharing group name to be \"{}\".", SG_NAME_PRIVATE),
+                        "/document/distribution/sharing_group/name",
+                    )],
                 ),
                 (
                     "03",
-                    vec![ValidationError {
-                        message: format!("Max UUID requires sharing group name to be \"{}\".", SG_NAME_PUBLIC),
-                        instance_path: "/document/distribution/sharing_group/name".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.41",
+                        format!("Max UUID requires sharing group name to be \"{}\".", SG_NAME_PUBLIC),
+                        "/document/distribution/sharing_group/name",
+                    )],
                 ),
                 (
                     "04",
-                    vec![ValidationError {
-                        message: format!("Nil UUID requires sharing group name to be \"{}\".", SG_NAME_PRIVATE),
-                        instance_path: "/document/distribution/sharing_group/name".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.41",
+                        format!("Nil UUID requires sharing group name to be \"{}\".", SG_NAME_PRIVATE),
+                        "/document/distribution/sharing_group/name",
+                    )],
                 ),
             ]),
         );
@@ -1,9 +1,9 @@
-use crate::csaf_traits::{CsafTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
+use crate::csaf_traits::{CsafTraitExt, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
 use crate::validation::ValidationError;
 use packageurl::PackageUrl;
 use std::str::FromStr;
 
-pub fn test_6_1_42_purl_consistency(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_42_purl_consistency(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let mut errors: Option<Vec<ValidationError>> = None;
 
     if let Some(product_tree) = doc.get_product_tree() {
@@ -21,10 +21,7 @@ pub fn test_6_1_42_purl_consistency(doc: &impl CsafTrait) -> Result<(), Vec<Vali
                         let mut purl = match PackageUrl::from_str(purl_str) {
                             Ok(p) => p,
                             Err(_) => {
-                                errors.get_or_insert_with(Vec::new).push(ValidationError {
-                                    message: format!("Invalid PURL format: {}", purl_str),
-                                    instance_path: format!("{}/product_identification_helper/purls/{}", path, i),
-                                });
+                                errors.get_or_insert_with(Vec::new).push(ValidationError::new("6.1.42", format!("Invalid PURL format: {}", purl_str), format!("{}/product_identification_helper/purls/{}", path, i)));
                                 continue;
                             }
                         };
@@ -35,10 +32,7 @@ pub fn test_6_1_42_purl_consistency(doc: &impl CsafTrait) -> Result<(), Vec<Vali
                         if let Some(ref base) = base_parts {
                             // Must always match
                             if current_parts != *base {
-                                errors.get_or_insert_with(Vec::new).push(ValidationError {
-                                    message: String::from("PURLs within the same product_identification_helper must only differ in qualifiers"),
-                                    instance_path: format!("{}/product_identification_helper/purls/{}", path, i),
-                                });
+                                errors.get_or_insert_with(Vec::new).push(ValidationError::new("6.1.42", String::from("PURLs within the same product_identification_helper must only differ in qualifiers"), format!("{}/product_identification_helper/purls/{}", path, i)));
                             }
                         } else {
                             // The first PURL becomes the base for comparison
@@ -66,16 +60,25 @@ mod tests {
     fn test_test_6_1_42() {
         run_csaf21_tests(
             "42",
-            test_6_1_42_purl_consistency, HashMap::from([
-                ("01", vec![ValidationError {
-                    message: ERROR_MESSAGE.to_string(),
-                    instance_path: "/product_tree/full_product_names/0/product_identification_helper/purls/1".to_string(),
-                }]),
-                ("02", vec![ValidationError {
-                    message: ERROR_MESSAGE.to_string(),
-                    instance_path: "/product_tree/branches/0/branches/0/branches/0/product/product_identification_helper/purls/2".to_string(),
-                }]),
-            ])
+            test_6_1_42_purl_consistency,
+            HashMap::from([
+                (
+                    "01",
+                    vec![ValidationError::new(
+                        "6.1.42",
+                        ERROR_MESSAGE.to_string(),
+                        "/product_tree/full_product_names/0/product_identification_helper/purls/1",
+                    )],
+                ),
+                (
+                    "02",
+                    vec![ValidationError::new(
+                        "6.1.42",
+                        ERROR_MESSAGE.to_string(),
+                        "/product_tree/branches/0/branches/0/branches/0/product/product_identification_helper/purls/2",
+                    )],
+                ),
+            ]),
         );
     }
 }
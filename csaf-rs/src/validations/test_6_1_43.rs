@@ -1,8 +1,8 @@
-use crate::csaf_traits::{CsafTrait, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
+use crate::csaf_traits::{CsafTraitExt, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
 use crate::helpers::count_unescaped_stars;
 use crate::validation::ValidationError;
 
-pub fn test_6_1_43_multiple_stars_in_model_number(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_43_multiple_stars_in_model_number(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let mut errors: Option<Vec<ValidationError>> = None;
 
     if let Some(product_tree) = doc.get_product_tree() {
@@ -11,14 +11,11 @@ pub fn test_6_1_43_multiple_stars_in_model_number(doc: &impl CsafTrait) -> Resul
                 if let Some(model_numbers) = helper.get_model_numbers() {
                     for (index, model_number) in model_numbers.enumerate() {
                         if count_unescaped_stars(model_number) > 1 {
-                            errors.get_or_insert_with(Vec::new).push(ValidationError {
-                                message: "Model number must not contain multiple unescaped asterisks (stars)"
-                                    .to_string(),
-                                instance_path: format!(
-                                    "{}/product_identification_helper/model_numbers/{}",
-                                    path, index
-                                ),
-                            });
+                            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                                "6.1.43",
+                                "Model number must not contain multiple unescaped asterisks (stars)",
+                                format!("{}/product_identification_helper/model_numbers/{}", path, index),
+                            ));
                         }
                     }
                 }
@@ -38,11 +35,11 @@ mod tests {
 
     #[test]
     fn test_test_6_1_43() {
-        let expected_error = ValidationError {
-            message: "Model number must not contain multiple unescaped asterisks (stars)".to_string(),
-            instance_path: "/product_tree/full_product_names/0/product_identification_helper/model_numbers/0"
-                .to_string(),
-        };
+        let expected_error = ValidationError::new(
+            "6.1.43",
+            "Model number must not contain multiple unescaped asterisks (stars)",
+            "/product_tree/full_product_names/0/product_identification_helper/model_numbers/0",
+        );
 
         run_csaf21_tests(
             "43",
@@ -1,11 +1,11 @@
 use crate::csaf_traits::{
-    CsafTrait, DistributionTrait, DocumentTrait, RevisionTrait, TlpTrait, TrackingTrait, VulnerabilityTrait,
+    CsafTraitExt, DistributionTrait, DocumentTrait, RevisionTrait, TlpTrait, TrackingTrait, VulnerabilityTrait,
 };
 use crate::schema::csaf2_1::schema::{DocumentStatus, LabelOfTlp};
 use crate::validation::ValidationError;
 use chrono::{DateTime, FixedOffset};
 
-pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     // Only check if document is TLP:CLEAR and status is final or interim
     let document = doc.get_document();
     let status = document.get_tracking().get_status();
@@ -42,10 +42,11 @@ pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> Result<
                 }
             })
             .map_err(|_| {
-                vec![ValidationError {
-                    message: format!("Invalid date format in revision history: {}", rev.get_date()),
-                    instance_path: format!("/document/tracking/revision_history/{}", i_rev),
-                }]
+                vec![ValidationError::new(
+                    "6.1.45",
+                    format!("Invalid date format in revision history: {}", rev.get_date()),
+                    format!("/document/tracking/revision_history/{}", i_rev),
+                )]
             })?;
     }
 
@@ -60,17 +61,19 @@ pub fn test_6_1_45_inconsistent_disclosure_date(doc: &impl CsafTrait) -> Result<
                             disclosure_datetime, newest_date
                         );
                         if disclosure_datetime > newest_date {
-                            return Err(vec![ValidationError {
-                                message: "Disclosure date must not be later than the newest revision history date for TLP:CLEAR documents with final or interim status".to_string(),
-                                instance_path: format!("/vulnerabilities/{}/discovery_date", i_v),
-                            }]);
+                            return Err(vec![ValidationError::new(
+                                "6.1.45",
+                                "Disclosure date must not be later than the newest revision history date for TLP:CLEAR documents with final or interim status",
+                                format!("/vulnerabilities/{}/discovery_date", i_v),
+                            )]);
                         }
                     },
                     Err(_) => {
-                        return Err(vec![ValidationError {
-                            message: format!("Invalid disclosure date format: {}", disclosure_date),
-                            instance_path: format!("/vulnerabilities/{}/discovery_date", i_v),
-                        }]);
+                        return Err(vec![ValidationError::new(
+                            "6.1.45",
+                            format!("Invalid disclosure date format: {}", disclosure_date),
+                            format!("/vulnerabilities/{}/discovery_date", i_v),
+                        )]);
                     },
                 }
             }
@@ -89,10 +92,11 @@ mod tests {
 
     #[test]
     fn test_test_6_1_45() {
-        let expected_error = ValidationError {
-            message: "Disclosure date must not be later than the newest revision history date for TLP:CLEAR documents with final or interim status".to_string(),
-            instance_path: "/vulnerabilities/0/discovery_date".to_string(),
-        };
+        let expected_error = ValidationError::new(
+            "6.1.45",
+            "Disclosure date must not be later than the newest revision history date for TLP:CLEAR documents with final or interim status",
+            "/vulnerabilities/0/discovery_date",
+        );
 
         run_csaf21_tests(
             "45",
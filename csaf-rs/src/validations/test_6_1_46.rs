@@ -1,17 +1,19 @@
-use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::csaf_traits::{ContentTrait, CsafTraitExt, MetricTrait, VulnerabilityTrait};
 use crate::validation::ValidationError;
 
-pub fn test_6_1_46_invalid_ssvc(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_46_invalid_ssvc(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     // /vulnerabilities[]/metrics[]/content/ssvc_v2
     for (i_v, v) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(metrics) = v.get_metrics() {
             for (i_m, m) in metrics.iter().enumerate() {
-                if m.get_content().has_ssvc() {
+                // SSVC of unsupported schema versions is reported by 6.1.94
+                if m.get_content().has_ssvc() && !m.get_content().has_unsupported_ssvc_schema() {
                     m.get_content().get_ssvc().map_err(|e| {
-                        vec![ValidationError {
-                            message: format!("Invalid SSVC object: {}", e),
-                            instance_path: format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2", i_v, i_m),
-                        }]
+                        vec![ValidationError::new(
+                            "6.1.46",
+                            format!("Invalid SSVC object: {}", e),
+                            format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2", i_v, i_m),
+                        )]
                     })?;
                 }
             }
@@ -36,17 +38,19 @@ mod tests {
             HashMap::from([
                 (
                     "01",
-                    vec![ValidationError {
-                        message: "Invalid SSVC object: missing field `selections`".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v2".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.46",
+                        "Invalid SSVC object: missing field `selections`",
+                        "/vulnerabilities/0/metrics/0/content/ssvc_v2",
+                    )],
                 ),
                 (
                     "02",
-                    vec![ValidationError {
-                        message: "Invalid SSVC object: missing field `key`".to_string(),
-                        instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v2".to_string(),
-                    }],
+                    vec![ValidationError::new(
+                        "6.1.46",
+                        "Invalid SSVC object: missing field `key`",
+                        "/vulnerabilities/0/metrics/0/content/ssvc_v2",
+                    )],
                 ),
             ]),
         );
@@ -1,9 +1,9 @@
 use crate::csaf_traits::{
-    ContentTrait, CsafTrait, DocumentTrait, MetricTrait, TrackingTrait, VulnerabilityIdTrait, VulnerabilityTrait,
+    ContentTrait, CsafTraitExt, DocumentTrait, MetricTrait, TrackingTrait, VulnerabilityIdTrait, VulnerabilityTrait,
 };
 use crate::validation::ValidationError;
 
-pub fn test_6_1_47_inconsistent_ssvc_id(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_47_inconsistent_ssvc_id(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let vulnerabilities = doc.get_vulnerabilities();
 
     for (i_v, v) in vulnerabilities.iter().enumerate() {
@@ -22,16 +22,17 @@ pub fn test_6_1_47_inconsistent_ssvc_id(doc: &impl CsafTrait) -> Result<(), Vec<
                                     if target_id == document_id {
                                         // If there are multiple vulnerabilities, the validation must fail here.
                                         if vulnerabilities.len() > 1 {
-                                            return Err(vec![ValidationError {
-                                                message: format!(
+                                            return Err(vec![ValidationError::new(
+                                                "6.1.47",
+                                                format!(
                                                     "The SSVC target ID equals the document ID '{}' and the document contains multiple vulnerabilities",
                                                     document_id
                                                 ),
-                                                instance_path: format!(
+                                                format!(
                                                     "/vulnerabilities/{}/metrics/{}/content/ssvc_v2/target_ids/{}",
                                                     i_v, i_m, i_t
                                                 ),
-                                            }]);
+                                            )]);
                                         }
                                         // Target ID is valid, continue to next
                                         continue;
@@ -52,24 +53,26 @@ pub fn test_6_1_47_inconsistent_ssvc_id(doc: &impl CsafTrait) -> Result<(), Vec<
                                     }
 
                                     // Return error if target ID is not valid
-                                    return Err(vec![ValidationError {
-                                        message: format!(
+                                    return Err(vec![ValidationError::new(
+                                        "6.1.47",
+                                        format!(
                                             "The SSVC target ID '{}' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability",
                                             target_id
                                         ),
-                                        instance_path: format!(
+                                        format!(
                                             "/vulnerabilities/{}/metrics/{}/content/ssvc_v2/target_ids/{}",
                                             i_v, i_m, i_t
                                         ),
-                                    }]);
+                                    )]);
                                 }
                             }
                         },
                         Err(err) => {
-                            return Err(vec![ValidationError {
-                                message: format!("Invalid SSVC object: {}", err),
-                                instance_path: format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2", i_v, i_m),
-                            }]);
+                            return Err(vec![ValidationError::new(
+                                "6.1.47",
+                                format!("Invalid SSVC object: {}", err),
+                                format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2", i_v, i_m),
+                            )]);
                         },
                     }
                 }
@@ -95,31 +98,55 @@ mod tests {
             "47",
             test_6_1_47_inconsistent_ssvc_id,
             HashMap::from([
-                ("01", vec![ValidationError {
-                    message: "The SSVC target ID 'CVE-1900-0002' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-                ("02", vec![ValidationError {
-                    message: "The SSVC target ID 'CVE-1900-0001' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-                ("03", vec![ValidationError {
-                    message: "The SSVC target ID '2723' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-                ("04", vec![ValidationError {
-                    message: "The SSVC target ID 'Bug#2723' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-                ("05", vec![ValidationError {
-                    message: "The SSVC target ID 'OASIS_CSAF_TC-CSAF_2.1-2024-6-1-47-15' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-                ("06", vec![ValidationError {
-                    message: "The SSVC target ID equals the document ID 'OASIS_CSAF_TC-CSAF_2.1-2024-6-1-47-06' and the document contains multiple vulnerabilities".to_string(),
-                    instance_path: "/vulnerabilities/1/metrics/0/content/ssvc_v2/target_ids/0".to_string(),
-                }]),
-            ])
+                (
+                    "01",
+                    vec![ValidationError::new(
+                        "6.1.47",
+                        "The SSVC target ID 'CVE-1900-0002' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability",
+                        instance_path.clone(),
+                    )],
+                ),
+                (
+                    "02",
+                    vec![ValidationError::new(
+                        "6.1.47",
+                        "The SSVC target ID 'CVE-1900-0001' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability",
+                        instance_path.clone(),
+                    )],
+                ),
+                (
+                    "03",
+                    vec![ValidationError::new(
+                        "6.1.47",
+                        "The SSVC target ID '2723' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability",
+                        instance_path.clone(),
+                    )],
+                ),
+                (
+                    "04",
+                    vec![ValidationError::new(
+                        "6.1.47",
+                        "The SSVC target ID 'Bug#2723' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability",
+                        instance_path.clone(),
+                    )],
+                ),
+                (
+                    "05",
+                    vec![ValidationError::new(
+                        "6.1.47",
+                        "The SSVC target ID 'OASIS_CSAF_TC-CSAF_2.1-2024-6-1-47-15' does not match the document ID, the CVE ID or any ID in the IDs array of the vulnerability",
+                        instance_path.clone(),
+                    )],
+                ),
+                (
+                    "06",
+                    vec![ValidationError::new(
+                        "6.1.47",
+                        "The SSVC target ID equals the document ID 'OASIS_CSAF_TC-CSAF_2.1-2024-6-1-47-06' and the document contains multiple vulnerabilities",
+                        "/vulnerabilities/1/metrics/0/content/ssvc_v2/target_ids/0",
+                    )],
+                ),
+            ]),
         );
     }
 }
@@ -1,15 +1,16 @@
-use crate::csaf_traits::{ContentTrait, CsafTrait, MetricTrait, VulnerabilityTrait};
+use crate::csaf_traits::{ContentTrait, CsafTraitExt, MetricTrait, VulnerabilityTrait};
 use crate::helpers::{DP_VAL_KEYS_LOOKUP, REGISTERED_SSVC_NAMESPACES, SSVC_DECISION_POINTS};
 use crate::validation::ValidationError;
 use std::ops::Deref;
 
-pub fn test_6_1_48_ssvc_decision_points(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_48_ssvc_decision_points(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let vulnerabilities = doc.get_vulnerabilities();
 
     for (i_v, v) in vulnerabilities.iter().enumerate() {
         if let Some(metrics) = v.get_metrics() {
             for (i_m, m) in metrics.iter().enumerate() {
-                if m.get_content().has_ssvc() {
+                // SSVC of unsupported schema versions is reported by 6.1.94
+                if m.get_content().has_ssvc() && !m.get_content().has_unsupported_ssvc_schema() {
                     match m.get_content().get_ssvc() {
                         Ok(ssvc) => {
                             for (i_s, selection) in ssvc.selections.iter().enumerate() {
@@ -36,34 +37,36 @@ pub fn test_6_1_48_ssvc_decision_points(doc: &impl CsafTrait) -> Result<(), Vec<
                                         {
                                             match reference_indices.get(v_key) {
                                                 None => {
-                                                    return Err(vec![ValidationError {
-                                                        message: format!(
+                                                    return Err(vec![ValidationError::new(
+                                                        "6.1.48",
+                                                        format!(
                                                             "The SSVC decision point '{}::{}' (version {}) doesn't have a value with key '{}'",
                                                             namespace,
                                                             dp.name.deref(),
                                                             version,
                                                             v_key
                                                         ),
-                                                        instance_path: format!(
+                                                        format!(
                                                             "/vulnerabilities/{}/metrics/{}/content/ssvc_v2/selections/{}/values/{}",
                                                             i_v, i_m, i_s, i_val
                                                         ),
-                                                    }]);
+                                                    )]);
                                                 },
                                                 Some(i_dp_val) => {
                                                     if last_index > *i_dp_val {
-                                                        return Err(vec![ValidationError {
-                                                            message: format!(
+                                                        return Err(vec![ValidationError::new(
+                                                            "6.1.48",
+                                                            format!(
                                                                 "The values for SSVC decision point '{}::{}' (version {}) are not in correct order",
                                                                 namespace,
                                                                 dp.name.deref(),
                                                                 version
                                                             ),
-                                                            instance_path: format!(
+                                                            format!(
                                                                 "/vulnerabilities/{}/metrics/{}/content/ssvc_v2/selections/{}/values/{}",
                                                                 i_v, i_m, i_s, i_val
                                                             ),
-                                                        }]);
+                                                        )]);
                                                     } else {
                                                         last_index = *i_dp_val;
                                                     }
@@ -72,25 +75,27 @@ pub fn test_6_1_48_ssvc_decision_points(doc: &impl CsafTrait) -> Result<(), Vec<
                                         }
                                     },
                                     None => {
-                                        return Err(vec![ValidationError {
-                                            message: format!(
+                                        return Err(vec![ValidationError::new(
+                                            "6.1.48",
+                                            format!(
                                                 "Unknown SSVC decision point '{}::{}' with version '{}'",
                                                 namespace, s_key, version
                                             ),
-                                            instance_path: format!(
+                                            format!(
                                                 "/vulnerabilities/{}/metrics/{}/content/ssvc_v2/selections/{}",
                                                 i_v, i_m, i_s
                                             ),
-                                        }]);
+                                        )]);
                                     },
                                 }
                             }
                         },
                         Err(err) => {
-                            return Err(vec![ValidationError {
-                                message: format!("Invalid SSVC object: {}", err),
-                                instance_path: format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2", i_v, i_m),
-                            }]);
+                            return Err(vec![ValidationError::new(
+                                "6.1.48",
+                                format!("Invalid SSVC object: {}", err),
+                                format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2", i_v, i_m),
+                            )]);
                         },
                     }
                 }
@@ -116,35 +121,59 @@ mod tests {
             "48",
             test_6_1_48_ssvc_decision_points,
             HashMap::from([
-                ("01", vec![ValidationError {
-                    message: "The SSVC decision point 'ssvc::Mission Impact' (version 1.0.0) doesn't have a value with key 'D'".to_string(),
-                    instance_path: format!("{}/values/1", instance_path),
-                }]),
-                ("02", vec![ValidationError {
-                    message: "Unknown SSVC decision point 'ssvc::SIs' with version '2.0.0'".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-                ("03", vec![ValidationError {
-                    message: "The values for SSVC decision point 'ssvc::Safety Impact' (version 2.0.0) are not in correct order".to_string(),
-                    instance_path: format!("{}/values/1", instance_path),
-                }]),
-                ("04", vec![ValidationError {
-                    message: "Unknown SSVC decision point 'ssvc::SI' with version '1.9.7'".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-                ("05", vec![ValidationError {
-                    message: "The SSVC decision point 'cvss::Attack Complexity' (version 3.0.1) doesn't have a value with key 'E'".to_string(),
-                    instance_path: "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/0/values/0".to_string(),
-                }]),
-                ("06", vec![ValidationError {
-                    message: "Unknown SSVC decision point 'cvss::E' with version '3.0.1'".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
+                (
+                    "01",
+                    vec![ValidationError::new(
+                        "6.1.48",
+                        "The SSVC decision point 'ssvc::Mission Impact' (version 1.0.0) doesn't have a value with key 'D'",
+                        format!("{}/values/1", instance_path),
+                    )],
+                ),
+                (
+                    "02",
+                    vec![ValidationError::new(
+                        "6.1.48",
+                        "Unknown SSVC decision point 'ssvc::SIs' with version '2.0.0'",
+                        instance_path.clone(),
+                    )],
+                ),
+                (
+                    "03",
+                    vec![ValidationError::new(
+                        "6.1.48",
+                        "The values for SSVC decision point 'ssvc::Safety Impact' (version 2.0.0) are not in correct order",
+                        format!("{}/values/1", instance_path),
+                    )],
+                ),
+                (
+                    "04",
+                    vec![ValidationError::new(
+                        "6.1.48",
+                        "Unknown SSVC decision point 'ssvc::SI' with version '1.9.7'",
+                        instance_path.clone(),
+                    )],
+                ),
+                (
+                    "05",
+                    vec![ValidationError::new(
+                        "6.1.48",
+                        "The SSVC decision point 'cvss::Attack Complexity' (version 3.0.1) doesn't have a value with key 'E'",
+                        "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections/0/values/0",
+                    )],
+                ),
+                (
+                    "06",
+                    vec![ValidationError::new(
+                        "6.1.48",
+                        "Unknown SSVC decision point 'cvss::E' with version '3.0.1'",
+                        instance_path.clone(),
+                    )],
+                ),
             ]),
             // Tests 07, 08, 09, 21 deal with complex SSVC namespace rules, skipped for now.
             // Test 16: There seems to be no Exploit Maturity (E) decision point version 3.0.1 in the SSVC repository, skipped for now.
             // Test 31: Erroneous JSON field "description", skipped for now.
-            &["07", "08", "09", "21", "16", "31"]
+            &["07", "08", "09", "21", "16", "31"],
         );
     }
 }
@@ -1,15 +1,15 @@
 use crate::csaf_traits::{
-    ContentTrait, CsafTrait, DocumentTrait, MetricTrait, RevisionTrait, TrackingTrait, VulnerabilityTrait,
+    ContentTrait, CsafTraitExt, DocumentTrait, MetricTrait, RevisionTrait, TrackingTrait, VulnerabilityTrait,
 };
 use crate::schema::csaf2_1::schema::DocumentStatus;
+use crate::timestamp::Timestamp;
 use crate::validation::ValidationError;
-use chrono::{DateTime, FixedOffset, Offset};
 
 /// 6.1.49 Inconsistent SSVC Timestamp
 ///
 /// For each vulnerability, it is tested that the SSVC `timestamp` is earlier or equal to the `date`
 /// of the newest item in the `revision_history` if the document status is `final` or `interim`.
-pub fn test_6_1_49_inconsistent_ssvc_timestamp(doc: &impl CsafTrait) -> Result<(), Vec<ValidationError>> {
+pub fn test_6_1_49_inconsistent_ssvc_timestamp(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
     let document = doc.get_document();
     let tracking = document.get_tracking();
     let status = tracking.get_status();
@@ -19,34 +19,24 @@ pub fn test_6_1_49_inconsistent_ssvc_timestamp(doc: &impl CsafTrait) -> Result<(
         return Ok(());
     }
 
-    // Parse the date of each revision and find the newest one
-    let mut newest_revision_date: Option<DateTime<FixedOffset>> = None;
-    for (i_r, revision) in tracking.get_revision_history().iter().enumerate() {
-        let date_str = revision.get_date();
-        match DateTime::parse_from_rfc3339(date_str) {
-            Ok(date) => {
-                newest_revision_date = match newest_revision_date {
-                    None => Some(date),
-                    Some(newest_date) => Some(newest_date.max(date)),
-                };
-            },
-            Err(_) => {
-                return Err(vec![ValidationError {
-                    message: format!("Invalid date format in revision history: {}", date_str),
-                    instance_path: format!("/document/tracking/revision_history/{}/date", i_r),
-                }]);
-            },
-        }
-    }
-
-    let newest_revision_date = match newest_revision_date {
-        Some(date) => date,
-        // No entries in revision history
-        None => {
-            return Err(vec![ValidationError {
-                message: "Revision history must not be empty for status final or interim".to_string(),
-                instance_path: "/document/tracking/revision_history".to_string(),
-            }]);
+    let newest_revision_date = match tracking.get_newest_revision_timestamp() {
+        Ok(Some(date)) => date,
+        Ok(None) => {
+            return Err(vec![ValidationError::new(
+                "6.1.49",
+                "Revision history must not be empty for status final or interim",
+                "/document/tracking/revision_history",
+            )]);
+        },
+        Err(i_r) => {
+            return Err(vec![ValidationError::new(
+                "6.1.49",
+                format!(
+                    "Invalid date format in revision history: {}",
+                    tracking.get_revision_history()[i_r].get_date()
+                ),
+                format!("/document/tracking/revision_history/{}/date", i_r),
+            )]);
         },
     };
 
@@ -54,27 +44,29 @@ pub fn test_6_1_49_inconsistent_ssvc_timestamp(doc: &impl CsafTrait) -> Result<(
     for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
         if let Some(metrics) = vulnerability.get_metrics() {
             for (i_m, metric) in metrics.iter().enumerate() {
-                if metric.get_content().has_ssvc() {
+                // SSVC of unsupported schema versions is reported by 6.1.94
+                if metric.get_content().has_ssvc() && !metric.get_content().has_unsupported_ssvc_schema() {
                     match metric.get_content().get_ssvc() {
                         Ok(ssvc) => {
-                                return Err(vec![ValidationError {
-                                    message: format!(
+                            if Timestamp::from(ssvc.timestamp) > newest_revision_date {
+                                return Err(vec![ValidationError::new(
+                                    "6.1.49",
+                                    format!(
                                         "SSVC timestamp ({}) for vulnerability at index {} is later than the newest revision date ({})",
                                         ssvc.timestamp.to_rfc3339(),
                                         i_v,
-                                        newest_revision_date.to_rfc3339()
-                                    ),
-                                    instance_path: format!(
-                                        "/vulnerabilities/{}/metrics/{}/content/ssvc_v2/timestamp",
-                                        i_v, i_m
+                                        newest_revision_date.0.to_rfc3339()
                                     ),
-                                }]);
+                                    format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2/timestamp", i_v, i_m),
+                                )]);
+                            }
                         },
                         Err(err) => {
-                            return Err(vec![ValidationError {
-                                message: format!("Invalid SSVC object: {}", err),
-                                instance_path: format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2", i_v, i_m),
-                            }]);
+                            return Err(vec![ValidationError::new(
+                                "6.1.49",
+                                format!("Invalid SSVC object: {}", err),
+                                format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2", i_v, i_m),
+                            )]);
                         },
                     }
                 }
@@ -100,19 +92,31 @@ mod tests {
             "49",
             test_6_1_49_inconsistent_ssvc_timestamp,
             HashMap::from([
-                ("01", vec![ValidationError {
-                    message: "SSVC timestamp (2024-07-13T10:00:00+00:00) for vulnerability at index 0 is later than the newest revision date (2024-01-24T10:00:00+00:00)".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-                ("02", vec![ValidationError {
-                    message: "SSVC timestamp (2024-02-29T10:30:00+00:00) for vulnerability at index 0 is later than the newest revision date (2024-02-29T10:00:00+00:00)".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-                ("03", vec![ValidationError {
-                    message: "SSVC timestamp (2024-02-29T10:30:00+00:00) for vulnerability at index 0 is later than the newest revision date (2024-02-29T10:00:00+00:00)".to_string(),
-                    instance_path: instance_path.clone(),
-                }]),
-            ])
+                (
+                    "01",
+                    vec![ValidationError::new(
+                        "6.1.49",
+                        "SSVC timestamp (2024-07-13T10:00:00+00:00) for vulnerability at index 0 is later than the newest revision date (2024-01-24T10:00:00+00:00)",
+                        instance_path.clone(),
+                    )],
+                ),
+                (
+                    "02",
+                    vec![ValidationError::new(
+                        "6.1.49",
+                        "SSVC timestamp (2024-02-29T10:30:00+00:00) for vulnerability at index 0 is later than the newest revision date (2024-02-29T10:00:00+00:00)",
+                        instance_path.clone(),
+                    )],
+                ),
+                (
+                    "03",
+                    vec![ValidationError::new(
+                        "6.1.49",
+                        "SSVC timestamp (2024-02-29T10:30:00+00:00) for vulnerability at index 0 is later than the newest revision date (2024-02-29T10:00:00+00:00)",
+                        instance_path.clone(),
+                    )],
+                ),
+            ]),
         );
     }
 }
@@ -0,0 +1,130 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, TrackingTrait};
+use crate::validation::ValidationError;
+use serde_json::Value;
+
+/// 6.1.51 Tracking ID Format
+///
+/// It is tested that the tracking ID of the document is not empty, has no leading or trailing
+/// whitespace and consists of printable ASCII characters only.
+pub fn test_6_1_51_tracking_id_format(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    check_tracking_id(doc.get_document().get_tracking().get_id())
+}
+
+/// Runs test 6.1.51 on the JSON of a document, e.g. before parsing it.
+///
+/// The schemas of both CSAF versions reject empty tracking IDs and tracking IDs with leading or
+/// trailing whitespace, so parsed documents can only fail for other characters. A missing
+/// tracking ID is reported as empty.
+pub fn check_raw_tracking_id(doc: &Value) -> Result<(), Vec<ValidationError>> {
+    let id = match doc.pointer("/document/tracking/id") {
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+        None => String::new(),
+    };
+    check_tracking_id(&id)
+}
+
+fn check_tracking_id(id: &str) -> Result<(), Vec<ValidationError>> {
+    let instance_path = "/document/tracking/id".to_string();
+
+    if id.is_empty() {
+        return Err(vec![ValidationError::new(
+            "6.1.51",
+            "Tracking ID must not be empty",
+            instance_path,
+        )]);
+    }
+
+    if id.trim() != id {
+        return Err(vec![ValidationError::new(
+            "6.1.51",
+            format!("Tracking ID '{}' must not have leading or trailing whitespace", id),
+            instance_path,
+        )]);
+    }
+
+    // Printable ASCII ranges from space (0x20) to tilde (0x7E)
+    if let Some(invalid_char) = id.chars().find(|c| !(' '..='~').contains(c)) {
+        return Err(vec![ValidationError::new(
+            "6.1.51",
+            format!(
+                "Tracking ID '{}' contains the character {:?}, only printable ASCII characters are allowed",
+                id, invalid_char
+            ),
+            instance_path,
+        )]);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_51::{check_raw_tracking_id, test_6_1_51_tracking_id_format};
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_51() {
+        let valid = mock_csaf21(|_| {});
+        assert_eq!(Ok(()), test_6_1_51_tracking_id_format(&valid));
+
+        // The schema rejects empty tracking IDs and leading or trailing whitespace
+        for (id, message) in [
+            (json!(""), "Tracking ID must not be empty".to_string()),
+            (
+                json!(" "),
+                "Tracking ID ' ' must not have leading or trailing whitespace".to_string(),
+            ),
+            (
+                json!("\t \n"),
+                "Tracking ID '\t \n' must not have leading or trailing whitespace".to_string(),
+            ),
+            (
+                json!(" MOCK-2024-0001 "),
+                "Tracking ID ' MOCK-2024-0001 ' must not have leading or trailing whitespace".to_string(),
+            ),
+        ] {
+            let raw = json!({ "document": { "tracking": { "id": id } } });
+            assert_eq!(
+                Err(vec![ValidationError::new("6.1.51", message, "/document/tracking/id")]),
+                check_raw_tracking_id(&raw)
+            );
+        }
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.51",
+                "Tracking ID must not be empty",
+                "/document/tracking/id"
+            )]),
+            check_raw_tracking_id(&json!({ "document": {} }))
+        );
+        assert_eq!(
+            Ok(()),
+            check_raw_tracking_id(&json!({ "document": { "tracking": { "id": "MOCK-2024-0001" } } }))
+        );
+
+        let non_ascii = mock_csaf21(|doc| doc["document"]["tracking"]["id"] = "MOCK-2024-0001-ü".into());
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.51",
+                "Tracking ID 'MOCK-2024-0001-ü' contains the character 'ü', only printable ASCII characters \
+                          are allowed",
+                "/document/tracking/id"
+            )]),
+            test_6_1_51_tracking_id_format(&non_ascii)
+        );
+
+        let control_char = mock_csaf21(|doc| doc["document"]["tracking"]["id"] = "MOCK-2024\t0001".into());
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.51",
+                "Tracking ID 'MOCK-2024\t0001' contains the character '\\t', only printable ASCII \
+                          characters are allowed",
+                "/document/tracking/id"
+            )]),
+            test_6_1_51_tracking_id_format(&control_char)
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::validation::ValidationError;
+use serde_json::Value;
+
+/// Product status categories defined by CSAF 2.0.
+static PRODUCT_STATUS_KEYS_2_0: &[&str] = &[
+    "first_affected",
+    "first_fixed",
+    "fixed",
+    "known_affected",
+    "known_not_affected",
+    "last_affected",
+    "recommended",
+    "under_investigation",
+];
+
+/// Product status categories defined by CSAF 2.1, which adds `unknown`.
+static PRODUCT_STATUS_KEYS_2_1: &[&str] = &[
+    "first_affected",
+    "first_fixed",
+    "fixed",
+    "known_affected",
+    "known_not_affected",
+    "last_affected",
+    "recommended",
+    "under_investigation",
+    "unknown",
+];
+
+/// 6.1.52 Product Status Categories
+///
+/// It is tested that every key of `product_status` is one of the categories defined by the
+/// CSAF version of the document, with exact spelling and capitalization.
+///
+/// The typed documents of CSAF 2.1 reject unknown keys during deserialization, and those of
+/// CSAF 2.0 drop them, so this test operates on the raw JSON value. It is executed while loading
+/// a document by [crate::parse::from_slice_lenient], the [Validator](crate::validation::validator::Validator)
+/// and the functions of [crate::validation::batch], see [strip_unknown_product_status_keys].
+pub fn test_6_1_52_product_status_keys(doc: &Value) -> Result<(), Vec<ValidationError>> {
+    let allowed = allowed_keys(doc);
+
+    let mut errors: Option<Vec<ValidationError>> = Option::None;
+
+    let vulnerabilities = doc.get("vulnerabilities").and_then(Value::as_array);
+    for (v_i, v) in vulnerabilities.into_iter().flatten().enumerate() {
+        let Some(product_status) = v.get("product_status").and_then(Value::as_object) else {
+            continue;
+        };
+        for key in product_status.keys() {
+            if !allowed.contains(&key.as_str()) {
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.52",
+                    format!(
+                        "Unknown product status category '{}', allowed categories are: {}",
+                        key,
+                        allowed.join(", ")
+                    ),
+                    format!("/vulnerabilities/{}/product_status", v_i),
+                ));
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Executes [test_6_1_52_product_status_keys] and removes the unknown categories it reports from
+/// `doc`, so that the document can be loaded into the typed schema afterwards.
+pub(crate) fn strip_unknown_product_status_keys(doc: &mut Value) -> Result<(), Vec<ValidationError>> {
+    let result = test_6_1_52_product_status_keys(doc);
+    if result.is_err() {
+        let allowed = allowed_keys(doc);
+        let vulnerabilities = doc.get_mut("vulnerabilities").and_then(Value::as_array_mut);
+        for v in vulnerabilities.into_iter().flatten() {
+            if let Some(product_status) = v.get_mut("product_status").and_then(Value::as_object_mut) {
+                product_status.retain(|key, _| allowed.contains(&key.as_str()));
+            }
+        }
+    }
+    result
+}
+
+/// Returns the product status categories of the CSAF version of `doc`.
+fn allowed_keys(doc: &Value) -> &'static [&'static str] {
+    match doc.pointer("/document/csaf_version").and_then(Value::as_str) {
+        Some("2.0") => PRODUCT_STATUS_KEYS_2_0,
+        _ => PRODUCT_STATUS_KEYS_2_1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_52::test_6_1_52_product_status_keys;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_52() {
+        let valid = json!({
+            "document": { "csaf_version": "2.1" },
+            "vulnerabilities": [
+                { "product_status": { "known_affected": ["CSAFPID-9080700"], "unknown": ["CSAFPID-9080701"] } }
+            ]
+        });
+        assert_eq!(Ok(()), test_6_1_52_product_status_keys(&valid));
+
+        let invalid = json!({
+            "document": { "csaf_version": "2.0" },
+            "vulnerabilities": [
+                { "product_status": { "fixed": ["CSAFPID-9080700"] } },
+                { "product_status": { "Known_Affected": ["CSAFPID-9080700"], "unknown": ["CSAFPID-9080701"] } }
+            ]
+        });
+        let allowed = "first_affected, first_fixed, fixed, known_affected, known_not_affected, last_affected, \
+                       recommended, under_investigation";
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.52",
+                    format!("Unknown product status category 'Known_Affected', allowed categories are: {allowed}"),
+                    "/vulnerabilities/1/product_status",
+                ),
+                ValidationError::new(
+                    "6.1.52",
+                    format!("Unknown product status category 'unknown', allowed categories are: {allowed}"),
+                    "/vulnerabilities/1/product_status",
+                ),
+            ]),
+            test_6_1_52_product_status_keys(&invalid)
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::csaf_traits::{CsafTraitExt, ProductStatusGroup, ProductStatusTrait, RemediationTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::CategoryOfTheRemediation;
+use crate::validation::{Severity, ValidationError};
+use std::collections::HashSet;
+
+/// 6.1.53 Vendor Fix for Not Affected Product
+///
+/// For each vulnerability, it is tested that no product referenced by a remediation of category
+/// `vendor_fix` (directly or via product groups) is listed in the product status group "not
+/// affected" (`known_not_affected`), as a product that is not affected needs no fix. Vendor fixes
+/// for products that are still listed as affected are fine, since the fix is how they get fixed.
+/// Findings are reported as warnings.
+pub fn test_6_1_53_vendor_fix_for_not_affected_product(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let not_affected: HashSet<String> = match v.get_product_status() {
+            Some(product_status) => product_status
+                .status_buckets()
+                .group(&ProductStatusGroup::NotAffected)
+                .into_iter()
+                .cloned()
+                .collect(),
+            None => HashSet::new(),
+        };
+
+        for (r_i, r) in v.get_remediations().iter().enumerate() {
+            if r.get_category() != CategoryOfTheRemediation::VendorFix {
+                continue;
+            }
+            let Some(product_ids) = r.get_all_product_ids(doc) else {
+                continue;
+            };
+            for product_id in product_ids {
+                if not_affected.contains(&product_id) {
+                    errors.get_or_insert_with(Vec::new).push(
+                        ValidationError::new(
+                            "6.1.53",
+                            format!(
+                                "Product {} is referenced by a vendor fix but is listed as not affected",
+                                product_id
+                            ),
+                            format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                        )
+                        .with_severity(Severity::Warning),
+                    );
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::csaf2_1::schema::CommonSecurityAdvisoryFramework;
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_53::test_6_1_53_vendor_fix_for_not_affected_product;
+    use serde_json::json;
+
+    fn mock_document(
+        product_status: serde_json::Value,
+        remediation: serde_json::Value,
+    ) -> CommonSecurityAdvisoryFramework {
+        mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [
+                    { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                    { "name": "Product B", "product_id": "CSAFPID-9080701" }
+                ],
+                "product_groups": [
+                    { "group_id": "CSAFGID-1020300", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] }
+                ]
+            });
+            doc["vulnerabilities"] = json!([{ "product_status": product_status, "remediations": [remediation] }]);
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_53() {
+        let fixed = mock_document(
+            json!({ "fixed": ["CSAFPID-9080700"], "first_fixed": ["CSAFPID-9080701"] }),
+            json!({ "category": "vendor_fix", "details": "Update.", "group_ids": ["CSAFGID-1020300"] }),
+        );
+        assert_eq!(Ok(()), test_6_1_53_vendor_fix_for_not_affected_product(&fixed));
+
+        // The vendor fix is how affected products get fixed
+        let still_affected = mock_document(
+            json!({ "known_affected": ["CSAFPID-9080700"], "fixed": ["CSAFPID-9080701"] }),
+            json!({ "category": "vendor_fix", "details": "Update.", "group_ids": ["CSAFGID-1020300"] }),
+        );
+        assert_eq!(Ok(()), test_6_1_53_vendor_fix_for_not_affected_product(&still_affected));
+
+        let other_category = mock_document(
+            json!({ "known_not_affected": ["CSAFPID-9080700"] }),
+            json!({ "category": "workaround", "details": "Disable it.", "product_ids": ["CSAFPID-9080700"] }),
+        );
+        assert_eq!(Ok(()), test_6_1_53_vendor_fix_for_not_affected_product(&other_category));
+
+        let not_affected = mock_document(
+            json!({ "known_affected": ["CSAFPID-9080700"], "known_not_affected": ["CSAFPID-9080701"] }),
+            json!({ "category": "vendor_fix", "details": "Update.", "group_ids": ["CSAFGID-1020300"] }),
+        );
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.53",
+                    "Product CSAFPID-9080701 is referenced by a vendor fix but is listed as not affected",
+                    "/vulnerabilities/0/remediations/0",
+                )
+                .with_severity(Severity::Warning)
+            ]),
+            test_6_1_53_vendor_fix_for_not_affected_product(&not_affected)
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, ThreatTrait, TrackingTrait, VulnerabilityTrait};
+use crate::timestamp::Timestamp;
+use crate::validation::ValidationError;
+
+/// 6.1.54 Threat Date After Current Release Date
+///
+/// For each vulnerability, it is tested that the `date` of every threat is not later than the
+/// `current_release_date` of the document. Threats without a date are skipped. Dates that cannot
+/// be parsed are skipped as well, as they are reported by test 6.1.37.
+pub fn test_6_1_54_threat_date_after_current_release_date(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let tracking = doc.get_document().get_tracking();
+    let current_release_date = tracking.get_current_release_date();
+    let Some(current_release) = tracking.get_current_release_timestamp() else {
+        return Ok(());
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = Option::None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (t_i, threat) in v.get_threats().iter().enumerate() {
+            let Some(date) = threat.get_date() else {
+                continue;
+            };
+            if let Ok(threat_date) = Timestamp::parse(date) {
+                if threat_date > current_release {
+                    errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                        "6.1.54",
+                        format!(
+                            "Threat {} has date {} which is later than the current release date {}",
+                            t_i, date, current_release_date
+                        ),
+                        format!("/vulnerabilities/{}/threats/{}/date", v_i, t_i),
+                    ));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_54::test_6_1_54_threat_date_after_current_release_date;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_54() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "threats": [
+                    { "category": "impact", "details": "No date." },
+                    { "category": "impact", "details": "Same date.", "date": "2024-01-24T10:00:00.000Z" },
+                    { "category": "impact", "details": "Earlier date.", "date": "2024-01-24T11:00:00+02:00" }
+                ]
+            }]);
+        });
+        assert_eq!(Ok(()), test_6_1_54_threat_date_after_current_release_date(&valid));
+
+        let invalid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([
+                { "threats": [{ "category": "impact", "details": "Earlier.", "date": "2024-01-23T10:00:00.000Z" }] },
+                { "threats": [
+                    { "category": "impact", "details": "Earlier.", "date": "2024-01-23T10:00:00.000Z" },
+                    { "category": "exploit_status", "details": "Later.", "date": "2024-01-24T10:00:01.000Z" }
+                ] }
+            ]);
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.54",
+                "Threat 1 has date 2024-01-24T10:00:01.000Z which is later than the current release date \
+                 2024-01-24T10:00:00.000Z",
+                "/vulnerabilities/1/threats/1/date",
+            )]),
+            test_6_1_54_threat_date_after_current_release_date(&invalid)
+        );
+    }
+}
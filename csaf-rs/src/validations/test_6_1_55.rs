@@ -0,0 +1,72 @@
+use crate::cpe::Cpe;
+use crate::csaf_traits::{CsafTraitExt, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
+use crate::validation::ValidationError;
+
+/// 6.1.55 Invalid CPE
+///
+/// It is tested that the `cpe` of every product identification helper is a well-formed CPE 2.3
+/// formatted string or CPE URI.
+pub fn test_6_1_55_invalid_cpe(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_products(&mut |product, path| {
+            if let Some(cpe) = product
+                .get_product_identification_helper()
+                .as_ref()
+                .and_then(|helper| helper.get_cpe())
+            {
+                if let Err(e) = Cpe::parse(cpe) {
+                    errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                        "6.1.55",
+                        format!("Invalid CPE '{}': {}", cpe, e),
+                        format!("{}/product_identification_helper/cpe", path),
+                    ));
+                }
+            }
+        });
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_55::test_6_1_55_invalid_cpe;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_55() {
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [
+                    {
+                        "name": "Product A",
+                        "product_id": "CSAFPID-9080700",
+                        "product_identification_helper": { "cpe": "cpe:2.3:a:vendor:product:1.0:*:*:*:*:*:*:*" }
+                    },
+                    {
+                        "name": "Product B",
+                        "product_id": "CSAFPID-9080701",
+                        "product_identification_helper": { "cpe": "cpe:/a:vendor:product:1.0" }
+                    },
+                    {
+                        "name": "Product C",
+                        "product_id": "CSAFPID-9080702",
+                        "product_identification_helper": { "cpe": "cpe:/a:vendor:product%zz" }
+                    }
+                ]
+            });
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.55",
+                "Invalid CPE 'cpe:/a:vendor:product%zz': CPE component 2 contains the illegal character '%'",
+                "/product_tree/full_product_names/2/product_identification_helper/cpe",
+            )]),
+            test_6_1_55_invalid_cpe(&doc)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::csaf_traits::{CsafTraitExt, ProductTrait, ProductTreeTrait, RelationshipTrait};
+use crate::validation::{Severity, ValidationError};
+
+/// 6.1.56 Relationship Product Name Not Composed
+///
+/// For each relationship, it is tested that the name of the resulting full product name is not
+/// empty and differs from the names of both referenced products, as a name identical to one of its
+/// components suggests that it was not composed from them. Findings are emitted as warnings.
+pub fn test_6_1_56_relationship_product_name(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let Some(product_tree) = doc.get_product_tree().as_ref() else {
+        return Ok(());
+    };
+    let product_names = product_tree.get_product_names();
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (r_i, relationship) in product_tree.get_relationships().iter().enumerate() {
+        let full_product_name = relationship.get_full_product_name();
+        let name = full_product_name.get_name();
+        let instance_path = format!("/product_tree/relationships/{}", r_i);
+
+        if name.trim().is_empty() {
+            errors.get_or_insert_with(Vec::new).push(
+                ValidationError::new(
+                    "6.1.56",
+                    format!(
+                        "Relationship product {} has an empty name",
+                        full_product_name.get_product_id()
+                    ),
+                    instance_path,
+                )
+                .with_severity(Severity::Warning),
+            );
+            continue;
+        }
+
+        for reference in [
+            relationship.get_product_reference(),
+            relationship.get_relates_to_product_reference(),
+        ] {
+            if product_names.get(reference) == Some(name) {
+                errors.get_or_insert_with(Vec::new).push(
+                    ValidationError::new(
+                        "6.1.56",
+                        format!(
+                            "Relationship product {} has the same name '{}' as its component {}",
+                            full_product_name.get_product_id(),
+                            name,
+                            reference
+                        ),
+                        instance_path.clone(),
+                    )
+                    .with_severity(Severity::Warning),
+                );
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_56::test_6_1_56_relationship_product_name;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_56() {
+        let doc = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [
+                    { "name": "Example Product 1.0", "product_id": "CSAFPID-9080700" },
+                    { "name": "Example OS 2.0", "product_id": "CSAFPID-9080701" }
+                ],
+                "relationships": [
+                    {
+                        "category": "installed_on",
+                        "full_product_name": {
+                            "name": "Example Product 1.0 installed on Example OS 2.0",
+                            "product_id": "CSAFPID-9080702"
+                        },
+                        "product_reference": "CSAFPID-9080700",
+                        "relates_to_product_reference": "CSAFPID-9080701"
+                    },
+                    {
+                        "category": "installed_on",
+                        "full_product_name": { "name": "Example Product 1.0", "product_id": "CSAFPID-9080703" },
+                        "product_reference": "CSAFPID-9080700",
+                        "relates_to_product_reference": "CSAFPID-9080701"
+                    }
+                ]
+            });
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.56",
+                    "Relationship product CSAFPID-9080703 has the same name 'Example Product 1.0' as its component \
+                     CSAFPID-9080700",
+                    "/product_tree/relationships/1",
+                )
+                .with_severity(Severity::Warning)
+            ]),
+            test_6_1_56_relationship_product_name(&doc)
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use crate::csaf_traits::CsafTraitExt;
+use crate::validation::ValidationError;
+use crate::walk::UrlKind;
+use url::Url;
+
+/// 6.1.57 Relative Acknowledgment URL
+///
+/// It is tested that every URL of an acknowledgment, both on document and on vulnerability
+/// level, is an absolute URL.
+pub fn test_6_1_57_acknowledgment_urls_absolute(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (url, path) in doc.iter_urls(UrlKind::Acknowledgment) {
+        if let Err(e) = Url::parse(&url) {
+            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                "6.1.57",
+                format!("Acknowledgment URL '{}' is not an absolute URL: {}", url, e),
+                path,
+            ));
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_57::test_6_1_57_acknowledgment_urls_absolute;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_57() {
+        let valid = mock_csaf20(|doc| {
+            doc["document"]["acknowledgments"] =
+                json!([{ "names": ["Jane Doe"], "urls": ["https://www.example.com/"] }]);
+        });
+        assert_eq!(Ok(()), test_6_1_57_acknowledgment_urls_absolute(&valid));
+
+        let invalid = mock_csaf21(|doc| {
+            doc["document"]["acknowledgments"] = json!([{ "urls": ["https://www.example.com/", "/credits"] }]);
+            doc["vulnerabilities"] = json!([
+                { "acknowledgments": [
+                    { "names": ["Jane Doe"] },
+                    { "organization": "Example Org", "urls": ["www.example.com/thanks"] }
+                ] }
+            ]);
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.57",
+                    "Acknowledgment URL '/credits' is not an absolute URL: relative URL without a base",
+                    "/document/acknowledgments/0/urls/1",
+                ),
+                ValidationError::new(
+                    "6.1.57",
+                    "Acknowledgment URL 'www.example.com/thanks' is not an absolute URL: relative URL without a base",
+                    "/vulnerabilities/0/acknowledgments/1/urls/0",
+                ),
+            ]),
+            test_6_1_57_acknowledgment_urls_absolute(&invalid)
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait};
+use crate::helpers::normalize_language_tag;
+use crate::validation::ValidationError;
+
+/// 6.1.58 Translation Into Source Language
+///
+/// A document with a `source_lang` is a translation. For such a document, it is tested that the
+/// `source_lang` differs from the `lang` of the document, as a translation into its own source
+/// language is degenerate. Language tags are compared after normalization.
+pub fn test_6_1_58_translation_into_source_language(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+    let (Some(source_lang), Some(lang)) = (document.get_source_lang(), document.get_lang()) else {
+        return Ok(());
+    };
+
+    if normalize_language_tag(source_lang) == normalize_language_tag(lang) {
+        return Err(vec![ValidationError::new(
+            "6.1.58",
+            format!(
+                "The source language {} of the translation is the same as the document language {}",
+                source_lang, lang
+            ),
+            "/document/source_lang",
+        )]);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::normalize_language_tag;
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_58::test_6_1_58_translation_into_source_language;
+
+    #[test]
+    fn test_test_6_1_58() {
+        assert_eq!(normalize_language_tag(" EN-latn-us "), "en-Latn-US");
+
+        let no_translation = mock_csaf21(|doc| doc["document"]["lang"] = "en-US".into());
+        assert_eq!(Ok(()), test_6_1_58_translation_into_source_language(&no_translation));
+
+        let translation = mock_csaf21(|doc| {
+            doc["document"]["lang"] = "de-DE".into();
+            doc["document"]["source_lang"] = "en-US".into();
+        });
+        assert_eq!(Ok(()), test_6_1_58_translation_into_source_language(&translation));
+
+        let degenerate = mock_csaf21(|doc| {
+            doc["document"]["lang"] = "en-US".into();
+            doc["document"]["source_lang"] = "EN-us".into();
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.58",
+                "The source language EN-us of the translation is the same as the document language en-US",
+                "/document/source_lang",
+            )]),
+            test_6_1_58_translation_into_source_language(&degenerate)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::csaf_traits::{
+    ContentTrait, CsafTraitExt, MetricTrait, ProductStatusGroup, ProductStatusTrait, StatusCategory,
+    VulnerabilityMetric, VulnerabilityTrait, get_metric_prop_name,
+};
+use crate::validation::ValidationError;
+use std::collections::BTreeSet;
+
+/// 6.1.59 Scored Product Not Affected
+///
+/// For each vulnerability, it is tested that no product with a CVSS base score is listed as
+/// known not affected without also being listed as affected, as scoring a product that is not
+/// affected is contradictory.
+pub fn test_6_1_59_scored_product_not_affected(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let (Some(product_status), Some(metrics)) = (v.get_product_status(), v.get_metrics()) else {
+            continue;
+        };
+        let buckets = product_status.status_buckets();
+        let affected = buckets.group(&ProductStatusGroup::Affected);
+        let not_affected: BTreeSet<&String> = buckets
+            .ids(StatusCategory::KnownNotAffected)
+            .difference(&affected)
+            .copied()
+            .collect();
+        if not_affected.is_empty() {
+            continue;
+        }
+
+        for (m_i, metric) in metrics.iter().enumerate() {
+            let Some(score_path) = get_base_score_path(metric.get_content(), v_i, m_i) else {
+                continue;
+            };
+            for product_id in metric.get_products() {
+                if !not_affected.contains(product_id) {
+                    continue;
+                }
+                for (x_i, status_id) in product_status
+                    .ids_for(StatusCategory::KnownNotAffected)
+                    .iter()
+                    .enumerate()
+                {
+                    if *status_id == product_id {
+                        errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                            "6.1.59",
+                            format!(
+                                "Product {} has a CVSS base score at {} but is only listed as known not affected",
+                                product_id, score_path
+                            ),
+                            format!("/vulnerabilities/{}/product_status/known_not_affected/{}", v_i, x_i),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Returns the path of the first CVSS base score of a metric content, if it has any.
+fn get_base_score_path(content: &impl ContentTrait, v_i: usize, m_i: usize) -> Option<String> {
+    let cvss = [
+        (VulnerabilityMetric::CvssV2, content.get_cvss_v2()),
+        (VulnerabilityMetric::CvssV3(String::new()), content.get_cvss_v3()),
+        (VulnerabilityMetric::CvssV4, content.get_cvss_v4()),
+    ];
+    cvss.into_iter().find_map(|(metric, cvss)| {
+        cvss.filter(|c| c.contains_key("baseScore")).map(|_| {
+            format!(
+                "{}/{}/baseScore",
+                content.get_content_json_path(v_i, m_i),
+                get_metric_prop_name(metric)
+            )
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_59::test_6_1_59_scored_product_not_affected;
+    use serde_json::json;
+
+    fn cvss_v3() -> serde_json::Value {
+        json!({
+            "version": "3.1",
+            "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+            "baseScore": 9.8,
+            "baseSeverity": "CRITICAL"
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_59() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "product_status": {
+                    "known_affected": ["CSAFPID-9080700", "CSAFPID-9080701"],
+                    "known_not_affected": ["CSAFPID-9080701", "CSAFPID-9080702"]
+                },
+                "metrics": [{ "content": { "cvss_v3": cvss_v3() }, "products": ["CSAFPID-9080700", "CSAFPID-9080701"] }]
+            }]);
+        });
+        assert_eq!(Ok(()), test_6_1_59_scored_product_not_affected(&valid));
+
+        let invalid_21 = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "product_status": {
+                    "known_affected": ["CSAFPID-9080700"],
+                    "known_not_affected": ["CSAFPID-9080701", "CSAFPID-9080702"]
+                },
+                "metrics": [
+                    { "content": { "epss": { "percentile": "0.5", "probability": "0.5", "timestamp": "2024-01-24T10:00:00.000Z" } }, "products": ["CSAFPID-9080701"] },
+                    { "content": { "cvss_v3": cvss_v3() }, "products": ["CSAFPID-9080700", "CSAFPID-9080702"] }
+                ]
+            }]);
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.59",
+                "Product CSAFPID-9080702 has a CVSS base score at /vulnerabilities/0/metrics/1/content/cvss_v3/baseScore \
+                 but is only listed as known not affected",
+                "/vulnerabilities/0/product_status/known_not_affected/1",
+            )]),
+            test_6_1_59_scored_product_not_affected(&invalid_21)
+        );
+
+        let invalid_20 = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "product_status": { "known_not_affected": ["CSAFPID-9080700"] },
+                "scores": [{ "cvss_v3": cvss_v3(), "products": ["CSAFPID-9080700"] }]
+            }]);
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.59",
+                "Product CSAFPID-9080700 has a CVSS base score at /vulnerabilities/0/scores/0/cvss_v3/baseScore \
+                 but is only listed as known not affected",
+                "/vulnerabilities/0/product_status/known_not_affected/0",
+            )]),
+            test_6_1_59_scored_product_not_affected(&invalid_20)
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, NoteTrait};
+use crate::profile::Profile;
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::ValidationError;
+
+/// 6.1.60 Required Document Note Category
+///
+/// Depending on the [Profile] selected by `/document/category`, documents must have at least one
+/// entry in `/document/notes` with one of the following `category` values:
+///
+/// * `csaf_security_advisory`: `summary` or `description`
+/// * `csaf_informational_advisory`: `description`
+///
+/// Documents with other categories, including `csaf_base`, are skipped.
+pub fn test_6_1_60_required_document_note_category(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let (required, expected): (&[NoteCategory], &str) = match Profile::of(doc) {
+        Profile::SecurityAdvisory => (
+            &[NoteCategory::Summary, NoteCategory::Description],
+            "'summary' or 'description'",
+        ),
+        Profile::InformationalAdvisory => (&[NoteCategory::Description], "'description'"),
+        _ => return Ok(()),
+    };
+
+    let found_required_note = doc
+        .get_document()
+        .get_notes()
+        .is_some_and(|notes| notes.iter().any(|note| required.contains(&note.get_category())));
+
+    if !found_required_note {
+        return Err(vec![ValidationError::new(
+            "6.1.60",
+            format!(
+                "Document with category '{}' must have at least one document note with category {}",
+                doc.get_document().get_category(),
+                expected
+            ),
+            "/document/notes",
+        )]);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_60::test_6_1_60_required_document_note_category;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_60() {
+        let base = mock_csaf21(|_| {});
+        assert_eq!(Ok(()), test_6_1_60_required_document_note_category(&base));
+
+        let advisory_with_summary = mock_csaf21(|doc| {
+            doc["document"]["category"] = "csaf_security_advisory".into();
+            doc["document"]["notes"] = json!([{ "category": "summary", "text": "Summary." }]);
+        });
+        assert_eq!(
+            Ok(()),
+            test_6_1_60_required_document_note_category(&advisory_with_summary)
+        );
+
+        let advisory_without_notes = mock_csaf20(|doc| {
+            doc["document"]["category"] = "csaf_security_advisory".into();
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.60",
+                "Document with category 'csaf_security_advisory' must have at least one document note with category \
+                 'summary' or 'description'",
+                "/document/notes",
+            )]),
+            test_6_1_60_required_document_note_category(&advisory_without_notes)
+        );
+
+        let informational_with_summary = mock_csaf21(|doc| {
+            doc["document"]["category"] = "csaf_informational_advisory".into();
+            doc["document"]["notes"] = json!([{ "category": "summary", "text": "Summary." }]);
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.60",
+                "Document with category 'csaf_informational_advisory' must have at least one document note with \
+                 category 'description'",
+                "/document/notes",
+            )]),
+            test_6_1_60_required_document_note_category(&informational_with_summary)
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use crate::csaf_traits::{CsafTraitExt, VulnerabilityTrait};
+use crate::validation::{Severity, ValidationError};
+
+/// 6.1.61 Vulnerability Order
+///
+/// It is tested that vulnerabilities with a CVE are listed in ascending order of their CVE, which
+/// keeps diffs between revisions small. As the order is not mandated, only the first inversion is
+/// reported as a warning. Vulnerabilities without CVE are ignored.
+///
+/// Publishers that intentionally order vulnerabilities otherwise, e.g. by severity, can skip
+/// this test.
+pub fn test_6_1_61_vulnerability_order(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let cves: Vec<(usize, &String, (u32, u64))> = doc
+        .get_vulnerabilities()
+        .iter()
+        .enumerate()
+        .filter_map(|(v_i, v)| v.get_cve().and_then(|cve| parse_cve(cve).map(|key| (v_i, cve, key))))
+        .collect();
+
+    for pair in cves.windows(2) {
+        let (_, previous_cve, previous_key) = pair[0];
+        let (v_i, cve, key) = pair[1];
+        if key < previous_key {
+            return Err(vec![
+                ValidationError::new(
+                    "6.1.61",
+                    format!(
+                        "Vulnerability {} is listed after {}, vulnerabilities should be ordered by CVE ascending",
+                        cve, previous_cve
+                    ),
+                    format!("/vulnerabilities/{}/cve", v_i),
+                )
+                .with_severity(Severity::Warning),
+            ]);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the year and the sequence number of a CVE ID, which sort numerically.
+fn parse_cve(cve: &str) -> Option<(u32, u64)> {
+    let mut parts = cve.strip_prefix("CVE-")?.splitn(2, '-');
+    let year = parts.next()?.parse().ok()?;
+    let number = parts.next()?.parse().ok()?;
+    Some((year, number))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_61::test_6_1_61_vulnerability_order;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_61() {
+        let ordered = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([
+                { "cve": "CVE-2023-9999" },
+                { "cve": "CVE-2024-9999" },
+                { "title": "No CVE" },
+                { "cve": "CVE-2024-10000" }
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_61_vulnerability_order(&ordered));
+
+        let unordered = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([
+                { "cve": "CVE-2024-10000" },
+                { "title": "No CVE" },
+                { "cve": "CVE-2024-9999" },
+                { "cve": "CVE-2023-0001" }
+            ]);
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.61",
+                    "Vulnerability CVE-2024-9999 is listed after CVE-2024-10000, vulnerabilities should be ordered by \
+                     CVE ascending",
+                    "/vulnerabilities/2/cve",
+                )
+                .with_severity(Severity::Warning)
+            ]),
+            test_6_1_61_vulnerability_order(&unordered)
+        );
+    }
+}
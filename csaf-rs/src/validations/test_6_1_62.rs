@@ -0,0 +1,87 @@
+use crate::csaf_traits::{CsafTraitExt, RemediationTrait, VulnerabilityTrait};
+use crate::timestamp::Timestamp;
+use crate::validation::ValidationError;
+
+/// 6.1.62 Remediation Date Before Discovery Date
+///
+/// For each vulnerability, it is tested that the `date` of every remediation is not earlier than
+/// the `discovery_date` of the vulnerability. Remediations without a date and vulnerabilities
+/// without a discovery date are skipped, as are dates that cannot be parsed.
+pub fn test_6_1_62_remediation_date_before_discovery_date(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(discovery_date) = v.get_discovery_date() else {
+            continue;
+        };
+        let Ok(discovery) = Timestamp::parse(discovery_date) else {
+            continue;
+        };
+
+        for (r_i, remediation) in v.get_remediations().iter().enumerate() {
+            let Some(date) = remediation.get_date() else {
+                continue;
+            };
+            if let Ok(remediation_date) = Timestamp::parse(date) {
+                if remediation_date < discovery {
+                    errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                        "6.1.62",
+                        format!(
+                            "Remediation {} has date {} which is earlier than the discovery date {}",
+                            r_i, date, discovery_date
+                        ),
+                        format!("/vulnerabilities/{}/remediations/{}/date", v_i, r_i),
+                    ));
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_62::test_6_1_62_remediation_date_before_discovery_date;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_62() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([
+                { "remediations": [
+                    { "category": "vendor_fix", "details": "No discovery date.", "date": "2024-01-01T10:00:00.000Z", "product_ids": ["CSAFPID-9080700"] }
+                ] },
+                {
+                    "discovery_date": "2024-01-10T10:00:00.000Z",
+                    "remediations": [
+                        { "category": "vendor_fix", "details": "No date.", "product_ids": ["CSAFPID-9080700"] },
+                        { "category": "vendor_fix", "details": "Same date.", "date": "2024-01-10T12:00:00+02:00", "product_ids": ["CSAFPID-9080700"] }
+                    ]
+                }
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_62_remediation_date_before_discovery_date(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "discovery_date": "2024-01-10T10:00:00.000Z",
+                "remediations": [
+                    { "category": "vendor_fix", "details": "Later.", "date": "2024-01-11T10:00:00.000Z", "product_ids": ["CSAFPID-9080700"] },
+                    { "category": "vendor_fix", "details": "Earlier.", "date": "2024-01-10T09:59:59.000Z", "product_ids": ["CSAFPID-9080700"] }
+                ]
+            }]);
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.62",
+                "Remediation 1 has date 2024-01-10T09:59:59.000Z which is earlier than the discovery date \
+                 2024-01-10T10:00:00.000Z",
+                "/vulnerabilities/0/remediations/1/date",
+            )]),
+            test_6_1_62_remediation_date_before_discovery_date(&invalid)
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, PublisherTrait};
+use crate::profile::Profile;
+use crate::schema::csaf2_1::schema::CategoryOfPublisher;
+use crate::validation::ValidationError;
+
+/// Allowed publisher categories per profile, profiles not listed allow any publisher
+const ALLOWED_PUBLISHER_CATEGORIES: &[(Profile, &[CategoryOfPublisher])] = &[(
+    Profile::Vex,
+    &[CategoryOfPublisher::Vendor, CategoryOfPublisher::Coordinator],
+)];
+
+/// 6.1.63 Publisher Category Per Profile
+///
+/// For profiles that restrict who may publish them, it is tested that the category of
+/// `/document/publisher` is allowed. E.g. a VEX document must be published by a `vendor` or a
+/// `coordinator`, as only they can make statements about the status of products.
+pub fn test_6_1_63_publisher_category_per_profile(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+    let profile = Profile::of(doc);
+    let Some((_, allowed)) = ALLOWED_PUBLISHER_CATEGORIES
+        .iter()
+        .find(|(allowed_profile, _)| *allowed_profile == profile)
+    else {
+        return Ok(());
+    };
+
+    let publisher_category = document.get_publisher().get_category();
+    if !allowed.contains(&publisher_category) {
+        return Err(vec![ValidationError::new(
+            "6.1.63",
+            format!(
+                "Document with category '{}' must not have a publisher with category '{}', allowed categories are: {}",
+                document.get_category(),
+                publisher_category,
+                allowed.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(", ")
+            ),
+            "/document/publisher/category",
+        )]);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_63::test_6_1_63_publisher_category_per_profile;
+
+    #[test]
+    fn test_test_6_1_63() {
+        let base_by_user = mock_csaf21(|doc| doc["document"]["publisher"]["category"] = "user".into());
+        assert_eq!(Ok(()), test_6_1_63_publisher_category_per_profile(&base_by_user));
+
+        let vex_by_vendor = mock_csaf21(|doc| {
+            doc["document"]["category"] = "csaf_vex".into();
+            doc["document"]["publisher"]["category"] = "vendor".into();
+        });
+        assert_eq!(Ok(()), test_6_1_63_publisher_category_per_profile(&vex_by_vendor));
+
+        let vex_by_user = mock_csaf20(|doc| {
+            doc["document"]["category"] = "csaf_vex".into();
+            doc["document"]["publisher"]["category"] = "user".into();
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.63",
+                "Document with category 'csaf_vex' must not have a publisher with category 'user', allowed \
+                 categories are: vendor, coordinator",
+                "/document/publisher/category",
+            )]),
+            test_6_1_63_publisher_category_per_profile(&vex_by_user)
+        );
+    }
+}
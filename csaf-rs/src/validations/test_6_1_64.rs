@@ -0,0 +1,105 @@
+use crate::csaf_traits::{CsafTraitExt, FileHashTrait, HashTrait, ProductTrait, ProductTreeTrait};
+use crate::validation::ValidationError;
+
+/// Expected number of hex digits of the hash value per known hash algorithm
+pub(crate) const HASH_LENGTHS: &[(&str, usize)] = &[("md5", 32), ("sha1", 40), ("sha256", 64), ("sha512", 128)];
+
+/// 6.1.64 Hash Value Format
+///
+/// For `*/file_hashes[]` with a known `algorithm`, it is tested that `value` is a lowercase hex
+/// string with the length of a digest of that algorithm. Values of unknown algorithms are not
+/// checked.
+pub fn test_6_1_64_hash_value_format(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_products(&mut |product, path| {
+            product.visit_hashes(path, &mut |hash, hash_path| {
+                for (file_hash_i, file_hash) in hash.get_file_hashes().iter().enumerate() {
+                    let algorithm = file_hash.get_algorithm().to_lowercase();
+                    let Some((_, length)) = HASH_LENGTHS.iter().find(|(a, _)| *a == algorithm) else {
+                        continue;
+                    };
+                    let value = file_hash.get_hash();
+                    let valid = value.len() == *length && value.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'));
+                    if !valid {
+                        errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                            "6.1.64",
+                            format!(
+                                "Hash value '{}' is not a valid {} digest, expected {} lowercase hex characters",
+                                value, algorithm, length
+                            ),
+                            format!("{}/file_hashes/{}/value", hash_path, file_hash_i),
+                        ));
+                    }
+                }
+            });
+        });
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_64::test_6_1_64_hash_value_format;
+    use serde_json::json;
+
+    fn product_tree(file_hashes: serde_json::Value) -> serde_json::Value {
+        json!({
+            "full_product_names": [{
+                "name": "Product A",
+                "product_id": "CSAFPID-9080700",
+                "product_identification_helper": {
+                    "hashes": [{ "filename": "product_a.so", "file_hashes": file_hashes }]
+                }
+            }]
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_64() {
+        let valid = mock_csaf21(|doc| {
+            doc["product_tree"] = product_tree(json!([
+                { "algorithm": "sha256", "value": "026a37919b182ef7c63791e82c9645e2f897a3f0b73c7a6028c7febf62e93838" },
+                { "algorithm": "md5", "value": "d41d8cd98f00b204e9800998ecf8427e" },
+                { "algorithm": "blake2b512", "value": "da39a3ee5e6b4b0d3255bfef95601890afd80709" }
+            ]))
+        });
+        assert_eq!(Ok(()), test_6_1_64_hash_value_format(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["product_tree"] = product_tree(json!([
+                { "algorithm": "sha256", "value": "026A37919B182EF7C63791E82C9645E2F897A3F0B73C7A6028C7FEBF62E93838" },
+                { "algorithm": "sha1", "value": "da39a3ee5e6b4b0d3255bfef95601890afd807" },
+                { "algorithm": "sha512", "value": "ab".repeat(63) }
+            ]))
+        });
+        let path = "/product_tree/full_product_names/0/product_identification_helper/hashes/0/file_hashes";
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.64",
+                    "Hash value '026A37919B182EF7C63791E82C9645E2F897A3F0B73C7A6028C7FEBF62E93838' is not a valid \
+                     sha256 digest, expected 64 lowercase hex characters",
+                    format!("{}/0/value", path),
+                ),
+                ValidationError::new(
+                    "6.1.64",
+                    "Hash value 'da39a3ee5e6b4b0d3255bfef95601890afd807' is not a valid sha1 digest, expected 40 \
+                     lowercase hex characters",
+                    format!("{}/1/value", path),
+                ),
+                ValidationError::new(
+                    "6.1.64",
+                    format!(
+                        "Hash value '{}' is not a valid sha512 digest, expected 128 lowercase hex characters",
+                        "ab".repeat(63)
+                    ),
+                    format!("{}/2/value", path),
+                ),
+            ]),
+            test_6_1_64_hash_value_format(&invalid)
+        );
+    }
+}
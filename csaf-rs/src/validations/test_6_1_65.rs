@@ -0,0 +1,54 @@
+use crate::csaf_traits::CsafTraitExt;
+use crate::validation::ValidationError;
+use crate::validations::presence::{PresenceRule, Requirement, check_document};
+use serde::Serialize;
+
+static RULES: &[PresenceRule] = &[
+    PresenceRule {
+        code: "6.1.65",
+        path: "/document/title",
+        condition: None,
+        requirement: Requirement::NonEmpty,
+        message: "The document title must not be empty",
+    },
+    PresenceRule {
+        code: "6.1.65",
+        path: "/document/tracking/id",
+        condition: None,
+        requirement: Requirement::NonEmpty,
+        message: "The document tracking ID must not be empty",
+    },
+];
+
+/// 6.1.65 Empty Title or Tracking ID
+///
+/// It is tested that `/document/title` and `/document/tracking/id` are not empty or consist of
+/// whitespace only.
+pub fn test_6_1_65_empty_title_or_tracking_id(
+    doc: &(impl CsafTraitExt + Serialize),
+) -> Result<(), Vec<ValidationError>> {
+    check_document(doc, RULES)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_65::test_6_1_65_empty_title_or_tracking_id;
+
+    #[test]
+    fn test_test_6_1_65() {
+        assert_eq!(Ok(()), test_6_1_65_empty_title_or_tracking_id(&mock_csaf21(|_| {})));
+
+        // The schema prevents an empty tracking ID, but allows a title of whitespace only
+        let doc = mock_csaf20(|doc| doc["document"]["title"] = " \t ".into());
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.65",
+                "The document title must not be empty",
+                "/document/title",
+            )]),
+            test_6_1_65_empty_title_or_tracking_id(&doc)
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::csaf_traits::{
+    BranchTrait, CsafTraitExt, ProductStatusTrait, ProductTrait, ProductTreeTrait, VersionNumber, VulnerabilityTrait,
+};
+use crate::schema::csaf2_1::schema::CategoryOfTheBranch;
+use crate::validation::{Severity, ValidationError};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// 6.1.66 Inconsistent First and Last Affected Versions
+///
+/// For each pair of products of the same product line (i.e. defined in `product_version` branches
+/// with the same parent branch) listed in `first_affected` and `last_affected` of a
+/// vulnerability, it is tested that the first affected version is not later than the last
+/// affected version. Versions are compared as integer or semantic versions, pairs that cannot be
+/// compared this way are reported with severity info.
+pub fn test_6_1_66_first_and_last_affected_order(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    // Product ID -> (version, path of the parent branch)
+    let mut versions = HashMap::<String, (String, String)>::new();
+    if let Some(branches) = doc.get_product_tree().as_ref().and_then(|t| t.get_branches()) {
+        for (b_i, branch) in branches.iter().enumerate() {
+            branch.visit_branches_rec(&format!("/product_tree/branches/{}", b_i), &mut |branch, path| {
+                if branch.get_category() != CategoryOfTheBranch::ProductVersion {
+                    return;
+                }
+                if let (Some(product), Some((parent, _))) = (branch.get_product(), path.rsplit_once("/branches/")) {
+                    versions.insert(
+                        product.get_product_id().to_string(),
+                        (branch.get_name().to_string(), parent.to_string()),
+                    );
+                }
+            });
+        }
+    }
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(status) = v.get_product_status() else {
+            continue;
+        };
+        let last_affected: Vec<&String> = status.get_last_affected().into_iter().flatten().collect();
+        for (f_i, first_id) in status.get_first_affected().into_iter().flatten().enumerate() {
+            let Some((first_version, first_parent)) = versions.get(first_id) else {
+                continue;
+            };
+            let path = format!("/vulnerabilities/{}/product_status/first_affected/{}", v_i, f_i);
+            for last_id in &last_affected {
+                let Some((last_version, last_parent)) = versions.get(*last_id) else {
+                    continue;
+                };
+                if first_parent != last_parent {
+                    continue;
+                }
+                let ordering = VersionNumber::parse(first_version)
+                    .zip(VersionNumber::parse(last_version))
+                    .and_then(|(first, last)| first.try_cmp(&last));
+                match ordering {
+                    Some(Ordering::Greater) => errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                        "6.1.66",
+                        format!(
+                            "Product {} is first affected in version {}, which is later than the last affected \
+                             version {} of product {}",
+                            first_id, first_version, last_version, last_id
+                        ),
+                        path.clone(),
+                    )),
+                    Some(_) => {},
+                    None => errors.get_or_insert_with(Vec::new).push(
+                        ValidationError::new(
+                            "6.1.66",
+                            format!(
+                                "Versions {} of product {} and {} of product {} cannot be compared as integer or \
+                                 semantic versions",
+                                first_version, first_id, last_version, last_id
+                            ),
+                            path.clone(),
+                        )
+                        .with_severity(Severity::Info),
+                    ),
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_66::test_6_1_66_first_and_last_affected_order;
+    use serde_json::{Value, json};
+
+    fn product_tree(versions: &[(&str, &str)]) -> Value {
+        let branches: Vec<Value> = versions
+            .iter()
+            .map(|(version, product_id)| {
+                json!({
+                    "category": "product_version",
+                    "name": version,
+                    "product": { "name": format!("Product A {}", version), "product_id": product_id }
+                })
+            })
+            .collect();
+        json!({
+            "branches": [{
+                "category": "vendor",
+                "name": "Example Company",
+                "branches": [{ "category": "product_name", "name": "Product A", "branches": branches }]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_66() {
+        let ordered = mock_csaf21(|doc| {
+            doc["product_tree"] = product_tree(&[("1.0.0", "CSAFPID-1"), ("2.1.0", "CSAFPID-2")]);
+            doc["vulnerabilities"] = json!([{
+                "product_status": { "first_affected": ["CSAFPID-1"], "last_affected": ["CSAFPID-2"] }
+            }]);
+        });
+        assert_eq!(Ok(()), test_6_1_66_first_and_last_affected_order(&ordered));
+
+        let inverted = mock_csaf20(|doc| {
+            doc["product_tree"] = product_tree(&[("10", "CSAFPID-1"), ("9", "CSAFPID-2"), ("beta", "CSAFPID-3")]);
+            doc["vulnerabilities"] = json!([{
+                "product_status": { "first_affected": ["CSAFPID-1", "CSAFPID-3"], "last_affected": ["CSAFPID-2"] }
+            }]);
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.66",
+                    "Product CSAFPID-1 is first affected in version 10, which is later than the last affected \
+                     version 9 of product CSAFPID-2",
+                    "/vulnerabilities/0/product_status/first_affected/0",
+                ),
+                ValidationError::new(
+                    "6.1.66",
+                    "Versions beta of product CSAFPID-3 and 9 of product CSAFPID-2 cannot be compared as integer \
+                     or semantic versions",
+                    "/vulnerabilities/0/product_status/first_affected/1",
+                )
+                .with_severity(Severity::Info),
+            ]),
+            test_6_1_66_first_and_last_affected_order(&inverted)
+        );
+    }
+}
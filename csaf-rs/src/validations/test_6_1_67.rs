@@ -0,0 +1,61 @@
+use crate::csaf_traits::{CsafTraitExt, ThreatTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::CategoryOfTheThreat;
+use crate::validation::ValidationError;
+
+/// 6.1.67 Missing Details of Exploit Status
+///
+/// For each item in `/vulnerabilities[]/threats[]` with category `exploit_status`, it is tested
+/// that `details` describes the exploit status, i.e. is not empty or whitespace only.
+pub fn test_6_1_67_missing_exploit_status_details(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (t_i, threat) in v.get_threats().iter().enumerate() {
+            if threat.get_category() == CategoryOfTheThreat::ExploitStatus && threat.get_details().trim().is_empty() {
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.67",
+                    "Threat with category exploit_status must describe the exploit status in its details",
+                    format!("/vulnerabilities/{}/threats/{}/details", v_i, t_i),
+                ));
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_67::test_6_1_67_missing_exploit_status_details;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_67() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "threats": [
+                    { "category": "exploit_status", "details": "Exploited in the wild." },
+                    { "category": "impact", "details": " " }
+                ]
+            }])
+        });
+        assert_eq!(Ok(()), test_6_1_67_missing_exploit_status_details(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "threats": [
+                    { "category": "impact", "details": "Remote code execution." },
+                    { "category": "exploit_status", "details": " \n " }
+                ]
+            }])
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.67",
+                "Threat with category exploit_status must describe the exploit status in its details",
+                "/vulnerabilities/0/threats/1/details",
+            )]),
+            test_6_1_67_missing_exploit_status_details(&invalid)
+        );
+    }
+}
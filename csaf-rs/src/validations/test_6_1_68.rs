@@ -0,0 +1,71 @@
+use crate::csaf_traits::{BranchTrait, CsafTraitExt, ProductTreeTrait};
+use crate::schema::csaf2_1::schema::CategoryOfTheBranch;
+use crate::validation::ValidationError;
+use crate::version_range;
+
+/// 6.1.68 Invalid Product Version Range
+///
+/// For each branch with category `product_version_range`, it is tested that its `name` is a
+/// version range in vers notation (e.g. `vers:npm/>=1.0.0|<2.0.0`) or in the simple notation of
+/// whitespace-separated constraints (e.g. `>=1.0.0 <2.0.0`).
+pub fn test_6_1_68_invalid_product_version_range(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    if let Some(branches) = doc.get_product_tree().as_ref().and_then(|t| t.get_branches()) {
+        for (b_i, branch) in branches.iter().enumerate() {
+            branch.visit_branches_rec(&format!("/product_tree/branches/{}", b_i), &mut |branch, path| {
+                if branch.get_category() != CategoryOfTheBranch::ProductVersionRange {
+                    return;
+                }
+                if let Err(e) = version_range::parse(branch.get_name()) {
+                    errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                        "6.1.68",
+                        format!("Invalid product version range '{}': {}", branch.get_name(), e),
+                        path,
+                    ));
+                }
+            });
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_68::test_6_1_68_invalid_product_version_range;
+    use serde_json::{Value, json};
+
+    fn product_tree(ranges: &[&str]) -> Value {
+        let branches: Vec<Value> = ranges
+            .iter()
+            .enumerate()
+            .map(|(i, range)| {
+                json!({
+                    "category": "product_version_range",
+                    "name": range,
+                    "product": { "name": format!("Product A {}", range), "product_id": format!("CSAFPID-{}", i) }
+                })
+            })
+            .collect();
+        json!({
+            "branches": [{ "category": "product_name", "name": "Product A", "branches": branches }]
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_68() {
+        let valid = mock_csaf21(|doc| doc["product_tree"] = product_tree(&["vers:npm/>=1.0.0|<2.0.0", ">=4.1 <4.4"]));
+        assert_eq!(Ok(()), test_6_1_68_invalid_product_version_range(&valid));
+
+        let invalid = mock_csaf20(|doc| doc["product_tree"] = product_tree(&["vers:npm/<2.0.0", "prior to 4.4"]));
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.68",
+                "Invalid product version range 'prior to 4.4': Constraint 0 of the version range has no comparator",
+                "/product_tree/branches/0/branches/1",
+            )]),
+            test_6_1_68_invalid_product_version_range(&invalid)
+        );
+    }
+}
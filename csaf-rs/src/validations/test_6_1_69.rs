@@ -0,0 +1,109 @@
+use crate::csaf_traits::{AggregateSeverityTrait, CsafTraitExt, DocumentTrait};
+use crate::helpers::CVSS_QUALITATIVE_SEVERITY_RATINGS;
+use crate::validation::ValidationError;
+use url::Url;
+
+/// 6.1.69 Aggregate Severity Namespace
+///
+/// If `/document/aggregate_severity/namespace` is present, it is tested that it is an absolute
+/// URL. If it denotes the CVSS specification of FIRST, `text` must be one of the qualitative
+/// severity ratings of CVSS. Custom namespaces may use arbitrary texts.
+pub fn test_6_1_69_aggregate_severity_namespace(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let Some(aggregate_severity) = doc.get_document().get_aggregate_severity() else {
+        return Ok(());
+    };
+    let Some(namespace) = aggregate_severity.get_namespace() else {
+        return Ok(());
+    };
+
+    let url = match Url::parse(namespace) {
+        Ok(url) => url,
+        Err(e) => {
+            return Err(vec![ValidationError::new(
+                "6.1.69",
+                format!(
+                    "Aggregate severity namespace '{}' is not an absolute URL: {}",
+                    namespace, e
+                ),
+                "/document/aggregate_severity",
+            )]);
+        },
+    };
+
+    let text = aggregate_severity.get_text();
+    if is_cvss_namespace(&url)
+        && !CVSS_QUALITATIVE_SEVERITY_RATINGS
+            .iter()
+            .any(|rating| rating.eq_ignore_ascii_case(text.trim()))
+    {
+        return Err(vec![ValidationError::new(
+            "6.1.69",
+            format!(
+                "Aggregate severity '{}' of the CVSS namespace {} is not a CVSS qualitative severity rating, \
+                 expected one of: {}",
+                text,
+                namespace,
+                CVSS_QUALITATIVE_SEVERITY_RATINGS.join(", ")
+            ),
+            "/document/aggregate_severity",
+        )]);
+    }
+    Ok(())
+}
+
+/// Returns whether `url` points to the CVSS specification on the website of FIRST.
+fn is_cvss_namespace(url: &Url) -> bool {
+    matches!(url.host_str(), Some("www.first.org" | "first.org")) && url.path().starts_with("/cvss")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_69::test_6_1_69_aggregate_severity_namespace;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_69() {
+        assert_eq!(Ok(()), test_6_1_69_aggregate_severity_namespace(&mock_csaf21(|_| {})));
+        for (namespace, text) in [
+            ("https://www.first.org/cvss/v3.1/specification-document", "Critical"),
+            ("https://www.first.org/cvss/", "medium"),
+            ("https://example.com/severity", "Important"),
+        ] {
+            let doc = mock_csaf21(|doc| {
+                doc["document"]["aggregate_severity"] = json!({ "namespace": namespace, "text": text })
+            });
+            assert_eq!(Ok(()), test_6_1_69_aggregate_severity_namespace(&doc));
+        }
+
+        let relative = mock_csaf20(|doc| {
+            doc["document"]["aggregate_severity"] = json!({ "namespace": "severity/ratings", "text": "High" })
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.69",
+                "Aggregate severity namespace 'severity/ratings' is not an absolute URL: relative URL without a base",
+                "/document/aggregate_severity",
+            )]),
+            test_6_1_69_aggregate_severity_namespace(&relative)
+        );
+
+        let not_a_rating = mock_csaf21(|doc| {
+            doc["document"]["aggregate_severity"] = json!({
+                "namespace": "https://www.first.org/cvss/v4.0/specification-document",
+                "text": "Important"
+            })
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.69",
+                "Aggregate severity 'Important' of the CVSS namespace \
+                 https://www.first.org/cvss/v4.0/specification-document is not a CVSS qualitative severity \
+                 rating, expected one of: None, Low, Medium, High, Critical",
+                "/document/aggregate_severity",
+            )]),
+            test_6_1_69_aggregate_severity_namespace(&not_a_rating)
+        );
+    }
+}
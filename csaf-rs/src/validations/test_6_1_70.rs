@@ -0,0 +1,87 @@
+use crate::csaf_traits::CsafTraitExt;
+use crate::validation::ValidationError;
+use crate::walk::UrlKind;
+use url::Url;
+
+/// 6.1.70 Remediation URL Format
+///
+/// For each item in `/vulnerabilities[]/remediations[]`, it is tested that `url`, if present, is
+/// an absolute URL with the scheme `http` or `https`. The URL is not fetched.
+pub fn test_6_1_70_remediation_url_format(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (url, path) in doc.iter_urls(UrlKind::Remediation) {
+        let message = match Url::parse(&url) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => continue,
+            Ok(parsed) => format!(
+                "Remediation URL '{}' must use the http or https scheme, found '{}'",
+                url,
+                parsed.scheme()
+            ),
+            Err(e) => format!("Remediation URL '{}' is not an absolute URL: {}", url, e),
+        };
+        errors
+            .get_or_insert_with(Vec::new)
+            .push(ValidationError::new("6.1.70", message, path));
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_70::test_6_1_70_remediation_url_format;
+    use serde_json::json;
+
+    fn remediation(url: &str) -> serde_json::Value {
+        json!({
+            "category": "vendor_fix",
+            "details": "Update to the latest version.",
+            "product_ids": ["CSAFPID-9080700"],
+            "url": url
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_70() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "remediations": [
+                    remediation("https://example.com/fix"),
+                    remediation("http://example.com/fix"),
+                    {
+                        "category": "workaround",
+                        "details": "Disable the service.",
+                        "product_ids": ["CSAFPID-9080700"]
+                    }
+                ]
+            }])
+        });
+        assert_eq!(Ok(()), test_6_1_70_remediation_url_format(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "remediations": [
+                    remediation("file:///srv/patches/fix.tar.gz"),
+                    remediation("https://example.com/fix"),
+                    remediation("downloads/fix.tar.gz")
+                ]
+            }])
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.70",
+                    "Remediation URL 'file:///srv/patches/fix.tar.gz' must use the http or https scheme, found 'file'",
+                    "/vulnerabilities/0/remediations/0/url",
+                ),
+                ValidationError::new(
+                    "6.1.70",
+                    "Remediation URL 'downloads/fix.tar.gz' is not an absolute URL: relative URL without a base",
+                    "/vulnerabilities/0/remediations/2/url",
+                ),
+            ]),
+            test_6_1_70_remediation_url_format(&invalid)
+        );
+    }
+}
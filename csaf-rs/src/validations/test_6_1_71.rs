@@ -0,0 +1,109 @@
+use crate::csaf_traits::{CsafTraitExt, ProductGroupTrait, ProductTreeTrait};
+use crate::validation::{Severity, ValidationError};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// 6.1.71 Unique Product Group Membership
+///
+/// For each item in `/product_tree/product_groups[]`, it is tested that no product ID is listed
+/// more than once in `product_ids`. Additionally, a group with the same set of products as a
+/// previous group, regardless of their order, is reported as a likely redundancy (warning).
+pub fn test_6_1_71_unique_product_group_membership(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let Some(product_tree) = doc.get_product_tree() else {
+        return Ok(());
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    let mut memberships: HashMap<BTreeSet<&String>, &String> = HashMap::new();
+    for (g_i, group) in product_tree.get_product_groups().iter().enumerate() {
+        let mut seen: HashSet<&String> = HashSet::new();
+        for (p_i, product_id) in group.get_product_ids().enumerate() {
+            if !seen.insert(product_id) {
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.71",
+                    format!(
+                        "Product ID {} is listed more than once in product group {}",
+                        product_id,
+                        group.get_group_id()
+                    ),
+                    format!("/product_tree/product_groups/{}/product_ids/{}", g_i, p_i),
+                ));
+            }
+        }
+
+        let members: BTreeSet<&String> = seen.into_iter().collect();
+        match memberships.get(&members) {
+            Some(first_group_id) => {
+                errors.get_or_insert_with(Vec::new).push(
+                    ValidationError::new(
+                        "6.1.71",
+                        format!(
+                            "Product group {} contains the same products as product group {}",
+                            group.get_group_id(),
+                            first_group_id
+                        ),
+                        format!("/product_tree/product_groups/{}", g_i),
+                    )
+                    .with_severity(Severity::Warning),
+                );
+            },
+            None => {
+                memberships.insert(members, group.get_group_id());
+            },
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_71::test_6_1_71_unique_product_group_membership;
+    use serde_json::json;
+
+    fn product_tree(groups: serde_json::Value) -> serde_json::Value {
+        json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                { "name": "Product B", "product_id": "CSAFPID-9080701" },
+                { "name": "Product C", "product_id": "CSAFPID-9080702" }
+            ],
+            "product_groups": groups
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_71() {
+        let valid = mock_csaf21(|doc| {
+            doc["product_tree"] = product_tree(json!([
+                { "group_id": "CSAFGID-0001", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] },
+                { "group_id": "CSAFGID-0002", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080702"] }
+            ]))
+        });
+        assert_eq!(Ok(()), test_6_1_71_unique_product_group_membership(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["product_tree"] = product_tree(json!([
+                { "group_id": "CSAFGID-0001", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] },
+                { "group_id": "CSAFGID-0002", "product_ids": ["CSAFPID-9080702", "CSAFPID-9080700", "CSAFPID-9080702"] },
+                { "group_id": "CSAFGID-0003", "product_ids": ["CSAFPID-9080701", "CSAFPID-9080700"] }
+            ]))
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.71",
+                    "Product ID CSAFPID-9080702 is listed more than once in product group CSAFGID-0002",
+                    "/product_tree/product_groups/1/product_ids/2",
+                ),
+                ValidationError::new(
+                    "6.1.71",
+                    "Product group CSAFGID-0003 contains the same products as product group CSAFGID-0001",
+                    "/product_tree/product_groups/2",
+                )
+                .with_severity(Severity::Warning),
+            ]),
+            test_6_1_71_unique_product_group_membership(&invalid)
+        );
+    }
+}
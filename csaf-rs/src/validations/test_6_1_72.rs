@@ -0,0 +1,111 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, RevisionHistorySortable, TrackingTrait, VersionNumber};
+use crate::validation::ValidationError;
+
+/// 6.1.72 Consistent Pre-release of Document Version and Revisions
+///
+/// If `/document/tracking/version` is a semantic version with a pre-release part, it is tested
+/// that the newest item of `/document/tracking/revision_history[]` (sorted by `date`, then
+/// `number`) has a `number` with the same pre-release part, and vice versa. Documents using
+/// integer versioning are skipped.
+pub fn test_6_1_72_consistent_pre_release(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let tracking = doc.get_document().get_tracking();
+    let VersionNumber::Semver(version) = tracking.get_version() else {
+        return Ok(());
+    };
+
+    let mut revision_history = tracking.get_revision_history_tuples();
+    revision_history.inplace_sort_by_date_then_number();
+    let Some(VersionNumber::Semver(latest)) = revision_history.last().map(|item| &item.number) else {
+        return Ok(());
+    };
+
+    let message = match (version.pre.is_empty(), latest.pre.is_empty()) {
+        (true, true) => return Ok(()),
+        (false, false) if version.pre == latest.pre => return Ok(()),
+        (false, false) => format!(
+            "Pre-release '{}' of the document version {} does not match the pre-release '{}' of the newest revision {}",
+            version.pre, version, latest.pre, latest
+        ),
+        (false, true) => format!(
+            "Document version {} has the pre-release '{}', but the newest revision {} has none",
+            version, version.pre, latest
+        ),
+        (true, false) => format!(
+            "Newest revision {} has the pre-release '{}', but the document version {} has none",
+            latest, latest.pre, version
+        ),
+    };
+    Err(vec![ValidationError::new(
+        "6.1.72",
+        message,
+        "/document/tracking/version",
+    )])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_72::test_6_1_72_consistent_pre_release;
+    use serde_json::json;
+
+    fn revision(date: &str, number: &str) -> serde_json::Value {
+        json!({ "date": date, "number": number, "summary": "Revision." })
+    }
+
+    #[test]
+    fn test_test_6_1_72() {
+        // Integer versioning is skipped
+        assert_eq!(Ok(()), test_6_1_72_consistent_pre_release(&mock_csaf21(|_| {})));
+
+        let valid = mock_csaf21(|doc| {
+            doc["document"]["tracking"]["version"] = json!("1.0.0-rc.1");
+            doc["document"]["tracking"]["revision_history"] = json!([
+                revision("2024-01-24T10:00:00.000Z", "0.9.0"),
+                revision("2024-02-24T10:00:00.000Z", "1.0.0-rc.1")
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_72_consistent_pre_release(&valid));
+
+        let released = mock_csaf20(|doc| {
+            doc["document"]["tracking"]["version"] = json!("1.0.0");
+            doc["document"]["tracking"]["revision_history"] = json!([
+                revision("2024-01-24T10:00:00.000Z", "1.0.0-rc.1"),
+                revision("2024-02-24T10:00:00.000Z", "1.0.0")
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_72_consistent_pre_release(&released));
+
+        for (version, number, message) in [
+            (
+                "1.0.0-rc.1",
+                "1.0.0",
+                "Document version 1.0.0-rc.1 has the pre-release 'rc.1', but the newest revision 1.0.0 has none",
+            ),
+            (
+                "1.0.0",
+                "1.0.0-rc.1",
+                "Newest revision 1.0.0-rc.1 has the pre-release 'rc.1', but the document version 1.0.0 has none",
+            ),
+            (
+                "1.0.0-rc.2",
+                "1.0.0-rc.1",
+                "Pre-release 'rc.2' of the document version 1.0.0-rc.2 does not match the pre-release 'rc.1' of \
+                 the newest revision 1.0.0-rc.1",
+            ),
+        ] {
+            let doc = mock_csaf21(|doc| {
+                doc["document"]["tracking"]["version"] = json!(version);
+                doc["document"]["tracking"]["revision_history"] = json!([revision("2024-01-24T10:00:00.000Z", number)]);
+            });
+            assert_eq!(
+                Err(vec![ValidationError::new(
+                    "6.1.72",
+                    message,
+                    "/document/tracking/version"
+                )]),
+                test_6_1_72_consistent_pre_release(&doc)
+            );
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, TrackingTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+use chrono::Datelike;
+
+/// The year of the first CVE IDs
+const FIRST_CVE_YEAR: u32 = 1999;
+
+/// 6.1.73 CVE Format
+///
+/// For each item in `/vulnerabilities[]`, it is tested that `cve` follows the CVE ID syntax:
+/// the uppercase prefix `CVE-`, a year between 1999 and the year after the current release date
+/// of the document, and a sequence number of at least four digits, where sequence numbers longer
+/// than four digits must not have leading zeros.
+///
+/// The latest year is taken from the document rather than the clock, so that the result does not
+/// change over time. If the current release date cannot be parsed, the year is not limited. Use
+/// [check_cve_format] to check against another year.
+pub fn test_6_1_73_cve_format(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let max_year = doc
+        .get_document()
+        .get_tracking()
+        .get_current_release_timestamp()
+        .map_or(u32::MAX, |timestamp| timestamp.0.year() as u32 + 1);
+    check_cve_format(doc, max_year)
+}
+
+/// Runs test 6.1.73 with `max_year` as the latest valid year of a CVE ID.
+pub fn check_cve_format(doc: &impl CsafTraitExt, max_year: u32) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        if let Some(cve) = v.get_cve()
+            && let Err(reason) = check_cve(cve, max_year)
+        {
+            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                "6.1.73",
+                format!("Invalid CVE ID '{}': {}", cve, reason),
+                format!("/vulnerabilities/{}/cve", v_i),
+            ));
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Checks a CVE ID, returning the reason if it is malformed.
+fn check_cve(cve: &str, max_year: u32) -> Result<(), String> {
+    let rest = cve
+        .strip_prefix("CVE-")
+        .ok_or_else(|| "must start with the uppercase prefix 'CVE-'".to_string())?;
+    let (year, sequence) = rest
+        .split_once('-')
+        .ok_or_else(|| "year and sequence number must be separated by a hyphen".to_string())?;
+
+    if year.len() != 4 || !year.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("the year must consist of four digits".to_string());
+    }
+    let year: u32 = year.parse().unwrap_or_default();
+    if !(FIRST_CVE_YEAR..=max_year).contains(&year) {
+        return Err(format!(
+            "the year {} is not between {} and {}",
+            year, FIRST_CVE_YEAR, max_year
+        ));
+    }
+
+    if sequence.len() < 4 || !sequence.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("the sequence number must consist of at least four digits".to_string());
+    }
+    if sequence.len() > 4 && sequence.starts_with('0') {
+        return Err("sequence numbers with more than four digits must not have leading zeros".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_73::{check_cve, check_cve_format, test_6_1_73_cve_format};
+    use serde_json::json;
+
+    #[test]
+    fn test_check_cve() {
+        assert_eq!(Ok(()), check_cve("CVE-1999-0001", 2025));
+        assert_eq!(Ok(()), check_cve("CVE-2024-12345", 2025));
+        for (cve, reason) in [
+            ("cve-2024-0001", "must start with the uppercase prefix 'CVE-'"),
+            ("CVE-20240001", "year and sequence number must be separated by a hyphen"),
+            ("CVE-24-0001", "the year must consist of four digits"),
+            ("CVE-1998-0001", "the year 1998 is not between 1999 and 2025"),
+            ("CVE-2026-0001", "the year 2026 is not between 1999 and 2025"),
+            (
+                "CVE-2024-001",
+                "the sequence number must consist of at least four digits",
+            ),
+            (
+                "CVE-2024-01234",
+                "sequence numbers with more than four digits must not have leading zeros",
+            ),
+        ] {
+            assert_eq!(Err(reason.to_string()), check_cve(cve, 2025), "{}", cve);
+        }
+    }
+
+    #[test]
+    fn test_test_6_1_73() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{ "cve": "CVE-2024-0001" }, { "title": "No CVE" }]);
+        });
+        assert_eq!(Ok(()), test_6_1_73_cve_format(&valid));
+
+        // The mock documents are released in 2024
+        let invalid = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([
+                { "cve": "CVE-2025-0001" },
+                { "cve": "CVE-1970-0001" },
+                { "cve": "CVE-2026-0001" }
+            ]);
+        });
+        let error = |cve: &str, year: u32, max_year: u32, v_i: usize| {
+            ValidationError::new(
+                "6.1.73",
+                format!(
+                    "Invalid CVE ID '{}': the year {} is not between 1999 and {}",
+                    cve, year, max_year
+                ),
+                format!("/vulnerabilities/{}/cve", v_i),
+            )
+        };
+        assert_eq!(
+            Err(vec![
+                error("CVE-1970-0001", 1970, 2025, 1),
+                error("CVE-2026-0001", 2026, 2025, 2)
+            ]),
+            test_6_1_73_cve_format(&invalid)
+        );
+        assert_eq!(
+            Err(vec![error("CVE-1970-0001", 1970, 2030, 1)]),
+            check_cve_format(&invalid, 2030)
+        );
+    }
+}
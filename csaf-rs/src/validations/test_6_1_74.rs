@@ -0,0 +1,104 @@
+use crate::csaf_traits::CsafTraitExt;
+use crate::validation::ValidationError;
+use crate::validations::presence::{Condition, PresenceRule, Requirement, check, check_document};
+use serde::Serialize;
+use serde_json::Value;
+
+static RULES: &[PresenceRule] = &[
+    PresenceRule {
+        code: "6.1.74",
+        path: "/document/distribution",
+        condition: None,
+        requirement: Requirement::AnyMember(&["text", "tlp"]),
+        message: "The distribution must contain at least one of text or tlp",
+    },
+    PresenceRule {
+        code: "6.1.74",
+        path: "/document/distribution",
+        condition: Some(Condition::FieldIn("csaf_version", &["2.1"])),
+        requirement: Requirement::Present,
+        message: "CSAF 2.1 requires a distribution with at least one of text or tlp",
+    },
+];
+
+/// 6.1.74 Distribution Text or TLP
+///
+/// If `/document/distribution` is present, it is tested that it contains at least one of `text`
+/// and `tlp`, as an empty distribution conveys no sharing policy. CSAF 2.1 requires the
+/// distribution, so its absence is reported for CSAF 2.1 documents.
+pub fn test_6_1_74_distribution_text_or_tlp(doc: &(impl CsafTraitExt + Serialize)) -> Result<(), Vec<ValidationError>> {
+    check_document(doc, RULES)
+}
+
+/// Runs test 6.1.74 on the JSON of a document, e.g. before parsing it.
+///
+/// The schema of CSAF 2.1 requires `tlp`, so parsed CSAF 2.1 documents cannot fail the test.
+pub fn check_raw_distribution(doc: &Value) -> Result<(), Vec<ValidationError>> {
+    check(doc, RULES)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_74::{check_raw_distribution, test_6_1_74_distribution_text_or_tlp};
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_74() {
+        assert_eq!(Ok(()), test_6_1_74_distribution_text_or_tlp(&mock_csaf21(|_| {})));
+        assert_eq!(Ok(()), test_6_1_74_distribution_text_or_tlp(&mock_csaf20(|_| {})));
+
+        let text_only = mock_csaf20(|doc| doc["document"]["distribution"] = json!({ "text": "Share freely." }));
+        assert_eq!(Ok(()), test_6_1_74_distribution_text_or_tlp(&text_only));
+
+        let without_distribution = mock_csaf20(|doc| {
+            doc["document"].as_object_mut().unwrap().remove("distribution");
+        });
+        assert_eq!(Ok(()), test_6_1_74_distribution_text_or_tlp(&without_distribution));
+
+        let empty = mock_csaf20(|doc| doc["document"]["distribution"] = json!({}));
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.74",
+                "The distribution must contain at least one of text or tlp",
+                "/document/distribution"
+            )]),
+            test_6_1_74_distribution_text_or_tlp(&empty)
+        );
+    }
+
+    #[test]
+    fn test_check_raw_distribution() {
+        let valid = serde_json::to_value(mock_csaf21(|_| {})).unwrap();
+        assert_eq!(Ok(()), check_raw_distribution(&valid));
+
+        let mut text_only = valid.clone();
+        text_only["document"]["distribution"] = json!({ "text": "Share freely." });
+        assert_eq!(Ok(()), check_raw_distribution(&text_only));
+
+        let error = Err(vec![ValidationError::new(
+            "6.1.74",
+            "The distribution must contain at least one of text or tlp",
+            "/document/distribution",
+        )]);
+        let mut empty = valid.clone();
+        empty["document"]["distribution"] = json!({});
+        assert_eq!(error, check_raw_distribution(&empty));
+
+        let mut missing = valid.clone();
+        missing["document"].as_object_mut().unwrap().remove("distribution");
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.74",
+                "CSAF 2.1 requires a distribution with at least one of text or tlp",
+                "/document/distribution"
+            )]),
+            check_raw_distribution(&missing)
+        );
+
+        let mut missing_20 = serde_json::to_value(mock_csaf20(|_| {})).unwrap();
+        missing_20["document"].as_object_mut().unwrap().remove("distribution");
+        assert_eq!(Ok(()), check_raw_distribution(&missing_20));
+    }
+}
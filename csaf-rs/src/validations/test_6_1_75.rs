@@ -0,0 +1,88 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentCategory, DocumentTrait, NoteTrait};
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::ValidationError;
+
+/// 6.1.75 Deprecation Note
+///
+/// For documents with `/document/category` `csaf_deprecated_security_advisory`, it is tested
+/// that `/document/notes` contains a note explaining the deprecation, i.e. a note of category
+/// `legal_disclaimer`, or a note of category `description` whose title or text mentions the
+/// deprecation.
+///
+/// The test only applies to CSAF 2.1, as CSAF 2.0 has no document category for deprecated
+/// advisories.
+pub fn test_6_1_75_deprecation_note(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+    if document.get_category() != DocumentCategory::CsafDeprecatedSecurityAdvisory {
+        return Ok(());
+    }
+
+    let explained = document
+        .get_notes()
+        .into_iter()
+        .flatten()
+        .any(|note| match note.get_category() {
+            NoteCategory::LegalDisclaimer => true,
+            NoteCategory::Description => {
+                mentions_deprecation(note.get_text()) || note.get_title().is_some_and(|t| mentions_deprecation(t))
+            },
+            _ => false,
+        });
+    if explained {
+        return Ok(());
+    }
+    Err(vec![ValidationError::new(
+        "6.1.75",
+        "Document with category 'csaf_deprecated_security_advisory' must have a note of category \
+         legal_disclaimer or a description note explaining the deprecation",
+        "/document/notes",
+    )])
+}
+
+fn mentions_deprecation(s: &str) -> bool {
+    s.to_lowercase().contains("deprecat")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_75::test_6_1_75_deprecation_note;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_75() {
+        // Other categories are not affected
+        assert_eq!(Ok(()), test_6_1_75_deprecation_note(&mock_csaf21(|_| {})));
+
+        for notes in [
+            json!([{ "category": "legal_disclaimer", "text": "This advisory is no longer maintained." }]),
+            json!([{ "category": "description", "text": "This advisory was Deprecated in favor of EX-2025-0001." }]),
+            json!([{ "category": "description", "title": "Deprecation notice", "text": "See EX-2025-0001." }]),
+        ] {
+            let doc = mock_csaf21(|doc| {
+                doc["document"]["category"] = json!("csaf_deprecated_security_advisory");
+                doc["document"]["notes"] = notes;
+            });
+            assert_eq!(Ok(()), test_6_1_75_deprecation_note(&doc));
+        }
+
+        let expected = Err(vec![ValidationError::new(
+            "6.1.75",
+            "Document with category 'csaf_deprecated_security_advisory' must have a note of category \
+             legal_disclaimer or a description note explaining the deprecation",
+            "/document/notes",
+        )]);
+        let without_notes = mock_csaf21(|doc| doc["document"]["category"] = json!("csaf_deprecated_security_advisory"));
+        assert_eq!(expected, test_6_1_75_deprecation_note(&without_notes));
+
+        let unrelated_notes = mock_csaf21(|doc| {
+            doc["document"]["category"] = json!("csaf_deprecated_security_advisory");
+            doc["document"]["notes"] = json!([
+                { "category": "description", "text": "A vulnerability in Product A." },
+                { "category": "summary", "text": "This advisory is deprecated." }
+            ]);
+        });
+        assert_eq!(expected, test_6_1_75_deprecation_note(&unrelated_notes));
+    }
+}
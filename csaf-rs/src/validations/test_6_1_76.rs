@@ -0,0 +1,91 @@
+use crate::csaf_traits::{CsafTraitExt, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
+use crate::validation::ValidationError;
+
+/// 6.1.76 Product Identification Helper Without Identifier
+///
+/// For each `product_identification_helper` in the product tree, it is tested that it contains
+/// at least one identifier, i.e. a `cpe`, a `purl`, `hashes`, `model_numbers`, `sbom_urls`,
+/// `serial_numbers`, `skus` or `x_generic_uris`. An empty helper does not help identifying
+/// anything.
+pub fn test_6_1_76_empty_product_identification_helper(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_products(&mut |product, path| {
+            if let Some(helper) = product.get_product_identification_helper()
+                && is_empty(helper)
+            {
+                errors.get_or_insert_with(Vec::new).push(ValidationError::with_args(
+                    "6.1.76",
+                    [product.get_product_id()],
+                    format!("{}/product_identification_helper", path),
+                ));
+            }
+        });
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+fn is_empty(helper: &impl ProductIdentificationHelperTrait) -> bool {
+    helper.get_cpe().is_none()
+        && helper.get_purls().is_none_or(|purls| purls.is_empty())
+        && helper.get_hashes().is_empty()
+        && helper.get_model_numbers().is_none_or(|mut m| m.next().is_none())
+        && helper.get_sbom_urls().is_empty()
+        && helper.get_serial_numbers().is_none_or(|mut s| s.next().is_none())
+        && helper.get_skus().next().is_none()
+        && helper.get_x_generic_uris().next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_76::test_6_1_76_empty_product_identification_helper;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_76() {
+        let valid = mock_csaf21(|doc| {
+            doc["product_tree"] = json!({
+                "full_product_names": [
+                    { "name": "Product A", "product_id": "CSAFPID-0001" },
+                    {
+                        "name": "Product B",
+                        "product_id": "CSAFPID-0002",
+                        "product_identification_helper": { "skus": ["B-100"] }
+                    }
+                ]
+            });
+        });
+        assert_eq!(Ok(()), test_6_1_76_empty_product_identification_helper(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["product_tree"] = json!({
+                "branches": [{
+                    "category": "vendor",
+                    "name": "Vendor",
+                    "product": {
+                        "name": "Product A",
+                        "product_id": "CSAFPID-0001",
+                        "product_identification_helper": {}
+                    }
+                }],
+                "full_product_names": [{
+                    "name": "Product B",
+                    "product_id": "CSAFPID-0002",
+                    "product_identification_helper": { "cpe": "cpe:2.3:a:vendor:product_b:1.0:*:*:*:*:*:*:*" }
+                }]
+            });
+        });
+        assert_eq!(
+            Err(vec![ValidationError::with_args(
+                "6.1.76",
+                ["CSAFPID-0001"],
+                "/product_tree/branches/0/product/product_identification_helper",
+            )]),
+            test_6_1_76_empty_product_identification_helper(&invalid)
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentReferenceTrait, DocumentTrait, PublisherTrait};
+use crate::schema::csaf2_1::schema::CategoryOfReference;
+use crate::validation::{Severity, ValidationError};
+use url::Url;
+
+/// 6.1.77 Self Reference Matching the Publisher Namespace
+///
+/// For each item in `/document/references[]` with `category` `self`, it is tested that the host
+/// of its `url` equals the host of `/document/publisher/namespace`. A self reference on a
+/// different domain is suspicious, but not forbidden, so differences are reported as warnings.
+/// URLs that cannot be parsed or have no host are skipped, as they are covered by the schema.
+pub fn test_6_1_77_self_reference_publisher_namespace(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let document = doc.get_document();
+    let namespace = document.get_publisher().get_namespace();
+    let Some(namespace_host) = host(namespace) else {
+        return Ok(());
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (r_i, reference) in document.get_references().into_iter().flatten().enumerate() {
+        if *reference.get_category() != CategoryOfReference::Self_ {
+            continue;
+        }
+        if let Some(reference_host) = host(reference.get_url())
+            && reference_host != namespace_host
+        {
+            errors.get_or_insert_with(Vec::new).push(
+                ValidationError::with_args(
+                    "6.1.77",
+                    [&reference_host, &namespace_host, namespace],
+                    format!("/document/references/{}/url", r_i),
+                )
+                .with_severity(Severity::Warning),
+            );
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+fn host(url: &str) -> Option<String> {
+    Url::parse(url).ok()?.host_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_77::test_6_1_77_self_reference_publisher_namespace;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_77() {
+        // Without references, there is nothing to compare
+        assert_eq!(
+            Ok(()),
+            test_6_1_77_self_reference_publisher_namespace(&mock_csaf21(|_| {}))
+        );
+
+        let valid = mock_csaf21(|doc| {
+            doc["document"]["publisher"]["namespace"] = json!("https://Example.com");
+            doc["document"]["references"] = json!([
+                {
+                    "category": "self",
+                    "summary": "This document.",
+                    "url": "https://example.com/advisories/mock-2024-0001.json"
+                },
+                {
+                    "category": "external",
+                    "summary": "An external reference.",
+                    "url": "https://other.example.org/report"
+                }
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_77_self_reference_publisher_namespace(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["document"]["publisher"]["namespace"] = json!("https://example.com");
+            doc["document"]["references"] = json!([{
+                "category": "self",
+                "summary": "This document.",
+                "url": "https://advisories.example.net/mock-2024-0001.json"
+            }]);
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::with_args(
+                    "6.1.77",
+                    ["advisories.example.net", "example.com", "https://example.com"],
+                    "/document/references/0/url",
+                )
+                .with_severity(Severity::Warning)
+            ]),
+            test_6_1_77_self_reference_publisher_namespace(&invalid)
+        );
+        assert_eq!(
+            "Host 'advisories.example.net' of the self reference differs from host 'example.com' of the publisher \
+             namespace https://example.com",
+            test_6_1_77_self_reference_publisher_namespace(&invalid).unwrap_err()[0].message
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::schema::csaf2_1::schema::DocumentStatus;
+use crate::validation::{Severity, ValidationError};
+
+/// The allowed transitions of `/document/tracking/status` between two versions of a document
+///
+/// A document evolves from `draft` over `interim` to `final`, where `interim` may be skipped.
+/// A `final` document may become `interim` again while an update is being prepared. Keeping the
+/// status is always allowed. A document never returns to `draft`.
+pub const ALLOWED_STATUS_TRANSITIONS: &[(DocumentStatus, DocumentStatus)] = &[
+    (DocumentStatus::Draft, DocumentStatus::Draft),
+    (DocumentStatus::Draft, DocumentStatus::Interim),
+    (DocumentStatus::Draft, DocumentStatus::Final),
+    (DocumentStatus::Interim, DocumentStatus::Interim),
+    (DocumentStatus::Interim, DocumentStatus::Final),
+    (DocumentStatus::Final, DocumentStatus::Final),
+    (DocumentStatus::Final, DocumentStatus::Interim),
+];
+
+/// 6.1.78 Monotonic Tracking Status
+///
+/// A single document does not know its predecessor, so this test is not part of the presets.
+/// Given the `/document/tracking/status` of the previous version of a document and of its new
+/// version, it is tested that the transition is listed in [ALLOWED_STATUS_TRANSITIONS]. Invalid
+/// transitions are reported as warnings at the status of the new version.
+///
+/// See [crate::validation::feed::validate_update] to check two versions of a document.
+pub fn check_status_transition(prev_status: DocumentStatus, new_status: DocumentStatus) -> Option<ValidationError> {
+    if ALLOWED_STATUS_TRANSITIONS.contains(&(prev_status, new_status)) {
+        return None;
+    }
+    Some(
+        ValidationError::with_args("6.1.78", [prev_status, new_status], "/document/tracking/status")
+            .with_severity(Severity::Warning),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::csaf2_1::schema::DocumentStatus::{Draft, Final, Interim};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_78::check_status_transition;
+
+    #[test]
+    fn test_check_status_transition() {
+        for (prev, new) in [
+            (Draft, Draft),
+            (Draft, Interim),
+            (Draft, Final),
+            (Interim, Final),
+            (Final, Interim),
+            (Final, Final),
+        ] {
+            assert_eq!(None, check_status_transition(prev, new), "{} -> {}", prev, new);
+        }
+
+        for (prev, new) in [(Final, Draft), (Interim, Draft)] {
+            assert_eq!(
+                Some(
+                    ValidationError::with_args("6.1.78", [prev, Draft], "/document/tracking/status",)
+                        .with_severity(Severity::Warning)
+                ),
+                check_status_transition(prev, new)
+            );
+        }
+    }
+}
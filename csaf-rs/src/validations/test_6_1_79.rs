@@ -0,0 +1,66 @@
+use crate::csaf_traits::{CsafTraitExt, VulnerabilityTrait, WithOptionalGroupIds};
+use crate::helpers::defined_product_group_ids;
+use crate::validation::ValidationError;
+
+/// 6.1.79 Missing Definition of Remediation Product Group ID
+///
+/// For each item in `/vulnerabilities[]/remediations[]/group_ids`, it is tested that the
+/// product group is defined in `/product_tree/product_groups`. Contrary to 6.1.4, which covers
+/// all group references, each undefined group is reported with a message naming the
+/// remediation it belongs to.
+pub fn test_6_1_79_remediation_group_ids(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let known_groups = defined_product_group_ids(doc);
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (r_i, remediation) in v.get_remediations().iter().enumerate() {
+            for (g_i, group_id) in remediation.get_group_ids().into_iter().flatten().enumerate() {
+                if !known_groups.contains(group_id) {
+                    errors.get_or_insert_with(Vec::new).push(ValidationError::with_args(
+                        "6.1.79",
+                        [r_i.to_string(), v_i.to_string(), group_id.to_string()],
+                        format!("/vulnerabilities/{}/remediations/{}/group_ids/{}", v_i, r_i, g_i),
+                    ));
+                }
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_79::test_6_1_79_remediation_group_ids;
+    use serde_json::json;
+
+    fn with_remediation_groups(doc: &mut serde_json::Value, group_ids: serde_json::Value) {
+        doc["product_tree"] = json!({
+            "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-0001" }],
+            "product_groups": [{ "group_id": "CSAFGID-0001", "product_ids": ["CSAFPID-0001"] }]
+        });
+        doc["vulnerabilities"] = json!([{
+            "remediations": [
+                { "category": "workaround", "details": "Disable the feature.", "product_ids": ["CSAFPID-0001"] },
+                { "category": "vendor_fix", "details": "Update.", "group_ids": group_ids }
+            ]
+        }]);
+    }
+
+    #[test]
+    fn test_test_6_1_79() {
+        let valid = mock_csaf21(|doc| with_remediation_groups(doc, json!(["CSAFGID-0001"])));
+        assert_eq!(Ok(()), test_6_1_79_remediation_group_ids(&valid));
+
+        let invalid = mock_csaf20(|doc| with_remediation_groups(doc, json!(["CSAFGID-0001", "CSAFGID-0002"])));
+        assert_eq!(
+            Err(vec![ValidationError::with_args(
+                "6.1.79",
+                ["1", "0", "CSAFGID-0002"],
+                "/vulnerabilities/0/remediations/1/group_ids/1",
+            )]),
+            test_6_1_79_remediation_group_ids(&invalid)
+        );
+    }
+}
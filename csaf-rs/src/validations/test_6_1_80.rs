@@ -0,0 +1,131 @@
+use crate::csaf_traits::{CsafTraitExt, InvolvementTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+use serde_json::Value;
+
+/// The values of `/vulnerabilities[]/involvements[]/status` defined by CSAF
+pub static INVOLVEMENT_STATUSES: &[&str] = &[
+    "completed",
+    "contact_attempted",
+    "disputed",
+    "in_progress",
+    "not_contacted",
+    "open",
+];
+
+/// The values of `/vulnerabilities[]/involvements[]/party` defined by CSAF
+pub static INVOLVEMENT_PARTIES: &[&str] = &["coordinator", "discoverer", "other", "user", "vendor"];
+
+/// 6.1.80 Involvement Status and Party
+///
+/// For each item in `/vulnerabilities[]/involvements[]`, it is tested that `status` and `party`
+/// have one of the values defined by CSAF. Parsed documents always pass, as the schema types
+/// reject other values. Use [check_raw_involvements] on the JSON of documents that are parsed
+/// leniently, where involvements with unknown values are dropped.
+pub fn test_6_1_80_involvement_values(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_i, involvement) in v.get_involvements().iter().flatten().enumerate() {
+            let path = format!("/vulnerabilities/{}/involvements/{}", v_i, i_i);
+            check_values(
+                &involvement.get_status().to_string(),
+                &involvement.get_party().to_string(),
+                &path,
+                &mut errors,
+            );
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Runs test 6.1.80 on the JSON of a document, e.g. before parsing it leniently.
+///
+/// Missing or non-string values are reported as well.
+pub fn check_raw_involvements(doc: &Value) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    let vulnerabilities = doc.get("vulnerabilities").and_then(Value::as_array);
+    for (v_i, v) in vulnerabilities.into_iter().flatten().enumerate() {
+        let involvements = v.get("involvements").and_then(Value::as_array);
+        for (i_i, involvement) in involvements.into_iter().flatten().enumerate() {
+            let value = |key: &str| match involvement.get(key) {
+                Some(Value::String(s)) => s.clone(),
+                Some(other) => other.to_string(),
+                None => "(missing)".to_string(),
+            };
+            let path = format!("/vulnerabilities/{}/involvements/{}", v_i, i_i);
+            check_values(&value("status"), &value("party"), &path, &mut errors);
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+fn check_values(status: &str, party: &str, path: &str, errors: &mut Option<Vec<ValidationError>>) {
+    for (field, value, allowed) in [
+        ("status", status, INVOLVEMENT_STATUSES),
+        ("party", party, INVOLVEMENT_PARTIES),
+    ] {
+        if !allowed.contains(&value) {
+            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                "6.1.80",
+                format!(
+                    "Involvement {} '{}' is not one of: {}",
+                    field,
+                    value,
+                    allowed.join(", ")
+                ),
+                path,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_80::{check_raw_involvements, test_6_1_80_involvement_values};
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_80() {
+        let involvements = json!([
+            { "party": "vendor", "status": "completed" },
+            { "party": "coordinator", "status": "contact_attempted" }
+        ]);
+        let doc21 = mock_csaf21(|doc| doc["vulnerabilities"] = json!([{ "involvements": involvements.clone() }]));
+        assert_eq!(Ok(()), test_6_1_80_involvement_values(&doc21));
+        let doc20 = mock_csaf20(|doc| doc["vulnerabilities"] = json!([{ "involvements": involvements.clone() }]));
+        assert_eq!(Ok(()), test_6_1_80_involvement_values(&doc20));
+
+        let raw = json!({
+            "vulnerabilities": [{
+                "involvements": [
+                    { "party": "vendor", "status": "completed" },
+                    { "party": "researcher", "status": "done" },
+                    { "party": "user" }
+                ]
+            }]
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.80",
+                    "Involvement status 'done' is not one of: completed, contact_attempted, disputed, in_progress, \
+                     not_contacted, open",
+                    "/vulnerabilities/0/involvements/1",
+                ),
+                ValidationError::new(
+                    "6.1.80",
+                    "Involvement party 'researcher' is not one of: coordinator, discoverer, other, user, vendor",
+                    "/vulnerabilities/0/involvements/1",
+                ),
+                ValidationError::new(
+                    "6.1.80",
+                    "Involvement status '(missing)' is not one of: completed, contact_attempted, disputed, \
+                     in_progress, not_contacted, open",
+                    "/vulnerabilities/0/involvements/2",
+                ),
+            ]),
+            check_raw_involvements(&raw)
+        );
+    }
+}
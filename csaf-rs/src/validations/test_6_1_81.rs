@@ -0,0 +1,85 @@
+use crate::csaf_traits::{AcknowledgmentTrait, CsafTraitExt, DocumentTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+
+/// 6.1.81 Acknowledgment Without Names or Organization
+///
+/// For each item in `/document/acknowledgments` and `/vulnerabilities[]/acknowledgments`, it is
+/// tested that it contains `names` or an `organization`. An acknowledgment with only a `summary`
+/// or `urls` does not acknowledge anyone.
+pub fn test_6_1_81_acknowledgment_without_names(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    check_acknowledgments(
+        doc.get_document().get_acknowledgments(),
+        "/document/acknowledgments",
+        &mut errors,
+    );
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        check_acknowledgments(
+            v.get_acknowledgments(),
+            &format!("/vulnerabilities/{}/acknowledgments", v_i),
+            &mut errors,
+        );
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+fn check_acknowledgments(
+    acknowledgments: Option<&Vec<impl AcknowledgmentTrait>>,
+    path: &str,
+    errors: &mut Option<Vec<ValidationError>>,
+) {
+    for (a_i, acknowledgment) in acknowledgments.into_iter().flatten().enumerate() {
+        if acknowledgment.get_names().next().is_none() && acknowledgment.get_organization().is_none() {
+            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                "6.1.81",
+                "Acknowledgment contains neither names nor an organization",
+                format!("{}/{}", path, a_i),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_81::test_6_1_81_acknowledgment_without_names;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_81() {
+        let valid = mock_csaf21(|doc| {
+            doc["document"]["acknowledgments"] = json!([
+                { "organization": "Example Org" },
+                { "names": ["Jane Doe"], "summary": "Finding the vulnerability." }
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_81_acknowledgment_without_names(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["document"]["acknowledgments"] = json!([{ "summary": "Thanks to everyone." }]);
+            doc["vulnerabilities"] = json!([{
+                "acknowledgments": [
+                    { "organization": "Example Org" },
+                    { "urls": ["https://example.com/thanks"] }
+                ]
+            }]);
+        });
+        let error = |path: &str| {
+            ValidationError::new(
+                "6.1.81",
+                "Acknowledgment contains neither names nor an organization",
+                path,
+            )
+        };
+        assert_eq!(
+            Err(vec![
+                error("/document/acknowledgments/0"),
+                error("/vulnerabilities/0/acknowledgments/1"),
+            ]),
+            test_6_1_81_acknowledgment_without_names(&invalid)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, TrackingTrait};
+use crate::validation::ValidationError;
+
+/// 6.1.82 Current Release Date Before Newest Revision Date
+///
+/// It is tested that the `current_release_date` is equal to or later than the `date` of the
+/// newest item in the `revision_history`, as a document cannot be released before its last
+/// change. Dates that cannot be parsed are skipped, as they are reported by other tests.
+pub fn test_6_1_82_current_release_date_before_revision(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let tracking = doc.get_document().get_tracking();
+    let (Some(current_release), Ok(Some(newest_revision))) = (
+        tracking.get_current_release_timestamp(),
+        tracking.get_newest_revision_timestamp(),
+    ) else {
+        return Ok(());
+    };
+
+    if current_release < newest_revision {
+        return Err(vec![ValidationError::new(
+            "6.1.82",
+            format!(
+                "Current release date {} is earlier than the date {} of the newest revision",
+                tracking.get_current_release_date(),
+                newest_revision
+            ),
+            "/document/tracking/current_release_date",
+        )]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_82::test_6_1_82_current_release_date_before_revision;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_82() {
+        let revisions = json!([
+            { "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." },
+            { "date": "2024-01-25T12:00:00+02:00", "number": "2", "summary": "Update." }
+        ]);
+
+        let valid = mock_csaf21(|doc| {
+            doc["document"]["tracking"]["revision_history"] = revisions.clone();
+            doc["document"]["tracking"]["current_release_date"] = "2024-01-25T10:00:00.000Z".into();
+            doc["document"]["tracking"]["version"] = "2".into();
+        });
+        assert_eq!(Ok(()), test_6_1_82_current_release_date_before_revision(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["document"]["tracking"]["revision_history"] = revisions.clone();
+            doc["document"]["tracking"]["current_release_date"] = "2024-01-25T09:59:59.000Z".into();
+            doc["document"]["tracking"]["version"] = "2".into();
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.82",
+                "Current release date 2024-01-25T09:59:59.000Z is earlier than the date 2024-01-25T10:00:00Z of \
+                 the newest revision",
+                "/document/tracking/current_release_date",
+            )]),
+            test_6_1_82_current_release_date_before_revision(&invalid)
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, NoteTrait, VulnerabilityTrait};
+use crate::schema::csaf2_1::schema::NoteCategory;
+use crate::validation::{Severity, ValidationError};
+
+/// The default maximum number of characters of a note with category `summary`
+pub static DEFAULT_MAX_SUMMARY_LENGTH: usize = 2000;
+
+/// 6.1.83 Note Text
+///
+/// For each item in `/document/notes` and `/vulnerabilities[]/notes`, it is tested that its
+/// `text` is not empty or whitespace only. Notes with category `summary` longer than
+/// [DEFAULT_MAX_SUMMARY_LENGTH] characters are reported as warnings, see [check_notes] for other
+/// limits.
+pub fn test_6_1_83_note_text(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    check_notes(doc, DEFAULT_MAX_SUMMARY_LENGTH)
+}
+
+/// Runs test 6.1.83 with a maximum of `max_summary_length` characters for summary notes.
+pub fn check_notes(doc: &impl CsafTraitExt, max_summary_length: usize) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    check_note_list(
+        doc.get_document().get_notes(),
+        "/document/notes",
+        max_summary_length,
+        &mut errors,
+    );
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        check_note_list(
+            v.get_notes(),
+            &format!("/vulnerabilities/{}/notes", v_i),
+            max_summary_length,
+            &mut errors,
+        );
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+fn check_note_list(
+    notes: Option<&Vec<impl NoteTrait>>,
+    path: &str,
+    max_summary_length: usize,
+    errors: &mut Option<Vec<ValidationError>>,
+) {
+    for (n_i, note) in notes.into_iter().flatten().enumerate() {
+        let text = note.get_text();
+        let text_path = format!("{}/{}/text", path, n_i);
+        if text.trim().is_empty() {
+            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                "6.1.83",
+                "Note text must not be empty",
+                text_path,
+            ));
+        } else if note.get_category() == NoteCategory::Summary {
+            let length = text.chars().count();
+            if length > max_summary_length {
+                errors.get_or_insert_with(Vec::new).push(
+                    ValidationError::new(
+                        "6.1.83",
+                        format!(
+                            "Summary note has {} characters, more than the recommended maximum of {}",
+                            length, max_summary_length
+                        ),
+                        text_path,
+                    )
+                    .with_severity(Severity::Warning),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_83::{check_notes, test_6_1_83_note_text};
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_83() {
+        let valid = mock_csaf21(|doc| {
+            doc["document"]["notes"] = json!([
+                { "category": "summary", "text": "Short summary." },
+                { "category": "details", "text": "x".repeat(3000) }
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_83_note_text(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["document"]["notes"] = json!([{ "category": "summary", "text": "ü".repeat(2001) }]);
+            doc["vulnerabilities"] = json!([{
+                "notes": [
+                    { "category": "general", "text": "Fine." },
+                    { "category": "description", "text": " \n\t" }
+                ]
+            }]);
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.83",
+                    "Summary note has 2001 characters, more than the recommended maximum of 2000",
+                    "/document/notes/0/text",
+                )
+                .with_severity(Severity::Warning),
+                ValidationError::new(
+                    "6.1.83",
+                    "Note text must not be empty",
+                    "/vulnerabilities/0/notes/1/text"
+                ),
+            ]),
+            test_6_1_83_note_text(&invalid)
+        );
+
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.83",
+                    "Summary note has 14 characters, more than the recommended maximum of 10",
+                    "/document/notes/0/text",
+                )
+                .with_severity(Severity::Warning),
+            ]),
+            check_notes(&valid, 10)
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::csaf_traits::{CsafTraitExt, ProductTreeTrait, RelationshipTrait};
+use crate::validation::ValidationError;
+use serde_json::Value;
+
+/// The values of `/product_tree/relationships[]/category` defined by CSAF
+pub static RELATIONSHIP_CATEGORIES: &[&str] = &[
+    "default_component_of",
+    "external_component_of",
+    "installed_on",
+    "installed_with",
+    "optional_component_of",
+];
+
+/// 6.1.84 Relationship Category
+///
+/// For each item in `/product_tree/relationships`, it is tested that its `category` has one of
+/// the values defined by CSAF. Like 6.1.80, parsed documents always pass, use
+/// [check_raw_relationships] on the JSON of documents that are parsed leniently.
+pub fn test_6_1_84_relationship_category(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    if let Some(product_tree) = doc.get_product_tree() {
+        for (r_i, relationship) in product_tree.get_relationships().iter().enumerate() {
+            check_category(
+                &relationship.get_category().to_string(),
+                &format!("/product_tree/relationships/{}", r_i),
+                &mut errors,
+            );
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Runs test 6.1.84 on the JSON of a document, e.g. before parsing it leniently.
+///
+/// Missing or non-string categories are reported as well.
+pub fn check_raw_relationships(doc: &Value) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    let relationships = doc.pointer("/product_tree/relationships").and_then(Value::as_array);
+    for (r_i, relationship) in relationships.into_iter().flatten().enumerate() {
+        let category = match relationship.get("category") {
+            Some(Value::String(s)) => s.clone(),
+            Some(other) => other.to_string(),
+            None => "(missing)".to_string(),
+        };
+        check_category(&category, &format!("/product_tree/relationships/{}", r_i), &mut errors);
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+fn check_category(category: &str, path: &str, errors: &mut Option<Vec<ValidationError>>) {
+    if !RELATIONSHIP_CATEGORIES.contains(&category) {
+        errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+            "6.1.84",
+            format!(
+                "Relationship category '{}' is not one of: {}",
+                category,
+                RELATIONSHIP_CATEGORIES.join(", ")
+            ),
+            path,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_84::{check_raw_relationships, test_6_1_84_relationship_category};
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_84() {
+        let product_tree = json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-0001" },
+                { "name": "Product B", "product_id": "CSAFPID-0002" }
+            ],
+            "relationships": [{
+                "category": "installed_on",
+                "full_product_name": { "name": "Product A on Product B", "product_id": "CSAFPID-0003" },
+                "product_reference": "CSAFPID-0001",
+                "relates_to_product_reference": "CSAFPID-0002"
+            }]
+        });
+        let doc21 = mock_csaf21(|doc| doc["product_tree"] = product_tree.clone());
+        assert_eq!(Ok(()), test_6_1_84_relationship_category(&doc21));
+        let doc20 = mock_csaf20(|doc| doc["product_tree"] = product_tree.clone());
+        assert_eq!(Ok(()), test_6_1_84_relationship_category(&doc20));
+
+        let raw = json!({
+            "product_tree": {
+                "relationships": [
+                    { "category": "installed_on" },
+                    { "category": "runs_on" },
+                    { "category": 42 }
+                ]
+            }
+        });
+        let error = |category: &str, path: &str| {
+            ValidationError::new(
+                "6.1.84",
+                format!(
+                    "Relationship category '{}' is not one of: default_component_of, external_component_of, \
+                     installed_on, installed_with, optional_component_of",
+                    category
+                ),
+                path,
+            )
+        };
+        assert_eq!(
+            Err(vec![
+                error("runs_on", "/product_tree/relationships/1"),
+                error("42", "/product_tree/relationships/2"),
+            ]),
+            check_raw_relationships(&raw)
+        );
+    }
+}
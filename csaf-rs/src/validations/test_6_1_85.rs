@@ -0,0 +1,79 @@
+use crate::csaf_traits::{CsafTraitExt, VulnerabilityIdTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+use std::collections::HashMap;
+
+/// 6.1.85 Duplicate Vulnerability ID
+///
+/// For each vulnerability, it is tested that no two items in `ids` share the same `system_name`
+/// and `text`. Both are compared exactly, i.e. case-sensitive. Every repetition is reported at
+/// its own index.
+pub fn test_6_1_85_duplicate_vulnerability_ids(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let mut seen: HashMap<(&String, &String), usize> = HashMap::new();
+        for (id_i, id) in v.get_ids().iter().flatten().enumerate() {
+            let key = (id.get_system_name(), id.get_text());
+            if let Some(first) = seen.get(&key) {
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.85",
+                    format!(
+                        "Vulnerability ID '{}' of system '{}' duplicates ID {}",
+                        key.1, key.0, first
+                    ),
+                    format!("/vulnerabilities/{}/ids/{}", v_i, id_i),
+                ));
+            } else {
+                seen.insert(key, id_i);
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_85::test_6_1_85_duplicate_vulnerability_ids;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_85() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([
+                { "ids": [
+                    { "system_name": "GHSA", "text": "GHSA-1234" },
+                    { "system_name": "ghsa", "text": "GHSA-1234" },
+                    { "system_name": "GHSA", "text": "GHSA-5678" }
+                ] },
+                { "ids": [{ "system_name": "GHSA", "text": "GHSA-1234" }] }
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_85_duplicate_vulnerability_ids(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "ids": [
+                    { "system_name": "GHSA", "text": "GHSA-1234" },
+                    { "system_name": "Vendor", "text": "VULN-1" },
+                    { "system_name": "GHSA", "text": "GHSA-1234" },
+                    { "system_name": "GHSA", "text": "GHSA-1234" }
+                ]
+            }]);
+        });
+        let error = |path: &str| {
+            ValidationError::new(
+                "6.1.85",
+                "Vulnerability ID 'GHSA-1234' of system 'GHSA' duplicates ID 0",
+                path,
+            )
+        };
+        assert_eq!(
+            Err(vec![
+                error("/vulnerabilities/0/ids/2"),
+                error("/vulnerabilities/0/ids/3")
+            ]),
+            test_6_1_85_duplicate_vulnerability_ids(&invalid)
+        );
+    }
+}
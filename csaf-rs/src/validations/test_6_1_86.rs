@@ -0,0 +1,164 @@
+use crate::csaf_traits::{
+    BranchTrait, CsafTraitExt, ProductStatusTrait, ProductTrait, ProductTreeTrait, RelationshipTrait, StatusCategory,
+    VulnerabilityTrait,
+};
+use crate::schema::csaf2_1::schema::CategoryOfTheBranch;
+use crate::validation::{Severity, ValidationError};
+use std::collections::HashSet;
+
+/// The status categories that only make sense for a specific version
+static VERSIONED_CATEGORIES: &[StatusCategory] = &[
+    StatusCategory::FirstAffected,
+    StatusCategory::FirstFixed,
+    StatusCategory::LastAffected,
+];
+
+/// 6.1.86 Version-less Product in Versioned Product Status
+///
+/// For each product listed in `first_affected`, `first_fixed` or `last_affected`, it is tested
+/// that it has a version context, i.e. it is defined in or below a branch of category
+/// `product_version` or `product_version_range`, or it is a relationship whose
+/// `product_reference` has a version context. Products only defined in `full_product_names`
+/// have none, as their version cannot be told apart from the name. Products without version
+/// context are reported as warnings.
+pub fn test_6_1_86_versionless_product_status(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let versioned = versioned_product_ids(doc);
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let Some(product_status) = v.get_product_status() else {
+            continue;
+        };
+        for category in VERSIONED_CATEGORIES {
+            for (x_i, product_id) in product_status.ids_for(*category).iter().enumerate() {
+                if !versioned.contains(product_id.as_str()) {
+                    errors.get_or_insert_with(Vec::new).push(
+                        ValidationError::new(
+                            "6.1.86",
+                            format!(
+                                "Product {} is listed as {} but is not defined with a version or version range",
+                                product_id,
+                                category.name()
+                            ),
+                            format!("/vulnerabilities/{}/product_status/{}/{}", v_i, category.name(), x_i),
+                        )
+                        .with_severity(Severity::Warning),
+                    );
+                }
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Returns the IDs of all products with a version context.
+fn versioned_product_ids(doc: &impl CsafTraitExt) -> HashSet<String> {
+    let mut versioned = HashSet::new();
+    let Some(product_tree) = doc.get_product_tree() else {
+        return versioned;
+    };
+    for branch in product_tree.get_branches().into_iter().flatten() {
+        collect_versioned(branch, false, &mut versioned);
+    }
+    // Relationships may build on each other, so repeat until no new product is found
+    loop {
+        let before = versioned.len();
+        for relationship in product_tree.get_relationships() {
+            if versioned.contains(relationship.get_product_reference()) {
+                versioned.insert(relationship.get_full_product_name().get_product_id().to_string());
+            }
+        }
+        if versioned.len() == before {
+            return versioned;
+        }
+    }
+}
+
+fn collect_versioned<FPN: ProductTrait>(
+    branch: &impl BranchTrait<FPN>,
+    in_version: bool,
+    versioned: &mut HashSet<String>,
+) {
+    let in_version = in_version
+        || matches!(
+            branch.get_category(),
+            CategoryOfTheBranch::ProductVersion | CategoryOfTheBranch::ProductVersionRange
+        );
+    if in_version && let Some(product) = branch.get_product() {
+        versioned.insert(product.get_product_id().to_string());
+    }
+    for child in branch.get_branches().into_iter().flatten() {
+        collect_versioned(child, in_version, versioned);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_86::test_6_1_86_versionless_product_status;
+    use serde_json::{Value, json};
+
+    fn with_status(doc: &mut Value, product_status: Value) {
+        doc["product_tree"] = json!({
+            "branches": [{
+                "category": "vendor",
+                "name": "Vendor",
+                "branches": [{
+                    "category": "product_name",
+                    "name": "Product",
+                    "product": { "name": "Vendor Product", "product_id": "CSAFPID-0001" },
+                    "branches": [{
+                        "category": "product_version",
+                        "name": "1.0",
+                        "product": { "name": "Vendor Product 1.0", "product_id": "CSAFPID-0002" }
+                    }]
+                }]
+            }],
+            "full_product_names": [{ "name": "Platform", "product_id": "CSAFPID-0003" }],
+            "relationships": [{
+                "category": "installed_on",
+                "full_product_name": { "name": "Vendor Product 1.0 on Platform", "product_id": "CSAFPID-0004" },
+                "product_reference": "CSAFPID-0002",
+                "relates_to_product_reference": "CSAFPID-0003"
+            }]
+        });
+        doc["vulnerabilities"] = json!([{ "product_status": product_status }]);
+    }
+
+    #[test]
+    fn test_test_6_1_86() {
+        let valid = mock_csaf21(|doc| {
+            with_status(
+                doc,
+                json!({ "first_affected": ["CSAFPID-0002"], "last_affected": ["CSAFPID-0004"], "known_affected": ["CSAFPID-0001"] }),
+            )
+        });
+        assert_eq!(Ok(()), test_6_1_86_versionless_product_status(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            with_status(
+                doc,
+                json!({ "first_affected": ["CSAFPID-0002", "CSAFPID-0001"], "first_fixed": ["CSAFPID-0003"] }),
+            )
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.86",
+                    "Product CSAFPID-0001 is listed as first_affected but is not defined with a version or version \
+                     range",
+                    "/vulnerabilities/0/product_status/first_affected/1",
+                )
+                .with_severity(Severity::Warning),
+                ValidationError::new(
+                    "6.1.86",
+                    "Product CSAFPID-0003 is listed as first_fixed but is not defined with a version or version range",
+                    "/vulnerabilities/0/product_status/first_fixed/0",
+                )
+                .with_severity(Severity::Warning),
+            ]),
+            test_6_1_86_versionless_product_status(&invalid)
+        );
+    }
+}
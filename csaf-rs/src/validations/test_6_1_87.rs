@@ -0,0 +1,127 @@
+use crate::csaf_traits::{
+    ContentTrait, CsafTraitExt, MetricTrait, VulnerabilityMetric, VulnerabilityTrait, get_metric_prop_name,
+};
+use crate::validation::ValidationError;
+use std::collections::HashMap;
+
+/// 6.1.87 Duplicate Metrics Content
+///
+/// For each vulnerability, it is tested that no two items in `metrics` apply to the same set of
+/// products and contain the same type of metric, e.g. two SSVC metrics, as it is unclear which
+/// one applies. Different types of metrics for the same products, like SSVC and CVSS, are fine.
+/// Unlike 6.1.7, the `source` of the metrics is not taken into account.
+///
+/// The test only applies to CSAF 2.1, whose `metrics` replace the `scores` of CSAF 2.0. Duplicate
+/// scores of CSAF 2.0 are covered by 6.1.7.
+pub fn test_6_1_87_duplicate_metrics_content(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let mut seen: HashMap<(Vec<&String>, VulnerabilityMetric), usize> = HashMap::new();
+        for (m_i, metric) in v.get_metrics().into_iter().flatten().enumerate() {
+            let mut products: Vec<&String> = metric.get_products().collect();
+            products.sort();
+            products.dedup();
+            let content = metric.get_content();
+            for kind in content.iter_metric_contents() {
+                let key = (products.clone(), kind);
+                if let Some(first) = seen.get(&key) {
+                    errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                        "6.1.87",
+                        format!(
+                            "Metric {} contains a {} metric for the same products as metric {}",
+                            m_i, key.1, first
+                        ),
+                        format!(
+                            "{}/{}",
+                            content.get_content_json_path(v_i, m_i),
+                            get_metric_prop_name(key.1.clone())
+                        ),
+                    ));
+                } else {
+                    seen.insert(key, m_i);
+                }
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_87::test_6_1_87_duplicate_metrics_content;
+    use serde_json::{Value, json};
+
+    fn ssvc(id: &str) -> Value {
+        json!({
+            "id": id,
+            "schemaVersion": "2.0.0",
+            "selections": [{
+                "name": "Exploitation",
+                "namespace": "ssvc",
+                "values": [{ "key": "N", "name": "None" }],
+                "key": "E",
+                "version": "1.1.0"
+            }],
+            "timestamp": "2024-01-24T10:00:00.000Z"
+        })
+    }
+
+    fn cvss_v3(vector: &str) -> Value {
+        json!({ "version": "3.1", "vectorString": vector, "baseScore": 10.0, "baseSeverity": "CRITICAL" })
+    }
+
+    #[test]
+    fn test_test_6_1_87() {
+        let vector = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H";
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "metrics": [
+                    { "products": ["CSAFPID-0001", "CSAFPID-0002"], "content": { "ssvc_v2": ssvc("CVE-1") } },
+                    { "products": ["CSAFPID-0002", "CSAFPID-0001"], "content": { "cvss_v3": cvss_v3(vector) } },
+                    { "products": ["CSAFPID-0001"], "content": { "ssvc_v2": ssvc("CVE-1") } }
+                ]
+            }]);
+        });
+        assert_eq!(Ok(()), test_6_1_87_duplicate_metrics_content(&valid));
+
+        let invalid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "metrics": [
+                    { "products": ["CSAFPID-0001", "CSAFPID-0002"], "content": { "ssvc_v2": ssvc("CVE-1") } },
+                    {
+                        "products": ["CSAFPID-0002", "CSAFPID-0001"],
+                        "content": { "ssvc_v2": ssvc("CVE-1"), "cvss_v3": cvss_v3(vector) },
+                        "source": "https://example.com"
+                    }
+                ]
+            }]);
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.87",
+                "Metric 1 contains a SSVC-v1 metric for the same products as metric 0",
+                "/vulnerabilities/0/metrics/1/content/ssvc_v1",
+            )]),
+            test_6_1_87_duplicate_metrics_content(&invalid)
+        );
+
+        let invalid = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "scores": [
+                    { "products": ["CSAFPID-0001"], "cvss_v3": cvss_v3(vector) },
+                    { "products": ["CSAFPID-0001"], "cvss_v3": cvss_v3(vector) }
+                ]
+            }]);
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.87",
+                "Metric 1 contains a CVSS-v3.1 metric for the same products as metric 0",
+                "/vulnerabilities/0/scores/1/cvss_v3",
+            )]),
+            test_6_1_87_duplicate_metrics_content(&invalid)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, GeneratorTrait, RevisionTrait, TrackingTrait};
+use crate::timestamp::{Offset, Timestamp};
+use crate::validation::{Severity, ValidationError};
+
+/// 6.1.88 Consistent Offset of Timestamps
+///
+/// It is tested that all date-times of `/document/tracking`, i.e. the initial and current release
+/// date, the generator date and the dates of the revision history, are written with the same
+/// offset, e.g. all with `Z`. `Z` and `+00:00` count as different offsets. Every date-time whose
+/// offset differs from the first one is reported as a warning, unparsable ones are ignored.
+pub fn test_6_1_88_consistent_timestamp_offsets(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let tracking = doc.get_document().get_tracking();
+    let mut dates: Vec<(String, &String)> = vec![
+        (
+            "/document/tracking/initial_release_date".to_string(),
+            tracking.get_initial_release_date(),
+        ),
+        (
+            "/document/tracking/current_release_date".to_string(),
+            tracking.get_current_release_date(),
+        ),
+    ];
+    if let Some(date) = tracking.get_generator().as_ref().and_then(|g| g.get_date().as_ref()) {
+        dates.push(("/document/tracking/generator/date".to_string(), date));
+    }
+    for (r_i, revision) in tracking.get_revision_history().iter().enumerate() {
+        dates.push((
+            format!("/document/tracking/revision_history/{}/date", r_i),
+            revision.get_date(),
+        ));
+    }
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    let mut first: Option<(Offset, String)> = None;
+    for (path, date) in dates {
+        let Ok((_, offset)) = Timestamp::parse_with_offset(date) else {
+            continue;
+        };
+        match &first {
+            None => first = Some((offset, path)),
+            Some((first_offset, first_path)) if *first_offset != offset => {
+                errors.get_or_insert_with(Vec::new).push(
+                    ValidationError::new(
+                        "6.1.88",
+                        format!(
+                            "Date-time '{}' uses the offset {}, while {} uses {}",
+                            date, offset, first_path, first_offset
+                        ),
+                        path,
+                    )
+                    .with_severity(Severity::Warning),
+                );
+            },
+            Some(_) => {},
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_88::test_6_1_88_consistent_timestamp_offsets;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_88() {
+        let valid = mock_csaf21(|doc| {
+            doc["document"]["tracking"]["generator"] = json!({
+                "date": "2024-01-24T09:00:00Z",
+                "engine": { "name": "Engine" }
+            });
+        });
+        assert_eq!(Ok(()), test_6_1_88_consistent_timestamp_offsets(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["document"]["tracking"]["current_release_date"] = json!("2024-01-25T12:00:00+02:00");
+            doc["document"]["tracking"]["revision_history"] = json!([
+                { "date": "2024-01-24T10:00:00.000Z", "number": "1", "summary": "Initial version." },
+                { "date": "2024-01-25T10:00:00+00:00", "number": "2", "summary": "Update." }
+            ]);
+            doc["document"]["tracking"]["version"] = json!("2");
+        });
+        let error = |date: &str, offset: &str, path: &str| {
+            ValidationError::new(
+                "6.1.88",
+                format!(
+                    "Date-time '{}' uses the offset {}, while /document/tracking/initial_release_date uses Z",
+                    date, offset
+                ),
+                path,
+            )
+            .with_severity(Severity::Warning)
+        };
+        assert_eq!(
+            Err(vec![
+                error(
+                    "2024-01-25T12:00:00+02:00",
+                    "+02:00",
+                    "/document/tracking/current_release_date"
+                ),
+                error(
+                    "2024-01-25T10:00:00+00:00",
+                    "+00:00",
+                    "/document/tracking/revision_history/1/date"
+                ),
+            ]),
+            test_6_1_88_consistent_timestamp_offsets(&invalid)
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::csaf_traits::CsafTraitExt;
+use crate::validation::ValidationError;
+use crate::validations::presence::{Condition, PresenceRule, Requirement, check_document};
+use serde::Serialize;
+
+static RULES: &[PresenceRule] = &[PresenceRule {
+    code: "6.1.89",
+    path: "/document/publisher/contact_details",
+    condition: Some(Condition::FieldIn("category", &["vendor", "coordinator"])),
+    requirement: Requirement::NonEmpty,
+    message: "Publisher with category {} must provide contact details",
+}];
+
+/// 6.1.89 Publisher Contact Details
+///
+/// If the category of `/document/publisher` is `vendor` or `coordinator`, it is tested that its
+/// `contact_details` are present and not whitespace only, so that recipients know how to reach
+/// the publisher. The test applies to documents of all profiles.
+pub fn test_6_1_89_publisher_contact_details(
+    doc: &(impl CsafTraitExt + Serialize),
+) -> Result<(), Vec<ValidationError>> {
+    check_document(doc, RULES)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_89::test_6_1_89_publisher_contact_details;
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_89() {
+        let valid = mock_csaf21(|_| {});
+        assert_eq!(Ok(()), test_6_1_89_publisher_contact_details(&valid));
+        let discoverer = mock_csaf20(|doc| doc["document"]["publisher"]["category"] = json!("discoverer"));
+        assert_eq!(Ok(()), test_6_1_89_publisher_contact_details(&discoverer));
+
+        let missing = mock_csaf21(|doc| {
+            doc["document"]["publisher"]
+                .as_object_mut()
+                .unwrap()
+                .remove("contact_details");
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.89",
+                "Publisher with category vendor must provide contact details",
+                "/document/publisher/contact_details",
+            )]),
+            test_6_1_89_publisher_contact_details(&missing)
+        );
+        let blank = mock_csaf20(|doc| {
+            doc["document"]["publisher"]["category"] = json!("coordinator");
+            doc["document"]["publisher"]["contact_details"] = json!("  ");
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.89",
+                "Publisher with category coordinator must provide contact details",
+                "/document/publisher/contact_details",
+            )]),
+            test_6_1_89_publisher_contact_details(&blank)
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use crate::csaf_traits::{CsafTraitExt, ProductTreeTrait};
+use crate::validation::ValidationError;
+
+/// 6.1.90 Undefined Product in Relationship
+///
+/// For each item in `/product_tree/relationships`, it is tested that `product_reference` and
+/// `relates_to_product_reference` refer to products defined in the product tree, i.e. in a
+/// branch, in `full_product_names` or by another relationship. Each undefined reference is
+/// reported at its own path.
+pub fn test_6_1_90_undefined_relationship_product(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let Some(product_tree) = doc.get_product_tree() else {
+        return Ok(());
+    };
+    let defined = product_tree.get_product_names();
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (product_id, path) in product_tree.get_relationships_product_references() {
+        if !defined.contains_key(&product_id) {
+            errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                "6.1.90",
+                format!("Relationship refers to undefined product {}", product_id),
+                path,
+            ));
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_90::test_6_1_90_undefined_relationship_product;
+    use serde_json::{Value, json};
+
+    fn product_tree(relationships: Value) -> Value {
+        json!({
+            "branches": [{
+                "category": "product_name",
+                "name": "Product A",
+                "product": { "name": "Product A", "product_id": "CSAFPID-0001" }
+            }],
+            "full_product_names": [{ "name": "Platform", "product_id": "CSAFPID-0002" }],
+            "relationships": relationships
+        })
+    }
+
+    fn relationship(product_id: &str, product_reference: &str, relates_to_product_reference: &str) -> Value {
+        json!({
+            "category": "installed_on",
+            "full_product_name": { "name": product_id, "product_id": product_id },
+            "product_reference": product_reference,
+            "relates_to_product_reference": relates_to_product_reference
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_90() {
+        let valid = mock_csaf21(|doc| {
+            doc["product_tree"] = product_tree(json!([
+                relationship("CSAFPID-0003", "CSAFPID-0001", "CSAFPID-0002"),
+                relationship("CSAFPID-0004", "CSAFPID-0003", "CSAFPID-0002")
+            ]))
+        });
+        assert_eq!(Ok(()), test_6_1_90_undefined_relationship_product(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["product_tree"] = product_tree(json!([
+                relationship("CSAFPID-0003", "CSAFPID-0001", "CSAFPID-0002"),
+                relationship("CSAFPID-0004", "CSAFPID-9999", "CSAFPID-9998")
+            ]))
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.90",
+                    "Relationship refers to undefined product CSAFPID-9999",
+                    "/product_tree/relationships/1/product_reference",
+                ),
+                ValidationError::new(
+                    "6.1.90",
+                    "Relationship refers to undefined product CSAFPID-9998",
+                    "/product_tree/relationships/1/relates_to_product_reference",
+                ),
+            ]),
+            test_6_1_90_undefined_relationship_product(&invalid)
+        );
+    }
+}
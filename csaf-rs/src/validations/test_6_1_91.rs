@@ -0,0 +1,73 @@
+use crate::csaf_traits::{CsafTraitExt, CweTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+use std::collections::HashMap;
+
+/// 6.1.91 Duplicate CWE
+///
+/// For each vulnerability, it is tested that no two items in `cwes` have the same `id`. Every
+/// repetition is reported at its own index. CSAF 2.0 allows a single `cwe` only, so the test
+/// always passes there.
+pub fn test_6_1_91_duplicate_cwes(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        let mut seen: HashMap<&String, usize> = HashMap::new();
+        for (c_i, cwe) in v.get_cwes().enumerate() {
+            if let Some(first) = seen.get(cwe.get_id()) {
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.91",
+                    format!(
+                        "{} duplicates the CWE at /vulnerabilities/{}/cwes/{}",
+                        cwe.get_id(),
+                        v_i,
+                        first
+                    ),
+                    format!("/vulnerabilities/{}/cwes/{}", v_i, c_i),
+                ));
+            } else {
+                seen.insert(cwe.get_id(), c_i);
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_91::test_6_1_91_duplicate_cwes;
+    use serde_json::{Value, json};
+
+    fn cwe(id: &str, version: &str) -> Value {
+        json!({ "id": id, "name": "Weakness", "version": version })
+    }
+
+    #[test]
+    fn test_test_6_1_91() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([
+                { "cwes": [cwe("CWE-79", "4.13"), cwe("CWE-89", "4.13")] },
+                { "cwes": [cwe("CWE-79", "4.13")] }
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_91_duplicate_cwes(&valid));
+        let single = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{ "cwe": { "id": "CWE-79", "name": "Weakness" } }]);
+        });
+        assert_eq!(Ok(()), test_6_1_91_duplicate_cwes(&single));
+
+        let invalid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "cwes": [cwe("CWE-79", "4.13"), cwe("CWE-89", "4.13"), cwe("CWE-79", "4.14")]
+            }]);
+        });
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.91",
+                "CWE-79 duplicates the CWE at /vulnerabilities/0/cwes/0",
+                "/vulnerabilities/0/cwes/2",
+            )]),
+            test_6_1_91_duplicate_cwes(&invalid)
+        );
+    }
+}
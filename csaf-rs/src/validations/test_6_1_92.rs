@@ -0,0 +1,67 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait};
+use crate::validation::ValidationError;
+
+/// 6.1.92 Untrimmed Document Category
+///
+/// It is tested that `/document/category` is not empty and has no leading or trailing
+/// whitespace. The profile is selected by comparing the category verbatim, so an untrimmed
+/// category silently selects CSAF Base. The runner therefore executes this test first, and its
+/// message suggests the trimmed value. Prohibited names are tested by 6.1.26.
+pub fn test_6_1_92_untrimmed_document_category(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    check_document_category(doc.get_document().get_category_string())
+}
+
+/// Tests a document category like [test_6_1_92_untrimmed_document_category].
+///
+/// The schemas already reject such categories when parsing, so this allows checking raw values,
+/// e.g. from JSON that failed to parse.
+pub fn check_document_category(category: &str) -> Result<(), Vec<ValidationError>> {
+    let trimmed = category.trim();
+    let message = if trimmed.is_empty() {
+        "Document category must not be empty".to_string()
+    } else if trimmed != category {
+        format!(
+            "Document category '{}' has leading or trailing whitespace, use '{}' instead",
+            category, trimmed
+        )
+    } else {
+        return Ok(());
+    };
+    Err(vec![ValidationError::new("6.1.92", message, "/document/category")])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_92::{check_document_category, test_6_1_92_untrimmed_document_category};
+    use serde_json::json;
+
+    #[test]
+    fn test_test_6_1_92() {
+        let valid = mock_csaf21(|doc| doc["document"]["category"] = json!("csaf_base"));
+        assert_eq!(Ok(()), test_6_1_92_untrimmed_document_category(&valid));
+        let custom = mock_csaf20(|doc| doc["document"]["category"] = json!("Example Company Advisory"));
+        assert_eq!(Ok(()), test_6_1_92_untrimmed_document_category(&custom));
+
+        // The schemas reject untrimmed categories, so they are only checked as raw values
+        assert_eq!(
+            Err(vec![ValidationError::new(
+                "6.1.92",
+                "Document category ' csaf_vex\t' has leading or trailing whitespace, use 'csaf_vex' instead",
+                "/document/category",
+            )]),
+            check_document_category(" csaf_vex\t")
+        );
+        for blank in ["", "  "] {
+            assert_eq!(
+                Err(vec![ValidationError::new(
+                    "6.1.92",
+                    "Document category must not be empty",
+                    "/document/category",
+                )]),
+                check_document_category(blank)
+            );
+        }
+    }
+}
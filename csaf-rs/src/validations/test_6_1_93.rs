@@ -0,0 +1,198 @@
+use crate::csaf_traits::{ContentTrait, CsafTraitExt, MetricTrait, VulnerabilityTrait};
+use crate::helpers::{REGISTERED_SSVC_NAMESPACES, SSVC_DECISION_POINTS};
+use crate::validation::ValidationError;
+use std::ops::Deref;
+
+/// 6.1.93 Invalid SSVC Decision Point Value
+///
+/// For each SSVC selection of a registered namespace, it is tested against the embedded SSVC
+/// decision point catalog that
+/// * the decision point with the given `key` and `version` exists,
+/// * its `name`, if given, is the one of the decision point,
+/// * all `values[]` are values of the decision point, and
+/// * their `name`, if given, is the one of the value with the same `key`.
+///
+/// Unlike 6.1.48, all invalid selections are reported, each at the path of the selection. The
+/// order of values is tested by 6.1.48 only, SSVC objects that cannot be parsed by 6.1.46 and
+/// unsupported schema versions by 6.1.94.
+///
+/// The test only applies to CSAF 2.1, as CSAF 2.0 does not support SSVC.
+pub fn test_6_1_93_ssvc_decision_point_values(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_m, m) in v.get_metrics().into_iter().flatten().enumerate() {
+            let content = m.get_content();
+            if !content.has_ssvc() || content.has_unsupported_ssvc_schema() {
+                continue;
+            }
+            let Ok(ssvc) = content.get_ssvc() else {
+                continue;
+            };
+            for (i_s, selection) in ssvc.selections.iter().enumerate() {
+                let (namespace, key, version) = (
+                    selection.namespace.deref(),
+                    selection.key.deref(),
+                    selection.version.deref(),
+                );
+                if !REGISTERED_SSVC_NAMESPACES.contains(namespace) {
+                    continue;
+                }
+                let mut report = |message: String| {
+                    errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                        "6.1.93",
+                        message,
+                        format!(
+                            "/vulnerabilities/{}/metrics/{}/content/ssvc_v2/selections/{}",
+                            i_v, i_m, i_s
+                        ),
+                    ))
+                };
+
+                let dp_key = (namespace.to_owned(), key.to_owned(), version.to_owned());
+                let Some(dp) = SSVC_DECISION_POINTS.get(&dp_key) else {
+                    report(format!(
+                        "Unknown SSVC decision point '{}::{}' with version '{}'",
+                        namespace, key, version
+                    ));
+                    continue;
+                };
+                if let Some(name) = selection.name.as_deref()
+                    && name != dp.name.deref()
+                {
+                    report(format!(
+                        "SSVC decision point '{}::{}' (version {}) is named '{}', not '{}'",
+                        namespace,
+                        key,
+                        version,
+                        dp.name.deref(),
+                        name
+                    ));
+                }
+                for value in &selection.values {
+                    match dp
+                        .values
+                        .iter()
+                        .find(|dp_value| dp_value.key.deref() == value.key.deref())
+                    {
+                        None => report(format!(
+                            "'{}' is not a value of SSVC decision point '{}::{}' (version {}), allowed are: {}",
+                            value.key.deref(),
+                            namespace,
+                            dp.name.deref(),
+                            version,
+                            dp.values
+                                .iter()
+                                .map(|v| v.key.deref().as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )),
+                        Some(dp_value) => {
+                            if let Some(name) = value.name.as_deref()
+                                && name != dp_value.name.deref()
+                            {
+                                report(format!(
+                                    "Value '{}' of SSVC decision point '{}::{}' (version {}) is named '{}', not '{}'",
+                                    value.key.deref(),
+                                    namespace,
+                                    dp.name.deref(),
+                                    version,
+                                    dp_value.name.deref(),
+                                    name
+                                ));
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_93::test_6_1_93_ssvc_decision_point_values;
+    use serde_json::{Value, json};
+
+    fn ssvc(selections: Value) -> Value {
+        json!([{
+            "metrics": [{
+                "products": ["CSAFPID-0001"],
+                "content": {
+                    "ssvc_v2": {
+                        "schemaVersion": "2.0.0",
+                        "selections": selections,
+                        "timestamp": "2024-01-24T10:00:00.000Z"
+                    }
+                }
+            }]
+        }])
+    }
+
+    #[test]
+    fn test_test_6_1_93() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = ssvc(json!([
+                {
+                    "name": "Exploitation",
+                    "namespace": "ssvc",
+                    "values": [{ "key": "N", "name": "None" }, { "key": "A" }],
+                    "key": "E",
+                    "version": "1.1.0"
+                },
+                { "namespace": "x_example.com", "values": [{ "key": "Q" }], "key": "Q", "version": "1.0.0" }
+            ]));
+        });
+        assert_eq!(Ok(()), test_6_1_93_ssvc_decision_point_values(&valid));
+
+        let invalid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = ssvc(json!([
+                {
+                    "name": "Exploit",
+                    "namespace": "ssvc",
+                    "values": [{ "key": "X" }, { "key": "P", "name": "PoC" }],
+                    "key": "E",
+                    "version": "1.1.0"
+                },
+                { "namespace": "ssvc", "values": [{ "key": "N" }], "key": "E", "version": "9.9.9" }
+            ]));
+        });
+        let mut newer_schema = invalid.clone();
+        newer_schema.vulnerabilities[0].metrics.as_mut().unwrap()[0]
+            .content
+            .ssvc_v2
+            .insert("schemaVersion".to_string(), json!("2.1.0"));
+        assert_eq!(Ok(()), test_6_1_93_ssvc_decision_point_values(&newer_schema));
+
+        let path = "/vulnerabilities/0/metrics/0/content/ssvc_v2/selections";
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.93",
+                    "SSVC decision point 'ssvc::E' (version 1.1.0) is named 'Exploitation', not 'Exploit'",
+                    format!("{}/0", path),
+                ),
+                ValidationError::new(
+                    "6.1.93",
+                    "'X' is not a value of SSVC decision point 'ssvc::Exploitation' (version 1.1.0), allowed \
+                     are: N, P, A",
+                    format!("{}/0", path),
+                ),
+                ValidationError::new(
+                    "6.1.93",
+                    "Value 'P' of SSVC decision point 'ssvc::Exploitation' (version 1.1.0) is named 'Public \
+                     PoC', not 'PoC'",
+                    format!("{}/0", path),
+                ),
+                ValidationError::new(
+                    "6.1.93",
+                    "Unknown SSVC decision point 'ssvc::E' with version '9.9.9'",
+                    format!("{}/1", path),
+                ),
+            ]),
+            test_6_1_93_ssvc_decision_point_values(&invalid)
+        );
+    }
+}
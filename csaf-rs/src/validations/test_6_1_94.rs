@@ -0,0 +1,78 @@
+use crate::csaf_traits::{ContentTrait, CsafTraitExt, MetricTrait, VulnerabilityTrait};
+use crate::helpers::SUPPORTED_SSVC_SCHEMA_VERSIONS;
+use crate::validation::{Severity, ValidationError};
+
+/// 6.1.94 Unsupported SSVC Schema Version
+///
+/// For each SSVC metric, it is tested that its `schemaVersion` is one of
+/// [SUPPORTED_SSVC_SCHEMA_VERSIONS]. Newer schema versions are valid SSVC, but their content
+/// cannot be interpreted, so they are reported as a warning and skipped by the other SSVC tests.
+/// A missing `schemaVersion` is reported by 6.1.46.
+///
+/// The test only applies to CSAF 2.1, as CSAF 2.0 does not support SSVC.
+pub fn test_6_1_94_ssvc_schema_version(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (i_v, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (i_m, m) in v.get_metrics().into_iter().flatten().enumerate() {
+            let content = m.get_content();
+            if !content.has_unsupported_ssvc_schema() {
+                continue;
+            }
+            errors.get_or_insert_with(Vec::new).push(
+                ValidationError::new(
+                    "6.1.94",
+                    format!(
+                        "SSVC schema version '{}' is not supported, supported are: {}",
+                        content.get_ssvc_schema_version().unwrap_or_default(),
+                        SUPPORTED_SSVC_SCHEMA_VERSIONS.join(", ")
+                    ),
+                    format!("/vulnerabilities/{}/metrics/{}/content/ssvc_v2/schemaVersion", i_v, i_m),
+                )
+                .with_severity(Severity::Warning),
+            );
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::mock_csaf21;
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_94::test_6_1_94_ssvc_schema_version;
+    use serde_json::{Value, json};
+
+    fn ssvc(schema_version: &str) -> Value {
+        json!({
+            "products": ["CSAFPID-0001"],
+            "content": {
+                "ssvc_v2": {
+                    "schemaVersion": schema_version,
+                    "selections": [{ "namespace": "ssvc", "values": [{ "key": "A" }], "key": "E", "version": "1.1.0" }],
+                    "timestamp": "2024-01-24T10:00:00.000Z"
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_94() {
+        let valid = mock_csaf21(|doc| doc["vulnerabilities"] = json!([{ "metrics": [ssvc("2.0.0")] }]));
+        assert_eq!(Ok(()), test_6_1_94_ssvc_schema_version(&valid));
+
+        let invalid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{ "metrics": [ssvc("2.0.0"), ssvc("2.1.0")] }]);
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.94",
+                    "SSVC schema version '2.1.0' is not supported, supported are: 2.0.0",
+                    "/vulnerabilities/0/metrics/1/content/ssvc_v2/schemaVersion",
+                )
+                .with_severity(Severity::Warning)
+            ]),
+            test_6_1_94_ssvc_schema_version(&invalid)
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::csaf_traits::{CsafTraitExt, RemediationTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+use serde_json::Value;
+
+/// The values of `/vulnerabilities[]/remediations[]/category` defined by CSAF 2.1
+///
+/// CSAF 2.0 defines all of them except `fix_planned` and `optional_patch`.
+pub static REMEDIATION_CATEGORIES: &[&str] = &[
+    "fix_planned",
+    "mitigation",
+    "no_fix_planned",
+    "none_available",
+    "optional_patch",
+    "vendor_fix",
+    "workaround",
+];
+
+/// The remediation categories for which `restart_required` is meaningful
+pub static RESTART_REQUIRED_CATEGORIES: &[&str] = &["mitigation", "optional_patch", "vendor_fix", "workaround"];
+
+/// 6.1.95 Remediation Category and Restart Required
+///
+/// For each item in `/vulnerabilities[]/remediations[]`, it is tested that `category` has one of
+/// the values defined by CSAF, and that `restart_required` is only given for the
+/// [RESTART_REQUIRED_CATEGORIES], i.e. for remediations that are actually applied. Parsed
+/// documents always pass the first check, as the schema types reject other values. Use
+/// [check_raw_remediations] on the JSON of documents that are parsed leniently.
+pub fn test_6_1_95_remediation_category(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (r_i, remediation) in v.get_remediations().iter().enumerate() {
+            check_remediation(
+                &remediation.get_category().to_string(),
+                remediation.has_restart_required(),
+                REMEDIATION_CATEGORIES,
+                &format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                &mut errors,
+            );
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Runs test 6.1.95 on the JSON of a document, e.g. before parsing it leniently.
+///
+/// The categories of CSAF 2.0 are used if `/document/csaf_version` is `2.0`. Missing or
+/// non-string categories are reported as well.
+pub fn check_raw_remediations(doc: &Value) -> Result<(), Vec<ValidationError>> {
+    let categories_2_0: Vec<&str> = REMEDIATION_CATEGORIES
+        .iter()
+        .copied()
+        .filter(|c| !matches!(*c, "fix_planned" | "optional_patch"))
+        .collect();
+    let allowed = match doc.pointer("/document/csaf_version").and_then(Value::as_str) {
+        Some("2.0") => categories_2_0.as_slice(),
+        _ => REMEDIATION_CATEGORIES,
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    let vulnerabilities = doc.get("vulnerabilities").and_then(Value::as_array);
+    for (v_i, v) in vulnerabilities.into_iter().flatten().enumerate() {
+        let remediations = v.get("remediations").and_then(Value::as_array);
+        for (r_i, remediation) in remediations.into_iter().flatten().enumerate() {
+            let category = match remediation.get("category") {
+                Some(Value::String(s)) => s.clone(),
+                Some(other) => other.to_string(),
+                None => "(missing)".to_string(),
+            };
+            check_remediation(
+                &category,
+                remediation.get("restart_required").is_some_and(|r| !r.is_null()),
+                allowed,
+                &format!("/vulnerabilities/{}/remediations/{}", v_i, r_i),
+                &mut errors,
+            );
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+fn check_remediation(
+    category: &str,
+    has_restart_required: bool,
+    allowed: &[&str],
+    path: &str,
+    errors: &mut Option<Vec<ValidationError>>,
+) {
+    if !allowed.contains(&category) {
+        errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+            "6.1.95",
+            format!(
+                "Remediation category '{}' is not one of: {}",
+                category,
+                allowed.join(", ")
+            ),
+            format!("{}/category", path),
+        ));
+    } else if has_restart_required && !RESTART_REQUIRED_CATEGORIES.contains(&category) {
+        errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+            "6.1.95",
+            format!(
+                "Remediation of category '{}' must not state restart_required, it is only meaningful for: {}",
+                category,
+                RESTART_REQUIRED_CATEGORIES.join(", ")
+            ),
+            format!("{}/restart_required", path),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_95::{check_raw_remediations, test_6_1_95_remediation_category};
+    use serde_json::{Value, json};
+
+    fn remediation(category: &str, restart_required: bool) -> Value {
+        let mut remediation = json!({ "category": category, "details": "Details.", "product_ids": ["CSAFPID-0001"] });
+        if restart_required {
+            remediation["restart_required"] = json!({ "category": "system" });
+        }
+        remediation
+    }
+
+    #[test]
+    fn test_test_6_1_95() {
+        let valid = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "remediations": [remediation("vendor_fix", true), remediation("no_fix_planned", false)]
+            }]);
+        });
+        assert_eq!(Ok(()), test_6_1_95_remediation_category(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "remediations": [remediation("workaround", true), remediation("none_available", true)]
+            }]);
+        });
+        let expected = vec![ValidationError::new(
+            "6.1.95",
+            "Remediation of category 'none_available' must not state restart_required, it is only meaningful \
+             for: mitigation, optional_patch, vendor_fix, workaround",
+            "/vulnerabilities/0/remediations/1/restart_required",
+        )];
+        assert_eq!(Err(expected.clone()), test_6_1_95_remediation_category(&invalid));
+        assert_eq!(
+            Err(expected),
+            check_raw_remediations(&serde_json::to_value(&invalid).unwrap())
+        );
+
+        let mut raw = serde_json::to_value(&invalid).unwrap();
+        raw["vulnerabilities"][0]["remediations"] = json!([remediation("optional_patch", true), { "details": "" }]);
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.95",
+                    "Remediation category 'optional_patch' is not one of: mitigation, no_fix_planned, \
+                     none_available, vendor_fix, workaround",
+                    "/vulnerabilities/0/remediations/0/category",
+                ),
+                ValidationError::new(
+                    "6.1.95",
+                    "Remediation category '(missing)' is not one of: mitigation, no_fix_planned, none_available, \
+                     vendor_fix, workaround",
+                    "/vulnerabilities/0/remediations/1/category",
+                ),
+            ]),
+            check_raw_remediations(&raw)
+        );
+    }
+}
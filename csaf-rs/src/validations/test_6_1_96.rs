@@ -0,0 +1,78 @@
+use crate::csaf_traits::{CsafTraitExt, ProductGroupTrait, ProductTreeTrait};
+use crate::validation::{Severity, ValidationError};
+
+/// 6.1.96 Product Group Summary
+///
+/// For each item in `/product_tree/product_groups[]`, it is tested that a `summary` describes the
+/// purpose of the group. A summary consisting only of whitespace counts as missing. As the
+/// summary is optional in the schema, missing ones are reported as warnings.
+pub fn test_6_1_96_product_group_summary(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let Some(product_tree) = doc.get_product_tree() else {
+        return Ok(());
+    };
+
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (g_i, group) in product_tree.get_product_groups().iter().enumerate() {
+        if group.get_summary().is_none_or(|summary| summary.trim().is_empty()) {
+            errors.get_or_insert_with(Vec::new).push(
+                ValidationError::new(
+                    "6.1.96",
+                    format!("Product group {} has no summary", group.get_group_id()),
+                    format!("/product_tree/product_groups/{}", g_i),
+                )
+                .with_severity(Severity::Warning),
+            );
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_96::test_6_1_96_product_group_summary;
+    use serde_json::{Value, json};
+
+    fn product_tree(groups: Value) -> Value {
+        json!({
+            "full_product_names": [
+                { "name": "Product A", "product_id": "CSAFPID-9080700" },
+                { "name": "Product B", "product_id": "CSAFPID-9080701" }
+            ],
+            "product_groups": groups
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_96() {
+        let valid = mock_csaf21(|doc| {
+            doc["product_tree"] = product_tree(json!([{
+                "group_id": "CSAFGID-0001",
+                "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"],
+                "summary": "The x64 versions of the product."
+            }]))
+        });
+        assert_eq!(Ok(()), test_6_1_96_product_group_summary(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["product_tree"] = product_tree(json!([
+                { "group_id": "CSAFGID-0001", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"] },
+                { "group_id": "CSAFGID-0002", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"], "summary": "Modbus" },
+                { "group_id": "CSAFGID-0003", "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"], "summary": " \t" }
+            ]))
+        });
+        let warning = |group_id: &str, g_i: usize| {
+            ValidationError::new(
+                "6.1.96",
+                format!("Product group {} has no summary", group_id),
+                format!("/product_tree/product_groups/{}", g_i),
+            )
+            .with_severity(Severity::Warning)
+        };
+        assert_eq!(
+            Err(vec![warning("CSAFGID-0001", 0), warning("CSAFGID-0003", 2)]),
+            test_6_1_96_product_group_summary(&invalid)
+        );
+    }
+}
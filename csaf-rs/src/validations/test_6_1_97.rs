@@ -0,0 +1,93 @@
+use crate::csaf_traits::{CsafTraitExt, ProductIdentificationHelperTrait, ProductTrait, ProductTreeTrait};
+use crate::validation::ValidationError;
+use url::Url;
+
+/// 6.1.97 SBOM URL Format
+///
+/// For each `product_identification_helper` in the product tree, it is tested that every item of
+/// `sbom_urls` is an absolute URL with the scheme `http` or `https`. The URL is not fetched.
+pub fn test_6_1_97_sbom_url_format(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        product_tree.visit_all_products(&mut |product, path| {
+            let Some(helper) = product.get_product_identification_helper() else {
+                return;
+            };
+            for (u_i, url) in helper.get_sbom_urls().iter().enumerate() {
+                let message = match Url::parse(url) {
+                    Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => continue,
+                    Ok(parsed) => format!(
+                        "SBOM URL '{}' must use the http or https scheme, found '{}'",
+                        url,
+                        parsed.scheme()
+                    ),
+                    Err(e) => format!("SBOM URL '{}' is not an absolute URL: {}", url, e),
+                };
+                errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+                    "6.1.97",
+                    message,
+                    format!("{}/product_identification_helper/sbom_urls/{}", path, u_i),
+                ));
+            }
+        });
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_97::test_6_1_97_sbom_url_format;
+    use serde_json::{Value, json};
+
+    fn product_tree(sbom_urls: Value) -> Value {
+        json!({
+            "branches": [{
+                "category": "vendor",
+                "name": "Example Company",
+                "product": {
+                    "name": "Product A",
+                    "product_id": "CSAFPID-9080700",
+                    "product_identification_helper": { "sbom_urls": sbom_urls }
+                }
+            }]
+        })
+    }
+
+    #[test]
+    fn test_test_6_1_97() {
+        let valid = mock_csaf21(|doc| {
+            doc["product_tree"] = product_tree(json!([
+                "https://example.com/sbom/product-a.cdx.json",
+                "http://example.com/sbom/product-a.spdx.json"
+            ]))
+        });
+        assert_eq!(Ok(()), test_6_1_97_sbom_url_format(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["product_tree"] = product_tree(json!([
+                "https://example.com/sbom/product-a.cdx.json",
+                "sbom/product-a.spdx.json",
+                "ftp://example.com/sbom/product-a.spdx.json"
+            ]))
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.1.97",
+                    "SBOM URL 'sbom/product-a.spdx.json' is not an absolute URL: relative URL without a base",
+                    "/product_tree/branches/0/product/product_identification_helper/sbom_urls/1",
+                ),
+                ValidationError::new(
+                    "6.1.97",
+                    "SBOM URL 'ftp://example.com/sbom/product-a.spdx.json' must use the http or https scheme, found 'ftp'",
+                    "/product_tree/branches/0/product/product_identification_helper/sbom_urls/2",
+                ),
+            ]),
+            test_6_1_97_sbom_url_format(&invalid)
+        );
+    }
+}
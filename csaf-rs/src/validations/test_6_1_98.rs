@@ -0,0 +1,115 @@
+use crate::csaf_traits::{CsafTraitExt, FlagTrait, VulnerabilityTrait};
+use crate::validation::ValidationError;
+use serde_json::Value;
+
+/// The values of `/vulnerabilities[]/flags[]/label`, i.e. the VEX justification codes
+pub static FLAG_LABELS: &[&str] = &[
+    "component_not_present",
+    "inline_mitigations_already_exist",
+    "vulnerable_code_cannot_be_controlled_by_adversary",
+    "vulnerable_code_not_in_execute_path",
+    "vulnerable_code_not_present",
+];
+
+/// 6.1.98 Flag Label
+///
+/// For each item in `/vulnerabilities[]/flags[]`, it is tested that `label` is one of the
+/// [FLAG_LABELS]. Parsed documents always pass, as the schema types reject other values. Use
+/// [check_raw_flags] on the JSON of documents that are parsed leniently. That a product has at
+/// most one flag is the subject of 6.1.33.
+pub fn test_6_1_98_flag_label(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        for (f_i, flag) in v.get_flags().iter().flatten().enumerate() {
+            check_label(
+                &flag.get_label().to_string(),
+                &format!("/vulnerabilities/{}/flags/{}/label", v_i, f_i),
+                &mut errors,
+            );
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+/// Runs test 6.1.98 on the JSON of a document, e.g. before parsing it leniently.
+///
+/// Missing or non-string labels are reported as well.
+pub fn check_raw_flags(doc: &Value) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+    let vulnerabilities = doc.get("vulnerabilities").and_then(Value::as_array);
+    for (v_i, v) in vulnerabilities.into_iter().flatten().enumerate() {
+        let flags = v.get("flags").and_then(Value::as_array);
+        for (f_i, flag) in flags.into_iter().flatten().enumerate() {
+            let label = match flag.get("label") {
+                Some(Value::String(s)) => s.clone(),
+                Some(other) => other.to_string(),
+                None => "(missing)".to_string(),
+            };
+            check_label(
+                &label,
+                &format!("/vulnerabilities/{}/flags/{}/label", v_i, f_i),
+                &mut errors,
+            );
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+fn check_label(label: &str, path: &str, errors: &mut Option<Vec<ValidationError>>) {
+    if !FLAG_LABELS.contains(&label) {
+        errors.get_or_insert_with(Vec::new).push(ValidationError::new(
+            "6.1.98",
+            format!("Flag label '{}' is not one of: {}", label, FLAG_LABELS.join(", ")),
+            path,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::ValidationError;
+    use crate::validations::test_6_1_98::{FLAG_LABELS, check_raw_flags, test_6_1_98_flag_label};
+    use serde_json::{Value, json};
+
+    fn flag(label: Value) -> Value {
+        json!({ "label": label, "product_ids": ["CSAFPID-9080700"] })
+    }
+
+    #[test]
+    fn test_test_6_1_98() {
+        let flags: Vec<Value> = FLAG_LABELS.iter().map(|label| flag(json!(label))).collect();
+        let valid = mock_csaf21(|doc| doc["vulnerabilities"] = json!([{ "flags": flags }]));
+        assert_eq!(Ok(()), test_6_1_98_flag_label(&valid));
+        let valid =
+            mock_csaf20(|doc| doc["vulnerabilities"] = json!([{ "flags": [flag(json!("component_not_present"))] }]));
+        assert_eq!(Ok(()), test_6_1_98_flag_label(&valid));
+
+        // The schemas reject unknown labels, so they are only checked as raw values
+        let raw = json!({
+            "vulnerabilities": [
+                { "flags": [flag(json!("vulnerable_code_not_present"))] },
+                { "flags": [flag(json!("not_affected")), { "product_ids": ["CSAFPID-9080700"] }] }
+            ]
+        });
+        let error = |label: &str, path: &str| {
+            ValidationError::new(
+                "6.1.98",
+                format!(
+                    "Flag label '{}' is not one of: component_not_present, inline_mitigations_already_exist, \
+                     vulnerable_code_cannot_be_controlled_by_adversary, vulnerable_code_not_in_execute_path, \
+                     vulnerable_code_not_present",
+                    label
+                ),
+                path,
+            )
+        };
+        assert_eq!(
+            Err(vec![
+                error("not_affected", "/vulnerabilities/1/flags/0/label"),
+                error("(missing)", "/vulnerabilities/1/flags/1/label"),
+            ]),
+            check_raw_flags(&raw)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::csaf_traits::{CsafTraitExt, DocumentTrait, NoteTrait, VulnerabilityTrait};
+use crate::validation::{Severity, ValidationError};
+use std::collections::HashMap;
+
+/// 6.1.99 Duplicate Note Title
+///
+/// For `/document/notes` and for the `notes` of each vulnerability, it is tested that no two
+/// notes have the same non-empty `title`, as readers cannot tell such notes apart. Every
+/// repetition is reported at its own index as a warning. Notes without a title are exempt.
+pub fn test_6_1_99_duplicate_note_title(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    check_note_titles(doc.get_document().get_notes(), "/document/notes", &mut errors);
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        check_note_titles(v.get_notes(), &format!("/vulnerabilities/{}/notes", v_i), &mut errors);
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+fn check_note_titles(notes: Option<&Vec<impl NoteTrait>>, path: &str, errors: &mut Option<Vec<ValidationError>>) {
+    let mut seen: HashMap<&String, usize> = HashMap::new();
+    for (n_i, note) in notes.into_iter().flatten().enumerate() {
+        let Some(title) = note.get_title().filter(|t| !t.is_empty()) else {
+            continue;
+        };
+        if let Some(first) = seen.get(title) {
+            errors.get_or_insert_with(Vec::new).push(
+                ValidationError::new(
+                    "6.1.99",
+                    format!("Note title '{}' duplicates the title of note {}", title, first),
+                    format!("{}/{}/title", path, n_i),
+                )
+                .with_severity(Severity::Warning),
+            );
+        } else {
+            seen.insert(title, n_i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_1_99::test_6_1_99_duplicate_note_title;
+    use serde_json::{Value, json};
+
+    fn note(title: Option<&str>) -> Value {
+        let mut note = json!({ "category": "description", "text": "Some text." });
+        if let Some(title) = title {
+            note["title"] = json!(title);
+        }
+        note
+    }
+
+    #[test]
+    fn test_test_6_1_99() {
+        let valid = mock_csaf21(|doc| {
+            doc["document"]["notes"] = json!([note(Some("Summary")), note(None), note(None)]);
+            // The same title on different levels is fine
+            doc["vulnerabilities"] = json!([
+                { "notes": [note(Some("Summary")), note(Some("Details"))] },
+                { "notes": [note(Some("Summary"))] }
+            ]);
+        });
+        assert_eq!(Ok(()), test_6_1_99_duplicate_note_title(&valid));
+
+        let invalid = mock_csaf20(|doc| {
+            doc["document"]["notes"] = json!([note(Some("Summary")), note(Some("Details")), note(Some("Summary"))]);
+            doc["vulnerabilities"] = json!([{
+                "notes": [note(Some("Details")), note(Some("Details")), note(Some("details")), note(Some("Details"))]
+            }]);
+        });
+        let warning = |title: &str, first: usize, path: &str| {
+            ValidationError::new(
+                "6.1.99",
+                format!("Note title '{}' duplicates the title of note {}", title, first),
+                path,
+            )
+            .with_severity(Severity::Warning)
+        };
+        assert_eq!(
+            Err(vec![
+                warning("Summary", 0, "/document/notes/2/title"),
+                warning("Details", 0, "/vulnerabilities/0/notes/1/title"),
+                warning("Details", 0, "/vulnerabilities/0/notes/3/title"),
+            ]),
+            test_6_1_99_duplicate_note_title(&invalid)
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::csaf_traits::{
+    ContentTrait, CsafTraitExt, MetricTrait, VulnerabilityMetric, VulnerabilityTrait, get_metric_prop_name,
+};
+use crate::validation::{Severity, ValidationError};
+
+/// 6.2.13 Deprecated CVSS v3.0
+///
+/// It is tested that no metric uses CVSS v3.0, as it has been superseded by CVSS v3.1.
+///
+/// The test only applies to CSAF 2.1, CSAF 2.0 still accepts CVSS v3.0 without restrictions.
+pub fn test_6_2_13_deprecated_cvss_v3_0(doc: &impl CsafTraitExt) -> Result<(), Vec<ValidationError>> {
+    let mut errors: Option<Vec<ValidationError>> = None;
+
+    for (i_v, vulnerability) in doc.get_vulnerabilities().iter().enumerate() {
+        if let Some(metrics) = vulnerability.get_metrics() {
+            for (metric_index, metric) in metrics.iter().enumerate() {
+                let content = metric.get_content();
+                if let Some(cvss3) = content.get_cvss_v3() {
+                    // Use as_str because otherwise additional quotation marks would be included
+                    if let Some(version @ "3.0") = cvss3.get("version").and_then(|v| v.as_str()) {
+                        errors.get_or_insert_with(Vec::new).push(
+                            ValidationError::new(
+                                "6.2.13",
+                                "CVSS v3.0 is deprecated, please use CVSS v3.1 instead",
+                                format!(
+                                    "{}/{}",
+                                    content.get_content_json_path(i_v, metric_index),
+                                    get_metric_prop_name(VulnerabilityMetric::CvssV3(version.to_string()))
+                                ),
+                            )
+                            .with_severity(Severity::Warning),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::validation::{Severity, ValidationError};
+    use crate::validations::test_6_2_13::test_6_2_13_deprecated_cvss_v3_0;
+    use serde_json::json;
+
+    fn cvss_v3(version: &str) -> serde_json::Value {
+        json!({
+            "version": version,
+            "vectorString": format!("CVSS:{}/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", version),
+            "baseScore": 9.8,
+            "baseSeverity": "CRITICAL"
+        })
+    }
+
+    #[test]
+    fn test_test_6_2_13() {
+        let current = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "metrics": [
+                    { "content": { "cvss_v3": cvss_v3("3.1") }, "products": ["CSAFPID-9080700"] }
+                ]
+            }]);
+        });
+        assert_eq!(Ok(()), test_6_2_13_deprecated_cvss_v3_0(&current));
+
+        let deprecated_21 = mock_csaf21(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "metrics": [
+                    { "content": { "cvss_v3": cvss_v3("3.1") }, "products": ["CSAFPID-9080700"] },
+                    { "content": { "cvss_v3": cvss_v3("3.0") }, "products": ["CSAFPID-9080701"] }
+                ]
+            }]);
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.2.13",
+                    "CVSS v3.0 is deprecated, please use CVSS v3.1 instead",
+                    "/vulnerabilities/0/metrics/1/content/cvss_v3",
+                )
+                .with_severity(Severity::Warning)
+            ]),
+            test_6_2_13_deprecated_cvss_v3_0(&deprecated_21)
+        );
+
+        let deprecated_20 = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "scores": [{ "cvss_v3": cvss_v3("3.0"), "products": ["CSAFPID-9080700"] }]
+            }]);
+        });
+        assert_eq!(
+            Err(vec![
+                ValidationError::new(
+                    "6.2.13",
+                    "CVSS v3.0 is deprecated, please use CVSS v3.1 instead",
+                    "/vulnerabilities/0/scores/0/cvss_v3",
+                )
+                .with_severity(Severity::Warning)
+            ]),
+            test_6_2_13_deprecated_cvss_v3_0(&deprecated_20)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Parsing of product version ranges
+//!
+//! Two grammars are recognized for names of `product_version_range` branches: the vers notation
+//! (`vers:<scheme>/<constraint>|<constraint>...`, e.g. `vers:npm/>=1.0.0|<2.0.0`) and a simple
+//! notation of whitespace-separated constraints with a mandatory comparator (e.g. `>=4.1 <4.4`).
+
+use std::fmt::{Display, Formatter};
+
+/// The comparator of a version constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Display for Comparator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Comparator::Equal => "=",
+            Comparator::NotEqual => "!=",
+            Comparator::Less => "<",
+            Comparator::LessOrEqual => "<=",
+            Comparator::Greater => ">",
+            Comparator::GreaterOrEqual => ">=",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A single constraint of a range, e.g. `>=1.0.0`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constraint {
+    pub comparator: Comparator,
+    pub version: String,
+}
+
+/// A parsed version range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Range {
+    /// The versioning scheme of a vers range, `None` for the simple notation
+    pub scheme: Option<String>,
+    /// The constraints, all of which must be satisfied. Empty for the vers range `*` of all
+    /// versions.
+    pub constraints: Vec<Constraint>,
+}
+
+/// Reasons why a string is not a well-formed version range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    /// The string is empty or consists of whitespace only
+    Empty,
+    /// A vers range has no `/` separating the scheme from the constraints
+    MissingScheme,
+    /// The versioning scheme of a vers range contains characters other than lowercase ASCII
+    /// letters, digits, `.`, `-` and `+`
+    InvalidScheme(String),
+    /// The constraint at the given index has no version
+    MissingVersion(usize),
+    /// The constraint at the given index of a simple range has no comparator
+    MissingComparator(usize),
+    /// The vers range `*` is combined with other constraints
+    WildcardNotAlone,
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeError::Empty => write!(f, "Version range is empty"),
+            RangeError::MissingScheme => write!(f, "vers range must have the form 'vers:<scheme>/<constraints>'"),
+            RangeError::InvalidScheme(scheme) => write!(f, "vers range has an invalid scheme: '{}'", scheme),
+            RangeError::MissingVersion(i) => write!(f, "Constraint {} of the version range has no version", i),
+            RangeError::MissingComparator(i) => {
+                write!(f, "Constraint {} of the version range has no comparator", i)
+            },
+            RangeError::WildcardNotAlone => write!(f, "vers range '*' must not be combined with other constraints"),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+/// Comparators in the order they are matched, two-character comparators first
+const COMPARATORS: &[(&str, Comparator)] = &[
+    ("!=", Comparator::NotEqual),
+    ("<=", Comparator::LessOrEqual),
+    (">=", Comparator::GreaterOrEqual),
+    ("<", Comparator::Less),
+    (">", Comparator::Greater),
+    ("=", Comparator::Equal),
+];
+
+/// Parses a version range in vers or simple notation.
+pub fn parse(s: &str) -> Result<Range, RangeError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(RangeError::Empty);
+    }
+    match s.strip_prefix("vers:") {
+        Some(rest) => parse_vers(rest),
+        None => parse_simple(s),
+    }
+}
+
+fn parse_vers(rest: &str) -> Result<Range, RangeError> {
+    let (scheme, constraints) = rest.split_once('/').ok_or(RangeError::MissingScheme)?;
+    if scheme.is_empty()
+        || !scheme
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '-' | '+'))
+    {
+        return Err(RangeError::InvalidScheme(scheme.to_string()));
+    }
+
+    let parts: Vec<&str> = constraints.split('|').map(str::trim).collect();
+    if parts.contains(&"*") {
+        return if parts.len() == 1 {
+            Ok(Range {
+                scheme: Some(scheme.to_string()),
+                constraints: Vec::new(),
+            })
+        } else {
+            Err(RangeError::WildcardNotAlone)
+        };
+    }
+
+    let constraints = parts
+        .iter()
+        .enumerate()
+        .map(|(i, part)| {
+            // A vers constraint without comparator means equality
+            let (comparator, version) = split_comparator(part).unwrap_or((Comparator::Equal, part));
+            parse_version(i, comparator, version)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Range {
+        scheme: Some(scheme.to_string()),
+        constraints,
+    })
+}
+
+fn parse_simple(s: &str) -> Result<Range, RangeError> {
+    let constraints = s
+        .split_whitespace()
+        .enumerate()
+        .map(|(i, part)| {
+            let (comparator, version) = split_comparator(part).ok_or(RangeError::MissingComparator(i))?;
+            parse_version(i, comparator, version)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Range {
+        scheme: None,
+        constraints,
+    })
+}
+
+fn split_comparator(part: &str) -> Option<(Comparator, &str)> {
+    COMPARATORS
+        .iter()
+        .find_map(|(prefix, comparator)| part.strip_prefix(prefix).map(|version| (*comparator, version)))
+}
+
+fn parse_version(i: usize, comparator: Comparator, version: &str) -> Result<Constraint, RangeError> {
+    let version = version.trim();
+    if version.is_empty() {
+        return Err(RangeError::MissingVersion(i));
+    }
+    Ok(Constraint {
+        comparator,
+        version: version.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::version_range::{Comparator, Constraint, Range, RangeError, parse};
+
+    fn constraint(comparator: Comparator, version: &str) -> Constraint {
+        Constraint {
+            comparator,
+            version: version.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_vers() {
+        assert_eq!(
+            Ok(Range {
+                scheme: Some("npm".to_string()),
+                constraints: vec![
+                    constraint(Comparator::GreaterOrEqual, "1.0.0"),
+                    constraint(Comparator::Less, "2.0.0"),
+                    constraint(Comparator::NotEqual, "1.5.0"),
+                    constraint(Comparator::Equal, "3.0.0"),
+                ],
+            }),
+            parse("vers:npm/>=1.0.0|<2.0.0|!=1.5.0|3.0.0")
+        );
+        assert_eq!(
+            Ok(Range {
+                scheme: Some("generic".to_string()),
+                constraints: vec![],
+            }),
+            parse("vers:generic/*")
+        );
+
+        assert_eq!(Err(RangeError::MissingScheme), parse("vers:>=1.0.0"));
+        assert_eq!(
+            Err(RangeError::InvalidScheme("NPM".to_string())),
+            parse("vers:NPM/1.0.0")
+        );
+        assert_eq!(Err(RangeError::MissingVersion(1)), parse("vers:npm/>=1.0.0|<"));
+        assert_eq!(Err(RangeError::WildcardNotAlone), parse("vers:npm/*|<2.0.0"));
+    }
+
+    #[test]
+    fn test_parse_simple() {
+        assert_eq!(
+            Ok(Range {
+                scheme: None,
+                constraints: vec![
+                    constraint(Comparator::GreaterOrEqual, "4.1"),
+                    constraint(Comparator::Less, "4.4"),
+                ],
+            }),
+            parse(" >=4.1 <4.4 ")
+        );
+
+        assert_eq!(Err(RangeError::Empty), parse("  "));
+        assert_eq!(Err(RangeError::MissingComparator(1)), parse(">=4.1 4.4"));
+        assert_eq!(Err(RangeError::MissingVersion(0)), parse("<= >4.1"));
+    }
+}
@@ -0,0 +1,467 @@
+//! Generic traversal of CSAF documents
+//!
+//! [walk] visits selected values of a document, independent of its CSAF version, and reports
+//! each of them to a [Visitor] together with its JSON pointer (RFC 6901). This allows custom
+//! analyses, e.g. collecting all product IDs or URLs, without knowing the shape of the schema.
+
+use crate::csaf_traits::{
+    AcknowledgmentTrait, CsafTraitExt, DocumentReferenceTrait, DocumentTrait, FirstKnownExploitationDatesTrait,
+    FlagTrait, GeneratorTrait, InvolvementTrait, NoteTrait, ProductIdentificationHelperTrait, ProductTrait,
+    ProductTreeTrait, PublisherTrait, RemediationTrait, RevisionTrait, ThreatTrait, TrackingTrait, VulnerabilityTrait,
+};
+
+/// Whether a product ID defines a product or refers to one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductIdKind {
+    /// The `product_id` of a full product name in the product tree
+    Definition,
+    /// Any other product ID, e.g. in a product group, relationship or vulnerability
+    Reference,
+}
+
+/// The kind of field a URL was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlKind {
+    /// The namespace of the publisher
+    Namespace,
+    /// The URL of a document or vulnerability reference
+    Reference,
+    /// A URL of a document or vulnerability acknowledgment
+    Acknowledgment,
+    /// The URL of a remediation
+    Remediation,
+    /// An SBOM URL of a product identification helper
+    Sbom,
+    /// A generic URI of a product identification helper
+    GenericUri,
+}
+
+/// Callbacks invoked by [walk]
+///
+/// All callbacks do nothing by default, so implementations only override the ones they need.
+/// The `instance_path` of each callback is the JSON pointer of the visited value.
+pub trait Visitor {
+    /// Called for each product ID, both where a product is defined in the product tree and where
+    /// it is referenced, as told by `kind`.
+    fn visit_product_id(&mut self, product_id: &str, kind: ProductIdKind, instance_path: &str) {
+        let _ = (product_id, kind, instance_path);
+    }
+
+    /// Called for each URL, i.e. the publisher namespace, document and vulnerability reference
+    /// URLs, remediation and acknowledgment URLs as well as the SBOM URLs and generic URIs of
+    /// product identification helpers. `kind` tells these fields apart.
+    fn visit_url(&mut self, url: &str, kind: UrlKind, instance_path: &str) {
+        let _ = (url, kind, instance_path);
+    }
+
+    /// Called for each document and vulnerability note.
+    fn visit_note(&mut self, note: &impl NoteTrait, instance_path: &str) {
+        let _ = (note, instance_path);
+    }
+
+    /// Called for each date-time string, as it appears in the document.
+    fn visit_date(&mut self, date: &str, instance_path: &str) {
+        let _ = (date, instance_path);
+    }
+}
+
+/// Collects all product IDs with their kinds and paths, see [CsafTraitExt::iter_all_product_ids]
+#[derive(Default)]
+pub(crate) struct ProductIdCollector(pub Vec<(String, ProductIdKind, String)>);
+
+impl Visitor for ProductIdCollector {
+    fn visit_product_id(&mut self, product_id: &str, kind: ProductIdKind, instance_path: &str) {
+        self.0.push((product_id.to_string(), kind, instance_path.to_string()));
+    }
+}
+
+/// Collects all URLs with their kinds and paths, see [CsafTraitExt::iter_all_urls]
+#[derive(Default)]
+pub(crate) struct UrlCollector(pub Vec<(String, UrlKind, String)>);
+
+impl Visitor for UrlCollector {
+    fn visit_url(&mut self, url: &str, kind: UrlKind, instance_path: &str) {
+        self.0.push((url.to_string(), kind, instance_path.to_string()));
+    }
+}
+
+/// Traverses `doc` once and invokes the callbacks of `visitor` for all matching values.
+///
+/// Values are visited in the following order: document metadata, product tree, vulnerabilities.
+pub fn walk(doc: &impl CsafTraitExt, visitor: &mut impl Visitor) {
+    walk_document(doc.get_document(), visitor);
+
+    if let Some(product_tree) = doc.get_product_tree() {
+        walk_product_tree(product_tree, visitor);
+    }
+
+    for (v_i, v) in doc.get_vulnerabilities().iter().enumerate() {
+        walk_vulnerability(v, &format!("/vulnerabilities/{}", v_i), visitor);
+    }
+}
+
+fn walk_product_tree(product_tree: &impl ProductTreeTrait, visitor: &mut impl Visitor) {
+    product_tree.visit_all_products(&mut |product, path| {
+        visitor.visit_product_id(
+            product.get_product_id(),
+            ProductIdKind::Definition,
+            &format!("{}/product_id", path),
+        );
+        if let Some(helper) = product.get_product_identification_helper() {
+            let prefix = format!("{}/product_identification_helper", path);
+            for (u_i, url) in helper.get_sbom_urls().iter().enumerate() {
+                visitor.visit_url(url, UrlKind::Sbom, &format!("{}/sbom_urls/{}", prefix, u_i));
+            }
+            for (x_i, uri) in helper.get_x_generic_uris().enumerate() {
+                visitor.visit_url(
+                    uri,
+                    UrlKind::GenericUri,
+                    &format!("{}/x_generic_uris/{}/uri", prefix, x_i),
+                );
+            }
+        }
+    });
+    let references = [
+        product_tree.get_product_groups_product_references(),
+        product_tree.get_relationships_product_references(),
+    ];
+    walk_product_references(references, "", visitor);
+}
+
+/// Reports product references whose paths are relative to `prefix`.
+fn walk_product_references<const N: usize>(
+    references: [Vec<(String, String)>; N],
+    prefix: &str,
+    visitor: &mut impl Visitor,
+) {
+    for (product_id, path) in references.into_iter().flatten() {
+        let path = if prefix.is_empty() {
+            path
+        } else {
+            format!("{}/{}", prefix, path)
+        };
+        visitor.visit_product_id(&product_id, ProductIdKind::Reference, &path);
+    }
+}
+
+fn walk_document(document: &impl DocumentTrait, visitor: &mut impl Visitor) {
+    visitor.visit_url(
+        document.get_publisher().get_namespace(),
+        UrlKind::Namespace,
+        "/document/publisher/namespace",
+    );
+    if let Some(references) = document.get_references() {
+        for (r_i, reference) in references.iter().enumerate() {
+            visitor.visit_url(
+                reference.get_url(),
+                UrlKind::Reference,
+                &format!("/document/references/{}/url", r_i),
+            );
+        }
+    }
+    walk_acknowledgments(document.get_acknowledgments(), "/document", visitor);
+    walk_notes(document.get_notes(), "/document", visitor);
+    walk_product_references([document.get_notes_product_references()], "", visitor);
+
+    let tracking = document.get_tracking();
+    visitor.visit_date(
+        tracking.get_initial_release_date(),
+        "/document/tracking/initial_release_date",
+    );
+    visitor.visit_date(
+        tracking.get_current_release_date(),
+        "/document/tracking/current_release_date",
+    );
+    if let Some(date) = tracking.get_generator().as_ref().and_then(|g| g.get_date().as_ref()) {
+        visitor.visit_date(date, "/document/tracking/generator/date");
+    }
+    for (r_i, revision) in tracking.get_revision_history().iter().enumerate() {
+        visitor.visit_date(
+            revision.get_date(),
+            &format!("/document/tracking/revision_history/{}/date", r_i),
+        );
+    }
+}
+
+fn walk_vulnerability(v: &impl VulnerabilityTrait, prefix: &str, visitor: &mut impl Visitor) {
+    walk_acknowledgments(v.get_acknowledgments(), prefix, visitor);
+    walk_notes(v.get_notes(), prefix, visitor);
+    for (r_i, reference) in v.get_references().into_iter().flatten().enumerate() {
+        visitor.visit_url(
+            reference.get_url(),
+            UrlKind::Reference,
+            &format!("{}/references/{}/url", prefix, r_i),
+        );
+    }
+    let references = [
+        v.get_flags_product_references(),
+        v.get_threats_product_references(),
+        v.get_remediations_product_references(),
+        v.get_product_status_product_references(),
+        v.get_metrics_product_references(),
+    ];
+    walk_product_references(references, prefix, visitor);
+
+    if let Some(date) = v.get_disclosure_date() {
+        visitor.visit_date(date, &format!("{}/disclosure_date", prefix));
+    }
+    if let Some(date) = v.get_discovery_date() {
+        visitor.visit_date(date, &format!("{}/discovery_date", prefix));
+    }
+    if let Some(dates) = v.get_first_known_exploitation_dates() {
+        for (d_i, d) in dates.iter().enumerate() {
+            visitor.visit_date(
+                d.get_date(),
+                &format!("{}/first_known_exploitation_dates/{}/date", prefix, d_i),
+            );
+        }
+    }
+    for (f_i, flag) in v.get_flags().iter().flatten().enumerate() {
+        if let Some(date) = flag.get_date() {
+            visitor.visit_date(date, &format!("{}/flags/{}/date", prefix, f_i));
+        }
+    }
+    for (i_i, involvement) in v.get_involvements().iter().flatten().enumerate() {
+        if let Some(date) = involvement.get_date() {
+            visitor.visit_date(date, &format!("{}/involvements/{}/date", prefix, i_i));
+        }
+    }
+    for (r_i, remediation) in v.get_remediations().iter().enumerate() {
+        if let Some(date) = remediation.get_date() {
+            visitor.visit_date(date, &format!("{}/remediations/{}/date", prefix, r_i));
+        }
+        if let Some(url) = remediation.get_url() {
+            visitor.visit_url(
+                url,
+                UrlKind::Remediation,
+                &format!("{}/remediations/{}/url", prefix, r_i),
+            );
+        }
+    }
+    for (t_i, threat) in v.get_threats().iter().enumerate() {
+        if let Some(date) = threat.get_date() {
+            visitor.visit_date(date, &format!("{}/threats/{}/date", prefix, t_i));
+        }
+    }
+}
+
+fn walk_acknowledgments<A: AcknowledgmentTrait>(
+    acknowledgments: Option<&Vec<A>>,
+    prefix: &str,
+    visitor: &mut impl Visitor,
+) {
+    for (a_i, acknowledgment) in acknowledgments.into_iter().flatten().enumerate() {
+        for (u_i, url) in acknowledgment.get_urls().iter().enumerate() {
+            visitor.visit_url(
+                url,
+                UrlKind::Acknowledgment,
+                &format!("{}/acknowledgments/{}/urls/{}", prefix, a_i, u_i),
+            );
+        }
+    }
+}
+
+fn walk_notes<N: NoteTrait>(notes: Option<&Vec<N>>, prefix: &str, visitor: &mut impl Visitor) {
+    for (n_i, note) in notes.into_iter().flatten().enumerate() {
+        visitor.visit_note(note, &format!("{}/notes/{}", prefix, n_i));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csaf_traits::{CsafTraitExt, NoteTrait};
+    use crate::schema::csaf2_1::schema::NoteCategory;
+    use crate::test_helper::{mock_csaf20, mock_csaf21};
+    use crate::walk::{ProductIdKind, UrlKind, Visitor, walk};
+    use serde_json::json;
+
+    #[derive(Default)]
+    struct Collector {
+        product_ids: Vec<(String, String)>,
+        urls: Vec<String>,
+        notes: Vec<(NoteCategory, String)>,
+        dates: Vec<String>,
+    }
+
+    impl Visitor for Collector {
+        fn visit_product_id(&mut self, product_id: &str, _kind: ProductIdKind, instance_path: &str) {
+            self.product_ids
+                .push((product_id.to_string(), instance_path.to_string()));
+        }
+
+        fn visit_url(&mut self, _url: &str, _kind: UrlKind, instance_path: &str) {
+            self.urls.push(instance_path.to_string());
+        }
+
+        fn visit_note(&mut self, note: &impl NoteTrait, instance_path: &str) {
+            self.notes.push((note.get_category(), instance_path.to_string()));
+        }
+
+        fn visit_date(&mut self, _date: &str, instance_path: &str) {
+            self.dates.push(instance_path.to_string());
+        }
+    }
+
+    #[test]
+    fn test_walk() {
+        let doc = mock_csaf21(|doc| {
+            doc["document"]["notes"] = json!([{ "category": "summary", "text": "Summary." }]);
+            doc["product_tree"] = json!({
+                "full_product_names": [{
+                    "name": "Product A",
+                    "product_id": "CSAFPID-9080700",
+                    "product_identification_helper": {
+                        "sbom_urls": ["https://example.com/sbom.json"],
+                        "x_generic_uris": [{
+                            "namespace": "https://example.com/namespace",
+                            "uri": "https://example.com/product-a"
+                        }]
+                    }
+                }],
+                "product_groups": [{
+                    "group_id": "CSAFGID-0001",
+                    "product_ids": ["CSAFPID-9080700", "CSAFPID-9080701"]
+                }]
+            });
+            doc["vulnerabilities"] = json!([{
+                "acknowledgments": [{ "urls": ["https://example.com/thanks"] }],
+                "references": [{ "summary": "Details", "url": "https://example.com/details" }],
+                "remediations": [{
+                    "category": "vendor_fix",
+                    "details": "Update.",
+                    "product_ids": ["CSAFPID-9080700"],
+                    "url": "https://example.com/fix"
+                }],
+                "threats": [{ "category": "impact", "details": "Impact.", "date": "2024-01-24T10:00:00.000Z" }]
+            }]);
+        });
+
+        let mut collector = Collector::default();
+        walk(&doc, &mut collector);
+
+        assert_eq!(
+            collector.product_ids,
+            vec![
+                (
+                    "CSAFPID-9080700".to_string(),
+                    "/product_tree/full_product_names/0/product_id".to_string()
+                ),
+                (
+                    "CSAFPID-9080700".to_string(),
+                    "/product_tree/product_groups/0/product_ids/0".to_string()
+                ),
+                (
+                    "CSAFPID-9080701".to_string(),
+                    "/product_tree/product_groups/0/product_ids/1".to_string()
+                ),
+                (
+                    "CSAFPID-9080700".to_string(),
+                    "/vulnerabilities/0/remediations/0/product_ids/0".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            collector.urls,
+            vec![
+                "/document/publisher/namespace",
+                "/product_tree/full_product_names/0/product_identification_helper/sbom_urls/0",
+                "/product_tree/full_product_names/0/product_identification_helper/x_generic_uris/0/uri",
+                "/vulnerabilities/0/acknowledgments/0/urls/0",
+                "/vulnerabilities/0/references/0/url",
+                "/vulnerabilities/0/remediations/0/url",
+            ]
+        );
+        assert_eq!(
+            collector.notes,
+            vec![(NoteCategory::Summary, "/document/notes/0".to_string())]
+        );
+        assert_eq!(
+            collector.dates,
+            vec![
+                "/document/tracking/initial_release_date",
+                "/document/tracking/current_release_date",
+                "/document/tracking/revision_history/0/date",
+                "/vulnerabilities/0/threats/0/date",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_iter_all() {
+        let doc = mock_csaf20(|doc| {
+            doc["document"]["references"] = json!([{ "summary": "Advisory", "url": "https://example.com/advisory" }]);
+            doc["product_tree"] = json!({
+                "full_product_names": [{ "name": "Product A", "product_id": "CSAFPID-9080700" }]
+            });
+            doc["vulnerabilities"] = json!([{
+                "product_status": { "fixed": ["CSAFPID-9080700"] },
+                "references": [{ "summary": "Details", "url": "https://example.com/details" }]
+            }]);
+        });
+
+        assert_eq!(
+            doc.iter_all_product_ids().collect::<Vec<_>>(),
+            vec![
+                (
+                    "CSAFPID-9080700".to_string(),
+                    "/product_tree/full_product_names/0/product_id".to_string()
+                ),
+                (
+                    "CSAFPID-9080700".to_string(),
+                    "/vulnerabilities/0/product_status/fixed/0".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            doc.iter_product_ids(ProductIdKind::Reference)
+                .map(|(_, path)| path)
+                .collect::<Vec<_>>(),
+            vec!["/vulnerabilities/0/product_status/fixed/0".to_string()]
+        );
+        assert_eq!(
+            doc.iter_all_urls().map(|(_, path)| path).collect::<Vec<_>>(),
+            vec![
+                "/document/publisher/namespace".to_string(),
+                "/document/references/0/url".to_string(),
+                "/vulnerabilities/0/references/0/url".to_string()
+            ]
+        );
+        assert_eq!(
+            doc.iter_urls(UrlKind::Reference).collect::<Vec<_>>(),
+            vec![
+                (
+                    "https://example.com/advisory".to_string(),
+                    "/document/references/0/url".to_string()
+                ),
+                (
+                    "https://example.com/details".to_string(),
+                    "/vulnerabilities/0/references/0/url".to_string()
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_walk_csaf20_scores() {
+        let doc = mock_csaf20(|doc| {
+            doc["vulnerabilities"] = json!([{
+                "scores": [{
+                    "cvss_v3": {
+                        "version": "3.1",
+                        "vectorString": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                        "baseScore": 9.8,
+                        "baseSeverity": "CRITICAL"
+                    },
+                    "products": ["CSAFPID-9080700"]
+                }]
+            }]);
+        });
+
+        let mut collector = Collector::default();
+        walk(&doc, &mut collector);
+        assert!(collector.product_ids.contains(&(
+            "CSAFPID-9080700".to_string(),
+            "/vulnerabilities/0/scores/0/products/0".to_string()
+        )));
+    }
+}
@@ -90,6 +90,7 @@ mod tests {
             success: true,
             version: "2.0".to_string(),
             num_errors: 0,
+            num_suppressed: 0,
             preset: ValidationPreset::Basic,
             test_results: vec![],
         };
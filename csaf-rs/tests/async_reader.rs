@@ -0,0 +1,43 @@
+//! Loading documents from asynchronous readers, with the `tokio` feature
+
+use csaf::error::Error;
+use csaf::{csaf2_0, csaf2_1};
+use std::path::Path;
+
+fn fixture() -> Vec<u8> {
+    std::fs::read(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/duplicate-product-id-2.1.json")).unwrap()
+}
+
+#[tokio::test]
+async fn test_from_async_reader() {
+    let bytes = fixture();
+    let doc = csaf2_1::loader::from_async_reader(bytes.as_slice()).await.unwrap();
+    assert_eq!(doc, csaf2_1::loader::from_slice(&bytes).unwrap());
+
+    // The validation itself is synchronous and runs on a blocking thread
+    let result = tokio::task::spawn_blocking(move || doc.validate()).await.unwrap();
+    assert!(!result.success);
+}
+
+#[tokio::test]
+async fn test_from_async_reader_chunked() {
+    // A duplex stream delivers the document in small chunks while it is being written
+    let bytes = fixture();
+    let (mut writer, reader) = tokio::io::duplex(64);
+    let write = tokio::spawn(async move {
+        tokio::io::AsyncWriteExt::write_all(&mut writer, &bytes).await.unwrap();
+    });
+    let doc = csaf2_1::loader::from_async_reader(reader).await.unwrap();
+    write.await.unwrap();
+    assert_eq!(doc.document.tracking.id.to_string(), "FIXTURE-2024-0001");
+}
+
+#[tokio::test]
+async fn test_from_async_reader_errors() {
+    let result = csaf2_1::loader::from_async_reader(&b"{"[..]).await;
+    assert!(matches!(result, Err(Error::Parse(_))));
+
+    // A CSAF 2.1 document is no valid CSAF 2.0 document
+    let result = csaf2_0::loader::from_async_reader(fixture().as_slice()).await;
+    assert!(result.is_err());
+}
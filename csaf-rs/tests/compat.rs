@@ -0,0 +1,319 @@
+//! Conformance with the test suite of the OASIS CSAF repository
+//!
+//! For every test of this crate in [CSAF_2_0] and [CSAF_2_1], each of the `failures` that
+//! `csaf_2.x/test/validator/data/testcases.json` lists for the mapped OASIS test must fail with
+//! findings at the expected instance paths, and each of its `valid` examples must pass.
+//!
+//! Only tests that implement the OASIS test of the same name are listed. The tests 6.1.51 and
+//! later as well as 6.2.13 are checks of this crate that were numbered after the tests of CSAF
+//! 2.0, so their IDs collide with unrelated tests of the CSAF 2.1 suite and they are not listed.
+//!
+//! The suite is read from a checkout of the OASIS CSAF repository in `tests/oasis/`, e.g. created
+//! with `git clone https://github.com/oasis-tcs/csaf tests/oasis`, or from the directory given in
+//! `CSAF_TEST_SUITE_DIR`. Without the suite the test fails, unless `CSAF_SKIP_OASIS_SUITE` is set.
+
+use csaf::csaf_traits::CsafTrait;
+use csaf::validation::TestResultStatus::{Failure, NotFound, Success};
+use csaf::validation::ValidationPreset;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// The environment variable overriding the location of the OASIS CSAF repository
+static SUITE_DIR_VAR: &str = "CSAF_TEST_SUITE_DIR";
+
+/// The environment variable skipping the test if the suite is not checked out
+static SKIP_VAR: &str = "CSAF_SKIP_OASIS_SUITE";
+
+/// A test of this crate implementing a test of the OASIS suite
+struct Conformance {
+    /// The ID of the test in this crate
+    test_id: &'static str,
+    /// The ID of the test in `testcases.json`
+    oasis_id: &'static str,
+    /// The instance paths of the findings for each failing example, by the number at the end of
+    /// its file name
+    failures: &'static [(&'static str, &'static [&'static str])],
+}
+
+static CSAF_2_0: &[Conformance] = &[Conformance {
+    test_id: "6.1.1",
+    oasis_id: "6.1.1",
+    failures: &[
+        (
+            "01",
+            &[
+                "/product_tree/product_groups/0/product_ids/0",
+                "/product_tree/product_groups/0/product_ids/1",
+            ],
+        ),
+        (
+            "02",
+            &[
+                "/vulnerabilities/0/flags/0/product_ids/1",
+                "/vulnerabilities/1/flags/0/product_ids/0",
+            ],
+        ),
+    ],
+}];
+
+static CSAF_2_1: &[Conformance] = &[
+    Conformance {
+        test_id: "6.1.2",
+        oasis_id: "6.1.2",
+        failures: &[("01", &["/product_tree/full_product_names/1/product_id"])],
+    },
+    Conformance {
+        test_id: "6.1.34",
+        oasis_id: "6.1.34",
+        failures: &[
+            (
+                "01",
+                &[
+                    "/product_tree/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
+                   /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
+                   /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
+                   /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0",
+                ],
+            ),
+            (
+                "02",
+                &[
+                    "/product_tree/branches/0/branches/0/branches/1/branches/0/branches/0/branches/0/branches/0\
+                   /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
+                   /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0\
+                   /branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0/branches/0",
+                ],
+            ),
+        ],
+    },
+    Conformance {
+        test_id: "6.1.35",
+        oasis_id: "6.1.35",
+        failures: &[
+            ("01", &["/vulnerabilities/0/remediations/1"]),
+            ("02", &["/vulnerabilities/0/remediations/1"]),
+            ("03", &["/vulnerabilities/0/remediations/2"]),
+            ("04", &["/vulnerabilities/0/remediations/2"]),
+        ],
+    },
+    Conformance {
+        test_id: "6.1.36",
+        oasis_id: "6.1.36",
+        failures: &[
+            ("01", &["/vulnerabilities/0/remediations/0"]),
+            ("02", &["/vulnerabilities/0/remediations/0"]),
+            ("03", &["/vulnerabilities/0/remediations/0"]),
+            ("04", &["/vulnerabilities/0/remediations/0"]),
+        ],
+    },
+    Conformance {
+        test_id: "6.1.37",
+        oasis_id: "6.1.37",
+        failures: &[
+            ("01", &["/document/tracking/initial_release_date"]),
+            ("02", &["/document/tracking/initial_release_date"]),
+            ("03", &["/vulnerabilities/0/disclosure_date"]),
+            ("04", &["/vulnerabilities/0/disclosure_date"]),
+            ("05", &["/vulnerabilities/0/disclosure_date"]),
+            ("06", &["/vulnerabilities/0/disclosure_date"]),
+            ("07", &["/vulnerabilities/0/disclosure_date"]),
+            ("08", &["/vulnerabilities/0/disclosure_date"]),
+            ("09", &["/vulnerabilities/0/disclosure_date"]),
+            ("20", &["/vulnerabilities/0/first_known_exploitation_dates/0/date"]),
+        ],
+    },
+    Conformance {
+        test_id: "6.1.38",
+        oasis_id: "6.1.38",
+        failures: &[
+            ("01", &["/document/distribution/sharing_group/tlp/label"]),
+            ("02", &["/document/distribution/sharing_group/tlp/label"]),
+            ("03", &["/document/distribution/sharing_group/tlp/label"]),
+            ("04", &["/document/distribution/sharing_group/tlp/label"]),
+        ],
+    },
+    Conformance {
+        test_id: "6.1.39",
+        oasis_id: "6.1.39",
+        failures: &[
+            ("01", &["/document/distribution/sharing_group/id"]),
+            ("02", &["/document/distribution/sharing_group/id"]),
+        ],
+    },
+    Conformance {
+        test_id: "6.1.40",
+        oasis_id: "6.1.40",
+        failures: &[
+            ("01", &["/document/distribution/sharing_group/name"]),
+            ("02", &["/document/distribution/sharing_group/name"]),
+        ],
+    },
+    Conformance {
+        test_id: "6.1.41",
+        oasis_id: "6.1.41",
+        failures: &[
+            ("01", &["/document/distribution/sharing_group/name"]),
+            ("02", &["/document/distribution/sharing_group/name"]),
+            ("03", &["/document/distribution/sharing_group/name"]),
+            ("04", &["/document/distribution/sharing_group/name"]),
+        ],
+    },
+    Conformance {
+        test_id: "6.1.42",
+        oasis_id: "6.1.42",
+        failures: &[
+            (
+                "01",
+                &["/product_tree/full_product_names/0/product_identification_helper/purls/1"],
+            ),
+            (
+                "02",
+                &["/product_tree/branches/0/branches/0/branches/0/product/product_identification_helper/purls/2"],
+            ),
+        ],
+    },
+];
+
+fn suite_dir() -> PathBuf {
+    std::env::var_os(SUITE_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/oasis"))
+}
+
+fn load(version: &str, bytes: &[u8]) -> Result<Box<dyn CsafTrait>, String> {
+    match version {
+        "2.0" => csaf::csaf2_0::loader::from_slice(bytes)
+            .map(|doc| Box::new(doc) as Box<dyn CsafTrait>)
+            .map_err(|e| e.to_string()),
+        _ => csaf::csaf2_1::loader::from_slice(bytes)
+            .map(|doc| Box::new(doc) as Box<dyn CsafTrait>)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+/// Runs the examples of `testcases.json` of one CSAF version for the tests in `conformance` and
+/// returns the mismatches.
+fn run_suite(version: &str, conformance: &[Conformance]) -> Vec<String> {
+    let data_dir = suite_dir().join(format!("csaf_{}/test/validator/data", version));
+    let testcases = match std::fs::read(data_dir.join("testcases.json")) {
+        Ok(testcases) => testcases,
+        Err(e) => return vec![format!("CSAF {}: cannot read testcases.json: {}", version, e)],
+    };
+    let testcases: Value = serde_json::from_slice(&testcases).expect("testcases.json is not valid JSON");
+
+    let mut mismatches = Vec::new();
+    let mut executed = 0;
+    for Conformance {
+        test_id,
+        oasis_id,
+        failures,
+    } in conformance
+    {
+        let Some(test) = testcases["tests"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|test| test["id"] == *oasis_id)
+        else {
+            mismatches.push(format!("{}: not listed in testcases.json", oasis_id));
+            continue;
+        };
+        let cases = [("failures", false), ("valid", true)]
+            .into_iter()
+            .flat_map(|(key, valid)| {
+                test[key]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|case| case["name"].as_str())
+                    .map(move |name| (name, valid))
+            });
+        for (name, valid) in cases {
+            let case = name
+                .strip_suffix(".json")
+                .and_then(|stem| stem.rsplit('-').next())
+                .unwrap_or(name);
+            let bytes = match std::fs::read(data_dir.join(name)) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    mismatches.push(format!("{} {}: cannot be read: {}", oasis_id, name, e));
+                    continue;
+                },
+            };
+            let doc = match load(version, &bytes) {
+                Ok(doc) => doc,
+                Err(e) => {
+                    mismatches.push(format!("{} {}: cannot be parsed: {}", oasis_id, name, e));
+                    continue;
+                },
+            };
+            executed += 1;
+            let result = doc.validate_by_tests(ValidationPreset::Full, &[test_id]);
+            let mut paths: Vec<&str> = match &result.test_results[0].status {
+                NotFound => {
+                    mismatches.push(format!("{}: test {} is not implemented", oasis_id, test_id));
+                    break;
+                },
+                Success => Vec::new(),
+                Failure { errors } => errors.iter().map(|e| e.instance_path.as_str()).collect(),
+            };
+            paths.sort_unstable();
+            if valid {
+                if !paths.is_empty() {
+                    mismatches.push(format!(
+                        "{} {}: expected success but failed at {}",
+                        oasis_id,
+                        name,
+                        paths.join(", ")
+                    ));
+                }
+                continue;
+            }
+            let Some((_, expected)) = failures.iter().find(|(number, _)| *number == case) else {
+                mismatches.push(format!(
+                    "{} {}: no expected instance paths for failing example {}",
+                    oasis_id, name, case
+                ));
+                continue;
+            };
+            let mut expected = expected.to_vec();
+            expected.sort_unstable();
+            if paths != expected {
+                mismatches.push(format!(
+                    "{} {}: expected failure at {} but found [{}]",
+                    oasis_id,
+                    name,
+                    expected.join(", "),
+                    paths.join(", ")
+                ));
+            }
+        }
+    }
+    println!(
+        "CSAF {}: {} example(s) of the OASIS test suite executed",
+        version, executed
+    );
+    mismatches
+}
+
+#[test]
+fn test_oasis_test_suite() {
+    let dir = suite_dir();
+    if !dir.is_dir() {
+        if std::env::var_os(SKIP_VAR).is_some() {
+            println!("Skipping the OASIS test suite, as {} is set", SKIP_VAR);
+            return;
+        }
+        panic!(
+            "The OASIS test suite is not checked out in {}: clone https://github.com/oasis-tcs/csaf there, set {} \
+             to a checkout, or set {} to skip this test",
+            dir.display(),
+            SUITE_DIR_VAR,
+            SKIP_VAR
+        );
+    }
+
+    let mut mismatches = run_suite("2.0", CSAF_2_0);
+    mismatches.extend(run_suite("2.1", CSAF_2_1));
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
@@ -0,0 +1,47 @@
+//! Determinism of validation results
+//!
+//! A validation result must only depend on the validated document, never on the wall clock, the
+//! network or the order in which tests happen to be scheduled. These tests validate the same
+//! document repeatedly and compare the serialized results.
+
+use csaf::validation::ValidationPreset;
+use csaf::validation::batch::validate_bytes;
+use serde_json::{Value, json};
+use std::path::Path;
+
+fn fixture() -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/duplicate-product-id-2.1.json");
+    serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap()
+}
+
+fn validate(doc: &Value) -> String {
+    let result = validate_bytes(&serde_json::to_vec(doc).unwrap(), ValidationPreset::Full).unwrap();
+    serde_json::to_string(&result).unwrap()
+}
+
+#[test]
+fn test_validation_is_deterministic() {
+    let doc = fixture();
+    let first = validate(&doc);
+    for _ in 0..3 {
+        assert_eq!(first, validate(&doc));
+    }
+}
+
+#[test]
+fn test_dates_are_relative_to_the_document() {
+    // The fixture is released in 2024, so CVE IDs of 2025 are valid and of 2026 are not,
+    // regardless of the current date
+    let mut doc = fixture();
+    doc["vulnerabilities"] = json!([{ "cve": "CVE-2025-0001" }, { "cve": "CVE-2026-0001" }]);
+    let result: Value = serde_json::from_str(&validate(&doc)).unwrap();
+    let cve_findings: Vec<&Value> = result["testResults"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|r| r["testId"] == "6.1.73")
+        .flat_map(|r| r["status"]["failure"]["errors"].as_array().unwrap())
+        .collect();
+    assert_eq!(cve_findings.len(), 1);
+    assert_eq!(cve_findings[0]["instancePath"], "/vulnerabilities/1/cve");
+}
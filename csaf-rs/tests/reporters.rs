@@ -0,0 +1,104 @@
+//! Snapshot tests for the report renderers
+//!
+//! Each test renders the same fixed validation result and compares the output with a committed
+//! `insta` snapshot in `tests/snapshots`. After an intended change of the output format, review
+//! and accept the new snapshots with `cargo insta review`.
+//!
+//! There is no SARIF reporter yet, so the text, HTML and JUnit reporters are covered.
+
+use csaf::report::{html, junit, text};
+use csaf::validation::TestResultStatus::{Failure, NotFound, Success};
+use csaf::validation::{Severity, TestResult, ValidationError, ValidationPreset, ValidationResult};
+
+fn report() -> ValidationResult {
+    ValidationResult {
+        success: false,
+        version: "2.1".to_string(),
+        preset: ValidationPreset::Basic,
+        test_results: vec![
+            TestResult {
+                test_id: "6.1.1".to_string(),
+                status: Success,
+            },
+            TestResult {
+                test_id: "6.1.2".to_string(),
+                status: Failure {
+                    errors: vec![
+                        ValidationError::new(
+                            "6.1.2",
+                            "Duplicate definition for product ID CSAFPID-9080700",
+                            "/product_tree/full_product_names/1/product_id",
+                        )
+                        .with_document_id("MOCK-2024-0001"),
+                        ValidationError::new(
+                            "6.1.2",
+                            "Duplicate definition for product ID CSAFPID-9080701",
+                            "/product_tree/full_product_names/3/product_id",
+                        )
+                        .with_document_id("MOCK-2024-0001"),
+                    ],
+                },
+            },
+            TestResult {
+                test_id: "6.2.13".to_string(),
+                status: Failure {
+                    errors: vec![
+                        ValidationError::new(
+                            "6.2.13",
+                            "CVSS v3.0 is deprecated, please use CVSS v3.1 instead",
+                            "/vulnerabilities/0/metrics/0/content/cvss_v3",
+                        )
+                        .with_severity(Severity::Warning)
+                        .with_document_id("MOCK-2024-0001"),
+                    ],
+                },
+            },
+            TestResult {
+                test_id: "6.1.99".to_string(),
+                status: NotFound,
+            },
+        ],
+        num_errors: 3,
+        num_suppressed: 0,
+    }
+}
+
+/// Removes ANSI escape sequences of the form `ESC [ ... m`.
+fn strip_ansi(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| *c == 'm');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[test]
+fn test_text_report() {
+    insta::assert_snapshot!("text_report", text::render(&report(), false));
+}
+
+#[test]
+fn test_text_report_colored() {
+    let colored = text::render(&report(), true);
+    assert!(colored.contains("\x1b[31m"));
+    insta::assert_snapshot!("text_report_colored", strip_ansi(&colored));
+}
+
+#[test]
+fn test_html_report() {
+    insta::assert_snapshot!("html_report", html::render(&report(), "Example <Advisory>"));
+}
+
+#[test]
+fn test_junit_report() {
+    let test_ids = ["6.1.1", "6.1.2", "6.1.3"].map(String::from);
+    insta::assert_snapshot!(
+        "junit_report",
+        junit::to_junit_with_tests(&report(), "MOCK-2024-0001", &test_ids)
+    );
+}
@@ -0,0 +1,47 @@
+//! Instrumentation of the test runner, with the `tracing` feature
+
+use csaf::validation::ValidationPreset;
+use csaf::validation::batch::validate_bytes;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// Collects the formatted output of a subscriber
+#[derive(Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_spans_per_test() {
+    let bytes =
+        std::fs::read(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/duplicate-product-id-2.1.json"))
+            .unwrap();
+    let output = Output::default();
+    let writer = output.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    tracing::subscriber::with_default(subscriber, || {
+        validate_bytes(&bytes, ValidationPreset::Basic).unwrap();
+    });
+
+    let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+    let line = output
+        .lines()
+        .find(|line| line.contains("test{test_id=\"6.1.2\"}"))
+        .unwrap_or_else(|| panic!("No span for 6.1.2 in:\n{}", output));
+    assert!(line.contains("test finished findings=1 duration="), "{}", line);
+    assert!(output.contains("test{test_id=\"6.1.51\"}"), "{}", output);
+}
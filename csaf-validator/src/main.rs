@@ -1,25 +1,29 @@
 use anyhow::{Result, bail};
-use clap::Parser;
-use csaf::csaf2_0::loader::load_document as load_document_2_0;
-use csaf::csaf2_1::loader::load_document as load_document_2_1;
-use csaf::validation::{
-    TestResult,
-    TestResultStatus::{Failure, NotFound, Success},
-    Validatable, ValidationPreset, ValidationResult, validate_by_preset, validate_by_tests,
-};
+use clap::{Parser, Subcommand};
+use csaf::report::text;
+use csaf::validation::baseline::Baseline;
+use csaf::validation::batch;
+use csaf::validation::rolie::validate_feed_dir;
+use csaf::validation::validator::Validator;
+use csaf::validation::{ValidationPreset, ValidationResult};
+use std::io::IsTerminal;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 /// A validator for CSAF documents
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, args_conflicts_with_subcommands = true)]
 struct Args {
-    /// Path to the CSAF document to validate (not used with --web)
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Path to the CSAF document to validate, in JSON or YAML (not used with --web)
     #[arg()]
     path: Option<String>,
 
-    /// Version of CSAF to use
-    #[arg(short, long, default_value = "2.0")]
-    csaf_version: String,
+    /// Expected version of CSAF, the version is detected from the document if omitted
+    #[arg(short, long)]
+    csaf_version: Option<String>,
 
     /// The validation preset to use
     #[arg(short, long, default_value = "basic")]
@@ -28,11 +32,40 @@ struct Args {
     /// Run only the selected tests, may be specified multiple times
     #[arg(short, long, action = clap::ArgAction::Append)]
     test_id: Vec<String>,
+
+    /// Skip the selected tests, may be specified multiple times
+    #[arg(short, long, action = clap::ArgAction::Append)]
+    skip_test: Vec<String>,
+
+    /// Suppress the known findings listed in this baseline file
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Write all findings to this baseline file, to suppress them in later runs
+    #[arg(long)]
+    write_baseline: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Validate all documents of a mirrored ROLIE feed, including their hashes
+    Feed {
+        /// Directory containing the provider-metadata.json, directly or in .well-known/csaf
+        dir: PathBuf,
+
+        /// The validation preset to use
+        #[arg(short, long, default_value = "basic")]
+        preset: String,
+    },
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
+    if let Some(Command::Feed { dir, preset }) = &args.command {
+        return validate_feed(dir, preset);
+    }
+
     let path = args
         .path
         .as_ref()
@@ -41,88 +74,81 @@ fn main() -> Result<()> {
     validate_file(path, &args)
 }
 
-/// Try to validate a file as a CSAF document based on the specified version.
+/// Validate a file as a CSAF document with the provided arguments.
+///
+/// The CSAF version is detected from the document. This prints the results of the tests on
+/// stdout.
 fn validate_file(path: &str, args: &Args) -> Result<()> {
-    match args.csaf_version.as_str() {
-        "2.0" => {
-            let document = load_document_2_0(path)?;
-            validate_document(document, "2.0", args)
-        },
-        "2.1" => {
-            let document = load_document_2_1(path)?;
-            validate_document(document, "2.1", args)
-        },
-        _ => bail!(format!("Invalid CSAF version: {}", args.csaf_version)),
+    if let Some(version) = &args.csaf_version {
+        if !["2.0", "2.1"].contains(&version.as_str()) {
+            bail!(format!("Invalid CSAF version: {}", version));
+        }
     }
-}
-
-/// Validate a CSAF document of the specified version with the provided arguments.
-///
-/// This prints the results of the tests on stdout.
-fn validate_document<T>(document: T, version: &str, args: &Args) -> Result<()>
-where
-    T: Validatable<T>,
-{
     let preset = ValidationPreset::from_str(args.preset.as_str())
         .map_err(|_| anyhow::anyhow!("Invalid validation preset: {}", args.preset))?;
 
-    let result = if !args.test_id.is_empty() {
-        // Individual test validation
-        let test_ids: Vec<&str> = args.test_id.iter().map(|s| s.as_str()).collect();
-        validate_by_tests(&document, version, preset, &test_ids)
-    } else {
-        // Preset validation
-        validate_by_preset(&document, version, preset)
+    let builder = match preset {
+        ValidationPreset::Basic => Validator::builder().mandatory_only(),
+        ValidationPreset::Extended => Validator::builder().include_optional(),
+        ValidationPreset::Full => Validator::builder().include_informative(),
     };
+    let mut builder = builder.ignore(args.skip_test.iter().cloned());
+    if !args.test_id.is_empty() {
+        // Individual test validation
+        builder = builder.only(args.test_id.iter().cloned());
+    }
+    let mut result = batch::validate_file(path, &builder.build()?)?;
+    if let Some(version) = &args.csaf_version {
+        if *version != result.version {
+            bail!(format!(
+                "Expected a CSAF {} document, but {} has version {}",
+                version, path, result.version
+            ));
+        }
+    }
+
+    if let Some(path) = &args.write_baseline {
+        Baseline::from_result(&result).write(path)?;
+    }
+    if let Some(path) = &args.baseline {
+        Baseline::load(path)?.apply(&mut result);
+    }
 
     print_validation_result(&result);
     Ok(())
 }
 
-/// Print a validation result to stdout (for CLI use)
-pub fn print_validation_result(result: &ValidationResult) {
-    println!("CSAF Version: {}", result.version);
-    println!("Validating document with {:?} preset...\n", result.preset);
-
-    // Print individual test results
-    for test_result in &result.test_results {
-        print_test_result(test_result);
+/// Validate a mirrored ROLIE feed and print its errors and the results of its documents.
+fn validate_feed(dir: &PathBuf, preset: &str) -> Result<()> {
+    let preset =
+        ValidationPreset::from_str(preset).map_err(|_| anyhow::anyhow!("Invalid validation preset: {}", preset))?;
+    let result = validate_feed_dir(dir, preset)?;
+
+    for error in &result.errors {
+        println!(
+            "{}{}: {}",
+            error.document_id.as_deref().unwrap_or_default(),
+            error.instance_path,
+            error.message
+        );
     }
-
-    // Print summary
-    println!();
-    if result.success {
-        println!("✅ Validation passed! No errors found.\n");
-    } else {
-        println!("❌ Validation failed with {} error(s)\n", result.num_errors,);
+    for document in &result.documents {
+        println!("\n{}", document.path.display());
+        match (&document.parse_error, &document.findings) {
+            (Some(e), _) => println!("Could not be parsed: {}", e),
+            (None, Some(findings)) => print_validation_result(findings),
+            (None, None) => {},
+        }
     }
+    println!(
+        "{} feed error(s), {} document(s) validated",
+        result.errors.len(),
+        result.documents.len()
+    );
+    Ok(())
 }
 
-/// Print individual test result to stdout.
-fn print_test_result(test_result: &TestResult) {
-    // Common prefix for all test statuses
-    let prefix = format!("Executing Test {} ... ", test_result.test_id);
-
-    match &test_result.status {
-        Success => {
-            // Yay, success!
-            println!("{}✅ Success", prefix);
-        },
-        Failure { errors } => {
-            // We want to print multiple errors nicely indented
-            let error_msg = "❌ ";
-            print!("{}{}", prefix, error_msg);
-            let indent = " ".repeat(prefix.len() + error_msg.len());
-            for (i, error) in errors.iter().enumerate() {
-                if i > 0 {
-                    print!("{}", indent);
-                }
-                println!("Error: {}", error.message);
-            }
-        },
-        NotFound => {
-            // Test not found
-            println!("{}⚠️  Test not found", prefix);
-        },
-    }
+/// Print a validation result to stdout (for CLI use)
+pub fn print_validation_result(result: &ValidationResult) {
+    print!("{}", text::render(result, std::io::stdout().is_terminal()));
 }